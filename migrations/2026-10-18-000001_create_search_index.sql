@@ -0,0 +1,75 @@
+-- Create search_index full-text table (sessions, training content, templates, notes)
+CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+    kind UNINDEXED,
+    ref_id UNINDEXED,
+    session_id UNINDEXED,
+    title,
+    body,
+    tokenize = 'porter unicode61'
+);
+
+-- Keep the index in sync with sessions
+CREATE TRIGGER IF NOT EXISTS search_sessions_insert AFTER INSERT ON sessions BEGIN
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    VALUES ('session', new.id, new.id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_sessions_update AFTER UPDATE OF title, description ON sessions BEGIN
+    DELETE FROM search_index WHERE kind = 'session' AND ref_id = old.id;
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    VALUES ('session', new.id, new.id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_sessions_delete AFTER DELETE ON sessions BEGIN
+    DELETE FROM search_index WHERE kind != 'template' AND session_id = old.id;
+END;
+
+-- Keep the index in sync with training content
+CREATE TRIGGER IF NOT EXISTS search_training_content_insert AFTER INSERT ON training_content BEGIN
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    VALUES ('content', new.id, new.session_id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_training_content_update AFTER UPDATE OF title, description ON training_content BEGIN
+    DELETE FROM search_index WHERE kind = 'content' AND ref_id = old.id;
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    VALUES ('content', new.id, new.session_id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_training_content_delete AFTER DELETE ON training_content BEGIN
+    DELETE FROM search_index WHERE kind = 'content' AND ref_id = old.id;
+END;
+
+-- Keep the index in sync with training templates
+CREATE TRIGGER IF NOT EXISTS search_training_templates_insert AFTER INSERT ON training_templates BEGIN
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    VALUES ('template', new.id, NULL, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_training_templates_update AFTER UPDATE OF title, description ON training_templates BEGIN
+    DELETE FROM search_index WHERE kind = 'template' AND ref_id = old.id;
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    VALUES ('template', new.id, NULL, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_training_templates_delete AFTER DELETE ON training_templates BEGIN
+    DELETE FROM search_index WHERE kind = 'template' AND ref_id = old.id;
+END;
+
+-- Keep the index in sync with subscription notes (only non-empty notes are indexed)
+CREATE TRIGGER IF NOT EXISTS search_subscriptions_insert AFTER INSERT ON subscriptions
+WHEN new.notes IS NOT NULL AND new.notes != '' BEGIN
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    VALUES ('note', new.id, new.session_id, '', new.notes);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_subscriptions_update AFTER UPDATE OF notes ON subscriptions BEGIN
+    DELETE FROM search_index WHERE kind = 'note' AND ref_id = old.id;
+    INSERT INTO search_index (kind, ref_id, session_id, title, body)
+    SELECT 'note', new.id, new.session_id, '', new.notes
+    WHERE new.notes IS NOT NULL AND new.notes != '';
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_subscriptions_delete AFTER DELETE ON subscriptions BEGIN
+    DELETE FROM search_index WHERE kind = 'note' AND ref_id = old.id;
+END;
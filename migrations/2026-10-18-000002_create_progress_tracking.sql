@@ -0,0 +1,30 @@
+-- Skill level history, recorded automatically whenever a user's level changes
+CREATE TABLE IF NOT EXISTS skill_level_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    old_level TEXT,
+    new_level TEXT,
+    changed_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE TRIGGER IF NOT EXISTS users_skill_level_history AFTER UPDATE OF skill_level ON users
+WHEN OLD.skill_level IS NOT NEW.skill_level
+BEGIN
+    INSERT INTO skill_level_changes (user_id, old_level, new_level)
+    VALUES (NEW.id, OLD.skill_level, NEW.skill_level);
+END;
+
+-- Coach comments on a player, shown in progress reports
+CREATE TABLE IF NOT EXISTS player_comments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    player_id INTEGER NOT NULL,
+    coach_id INTEGER NOT NULL,
+    body TEXT NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (player_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (coach_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_skill_level_changes_user ON skill_level_changes(user_id);
+CREATE INDEX IF NOT EXISTS idx_player_comments_player ON player_comments(player_id);
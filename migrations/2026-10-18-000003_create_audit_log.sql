@@ -0,0 +1,18 @@
+-- Change history of club data. Rows are written by per-connection TEMP
+-- triggers (see AuditRepository::install_triggers) so that the acting user,
+-- which only the application knows, can be recorded.
+CREATE TABLE IF NOT EXISTS audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    actor_id INTEGER,                 -- users.id; NULL for CLI and system changes
+    entity TEXT NOT NULL,             -- table name
+    entity_id INTEGER,                -- rowid of the changed row
+    session_id INTEGER,               -- session the change belongs to, if any
+    action TEXT NOT NULL CHECK(action IN ('insert', 'update', 'delete')),
+    before_json TEXT,
+    after_json TEXT,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_session ON audit_log(session_id);
+CREATE INDEX IF NOT EXISTS idx_audit_log_actor ON audit_log(actor_id);
+CREATE INDEX IF NOT EXISTS idx_audit_log_created ON audit_log(created_at);
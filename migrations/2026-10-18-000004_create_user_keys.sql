@@ -0,0 +1,16 @@
+-- SSH public keys that log users in. sshd asks for them through
+-- AuthorizedKeysCommand (`tennis-tui authorized-keys`), so adding or
+-- revoking a key needs no change to system files.
+CREATE TABLE IF NOT EXISTS user_keys (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    key_type TEXT NOT NULL,           -- e.g. ssh-ed25519
+    public_key TEXT NOT NULL,         -- base64 key blob
+    fingerprint TEXT NOT NULL UNIQUE, -- SHA256:..., as printed by ssh-keygen -l
+    label TEXT,                       -- e.g. "laptop"
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    revoked_at DATETIME,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_keys_user ON user_keys(user_id);
@@ -0,0 +1,13 @@
+-- Bearer tokens for the REST API (`tennis-tui serve`). Only a SHA-256 hash
+-- of each token is stored; the token itself is shown once when created.
+CREATE TABLE IF NOT EXISTS api_tokens (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,  -- hex SHA-256 of the token
+    label TEXT,                       -- e.g. "club website"
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    revoked_at DATETIME,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_tokens_user ON api_tokens(user_id);
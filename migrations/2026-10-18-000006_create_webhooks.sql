@@ -0,0 +1,77 @@
+-- Outbound webhooks for session events. Events are derived from the change
+-- history: every audit_log row for a session or subscription enqueues a JSON
+-- payload for each matching webhook in the same transaction, and
+-- `tennis-tui webhook deliver` posts the outbox with retries.
+CREATE TABLE IF NOT EXISTS webhooks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    events TEXT NOT NULL DEFAULT '*', -- comma-separated event names, or * for all
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS webhook_outbox (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    webhook_id INTEGER NOT NULL,
+    event TEXT NOT NULL,
+    payload TEXT NOT NULL,            -- JSON body, as of the change
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'delivered', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    last_error TEXT,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    delivered_at DATETIME,
+    FOREIGN KEY (webhook_id) REFERENCES webhooks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_outbox_due ON webhook_outbox(status, next_attempt_at);
+
+-- Archiving a session is reported as session.deleted (and restoring it as
+-- session.restored); purging an already archived session, and the
+-- subscriptions removed with it, send nothing further.
+CREATE TRIGGER IF NOT EXISTS webhook_session_events AFTER INSERT ON audit_log
+WHEN NEW.entity IN ('sessions', 'subscriptions')
+BEGIN
+    INSERT INTO webhook_outbox (webhook_id, event, payload)
+    SELECT w.id, e.event, json_object(
+        'event', e.event,
+        'occurred_at', NEW.created_at,
+        'actor_id', NEW.actor_id,
+        'session_id', NEW.session_id,
+        'session', (
+            SELECT json_object(
+                'id', s.id, 'title', s.title, 'scheduled_date', s.scheduled_date,
+                'scheduled_time', s.scheduled_time, 'duration_minutes', s.duration_minutes,
+                'skill_level', s.skill_level, 'created_by', s.created_by
+            )
+            FROM sessions s WHERE s.id = NEW.session_id
+        ),
+        'subscriber_count', (
+            SELECT COUNT(*) FROM subscriptions WHERE session_id = NEW.session_id AND status != 'cancelled'
+        ),
+        'before', json(NEW.before_json),
+        'after', json(NEW.after_json)
+    )
+    FROM webhooks w, (
+        SELECT CASE
+            WHEN NEW.entity = 'sessions' AND NEW.action = 'insert' THEN 'session.created'
+            WHEN NEW.entity = 'sessions' AND NEW.action = 'update' THEN CASE
+                WHEN json_extract(NEW.before_json, '$.archived_at') IS NULL
+                    AND json_extract(NEW.after_json, '$.archived_at') IS NOT NULL THEN 'session.deleted'
+                WHEN json_extract(NEW.before_json, '$.archived_at') IS NOT NULL
+                    AND json_extract(NEW.after_json, '$.archived_at') IS NULL THEN 'session.restored'
+                WHEN json_extract(NEW.after_json, '$.archived_at') IS NULL THEN 'session.updated'
+            END
+            WHEN NEW.entity = 'sessions' AND NEW.action = 'delete'
+                AND json_extract(NEW.before_json, '$.archived_at') IS NULL THEN 'session.deleted'
+            WHEN NEW.entity = 'subscriptions' AND NEW.action = 'insert' THEN 'session.subscribed'
+            WHEN NEW.entity = 'subscriptions' AND NEW.action = 'delete'
+                AND EXISTS (SELECT 1 FROM sessions WHERE id = NEW.session_id AND archived_at IS NULL)
+                THEN 'session.unsubscribed'
+            WHEN NEW.entity = 'subscriptions' AND NEW.action = 'update'
+                AND json_extract(NEW.before_json, '$.completed_at') IS NULL
+                AND json_extract(NEW.after_json, '$.completed_at') IS NOT NULL THEN 'session.completed'
+        END AS event
+    ) e
+    WHERE e.event IS NOT NULL
+      AND (w.events = '*' OR instr(',' || w.events || ',', ',' || e.event || ',') > 0);
+END;
@@ -0,0 +1,13 @@
+-- Session reminders already emailed (`tennis-tui remind`). A player gets one
+-- reminder per session start; rescheduling a session sends a new one.
+CREATE TABLE IF NOT EXISTS sent_reminders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    starts_at TEXT NOT NULL,          -- session start reminded of, YYYY-MM-DD HH:MM
+    email TEXT NOT NULL,              -- address the reminder went to
+    sent_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE(session_id, user_id, starts_at)
+);
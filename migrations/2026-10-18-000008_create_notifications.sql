@@ -0,0 +1,74 @@
+-- In-app notifications shown in the player's inbox. They are written by
+-- triggers, so every change reaches the inbox whether it was made in the TUI,
+-- the CLI or the API. The message is kept as text so it still reads well
+-- after the session is gone.
+CREATE TABLE IF NOT EXISTS notifications (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    session_id INTEGER,
+    kind TEXT NOT NULL CHECK(kind IN ('session_changed', 'session_deleted', 'session_restored', 'feedback')),
+    message TEXT NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    read_at DATETIME,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_notifications_user ON notifications(user_id, read_at);
+
+-- Subscribers hear about changes to what, when and for whom a session is;
+-- edits that only touch bookkeeping columns send nothing.
+CREATE TRIGGER IF NOT EXISTS notify_session_changed AFTER UPDATE ON sessions
+WHEN OLD.archived_at IS NULL AND NEW.archived_at IS NULL
+  AND (OLD.title IS NOT NEW.title
+    OR OLD.description IS NOT NEW.description
+    OR OLD.scheduled_date IS NOT NEW.scheduled_date
+    OR OLD.scheduled_time IS NOT NEW.scheduled_time
+    OR OLD.duration_minutes IS NOT NEW.duration_minutes
+    OR OLD.skill_level IS NOT NEW.skill_level)
+BEGIN
+    INSERT INTO notifications (user_id, session_id, kind, message)
+    SELECT sub.user_id, NEW.id, 'session_changed', CASE
+        WHEN OLD.scheduled_date IS NOT NEW.scheduled_date OR OLD.scheduled_time IS NOT NEW.scheduled_time
+            THEN '"' || NEW.title || '" was moved to '
+                || COALESCE(NEW.scheduled_date || COALESCE(' ' || substr(NEW.scheduled_time, 1, 5), ''), 'no fixed date')
+        WHEN OLD.title IS NOT NEW.title
+            THEN '"' || OLD.title || '" was renamed to "' || NEW.title || '"'
+        ELSE '"' || NEW.title || '" was updated'
+    END
+    FROM subscriptions sub
+    WHERE sub.session_id = NEW.id AND sub.status = 'active';
+END;
+
+-- Deleting a session in the TUI archives it
+CREATE TRIGGER IF NOT EXISTS notify_session_archived AFTER UPDATE OF archived_at ON sessions
+WHEN (OLD.archived_at IS NULL) IS NOT (NEW.archived_at IS NULL)
+BEGIN
+    INSERT INTO notifications (user_id, session_id, kind, message)
+    SELECT sub.user_id, NEW.id,
+        CASE WHEN NEW.archived_at IS NULL THEN 'session_restored' ELSE 'session_deleted' END,
+        '"' || NEW.title || '"'
+            || CASE WHEN NEW.archived_at IS NULL THEN ' is back on the schedule' ELSE ' was deleted' END
+    FROM subscriptions sub
+    WHERE sub.session_id = NEW.id AND sub.status = 'active';
+END;
+
+-- Runs before the subscriptions are cascaded away. Purging an already
+-- archived session sends nothing further.
+CREATE TRIGGER IF NOT EXISTS notify_session_deleted BEFORE DELETE ON sessions
+WHEN OLD.archived_at IS NULL
+BEGIN
+    INSERT INTO notifications (user_id, session_id, kind, message)
+    SELECT sub.user_id, OLD.id, 'session_deleted', '"' || OLD.title || '" was deleted'
+    FROM subscriptions sub
+    WHERE sub.session_id = OLD.id AND sub.status = 'active';
+END;
+
+-- Coach comments on a player, which is where homework feedback goes
+CREATE TRIGGER IF NOT EXISTS notify_player_comment AFTER INSERT ON player_comments
+BEGIN
+    INSERT INTO notifications (user_id, kind, message)
+    SELECT NEW.player_id, 'feedback', u.display_name || ' left you feedback: ' || NEW.body
+    FROM users u
+    WHERE u.id = NEW.coach_id;
+END;
@@ -0,0 +1,18 @@
+-- Cancelled sessions stay listed with their subscriptions (set to
+-- 'cancelled' by SessionRepository::cancel), unlike deleted ones. The
+-- cancelled_at and cancel_reason columns are added in migrations.rs.
+--
+-- Runs before the subscriptions are cancelled, so it still finds the players
+-- to tell.
+CREATE TRIGGER IF NOT EXISTS notify_session_cancelled AFTER UPDATE OF cancelled_at ON sessions
+WHEN OLD.cancelled_at IS NULL AND NEW.cancelled_at IS NOT NULL AND NEW.archived_at IS NULL
+BEGIN
+    INSERT INTO notifications (user_id, session_id, kind, message)
+    SELECT sub.user_id, NEW.id, 'session_cancelled',
+        '"' || NEW.title || '"'
+            || COALESCE(' on ' || NEW.scheduled_date, '')
+            || ' was cancelled'
+            || COALESCE(': ' || NULLIF(NEW.cancel_reason, ''), '')
+    FROM subscriptions sub
+    WHERE sub.session_id = NEW.id AND sub.status = 'active';
+END;
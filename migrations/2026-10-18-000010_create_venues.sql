@@ -0,0 +1,17 @@
+-- Where sessions take place. A session may be booked on one court
+-- (sessions.court_id, added in migrations.rs); sessions on the same court or
+-- with the same coach must not overlap (SessionRepository::find_clashes).
+CREATE TABLE IF NOT EXISTS venues (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    address TEXT,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS courts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    venue_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    FOREIGN KEY (venue_id) REFERENCES venues(id) ON DELETE CASCADE,
+    UNIQUE(venue_id, name)
+);
@@ -0,0 +1,27 @@
+-- Allow 'session_cancelled' notifications (see 000009). SQLite can't alter a
+-- CHECK constraint, so the table is rebuilt with its rows; migrations.rs only
+-- runs this while the stored definition lacks the new kind.
+CREATE TABLE notifications_rebuilt (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    session_id INTEGER,
+    kind TEXT NOT NULL CHECK(kind IN ('session_changed', 'session_cancelled', 'session_deleted', 'session_restored', 'feedback')),
+    message TEXT NOT NULL,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    read_at DATETIME,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE SET NULL
+);
+
+INSERT INTO notifications_rebuilt (id, user_id, session_id, kind, message, created_at, read_at)
+SELECT id, user_id, session_id, kind, message, created_at, read_at FROM notifications;
+
+-- The notify_* triggers on other tables insert into notifications by name.
+-- Legacy rename leaves them alone instead of checking them while the table
+-- is missing, and they work again once it is back.
+PRAGMA legacy_alter_table = ON;
+DROP TABLE notifications;
+ALTER TABLE notifications_rebuilt RENAME TO notifications;
+PRAGMA legacy_alter_table = OFF;
+
+CREATE INDEX IF NOT EXISTS idx_notifications_user ON notifications(user_id, read_at);
@@ -0,0 +1,172 @@
+use serde::Serialize;
+use std::io::{BufRead, BufReader, Read, Write};
+
+use crate::utils::{AppError, Result};
+
+/// Largest request body accepted, in bytes
+const MAX_BODY: usize = 1024 * 1024;
+/// Largest request line or header line accepted, in bytes
+const MAX_LINE: usize = 8 * 1024;
+/// Most header lines accepted
+const MAX_HEADERS: usize = 64;
+/// Largest request line and headers accepted together, in bytes
+const MAX_HEAD: usize = 32 * 1024;
+
+/// An HTTP/1.1 request; only what the API needs
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    /// Path without the query string
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Read one request. Bodies need a `Content-Length`; chunked uploads
+    /// aren't supported.
+    pub fn read_from(stream: impl Read) -> Result<Self> {
+        let mut reader = BufReader::new(stream);
+
+        let request_line = read_line(&mut reader)?;
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(AppError::Validation(format!("Malformed request line '{}'", request_line)));
+        };
+        let path = target.split('?').next().unwrap_or_default().to_string();
+
+        let mut headers = Vec::new();
+        let mut head_size = request_line.len();
+        loop {
+            let line = read_line(&mut reader)?;
+            if line.is_empty() {
+                break;
+            }
+            head_size += line.len();
+            if headers.len() == MAX_HEADERS || head_size > MAX_HEAD {
+                return Err(AppError::Validation("Request headers are too large".to_string()));
+            }
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| AppError::Validation(format!("Malformed header '{}'", line)))?;
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+
+        let mut request = Self { method: method.to_string(), path, headers, body: Vec::new() };
+        if let Some(length) = request.header("content-length") {
+            let length: usize = length
+                .parse()
+                .map_err(|_| AppError::Validation(format!("Invalid Content-Length '{}'", length)))?;
+            if length > MAX_BODY {
+                return Err(AppError::Validation("Request body is too large".to_string()));
+            }
+            request.body = vec![0; length];
+            reader.read_exact(&mut request.body)?;
+        }
+
+        Ok(request)
+    }
+
+    /// Value of a header; `name` must be lowercase
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+
+    /// The token from `Authorization: Bearer <token>`
+    pub fn bearer_token(&self) -> Option<&str> {
+        let value = self.header("authorization")?;
+        let (scheme, token) = value.split_once(' ')?;
+        scheme.eq_ignore_ascii_case("bearer").then(|| token.trim())
+    }
+
+    /// The JSON body, parsed
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_slice(&self.body).map_err(|e| AppError::Validation(format!("Invalid JSON body: {}", e)))
+    }
+}
+
+/// A JSON response
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: serde_json::Value,
+}
+
+impl Response {
+    pub fn ok<T: Serialize + ?Sized>(value: &T) -> Result<Self> {
+        Self::with_status(200, value)
+    }
+
+    pub fn created<T: Serialize + ?Sized>(value: &T) -> Result<Self> {
+        Self::with_status(201, value)
+    }
+
+    fn with_status<T: Serialize + ?Sized>(status: u16, value: &T) -> Result<Self> {
+        let body = serde_json::to_value(value)
+            .map_err(|e| AppError::Other(format!("JSON serialization failed: {}", e)))?;
+        Ok(Self { status, body })
+    }
+
+    /// `{"error": message}`
+    pub fn error(status: u16, message: impl Into<String>) -> Self {
+        Self { status, body: serde_json::json!({ "error": message.into() }) }
+    }
+
+    /// Error response for a failed request, by kind of error. Database and
+    /// internal errors are logged; the client only gets a generic message.
+    pub fn from_error(err: &AppError) -> Self {
+        match err {
+            AppError::Validation(_) => Self::error(400, err.to_string()),
+            AppError::Unauthorized(_) => Self::error(403, err.to_string()),
+            AppError::NotFound(_) => Self::error(404, err.to_string()),
+            AppError::Database(_) if err.is_busy() => {
+                tracing::warn!(error = %err, "api request hit a busy database");
+                Self::error(503, "The database is busy, try again")
+            }
+            _ => {
+                tracing::error!(error = %err, "api request failed");
+                Self::error(500, "Internal server error")
+            }
+        }
+    }
+
+    /// Write the response; every connection is closed after one request
+    pub fn write_to(&self, mut out: impl Write) -> Result<()> {
+        let body = self.body.to_string();
+        write!(
+            out,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason(self.status),
+            body.len(),
+            body
+        )?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// One CRLF- or LF-terminated line, without the terminator
+fn read_line(reader: &mut impl BufRead) -> Result<String> {
+    let mut line = Vec::new();
+    reader.by_ref().take(MAX_LINE as u64).read_until(b'\n', &mut line)?;
+    if !line.ends_with(b"\n") {
+        return Err(AppError::Validation("Request line or header is incomplete or too long".to_string()));
+    }
+    let line = String::from_utf8(line).map_err(|_| AppError::Validation("Request is not UTF-8".to_string()))?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
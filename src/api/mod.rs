@@ -0,0 +1,132 @@
+//! JSON REST API over the same repositories and role checks as the TUI
+//! (`tennis-tui serve`). Requests authenticate with a bearer token from
+//! `tennis-tui token create`.
+//!
+//! HTTP/1.1 is handled in-tree on `std::net`. Each connection is read and
+//! answered on its own thread, so a slow client only holds up itself; the
+//! requests are then run one at a time on the caller's database connection.
+//! Every connection is closed after its response.
+
+pub mod http;
+pub mod routes;
+
+use rusqlite::Connection;
+use std::io::{self, Read};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::utils::{AppError, Result};
+use http::{Request, Response};
+
+/// How long a client may take to send its whole request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long a client may take to accept the response
+const WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Connections served at once; more are answered with 503 straight away
+const MAX_CONNECTIONS: usize = 32;
+
+/// A parsed request and where to send its response
+type Job = (Request, Sender<Response>);
+
+pub struct ApiServer {
+    listener: TcpListener,
+}
+
+impl ApiServer {
+    /// Listen on `addr`, e.g. `127.0.0.1:8080` (port 0 picks a free port)
+    pub fn bind(addr: &str) -> Result<Self> {
+        Ok(Self { listener: TcpListener::bind(addr)? })
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Serve requests until the process is stopped. A failing client is
+    /// logged and doesn't stop the server.
+    pub fn run(&self, conn: &Connection) -> Result<()> {
+        let listener = self.listener.try_clone()?;
+        let (jobs, queue) = mpsc::channel::<Job>();
+        thread::spawn(move || accept(listener, jobs));
+
+        for (request, reply) in queue {
+            let response = routes::handle(conn, &request);
+            tracing::info!(method = %request.method, path = %request.path, status = response.status, "api request");
+            // The client thread may have given up already
+            let _ = reply.send(response);
+        }
+        Ok(())
+    }
+}
+
+/// Hand each connection to a thread of its own
+fn accept(listener: TcpListener, jobs: Sender<Job>) {
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                tracing::warn!(error = %e, "api accept failed");
+                continue;
+            }
+        };
+        if active.load(Ordering::SeqCst) >= MAX_CONNECTIONS {
+            tracing::warn!("api connection refused, too many clients");
+            let _ = refuse(&stream);
+            continue;
+        }
+
+        active.fetch_add(1, Ordering::SeqCst);
+        let (jobs, active) = (jobs.clone(), Arc::clone(&active));
+        thread::spawn(move || {
+            if let Err(e) = serve_connection(&stream, &jobs) {
+                tracing::warn!(error = %e, "api connection failed");
+            }
+            active.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+}
+
+fn serve_connection(stream: &TcpStream, jobs: &Sender<Job>) -> Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let reader = DeadlineReader { stream, deadline: Instant::now() + REQUEST_TIMEOUT };
+    let response = match Request::read_from(reader) {
+        Ok(request) => {
+            let (reply, response) = mpsc::channel();
+            jobs.send((request, reply))
+                .map_err(|_| AppError::Other("API server has stopped".to_string()))?;
+            response
+                .recv()
+                .map_err(|_| AppError::Other("API server has stopped".to_string()))?
+        }
+        Err(e) => Response::error(400, e.to_string()),
+    };
+    response.write_to(stream)
+}
+
+fn refuse(stream: &TcpStream) -> Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    Response::error(503, "Too many connections, try again later").write_to(stream)
+}
+
+/// Reads from the socket until a fixed deadline, however the client spreads
+/// its bytes out; a per-read timeout alone lets a trickle go on forever
+struct DeadlineReader<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Read for DeadlineReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "request took too long"));
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        self.stream.read(buf)
+    }
+}
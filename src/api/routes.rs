@@ -0,0 +1,232 @@
+use rusqlite::Connection;
+use serde::Deserialize;
+
+use super::http::{Request, Response};
+use crate::auth::UserContext;
+use crate::cli::args::SessionCreateArgs;
+use crate::cli::commands;
+use crate::db::repositories::{
+    ApiTokenRepository, ArchiveRepository, AuditRepository, SessionRepository, SubscriptionRepository, TagRepository,
+    TrainingContentRepository, TrainingTemplateRepository, UserRepository, VenueRepository,
+};
+use crate::models::{ArchiveKind, Session, SessionWithSubscription};
+use crate::utils::logging::AUDIT_TARGET;
+use crate::utils::{AppError, Result};
+
+/// Body of `POST /api/sessions`; same fields and rules as `session create`
+#[derive(Debug, Deserialize)]
+pub struct NewSession {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// YYYY-MM-DD
+    pub date: Option<String>,
+    /// HH:MM
+    pub time: Option<String>,
+    pub duration_minutes: Option<u32>,
+    pub skill_level: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Court to book, from `GET /api/courts`
+    #[serde(default)]
+    pub court_id: Option<i64>,
+}
+
+/// Body of `POST /api/sessions/{id}/cancel`
+#[derive(Debug, Deserialize)]
+pub struct CancelSession {
+    pub reason: String,
+}
+
+/// Answer one request as the owner of its bearer token
+pub fn handle(conn: &Connection, request: &Request) -> Response {
+    let user = match request.bearer_token().map(|token| ApiTokenRepository::authenticate(conn, token)) {
+        Some(Ok(Some(user))) => user,
+        Some(Err(e)) => return Response::from_error(&e),
+        _ => return Response::error(401, "Missing or invalid bearer token"),
+    };
+    let ctx = UserContext::new(user);
+
+    // Changes are attributed to the token's owner, as in the TUI
+    AuditRepository::set_actor(conn, Some(ctx.user.id))
+        .and_then(|_| route(conn, &ctx, request))
+        .unwrap_or_else(|e| Response::from_error(&e))
+}
+
+fn route(conn: &Connection, ctx: &UserContext, request: &Request) -> Result<Response> {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["api", "me"]) => Response::ok(&ctx.user),
+        ("GET", ["api", "users"]) => list_users(conn, ctx),
+        ("GET", ["api", "users", id]) => get_user(conn, ctx, parse_id(id)?),
+        ("GET", ["api", "sessions"]) => list_sessions(conn, ctx),
+        ("POST", ["api", "sessions"]) => create_session(conn, ctx, request.json()?),
+        ("GET", ["api", "sessions", id]) => get_session(conn, parse_id(id)?),
+        ("DELETE", ["api", "sessions", id]) => delete_session(conn, ctx, parse_id(id)?),
+        ("POST", ["api", "sessions", id, "cancel"]) => cancel_session(conn, ctx, parse_id(id)?, request.json()?),
+        ("GET", ["api", "sessions", id, "subscriptions"]) => list_subscriptions(conn, ctx, parse_id(id)?),
+        ("POST", ["api", "sessions", id, "subscription"]) => subscribe(conn, ctx, parse_id(id)?),
+        ("DELETE", ["api", "sessions", id, "subscription"]) => unsubscribe(conn, ctx, parse_id(id)?),
+        ("GET", ["api", "templates"]) => list_templates(conn, ctx),
+        ("GET", ["api", "courts"]) => Response::ok(&VenueRepository::find_courts(conn)?),
+        (method, _) => Ok(Response::error(404, format!("No route for {} {}", method, request.path))),
+    }
+}
+
+fn parse_id(segment: &str) -> Result<i64> {
+    segment
+        .parse()
+        .map_err(|_| AppError::NotFound(format!("'{}' is not an ID", segment)))
+}
+
+fn require_coach(ctx: &UserContext, action: &str) -> Result<()> {
+    if !ctx.is_coach() {
+        return Err(AppError::Unauthorized(format!("Only coaches can {}", action)));
+    }
+    Ok(())
+}
+
+fn require_player(ctx: &UserContext, action: &str) -> Result<()> {
+    if !ctx.is_player() {
+        return Err(AppError::Unauthorized(format!("Only players can {}", action)));
+    }
+    Ok(())
+}
+
+fn find_session(conn: &Connection, id: i64) -> Result<Session> {
+    SessionRepository::find_by_id(conn, id)?.ok_or_else(|| AppError::NotFound(format!("Session {}", id)))
+}
+
+/// A session the coach created; coaches only manage their own sessions
+fn find_own_session(conn: &Connection, ctx: &UserContext, id: i64) -> Result<Session> {
+    let session = find_session(conn, id)?;
+    if session.created_by != ctx.user.id {
+        return Err(AppError::Unauthorized(format!("Session {} belongs to another coach", id)));
+    }
+    Ok(session)
+}
+
+fn list_users(conn: &Connection, ctx: &UserContext) -> Result<Response> {
+    require_coach(ctx, "list users")?;
+    Response::ok(&UserRepository::find_all(conn)?)
+}
+
+fn get_user(conn: &Connection, ctx: &UserContext, id: i64) -> Result<Response> {
+    if !ctx.is_coach() && ctx.user.id != id {
+        return Err(AppError::Unauthorized("Players can only view their own profile".to_string()));
+    }
+    let user = UserRepository::find_by_id(conn, id)?.ok_or_else(|| AppError::NotFound(format!("User {}", id)))?;
+    Response::ok(&user)
+}
+
+/// Coaches get the sessions they created; players get every session with
+/// their own subscription, if any
+fn list_sessions(conn: &Connection, ctx: &UserContext) -> Result<Response> {
+    let sessions: Vec<SessionWithSubscription> = if ctx.is_coach() {
+        SessionRepository::find_by_coach(conn, ctx.user.id)?
+            .into_iter()
+            .map(|session| SessionWithSubscription::new(session, None))
+            .collect()
+    } else {
+        let subscriptions = SubscriptionRepository::find_by_user(conn, ctx.user.id)?;
+        SessionRepository::find_all(conn)?
+            .into_iter()
+            .map(|session| {
+                let subscription = subscriptions.iter().find(|sub| sub.session_id == session.id).cloned();
+                SessionWithSubscription::new(session, subscription)
+            })
+            .collect()
+    };
+    Response::ok(&sessions)
+}
+
+fn get_session(conn: &Connection, id: i64) -> Result<Response> {
+    let session = find_session(conn, id)?;
+    Response::ok(&serde_json::json!({
+        "session": session,
+        "content": TrainingContentRepository::find_by_session(conn, id)?,
+        "tags": TagRepository::find_by_session(conn, id)?,
+    }))
+}
+
+fn create_session(conn: &Connection, ctx: &UserContext, body: NewSession) -> Result<Response> {
+    require_coach(ctx, "create sessions")?;
+    let args = SessionCreateArgs {
+        coach: ctx.username().to_string(),
+        title: body.title,
+        description: body.description,
+        date: body.date,
+        time: body.time,
+        duration: body.duration_minutes,
+        skill_level: body.skill_level,
+        tags: body.tags.join(","),
+        court: body.court_id,
+    };
+    let session_id = commands::create_session(conn, &args)?;
+    tracing::info!(target: AUDIT_TARGET, user = %ctx.username(), session_id, title = %args.title.trim(), "session created via api");
+    Response::created(&find_session(conn, session_id)?)
+}
+
+/// Moves the session to the archive, like deleting it in the TUI
+fn delete_session(conn: &Connection, ctx: &UserContext, id: i64) -> Result<Response> {
+    require_coach(ctx, "delete sessions")?;
+    find_own_session(conn, ctx, id)?;
+    ArchiveRepository::archive(conn, ArchiveKind::Session, id)?;
+    tracing::info!(target: AUDIT_TARGET, user = %ctx.username(), session_id = id, "session archived via api");
+    Response::ok(&serde_json::json!({ "deleted": id }))
+}
+
+/// Keeps the session listed; active subscriptions are cancelled and their
+/// players notified
+fn cancel_session(conn: &Connection, ctx: &UserContext, id: i64, body: CancelSession) -> Result<Response> {
+    require_coach(ctx, "cancel sessions")?;
+    find_own_session(conn, ctx, id)?;
+    SessionRepository::cancel(conn, id, &body.reason)?;
+    tracing::info!(target: AUDIT_TARGET, user = %ctx.username(), session_id = id, reason = %body.reason.trim(), "session cancelled via api");
+    Response::ok(&find_session(conn, id)?)
+}
+
+fn list_subscriptions(conn: &Connection, ctx: &UserContext, id: i64) -> Result<Response> {
+    require_coach(ctx, "list subscribers")?;
+    find_own_session(conn, ctx, id)?;
+    Response::ok(&SubscriptionRepository::find_by_session(conn, id)?)
+}
+
+fn subscribe(conn: &Connection, ctx: &UserContext, session_id: i64) -> Result<Response> {
+    require_player(ctx, "subscribe to sessions")?;
+    if find_session(conn, session_id)?.is_cancelled() {
+        return Err(AppError::Validation(format!("Session {} is cancelled", session_id)));
+    }
+    if SubscriptionRepository::is_subscribed(conn, ctx.user.id, session_id)? {
+        return Err(AppError::Validation(format!("Already subscribed to session {}", session_id)));
+    }
+    let id = SubscriptionRepository::create(conn, ctx.user.id, session_id)?;
+    tracing::info!(target: AUDIT_TARGET, user = %ctx.username(), session_id, "subscribed via api");
+    Response::created(&serde_json::json!({ "id": id }))
+}
+
+fn unsubscribe(conn: &Connection, ctx: &UserContext, session_id: i64) -> Result<Response> {
+    require_player(ctx, "unsubscribe from sessions")?;
+    // The subscription is kept as history once the session is cancelled
+    if find_session(conn, session_id)?.is_cancelled() {
+        return Err(AppError::Validation(format!("Session {} is cancelled", session_id)));
+    }
+    if !SubscriptionRepository::is_subscribed(conn, ctx.user.id, session_id)? {
+        return Err(AppError::NotFound(format!("Subscription to session {}", session_id)));
+    }
+    SubscriptionRepository::delete_by_user_and_session(conn, ctx.user.id, session_id)?;
+    tracing::info!(target: AUDIT_TARGET, user = %ctx.username(), session_id, "unsubscribed via api");
+    Response::ok(&serde_json::json!({ "unsubscribed": session_id }))
+}
+
+/// Public templates plus the coach's own private ones
+fn list_templates(conn: &Connection, ctx: &UserContext) -> Result<Response> {
+    require_coach(ctx, "browse templates")?;
+    let mut templates = TrainingTemplateRepository::list_all(conn)?;
+    for template in TrainingTemplateRepository::list_by_coach(conn, ctx.user.id)? {
+        if !template.is_public {
+            templates.push(template);
+        }
+    }
+    Response::ok(&templates)
+}
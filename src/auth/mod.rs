@@ -1,2 +1,6 @@
+pub(crate) mod digest;
+pub mod ssh_key;
+pub mod token;
 pub mod user;
+pub use ssh_key::PublicKey;
 pub use user::UserContext;
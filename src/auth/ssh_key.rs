@@ -0,0 +1,99 @@
+use std::fmt;
+
+use super::digest::{base64_decode, base64_encode, sha256};
+use crate::utils::{AppError, Result};
+
+/// Set by sshd when `ExposeAuthInfo yes`: a file listing how the user
+/// authenticated, e.g. `publickey ssh-ed25519 AAAA...`
+pub const SSH_USER_AUTH_ENV: &str = "SSH_USER_AUTH";
+
+/// Key algorithms OpenSSH accepts for user authentication
+const KEY_TYPES: [&str; 7] = [
+    "ssh-ed25519",
+    "ssh-rsa",
+    "ecdsa-sha2-nistp256",
+    "ecdsa-sha2-nistp384",
+    "ecdsa-sha2-nistp521",
+    "sk-ssh-ed25519@openssh.com",
+    "sk-ecdsa-sha2-nistp256@openssh.com",
+];
+
+/// An OpenSSH public key, as in a `.pub` file or `authorized_keys` line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    pub key_type: String,
+    /// Base64 key blob
+    pub data: String,
+    pub comment: Option<String>,
+}
+
+impl PublicKey {
+    /// Parse `<type> <base64> [comment]`. The blob must decode and name the
+    /// same key type, which catches truncated or mangled copy-and-paste.
+    pub fn parse(line: &str) -> Result<Self> {
+        let mut parts = line.split_whitespace();
+        let (Some(key_type), Some(data)) = (parts.next(), parts.next()) else {
+            return Err(AppError::Validation("Expected '<type> <base64 key> [comment]'".to_string()));
+        };
+        if !KEY_TYPES.contains(&key_type) {
+            return Err(AppError::Validation(format!("Unsupported key type '{}'", key_type)));
+        }
+
+        let blob = base64_decode(data)
+            .ok_or_else(|| AppError::Validation("Key data is not valid base64".to_string()))?;
+        if blob_key_type(&blob) != Some(key_type.as_bytes()) {
+            return Err(AppError::Validation(format!("Key data is not a {} key", key_type)));
+        }
+
+        let comment: Vec<&str> = parts.collect();
+        Ok(Self {
+            key_type: key_type.to_string(),
+            data: data.to_string(),
+            comment: (!comment.is_empty()).then(|| comment.join(" ")),
+        })
+    }
+
+    /// SHA-256 fingerprint in the format printed by `ssh-keygen -l`
+    pub fn fingerprint(&self) -> String {
+        let blob = base64_decode(&self.data).unwrap_or_default();
+        let mut encoded = base64_encode(&sha256(&blob));
+        encoded.truncate(encoded.trim_end_matches('=').len());
+        format!("SHA256:{}", encoded)
+    }
+}
+
+impl fmt::Display for PublicKey {
+    /// `<type> <base64>`, without the comment
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.key_type, self.data)
+    }
+}
+
+/// Fingerprint of the key the current SSH login authenticated with, for
+/// `run --user-from-key`
+///
+/// The key is only as trustworthy as the environment: this must run as the
+/// sshd `ForceCommand` of an account with `ExposeAuthInfo yes` and no
+/// `AcceptEnv` or `PermitUserEnvironment`, so that `SSH_USER_AUTH` can only
+/// have been set by sshd. There is deliberately no way to pass a fingerprint
+/// directly.
+pub fn login_fingerprint(env: impl Fn(&str) -> Option<String>) -> Result<String> {
+    let path = env(SSH_USER_AUTH_ENV).filter(|path| !path.is_empty()).ok_or_else(|| {
+        AppError::Unauthorized(format!(
+            "No SSH key for this login: {} is not set (needs sshd `ExposeAuthInfo yes`)",
+            SSH_USER_AUTH_ENV
+        ))
+    })?;
+    let auth_info = std::fs::read_to_string(&path)?;
+    let key = auth_info
+        .lines()
+        .find_map(|line| line.strip_prefix("publickey "))
+        .ok_or_else(|| AppError::Unauthorized("This SSH login did not use a public key".to_string()))?;
+    Ok(PublicKey::parse(key)?.fingerprint())
+}
+
+/// The key type string that starts every public key blob
+fn blob_key_type(blob: &[u8]) -> Option<&[u8]> {
+    let len = u32::from_be_bytes(blob.get(..4)?.try_into().ok()?) as usize;
+    blob.get(4..4 + len)
+}
@@ -0,0 +1,19 @@
+use std::io::Read;
+
+use super::digest::{hex, sha256};
+use crate::utils::Result;
+
+/// Random bytes in a generated API token
+const TOKEN_BYTES: usize = 32;
+
+/// A new random API token, as 64 hex characters
+pub fn generate() -> Result<String> {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    std::fs::File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    Ok(hex(&bytes))
+}
+
+/// What the database stores for a token
+pub fn hash(token: &str) -> String {
+    hex(&sha256(token.as_bytes()))
+}
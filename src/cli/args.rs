@@ -0,0 +1,514 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use crate::db::backup::ImportMode;
+use crate::db::player_import::DEFAULT_BIN_PATH;
+use crate::export::DocumentFormat;
+
+#[derive(Parser, Debug)]
+#[command(name = "tennis-tui")]
+#[command(about = "Tennis Training TUI Application", long_about = None)]
+pub struct Cli {
+    /// Path to SQLite database [default: data/tennis.db, or `database.path` from config]
+    #[arg(long, global = true)]
+    pub db_path: Option<String>,
+
+    /// Config file to use instead of ~/.config/tennis-tui/config.toml
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Override a setting for this run, e.g. `--set ui.theme=light` (repeatable)
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+    pub set: Vec<String>,
+
+    /// Print machine-readable JSON instead of tables
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Username to authenticate as (shorthand for `run --user`)
+    #[arg(long)]
+    pub user: Option<String>,
+
+    /// Initialize database with sample data (shorthand for `init --sample-data`)
+    #[arg(long)]
+    pub init_db: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Start the interactive TUI
+    Run {
+        /// Username to authenticate as
+        #[arg(long, required_unless_present = "user_from_key")]
+        user: Option<String>,
+        /// Log in as the owner of the SSH key this login authenticated with
+        /// (needs sshd `ExposeAuthInfo yes` and `ForceCommand`, without
+        /// `AcceptEnv`; see DESIGN.md §8.3)
+        #[arg(long, conflicts_with = "user")]
+        user_from_key: bool,
+    },
+    /// Create the database schema
+    Init {
+        /// Also insert the sample coach and players
+        #[arg(long)]
+        sample_data: bool,
+    },
+    /// Manage sessions
+    #[command(subcommand)]
+    Session(SessionCommand),
+    /// Manage training templates
+    #[command(subcommand)]
+    Template(TemplateCommand),
+    /// Manage players
+    #[command(subcommand)]
+    Player(PlayerCommand),
+    /// Export session plans as CSV, or the whole club database as JSON
+    Export {
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+        /// Only export sessions created by this coach (CSV only)
+        #[arg(long)]
+        coach: Option<String>,
+        /// Write to a file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Import a JSON export of the club database
+    Import {
+        /// JSON file produced by `export --format json`
+        file: PathBuf,
+        #[arg(long, value_enum, default_value_t = ImportMode::Merge)]
+        mode: ImportMode,
+    },
+    /// Export training history as CSV
+    History(HistoryArgs),
+    /// Attendance report: subscriptions and completions per session
+    Report(ReportArgs),
+    /// Player progress report as Markdown or HTML
+    Progress(ProgressArgs),
+    /// Browse the change history of club data, newest first
+    Audit(AuditArgs),
+    /// Print the club's SSH keys for sshd's AuthorizedKeysCommand; each key
+    /// is forced to start the TUI as its owner
+    AuthorizedKeys {
+        /// Program sshd runs for a login [default: this executable]
+        #[arg(long)]
+        program: Option<PathBuf>,
+    },
+    /// Manage the SSH keys users log in with
+    #[command(subcommand)]
+    Key(KeyCommand),
+    /// Serve the JSON REST API; requests authenticate with a bearer token
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        bind: String,
+    },
+    /// Manage REST API tokens
+    #[command(subcommand)]
+    Token(TokenCommand),
+    /// Email players about sessions starting soon; run it from cron
+    Remind {
+        /// Look this many hours ahead [default: reminders.hours from config]
+        #[arg(long)]
+        hours: Option<u32>,
+        /// List the reminders that are due without sending them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Manage venues and the courts sessions are booked on
+    #[command(subcommand)]
+    Venue(VenueCommand),
+    /// Send session events to other systems, e.g. the club website
+    #[command(subcommand)]
+    Webhook(WebhookCommand),
+    /// Inspect configuration
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One row per training plan item
+    Csv,
+    /// Versioned document of the whole database
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SessionCommand {
+    /// List sessions
+    List {
+        /// Only list sessions created by this coach
+        #[arg(long)]
+        coach: Option<String>,
+    },
+    /// Create a session
+    Create(SessionCreateArgs),
+    /// Delete a session; it is kept in the archive and can be restored in the TUI
+    Delete {
+        /// Session ID
+        id: i64,
+    },
+    /// Cancel a session; it stays listed and its subscribers are notified
+    Cancel {
+        /// Session ID
+        id: i64,
+        /// Why the session is cancelled, shown to the subscribed players
+        #[arg(long)]
+        reason: String,
+    },
+    /// Add a quiz question to a session; players answer with `player answer`
+    Quiz {
+        /// Session ID
+        id: i64,
+        #[arg(long)]
+        question: String,
+        /// Correct answer; players' answers are compared ignoring case
+        #[arg(long)]
+        answer: String,
+        /// Comma separated choices shown with the question
+        #[arg(long)]
+        options: Option<String>,
+    },
+    /// Set homework for a session; players hand it in with `player submit`
+    Homework {
+        /// Session ID
+        id: i64,
+        #[arg(long)]
+        title: String,
+        #[arg(long, default_value = "")]
+        description: String,
+        /// Due date (YYYY-MM-DD)
+        #[arg(long)]
+        due: Option<String>,
+    },
+    /// Print a session plan with timings, for handing out on court
+    Plan {
+        /// Session ID
+        id: i64,
+        #[arg(long, value_enum, default_value_t = DocumentFormat::Markdown)]
+        format: DocumentFormat,
+        /// Write to a file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct SessionCreateArgs {
+    /// Username of the coach creating the session
+    #[arg(long)]
+    pub coach: String,
+    #[arg(long)]
+    pub title: String,
+    #[arg(long, default_value = "")]
+    pub description: String,
+    /// Date (YYYY-MM-DD)
+    #[arg(long)]
+    pub date: Option<String>,
+    /// Time (HH:MM)
+    #[arg(long)]
+    pub time: Option<String>,
+    /// Duration in minutes
+    #[arg(long)]
+    pub duration: Option<u32>,
+    /// beginner, intermediate or advanced
+    #[arg(long)]
+    pub skill_level: Option<String>,
+    /// Comma separated tags
+    #[arg(long, default_value = "")]
+    pub tags: String,
+    /// Court ID to book (see `venue list`)
+    #[arg(long)]
+    pub court: Option<i64>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TemplateCommand {
+    /// List public templates, or every template of one coach
+    List {
+        #[arg(long)]
+        coach: Option<String>,
+        /// Only list templates carrying this tag; repeat to require several
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+    },
+    /// Import templates from a JSON file (an array of templates)
+    Import {
+        /// JSON file to import
+        file: PathBuf,
+        /// Username of the coach owning the imported templates
+        #[arg(long)]
+        coach: String,
+    },
+    /// Delete a template; it is kept in the archive and can be restored in the TUI
+    Delete {
+        /// Template ID
+        id: i64,
+    },
+    /// Replace a template's tags
+    Tag {
+        /// Template ID
+        id: i64,
+        /// Comma separated tags; empty clears them
+        tags: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum KeyCommand {
+    /// Register a public key for a user
+    Add {
+        /// Username of the key's owner
+        #[arg(long)]
+        user: String,
+        /// Public key file (`-` reads stdin)
+        file: PathBuf,
+        /// Label shown in key lists [default: the key's comment]
+        #[arg(long)]
+        label: Option<String>,
+    },
+    /// List keys, revoked ones included
+    List {
+        /// Only list this user's keys
+        #[arg(long)]
+        user: Option<String>,
+    },
+    /// Stop a key from logging in
+    Revoke {
+        /// Key ID or fingerprint
+        key: String,
+    },
+    /// Change a key's label (an empty label clears it)
+    Label {
+        /// Key ID or fingerprint
+        key: String,
+        label: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum TokenCommand {
+    /// Issue a token for a user; it is printed once and can't be shown again
+    Create {
+        /// Username the token acts as
+        #[arg(long)]
+        user: String,
+        /// What the token is for
+        #[arg(long)]
+        label: Option<String>,
+    },
+    /// List tokens, revoked ones included
+    List,
+    /// Stop a token from authenticating
+    Revoke {
+        /// Token ID
+        id: i64,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum VenueCommand {
+    /// List venues with their courts
+    List,
+    /// Add a venue
+    Add {
+        name: String,
+        #[arg(long)]
+        address: Option<String>,
+        /// Court to add with the venue; repeat for more
+        #[arg(long = "court")]
+        courts: Vec<String>,
+    },
+    /// Add a court to a venue
+    AddCourt {
+        /// Venue name
+        venue: String,
+        /// Court name, e.g. "Court 3"
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WebhookCommand {
+    /// Register an endpoint that is POSTed session events as JSON
+    Add {
+        /// Endpoint URL (http:// only)
+        url: String,
+        /// Event to send, e.g. session.created; repeat for more [default: all]
+        #[arg(long = "event")]
+        events: Vec<String>,
+    },
+    /// List endpoints
+    List,
+    /// Remove an endpoint and its undelivered events
+    Remove {
+        /// Webhook ID
+        id: i64,
+    },
+    /// Show pending and failed deliveries, most recent first
+    Outbox {
+        /// Include delivered events
+        #[arg(long)]
+        all: bool,
+        /// Number of deliveries to show
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+    },
+    /// Send every delivery that is due
+    Deliver {
+        /// Keep running, checking the outbox every few seconds
+        #[arg(long)]
+        watch: bool,
+    },
+    /// Queue a failed delivery again
+    Retry {
+        /// Delivery ID
+        id: i64,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Print the effective settings and where each value comes from
+    Show,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PlayerCommand {
+    /// Add players from a CSV file (all rows are imported, or none)
+    Import(PlayerImportArgs),
+    /// Add a coach comment to a player, shown in progress reports and their inbox
+    Comment {
+        /// Username of the player
+        player: String,
+        /// Username of the commenting coach
+        #[arg(long)]
+        coach: String,
+        /// Comment text
+        text: String,
+    },
+    /// Change a player's skill level; progress reports show the change
+    Skill {
+        /// Username of the player
+        player: String,
+        /// beginner, intermediate or advanced
+        level: String,
+    },
+    /// Record a player's answer to a quiz question
+    Answer {
+        /// Username of the player
+        player: String,
+        /// Quiz question ID
+        quiz: i64,
+        answer: String,
+    },
+    /// Record that a player handed in homework
+    Submit {
+        /// Username of the player
+        player: String,
+        /// Homework ID
+        homework: i64,
+        #[arg(long)]
+        notes: Option<String>,
+    },
+    /// Set the address session reminders are sent to
+    Email {
+        /// Username of the player
+        player: String,
+        /// Email address; omit to clear it
+        email: Option<String>,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct PlayerImportArgs {
+    /// CSV with columns username, display_name and optionally role,
+    /// skill_level, goals, email, group, ssh_key
+    pub file: PathBuf,
+    /// Username of the coach owning groups created by the import
+    #[arg(long)]
+    pub coach: String,
+    /// Print an authorized_keys line for each new user
+    #[arg(long)]
+    pub authorized_keys: bool,
+    /// Binary path used in the authorized_keys forced command
+    #[arg(long, default_value = DEFAULT_BIN_PATH)]
+    pub bin_path: String,
+}
+
+#[derive(Args, Debug)]
+pub struct HistoryArgs {
+    /// Username of the player whose history to export
+    #[arg(long, required_unless_present = "coach", conflicts_with = "coach")]
+    pub player: Option<String>,
+    /// Export the history of every player subscribed to this coach's sessions
+    #[arg(long)]
+    pub coach: Option<String>,
+    /// Write to a file instead of stdout
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct ProgressArgs {
+    /// Username of the player to report on
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    pub player: Option<String>,
+    /// Write one report per player into --output-dir (end of term)
+    #[arg(long, requires = "output_dir")]
+    pub all: bool,
+    /// With --all, only report on members of this group
+    #[arg(long, requires = "all")]
+    pub group: Option<String>,
+    /// First date to include (YYYY-MM-DD)
+    #[arg(long)]
+    pub from: Option<String>,
+    /// Last date to include (YYYY-MM-DD)
+    #[arg(long)]
+    pub to: Option<String>,
+    #[arg(long, value_enum, default_value_t = DocumentFormat::Markdown)]
+    pub format: DocumentFormat,
+    /// Write the report to a file instead of stdout
+    #[arg(long, short, conflicts_with = "all")]
+    pub output: Option<PathBuf>,
+    /// Directory for --all reports
+    #[arg(long)]
+    pub output_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct ReportArgs {
+    /// Only include sessions created by this coach
+    #[arg(long)]
+    pub coach: Option<String>,
+    /// First session date to include (YYYY-MM-DD)
+    #[arg(long)]
+    pub from: Option<String>,
+    /// Last session date to include (YYYY-MM-DD)
+    #[arg(long)]
+    pub to: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuditArgs {
+    /// Only show changes made by this user
+    #[arg(long)]
+    pub user: Option<String>,
+    /// Only show changes to this session, its content and subscriptions
+    #[arg(long)]
+    pub session: Option<i64>,
+    /// First date to include (YYYY-MM-DD)
+    #[arg(long)]
+    pub from: Option<String>,
+    /// Last date to include (YYYY-MM-DD)
+    #[arg(long)]
+    pub to: Option<String>,
+    /// Maximum number of changes to show
+    #[arg(long, default_value_t = 100)]
+    pub limit: usize,
+}
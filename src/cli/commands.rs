@@ -0,0 +1,1322 @@
+use chrono::{NaiveDate, NaiveTime};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+use crate::api::ApiServer;
+use crate::auth::PublicKey;
+use crate::cli::args::{AuditArgs, PlayerImportArgs, ProgressArgs, ReportArgs, SessionCreateArgs};
+use crate::cli::output::{cell, write_json, Table};
+use crate::config::Settings;
+use crate::db::backup::{self, ImportMode};
+use crate::db::player_import;
+use crate::db::UnitOfWork;
+use crate::db::repositories::{
+    ApiTokenRepository, ArchiveRepository, AuditRepository, GroupRepository, ProgressRepository, SessionRepository, SessionTrainingLinkRepository,
+    SubscriptionRepository, TagRepository, TrainingContentRepository, TrainingTemplateRepository,
+    UserKeyRepository, UserRepository, VenueRepository, WebhookRepository,
+};
+use crate::export::plan::SessionPlan;
+use crate::export::progress::ProgressReport;
+use crate::export::{self, csv_error, DocumentFormat};
+use crate::models::{ArchiveKind, AuditAction, AuditQuery, ClubExport, ReminderStatus, Session, SkillLevel, SubscriptionStatus, Tag, TrainingTemplate, User, UserKey, WEBHOOK_EVENTS};
+use crate::ui::SessionForm;
+use crate::utils::logging::AUDIT_TARGET;
+use crate::utils::{validate_email, AppError, Result};
+use crate::reminders::{self, smtp::Relay};
+use crate::webhooks::{self, client::Endpoint};
+
+/// Content types accepted for training templates
+const TEMPLATE_CONTENT_TYPES: [&str; 6] = ["drill", "exercise", "warmup", "cooldown", "quiz", "homework"];
+
+/// Insert the sample coach and players (idempotent)
+pub fn insert_sample_data(conn: &Connection) -> Result<()> {
+    // Insert coach
+    conn.execute(
+        "INSERT OR IGNORE INTO users (username, display_name, role, created_at, updated_at)
+         VALUES (?, ?, ?, datetime('now'), datetime('now'))",
+        ["coach", "Coach Peter", "coach"],
+    )?;
+
+    // Insert sample players
+    conn.execute(
+        "INSERT OR IGNORE INTO users (username, display_name, role, skill_level, goals, created_at, updated_at)
+         VALUES (?, ?, ?, ?, ?, datetime('now'), datetime('now'))",
+        ["alice", "Alice Smith", "player", "beginner", "Improve serve and backhand"],
+    )?;
+
+    conn.execute(
+        "INSERT OR IGNORE INTO users (username, display_name, role, skill_level, goals, created_at, updated_at)
+         VALUES (?, ?, ?, ?, ?, datetime('now'), datetime('now'))",
+        ["bob", "Bob Jones", "player", "intermediate", "Prepare for tournament"],
+    )?;
+
+    Ok(())
+}
+
+/// `init`: the schema is created on connect, optionally add sample data
+pub fn init(conn: &Connection, sample_data: bool, out: &mut dyn Write) -> Result<()> {
+    if sample_data {
+        insert_sample_data(conn)?;
+        writeln!(out, "Database initialized with sample data.")?;
+    } else {
+        writeln!(out, "Database initialized.")?;
+    }
+    Ok(())
+}
+
+/// Session as listed by `session list --json`
+#[derive(Debug, Serialize)]
+pub struct SessionSummary {
+    #[serde(flatten)]
+    pub session: Session,
+    pub tags: Vec<String>,
+    pub subscribers: usize,
+}
+
+/// `session list`
+pub fn session_list(conn: &Connection, coach: Option<&str>, json: bool, out: &mut dyn Write) -> Result<()> {
+    let sessions = match coach {
+        Some(username) => SessionRepository::find_by_coach(conn, find_coach(conn, username)?.id)?,
+        None => SessionRepository::find_all(conn)?,
+    };
+    let mut tags = TagRepository::find_all_session_tags(conn)?;
+
+    let mut summaries = Vec::new();
+    for session in sessions {
+        let subscribers = active_subscriptions(conn, session.id)?.0;
+        summaries.push(SessionSummary {
+            tags: tags.remove(&session.id).unwrap_or_default(),
+            subscribers,
+            session,
+        });
+    }
+
+    if json {
+        return write_json(out, &summaries);
+    }
+
+    let mut table = Table::new(&["ID", "DATE", "TIME", "TITLE", "LEVEL", "MIN", "SUBS", "TAGS"]);
+    for s in &summaries {
+        table.add_row(vec![
+            s.session.id.to_string(),
+            cell(s.session.scheduled_date),
+            cell(s.session.scheduled_time.map(|t| t.format("%H:%M"))),
+            if s.session.is_cancelled() {
+                format!("{} (cancelled)", s.session.title)
+            } else {
+                s.session.title.clone()
+            },
+            cell(s.session.skill_level.as_ref().map(|l| l.as_str())),
+            cell(s.session.duration_minutes),
+            s.subscribers.to_string(),
+            s.tags.join(","),
+        ]);
+    }
+    table.write_to(out)
+}
+
+/// `session create`, validated with the same rules as the TUI form
+pub fn session_create(conn: &Connection, args: &SessionCreateArgs, json: bool, out: &mut dyn Write) -> Result<()> {
+    let session_id = create_session(conn, args)?;
+    tracing::info!(target: AUDIT_TARGET, user = %args.coach, session_id, title = %args.title.trim(), "session created via cli");
+
+    if json {
+        write_json(out, &serde_json::json!({ "id": session_id }))
+    } else {
+        writeln!(out, "Created session {}", session_id)?;
+        Ok(())
+    }
+}
+
+/// Validate and create a session with its tags (shared with the REST API)
+pub fn create_session(conn: &Connection, args: &SessionCreateArgs) -> Result<i64> {
+    let coach = find_coach(conn, &args.coach)?;
+
+    let form = SessionForm {
+        title: args.title.clone(),
+        description: args.description.clone(),
+        scheduled_date: args.date.clone().unwrap_or_default(),
+        scheduled_time: args.time.clone().unwrap_or_default(),
+        duration_minutes: args.duration.map(|d| d.to_string()).unwrap_or_default(),
+        skill_level: args.skill_level.clone().unwrap_or_default(),
+        tags: args.tags.clone(),
+        ..SessionForm::new()
+    };
+    form.validate().map_err(AppError::Validation)?;
+
+    let date = args.date.as_deref().map(parse_date).transpose()?;
+    let time = args
+        .time
+        .as_deref()
+        .map(|t| {
+            NaiveTime::parse_from_str(t, "%H:%M")
+                .map_err(|_| AppError::Validation(format!("Invalid time '{}', expected HH:MM", t)))
+        })
+        .transpose()?;
+    let skill_level = args
+        .skill_level
+        .as_deref()
+        .map(|s| {
+            SkillLevel::from_str(s).ok_or_else(|| AppError::Validation(format!("Unknown skill level '{}'", s)))
+        })
+        .transpose()?;
+    if let Some(court_id) = args.court {
+        VenueRepository::find_court(conn, court_id)?
+            .ok_or_else(|| AppError::NotFound(format!("Court {}", court_id)))?;
+    }
+
+    // The repository refuses double-bookings; the session is only kept if
+    // its court and tags are saved too
+    let tx = UnitOfWork::begin(conn)?;
+    let session_id = SessionRepository::create(
+        &tx,
+        form.title.trim(),
+        if form.description.is_empty() { None } else { Some(form.description.as_str()) },
+        date,
+        time,
+        args.duration.map(|d| d as i32),
+        skill_level.as_ref(),
+        coach.id,
+    )?;
+    if args.court.is_some() {
+        SessionRepository::set_court(&tx, session_id, args.court)?;
+    }
+    TagRepository::set_session_tags(&tx, session_id, &form.tag_list())?;
+    tx.commit()?;
+    Ok(session_id)
+}
+
+/// `session delete`: moves the session to the archive
+pub fn session_delete(conn: &Connection, id: i64, json: bool, out: &mut dyn Write) -> Result<()> {
+    let session = SessionRepository::find_by_id(conn, id)?
+        .ok_or_else(|| AppError::NotFound(format!("Session {}", id)))?;
+    ArchiveRepository::archive(conn, ArchiveKind::Session, id)?;
+    tracing::info!(target: AUDIT_TARGET, session_id = id, title = %session.title, "session archived via cli");
+
+    if json {
+        write_json(out, &serde_json::json!({ "deleted": id }))
+    } else {
+        writeln!(out, "Deleted session {} ({})", id, session.title)?;
+        Ok(())
+    }
+}
+
+/// `session cancel`: keeps the session listed and cancels its active subscriptions
+pub fn session_cancel(conn: &Connection, id: i64, reason: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let cancelled = SessionRepository::cancel(conn, id, reason)?;
+    tracing::info!(target: AUDIT_TARGET, session_id = id, reason = %reason.trim(), "session cancelled via cli");
+
+    if json {
+        write_json(out, &serde_json::json!({ "cancelled": id, "subscriptions": cancelled }))
+    } else {
+        writeln!(out, "Cancelled session {}; {} subscription(s) cancelled", id, cancelled)?;
+        Ok(())
+    }
+}
+
+/// `session quiz`: add a quiz question to a session
+pub fn session_quiz(
+    conn: &Connection,
+    id: i64,
+    question: &str,
+    answer: &str,
+    options: Option<&str>,
+    json: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
+    SessionRepository::find_by_id(conn, id)?.ok_or_else(|| AppError::NotFound(format!("Session {}", id)))?;
+    let (question, answer) = (question.trim(), answer.trim());
+    if question.is_empty() || answer.is_empty() {
+        return Err(AppError::Validation("A quiz question needs a question and an answer".to_string()));
+    }
+    let options = options.map(str::trim).filter(|o| !o.is_empty());
+
+    let quiz_id = ProgressRepository::add_quiz(conn, id, question, answer, options)?;
+    tracing::info!(target: AUDIT_TARGET, session_id = id, quiz_id, "quiz question added via cli");
+    if json {
+        return write_json(out, &serde_json::json!({ "id": quiz_id }));
+    }
+    writeln!(out, "Added quiz question {} to session {}", quiz_id, id)?;
+    Ok(())
+}
+
+/// `session homework`: set homework for a session
+pub fn session_homework(
+    conn: &Connection,
+    id: i64,
+    title: &str,
+    description: &str,
+    due: Option<&str>,
+    json: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
+    SessionRepository::find_by_id(conn, id)?.ok_or_else(|| AppError::NotFound(format!("Session {}", id)))?;
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(AppError::Validation("Homework title is required".to_string()));
+    }
+    let due = due.map(parse_date).transpose()?;
+
+    let homework_id = ProgressRepository::add_homework(conn, id, title, description.trim(), due)?;
+    tracing::info!(target: AUDIT_TARGET, session_id = id, homework_id, "homework set via cli");
+    if json {
+        return write_json(out, &serde_json::json!({ "id": homework_id }));
+    }
+    writeln!(out, "Added homework {} to session {}", homework_id, id)?;
+    Ok(())
+}
+
+/// `session plan`: Markdown or HTML plan with start times per activity
+pub fn session_plan(conn: &Connection, id: i64, format: DocumentFormat, json: bool, out: &mut dyn Write) -> Result<()> {
+    let plan = SessionPlan::load(conn, id)?;
+    if json {
+        return write_json(out, &plan);
+    }
+    out.write_all(plan.render(format).as_bytes())?;
+    Ok(())
+}
+
+/// `template list`
+pub fn template_list(conn: &Connection, coach: Option<&str>, tags: &[String], json: bool, out: &mut dyn Write) -> Result<()> {
+    let templates = match coach {
+        Some(username) => TrainingTemplateRepository::list_by_coach(conn, find_coach(conn, username)?.id)?,
+        None => TrainingTemplateRepository::list_all(conn)?,
+    };
+    let wanted = Tag::parse_list(&tags.join(","));
+
+    let mut summaries = Vec::new();
+    for template in templates {
+        let tags = TagRepository::find_by_template(conn, template.id)?;
+        if wanted.iter().all(|tag| tags.contains(tag)) {
+            summaries.push(TemplateSummary { template, tags });
+        }
+    }
+
+    if json {
+        return write_json(out, &summaries);
+    }
+
+    let mut table = Table::new(&["ID", "TYPE", "TITLE", "MIN", "PUBLIC", "USED", "TAGS"]);
+    for s in &summaries {
+        table.add_row(vec![
+            s.template.id.to_string(),
+            s.template.content_type.clone(),
+            s.template.title.clone(),
+            cell(s.template.duration_minutes),
+            if s.template.is_public { "yes" } else { "no" }.to_string(),
+            TrainingTemplateRepository::get_usage_count(conn, s.template.id)?.to_string(),
+            s.tags.join(","),
+        ]);
+    }
+    table.write_to(out)
+}
+
+/// Template as listed by `template list --json`
+#[derive(Debug, Serialize)]
+pub struct TemplateSummary {
+    #[serde(flatten)]
+    pub template: TrainingTemplate,
+    pub tags: Vec<String>,
+}
+
+/// `template tag`: replace a template's tags
+pub fn template_tag(conn: &Connection, id: i64, tags: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    Tag::validate_list(tags).map_err(AppError::Validation)?;
+    let template = TrainingTemplateRepository::get_by_id(conn, id).map_err(|_| AppError::NotFound(format!("Template {}", id)))?;
+    let tags = Tag::parse_list(tags);
+    TagRepository::set_template_tags(conn, id, &tags)?;
+    tracing::info!(target: AUDIT_TARGET, template_id = id, tags = %tags.join(","), "template tagged via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "id": id, "tags": tags }));
+    }
+    if tags.is_empty() {
+        writeln!(out, "Cleared the tags of template {} ({})", id, template.title)?;
+    } else {
+        writeln!(out, "Tagged template {} ({}): {}", id, template.title, tags.join(", "))?;
+    }
+    Ok(())
+}
+
+/// One entry of a `template import` file
+#[derive(Debug, Deserialize)]
+pub struct TemplateImport {
+    pub title: String,
+    pub content_type: String,
+    pub description: Option<String>,
+    pub duration_minutes: Option<i32>,
+    #[serde(default = "default_public")]
+    pub is_public: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+fn default_public() -> bool {
+    true
+}
+
+/// `template delete`: moves the template to the archive
+pub fn template_delete(conn: &Connection, id: i64, json: bool, out: &mut dyn Write) -> Result<()> {
+    ArchiveRepository::archive(conn, ArchiveKind::Template, id)?;
+    let template = TrainingTemplateRepository::get_by_id(conn, id)?;
+    tracing::info!(target: AUDIT_TARGET, template_id = id, title = %template.title, "template archived via cli");
+
+    if json {
+        write_json(out, &serde_json::json!({ "deleted": id }))
+    } else {
+        writeln!(out, "Deleted template {} ({})", id, template.title)?;
+        Ok(())
+    }
+}
+
+/// `template import`: all templates are imported, or none if any is invalid
+pub fn template_import(conn: &Connection, file: &Path, coach: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let coach = find_coach(conn, coach)?;
+    let contents = std::fs::read_to_string(file)?;
+    let entries: Vec<TemplateImport> = serde_json::from_str(&contents)
+        .map_err(|e| AppError::Validation(format!("Invalid template file: {}", e)))?;
+
+    for (i, entry) in entries.iter().enumerate() {
+        if entry.title.trim().is_empty() {
+            return Err(AppError::Validation(format!("Template #{}: title is required", i + 1)));
+        }
+        if !TEMPLATE_CONTENT_TYPES.contains(&entry.content_type.as_str()) {
+            return Err(AppError::Validation(format!(
+                "Template #{}: unknown content type '{}'",
+                i + 1,
+                entry.content_type
+            )));
+        }
+        Tag::validate_list(&entry.tags.join(","))
+            .map_err(|e| AppError::Validation(format!("Template #{}: {}", i + 1, e)))?;
+    }
+
+    let tx = UnitOfWork::begin(conn)?;
+    let mut ids = Vec::new();
+    for entry in entries {
+        let tags = Tag::parse_list(&entry.tags.join(","));
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id: coach.id,
+            title: entry.title.trim().to_string(),
+            content_type: entry.content_type,
+            description: entry.description,
+            duration_minutes: entry.duration_minutes,
+            created_at: String::new(),
+            created_by: coach.id,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: entry.is_public,
+        };
+        let id = TrainingTemplateRepository::create(&tx, &template, coach.id)?;
+        TagRepository::set_template_tags(&tx, id, &tags)?;
+        ids.push(id);
+    }
+    tx.commit()?;
+
+    if json {
+        write_json(out, &serde_json::json!({ "imported": ids }))
+    } else {
+        writeln!(out, "Imported {} templates", ids.len())?;
+        Ok(())
+    }
+}
+
+/// `player import`: bulk-create players from CSV
+pub fn player_import(conn: &Connection, args: &PlayerImportArgs, json: bool, out: &mut dyn Write) -> Result<()> {
+    if args.bin_path.contains('"') {
+        return Err(AppError::Validation("--bin-path must not contain quotes".to_string()));
+    }
+    let coach = find_coach(conn, &args.coach)?;
+    let file = std::fs::File::open(&args.file)?;
+    let summary = player_import::import(conn, file, coach.id, &args.bin_path)?;
+
+    if json {
+        return write_json(out, &summary);
+    }
+
+    let mut table = Table::new(&["ID", "USERNAME", "NAME", "ROLE", "SKILL", "GROUP"]);
+    for player in &summary.players {
+        table.add_row(vec![
+            player.id.to_string(),
+            player.username.clone(),
+            player.display_name.clone(),
+            player.role.as_str().to_string(),
+            cell(player.skill_level.as_ref().map(|s| s.as_str())),
+            cell(player.group.as_deref()),
+        ]);
+    }
+    table.write_to(out)?;
+
+    writeln!(out, "\nImported {} users", summary.players.len())?;
+    if !summary.groups_created.is_empty() {
+        writeln!(out, "Created groups: {}", summary.groups_created.join(", "))?;
+    }
+
+    if args.authorized_keys {
+        writeln!(out, "\n# Add to /home/tennis-tui/.ssh/authorized_keys")?;
+        for player in &summary.players {
+            writeln!(out, "{}", player.authorized_keys_line)?;
+        }
+    }
+    Ok(())
+}
+
+/// `export`: one CSV row per training plan item (content first, then linked
+/// templates); sessions without a plan get a single row with empty item columns
+pub fn export_sessions(conn: &Connection, coach: Option<&str>, out: &mut dyn Write) -> Result<()> {
+    let sessions = match coach {
+        Some(username) => SessionRepository::find_by_coach(conn, find_coach(conn, username)?.id)?,
+        None => SessionRepository::find_all(conn)?,
+    };
+    let tags = TagRepository::find_all_session_tags(conn)?;
+
+    let mut writer = csv::Writer::from_writer(out);
+    writer
+        .write_record([
+            "session_id", "title", "date", "time", "duration_minutes", "skill_level", "tags",
+            "item_order", "item_source", "item_type", "item_title", "item_duration_minutes",
+            "item_description", "item_notes",
+        ])
+        .map_err(csv_error)?;
+
+    for session in &sessions {
+        let session_columns = [
+            session.id.to_string(),
+            session.title.clone(),
+            session.scheduled_date.map(|d| d.to_string()).unwrap_or_default(),
+            session.scheduled_time.map(|t| t.format("%H:%M").to_string()).unwrap_or_default(),
+            session.duration_minutes.map(|d| d.to_string()).unwrap_or_default(),
+            session.skill_level.as_ref().map(|l| l.as_str().to_string()).unwrap_or_default(),
+            tags.get(&session.id).map(|t| t.join(",")).unwrap_or_default(),
+        ];
+
+        let mut items: Vec<[String; 7]> = Vec::new();
+        for content in TrainingContentRepository::find_by_session(conn, session.id)? {
+            items.push([
+                content.order_index.to_string(),
+                "content".to_string(),
+                content.content_type.as_str().to_string(),
+                content.title,
+                content.duration_minutes.map(|d| d.to_string()).unwrap_or_default(),
+                content.description.unwrap_or_default(),
+                String::new(),
+            ]);
+        }
+        for link in SessionTrainingLinkRepository::get_for_session_with_templates(conn, session.id)? {
+            let Some(template) = link.template else { continue };
+            items.push([
+                link.link.order_index.to_string(),
+                "template".to_string(),
+                template.content_type,
+                template.title,
+                template.duration_minutes.map(|d| d.to_string()).unwrap_or_default(),
+                template.description.unwrap_or_default(),
+                link.link.custom_notes.unwrap_or_default(),
+            ]);
+        }
+        if items.is_empty() {
+            items.push(Default::default());
+        }
+
+        for item in items {
+            writer
+                .write_record(session_columns.iter().chain(item.iter()))
+                .map_err(csv_error)?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// `export --format json`: the whole club database
+pub fn export_json(conn: &Connection, out: &mut dyn Write) -> Result<()> {
+    write_json(out, &backup::export(conn)?)
+}
+
+/// `import`: load a JSON export in merge or replace mode
+pub fn import_json(conn: &Connection, file: &Path, mode: ImportMode, json: bool, out: &mut dyn Write) -> Result<()> {
+    let contents = std::fs::read_to_string(file)?;
+    let doc: ClubExport = serde_json::from_str(&contents)
+        .map_err(|e| AppError::Validation(format!("Invalid export file: {}", e)))?;
+    let summary = backup::import(conn, &doc, mode)?;
+
+    if json {
+        return write_json(out, &summary);
+    }
+
+    let mut table = Table::new(&["TABLE", "ADDED", "EXISTING"]);
+    for (name, count) in summary.rows() {
+        table.add_row(vec![name.to_string(), count.added.to_string(), count.existing.to_string()]);
+    }
+    table.write_to(out)
+}
+
+/// `history`: training history of one player, or of every player subscribed
+/// to a coach's sessions. CSV by default, a JSON array with `--json`.
+pub fn history(conn: &Connection, player: Option<&str>, coach: Option<&str>, json: bool, out: &mut dyn Write) -> Result<()> {
+    let entries = match (player, coach) {
+        (Some(username), _) => {
+            let user = UserRepository::find_by_username(conn, username)?
+                .ok_or_else(|| AppError::NotFound(format!("User '{}'", username)))?;
+            SubscriptionRepository::history_for_player(conn, user.id)?
+        }
+        (None, Some(username)) => SubscriptionRepository::history_for_coach(conn, find_coach(conn, username)?.id)?,
+        (None, None) => return Err(AppError::Validation("Either --player or --coach is required".to_string())),
+    };
+
+    if json {
+        return write_json(out, &entries);
+    }
+    export::history::write_csv(&entries, out)
+}
+
+/// Attendance figures for one session
+#[derive(Debug, Serialize)]
+pub struct AttendanceRow {
+    pub session_id: i64,
+    pub date: Option<NaiveDate>,
+    pub title: String,
+    pub subscribed: usize,
+    pub completed: usize,
+    pub completion_rate: f64,
+}
+
+/// `report`: subscriptions and completions per session
+pub fn report(conn: &Connection, args: &ReportArgs, json: bool, out: &mut dyn Write) -> Result<()> {
+    let from = args.from.as_deref().map(parse_date).transpose()?;
+    let to = args.to.as_deref().map(parse_date).transpose()?;
+
+    let sessions = match &args.coach {
+        Some(username) => SessionRepository::find_by_coach(conn, find_coach(conn, username)?.id)?,
+        None => SessionRepository::find_all(conn)?,
+    };
+
+    let mut rows = Vec::new();
+    for session in sessions {
+        if from.is_some() || to.is_some() {
+            let Some(date) = session.scheduled_date else { continue };
+            if from.is_some_and(|from| date < from) || to.is_some_and(|to| date > to) {
+                continue;
+            }
+        }
+
+        let (subscribed, completed) = active_subscriptions(conn, session.id)?;
+        rows.push(AttendanceRow {
+            session_id: session.id,
+            date: session.scheduled_date,
+            title: session.title,
+            subscribed,
+            completed,
+            completion_rate: if subscribed == 0 { 0.0 } else { completed as f64 / subscribed as f64 },
+        });
+    }
+
+    if json {
+        return write_json(out, &rows);
+    }
+
+    let mut table = Table::new(&["ID", "DATE", "TITLE", "SUBSCRIBED", "COMPLETED", "RATE"]);
+    for row in &rows {
+        table.add_row(vec![
+            row.session_id.to_string(),
+            cell(row.date),
+            row.title.clone(),
+            row.subscribed.to_string(),
+            row.completed.to_string(),
+            format!("{:.0}%", row.completion_rate * 100.0),
+        ]);
+    }
+    table.write_to(out)?;
+
+    let subscribed: usize = rows.iter().map(|r| r.subscribed).sum();
+    let completed: usize = rows.iter().map(|r| r.completed).sum();
+    writeln!(
+        out,
+        "\n{} sessions, {} subscriptions, {} completed",
+        rows.len(),
+        subscribed,
+        completed
+    )?;
+    Ok(())
+}
+
+/// `player comment`: record a coach's comment for progress reports
+pub fn player_comment(conn: &Connection, player: &str, coach: &str, text: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let coach = find_coach(conn, coach)?;
+    let player = find_player(conn, player)?;
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(AppError::Validation("Comment text is required".to_string()));
+    }
+    if text.len() > 2000 {
+        return Err(AppError::Validation("Comment must be less than 2000 characters".to_string()));
+    }
+
+    let id = ProgressRepository::add_comment(conn, player.id, coach.id, text)?;
+    if json {
+        return write_json(out, &serde_json::json!({ "id": id }));
+    }
+    writeln!(out, "Added comment {} for {}", id, player.username)?;
+    Ok(())
+}
+
+/// `player skill`: change a player's skill level
+pub fn player_skill(conn: &Connection, player: &str, level: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let player = find_player(conn, player)?;
+    let level = SkillLevel::from_str(level.trim())
+        .ok_or_else(|| AppError::Validation(format!("Unknown skill level '{}'", level)))?;
+
+    UserRepository::set_skill_level(conn, player.id, Some(&level))?;
+    tracing::info!(target: AUDIT_TARGET, user = %player.username, skill_level = level.as_str(), "skill level changed via cli");
+    if json {
+        return write_json(out, &serde_json::json!({ "user": player.username, "skill_level": level.as_str() }));
+    }
+    writeln!(out, "{} is now {}", player.username, level.as_str())?;
+    Ok(())
+}
+
+/// `player answer`: record a player's quiz answer
+pub fn player_answer(conn: &Connection, player: &str, quiz: i64, answer: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let player = find_player(conn, player)?;
+    let session_id = ProgressRepository::find_quiz_session(conn, quiz)?
+        .ok_or_else(|| AppError::NotFound(format!("Quiz {}", quiz)))?;
+    let subscription_id = active_subscription(conn, &player, session_id)?;
+    if answer.trim().is_empty() {
+        return Err(AppError::Validation("Answer is required".to_string()));
+    }
+
+    let correct = ProgressRepository::record_answer(conn, subscription_id, quiz, answer)?;
+    if json {
+        return write_json(out, &serde_json::json!({ "quiz": quiz, "correct": correct }));
+    }
+    let verdict = if correct { "correct" } else { "incorrect" };
+    writeln!(out, "Recorded {}'s answer to quiz {}: {}", player.username, quiz, verdict)?;
+    Ok(())
+}
+
+/// `player submit`: record that a player handed in homework
+pub fn player_submit(
+    conn: &Connection,
+    player: &str,
+    homework: i64,
+    notes: Option<&str>,
+    json: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let player = find_player(conn, player)?;
+    let session_id = ProgressRepository::find_homework_session(conn, homework)?
+        .ok_or_else(|| AppError::NotFound(format!("Homework {}", homework)))?;
+    let subscription_id = active_subscription(conn, &player, session_id)?;
+    let notes = notes.map(str::trim).filter(|n| !n.is_empty());
+
+    let id = ProgressRepository::submit_homework(conn, subscription_id, homework, notes)?;
+    if json {
+        return write_json(out, &serde_json::json!({ "id": id }));
+    }
+    writeln!(out, "Recorded {}'s homework {}", player.username, homework)?;
+    Ok(())
+}
+
+/// `player email`: set or clear where a player's reminders go
+pub fn player_email(conn: &Connection, player: &str, email: Option<&str>, json: bool, out: &mut dyn Write) -> Result<()> {
+    let player = find_player(conn, player)?;
+    let email = email.map(str::trim).filter(|email| !email.is_empty());
+    if let Some(email) = email {
+        validate_email(email).map_err(AppError::Validation)?;
+    }
+
+    UserRepository::set_email(conn, player.id, email)?;
+    tracing::info!(target: AUDIT_TARGET, user = %player.username, "player email changed via cli");
+    if json {
+        return write_json(out, &serde_json::json!({ "user": player.username, "email": email }));
+    }
+    match email {
+        Some(email) => writeln!(out, "Reminders for {} go to {}", player.username, email)?,
+        None => writeln!(out, "Cleared the email address of {}", player.username)?,
+    }
+    Ok(())
+}
+
+/// `progress`: one player's report, or with `--all` one file per player
+pub fn progress(conn: &Connection, args: &ProgressArgs, json: bool, out: &mut dyn Write) -> Result<()> {
+    let from = args.from.as_deref().map(parse_date).transpose()?;
+    let to = args.to.as_deref().map(parse_date).transpose()?;
+    if from.zip(to).is_some_and(|(from, to)| from > to) {
+        return Err(AppError::Validation("--from must not be after --to".to_string()));
+    }
+    let today = chrono::Local::now().date_naive();
+
+    if let Some(username) = &args.player {
+        let report = ProgressReport::load(conn, find_player(conn, username)?.id, from, to, today)?;
+        if json {
+            return write_json(out, &report);
+        }
+        out.write_all(report.render(args.format).as_bytes())?;
+        return Ok(());
+    }
+
+    let dir = args
+        .output_dir
+        .as_deref()
+        .ok_or_else(|| AppError::Validation("--all requires --output-dir".to_string()))?;
+    let players = match &args.group {
+        Some(name) => {
+            let group = GroupRepository::find_by_name(conn, name)?
+                .ok_or_else(|| AppError::NotFound(format!("Group '{}'", name)))?;
+            GroupRepository::find_members(conn, group.id)?
+        }
+        None => UserRepository::find_players(conn)?,
+    };
+
+    std::fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    for player in &players {
+        let report = ProgressReport::load(conn, player.id, from, to, today)?;
+        let path = dir.join(report.file_name(args.format));
+        std::fs::write(&path, report.render(args.format))?;
+        written.push(WrittenReport {
+            username: player.username.clone(),
+            path: path.display().to_string(),
+        });
+    }
+
+    if json {
+        return write_json(out, &written);
+    }
+    let mut table = Table::new(&["PLAYER", "FILE"]);
+    for report in &written {
+        table.add_row(vec![report.username.clone(), report.path.clone()]);
+    }
+    table.write_to(out)?;
+    writeln!(out, "\n{} reports written", written.len())?;
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct WrittenReport {
+    username: String,
+    path: String,
+}
+
+/// `audit`: recorded changes matching the filters, newest first
+pub fn audit(conn: &Connection, args: &AuditArgs, json: bool, out: &mut dyn Write) -> Result<()> {
+    let from = args.from.as_deref().map(parse_date).transpose()?;
+    let to = args.to.as_deref().map(parse_date).transpose()?;
+    if from.zip(to).is_some_and(|(from, to)| from > to) {
+        return Err(AppError::Validation("--from must not be after --to".to_string()));
+    }
+    let actor_id = match &args.user {
+        Some(username) => Some(
+            UserRepository::find_by_username(conn, username)?
+                .ok_or_else(|| AppError::NotFound(format!("User '{}'", username)))?
+                .id,
+        ),
+        None => None,
+    };
+
+    let entries = AuditRepository::find(
+        conn,
+        &AuditQuery {
+            actor_id,
+            session_id: args.session,
+            from,
+            to,
+            limit: Some(args.limit),
+        },
+    )?;
+
+    if json {
+        return write_json(out, &entries);
+    }
+    let mut table = Table::new(&["TIME", "ACTOR", "ENTITY", "ID", "ACTION", "CHANGES"]);
+    for entry in &entries {
+        let changes = match entry.action {
+            AuditAction::Update => entry.changed_fields().join(", "),
+            _ => cell(entry.subject()),
+        };
+        table.add_row(vec![
+            entry.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            entry.actor_label(),
+            entry.entity.clone(),
+            cell(entry.entity_id),
+            entry.action.as_str().to_string(),
+            changes,
+        ]);
+    }
+    table.write_to(out)
+}
+
+/// `authorized-keys`: an authorized_keys file for sshd's AuthorizedKeysCommand.
+/// Each active key may only run the TUI, as its owner, on this database.
+pub fn authorized_keys(conn: &Connection, program: &Path, out: &mut dyn Write) -> Result<()> {
+    let db_path = conn.path().unwrap_or_default();
+    for key in UserKeyRepository::find_active(conn)? {
+        let command = format!(
+            "{} --db-path {} run --user {}",
+            shell_quote(&program.to_string_lossy()),
+            shell_quote(db_path),
+            shell_quote(&key.username)
+        );
+        writeln!(
+            out,
+            "command=\"{}\",restrict,pty {} {}",
+            command.replace('"', "\\\""),
+            key.openssh(),
+            key.username
+        )?;
+    }
+    Ok(())
+}
+
+/// Quote for `sh -c`, which is how sshd runs forced commands
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// `key add`: register a public key (`-` reads it from stdin)
+pub fn key_add(conn: &Connection, username: &str, file: &Path, label: Option<&str>, json: bool, out: &mut dyn Write) -> Result<()> {
+    let user = UserRepository::find_by_username(conn, username)?
+        .ok_or_else(|| AppError::NotFound(format!("User '{}'", username)))?;
+    let contents = if file == Path::new("-") {
+        std::io::read_to_string(std::io::stdin())?
+    } else {
+        std::fs::read_to_string(file)?
+    };
+    let key = PublicKey::parse(contents.trim())?;
+    let label = label.map(str::trim).or(key.comment.as_deref()).filter(|label| !label.is_empty());
+
+    let id = UserKeyRepository::add(conn, user.id, &key, label)?;
+    let fingerprint = key.fingerprint();
+    tracing::info!(target: AUDIT_TARGET, key_id = id, user = %user.username, %fingerprint, "ssh key added via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "id": id, "fingerprint": fingerprint }));
+    }
+    writeln!(out, "Added key {} ({}) for {}", id, fingerprint, user.username)?;
+    Ok(())
+}
+
+/// `key list`: every key, or one user's, revoked ones included
+pub fn key_list(conn: &Connection, username: Option<&str>, json: bool, out: &mut dyn Write) -> Result<()> {
+    let keys = match username {
+        Some(username) => {
+            let user = UserRepository::find_by_username(conn, username)?
+                .ok_or_else(|| AppError::NotFound(format!("User '{}'", username)))?;
+            UserKeyRepository::find_by_user(conn, user.id)?
+        }
+        None => UserKeyRepository::find_all(conn)?,
+    };
+
+    if json {
+        return write_json(out, &keys);
+    }
+
+    let mut table = Table::new(&["ID", "USER", "LABEL", "FINGERPRINT", "TYPE", "ADDED", "STATUS"]);
+    for key in &keys {
+        table.add_row(vec![
+            key.id.to_string(),
+            key.username.clone(),
+            cell(key.label.as_ref()),
+            key.fingerprint.clone(),
+            key.key_type.clone(),
+            key.created_at.clone(),
+            match &key.revoked_at {
+                Some(revoked_at) => format!("revoked {}", revoked_at),
+                None => "active".to_string(),
+            },
+        ]);
+    }
+    table.write_to(out)
+}
+
+/// `key revoke`: the key stays listed but can no longer log in
+pub fn key_revoke(conn: &Connection, key: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let key = find_key(conn, key)?;
+    UserKeyRepository::revoke(conn, key.id)?;
+    tracing::info!(target: AUDIT_TARGET, key_id = key.id, user = %key.username, fingerprint = %key.fingerprint, "ssh key revoked via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "revoked": key.id }));
+    }
+    writeln!(out, "Revoked key {} ({}) of {}", key.id, key.fingerprint, key.username)?;
+    Ok(())
+}
+
+/// `key label`: rename a key; an empty label clears it
+pub fn key_label(conn: &Connection, key: &str, label: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let key = find_key(conn, key)?;
+    let label = Some(label.trim()).filter(|label| !label.is_empty());
+    UserKeyRepository::set_label(conn, key.id, label)?;
+
+    if json {
+        return write_json(out, &serde_json::json!({ "id": key.id, "label": label }));
+    }
+    match label {
+        Some(label) => writeln!(out, "Labelled key {} '{}'", key.id, label)?,
+        None => writeln!(out, "Cleared the label of key {}", key.id)?,
+    }
+    Ok(())
+}
+
+/// Look up a key by ID, or by fingerprint as printed by `key list`
+fn find_key(conn: &Connection, key: &str) -> Result<UserKey> {
+    let found = match key.parse::<i64>() {
+        Ok(id) => UserKeyRepository::find_by_id(conn, id)?,
+        Err(_) => UserKeyRepository::find_by_fingerprint(conn, key)?,
+    };
+    found.ok_or_else(|| AppError::NotFound(format!("Key '{}'", key)))
+}
+
+/// `serve`: answer REST API requests until stopped
+pub fn serve(conn: &Connection, bind: &str, out: &mut dyn Write) -> Result<()> {
+    let server = ApiServer::bind(bind)?;
+    writeln!(out, "Serving the API on http://{}", server.local_addr()?)?;
+    out.flush()?;
+    server.run(conn)
+}
+
+/// `token create`: the token is only ever shown here
+pub fn token_create(conn: &Connection, username: &str, label: Option<&str>, json: bool, out: &mut dyn Write) -> Result<()> {
+    let user = UserRepository::find_by_username(conn, username)?
+        .ok_or_else(|| AppError::NotFound(format!("User '{}'", username)))?;
+    let (id, token) = ApiTokenRepository::create(conn, user.id, label)?;
+    tracing::info!(target: AUDIT_TARGET, token_id = id, user = %user.username, "api token created via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "id": id, "token": token }));
+    }
+    writeln!(out, "Created token {} for {}. Store it now; it can't be shown again:", id, user.username)?;
+    writeln!(out, "{}", token)?;
+    Ok(())
+}
+
+/// `token list`: every token, revoked ones included
+pub fn token_list(conn: &Connection, json: bool, out: &mut dyn Write) -> Result<()> {
+    let tokens = ApiTokenRepository::find_all(conn)?;
+    if json {
+        return write_json(out, &tokens);
+    }
+
+    let mut table = Table::new(&["ID", "USER", "LABEL", "CREATED", "STATUS"]);
+    for token in &tokens {
+        table.add_row(vec![
+            token.id.to_string(),
+            token.username.clone(),
+            cell(token.label.as_ref()),
+            token.created_at.clone(),
+            match &token.revoked_at {
+                Some(revoked_at) => format!("revoked {}", revoked_at),
+                None => "active".to_string(),
+            },
+        ]);
+    }
+    table.write_to(out)
+}
+
+/// `token revoke`
+pub fn token_revoke(conn: &Connection, id: i64, json: bool, out: &mut dyn Write) -> Result<()> {
+    ApiTokenRepository::revoke(conn, id)?;
+    tracing::info!(target: AUDIT_TARGET, token_id = id, "api token revoked via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "revoked": id }));
+    }
+    writeln!(out, "Revoked token {}", id)?;
+    Ok(())
+}
+
+/// `remind`: email players about sessions starting soon. Fails if any
+/// reminder couldn't be sent, so cron reports it.
+pub fn remind(
+    conn: &Connection,
+    settings: &Settings,
+    hours: Option<u32>,
+    dry_run: bool,
+    json: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let relay = if dry_run { None } else { Some(Relay::from_settings(settings)?) };
+    let hours = hours.unwrap_or(settings.reminder_hours);
+    let now = chrono::Local::now().naive_local();
+    let outcomes = reminders::send_due(conn, relay.as_ref(), now, hours, settings.club_location.as_deref())?;
+
+    let count = |status: ReminderStatus| outcomes.iter().filter(|o| o.status == status).count();
+    let failed = count(ReminderStatus::Failed);
+    if !dry_run {
+        tracing::info!(target: AUDIT_TARGET, sent = count(ReminderStatus::Sent), failed, "reminders sent via cli");
+    }
+
+    if json {
+        write_json(out, &outcomes)?;
+    } else {
+        let mut table = Table::new(&["SESSION", "TITLE", "STARTS", "PLAYER", "EMAIL", "STATUS"]);
+        for outcome in &outcomes {
+            table.add_row(vec![
+                outcome.reminder.session_id.to_string(),
+                outcome.title.clone(),
+                outcome.reminder.starts_at.clone(),
+                outcome.reminder.username.clone(),
+                cell(outcome.reminder.email.as_ref()),
+                match &outcome.error {
+                    Some(error) => format!("{}: {}", outcome.status.as_str(), error),
+                    None => outcome.status.as_str().to_string(),
+                },
+            ]);
+        }
+        table.write_to(out)?;
+    }
+
+    if failed > 0 {
+        return Err(AppError::Other(format!("{} reminder(s) could not be sent; they will be retried on the next run", failed)));
+    }
+    Ok(())
+}
+
+/// `webhook add`
+pub fn webhook_add(conn: &Connection, url: &str, events: &[String], json: bool, out: &mut dyn Write) -> Result<()> {
+    Endpoint::parse(url)?;
+    for event in events {
+        if !WEBHOOK_EVENTS.contains(&event.as_str()) {
+            return Err(AppError::Validation(format!(
+                "Unknown event '{}' (expected one of: {})",
+                event,
+                WEBHOOK_EVENTS.join(", ")
+            )));
+        }
+    }
+    let (id, secret) = WebhookRepository::create(conn, url, events)?;
+    tracing::info!(target: AUDIT_TARGET, webhook_id = id, url, "webhook added via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "id": id, "secret": secret }));
+    }
+    writeln!(out, "Added webhook {} for {}. Deliveries are signed with this secret:", id, url)?;
+    writeln!(out, "{}", secret)?;
+    Ok(())
+}
+
+/// `webhook list`
+pub fn webhook_list(conn: &Connection, json: bool, out: &mut dyn Write) -> Result<()> {
+    let webhooks = WebhookRepository::find_all(conn)?;
+    if json {
+        return write_json(out, &webhooks);
+    }
+
+    let mut table = Table::new(&["ID", "URL", "EVENTS", "CREATED"]);
+    for webhook in &webhooks {
+        table.add_row(vec![
+            webhook.id.to_string(),
+            webhook.url.clone(),
+            webhook.events_label(),
+            webhook.created_at.clone(),
+        ]);
+    }
+    table.write_to(out)
+}
+
+/// `webhook remove`
+pub fn webhook_remove(conn: &Connection, id: i64, json: bool, out: &mut dyn Write) -> Result<()> {
+    WebhookRepository::delete(conn, id)?;
+    tracing::info!(target: AUDIT_TARGET, webhook_id = id, "webhook removed via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "removed": id }));
+    }
+    writeln!(out, "Removed webhook {}", id)?;
+    Ok(())
+}
+
+/// `webhook outbox`: pending and failed deliveries, or all of them
+pub fn webhook_outbox(conn: &Connection, all: bool, limit: usize, json: bool, out: &mut dyn Write) -> Result<()> {
+    let deliveries = WebhookRepository::find_deliveries(conn, all, limit)?;
+    if json {
+        return write_json(out, &deliveries);
+    }
+
+    let mut table = Table::new(&["ID", "WEBHOOK", "EVENT", "CREATED", "STATUS", "ATTEMPTS", "LAST ERROR"]);
+    for delivery in &deliveries {
+        table.add_row(vec![
+            delivery.id.to_string(),
+            delivery.webhook_id.to_string(),
+            delivery.event.clone(),
+            delivery.created_at.clone(),
+            match delivery.status.as_str() {
+                "pending" if delivery.attempts > 0 => format!("retry at {}", delivery.next_attempt_at),
+                status => status.to_string(),
+            },
+            delivery.attempts.to_string(),
+            cell(delivery.last_error.as_ref()),
+        ]);
+    }
+    table.write_to(out)
+}
+
+/// How often `webhook deliver --watch` checks the outbox
+const DELIVERY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// `webhook deliver`: one pass over the outbox, or one every few seconds
+/// with `watch`
+pub fn webhook_deliver(conn: &Connection, watch: bool, json: bool, out: &mut dyn Write) -> Result<()> {
+    loop {
+        let summary = webhooks::deliver_due(conn, chrono::Utc::now())?;
+        if json {
+            write_json(out, &summary)?;
+        } else if !watch || !summary.is_empty() {
+            writeln!(
+                out,
+                "Delivered {}, retrying {}, failed {}",
+                summary.delivered, summary.retrying, summary.failed
+            )?;
+        }
+        out.flush()?;
+
+        if !watch {
+            return Ok(());
+        }
+        std::thread::sleep(DELIVERY_INTERVAL);
+    }
+}
+
+/// `webhook retry`
+pub fn webhook_retry(conn: &Connection, id: i64, json: bool, out: &mut dyn Write) -> Result<()> {
+    WebhookRepository::retry(conn, id)?;
+    tracing::info!(target: AUDIT_TARGET, delivery_id = id, "webhook delivery requeued via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "queued": id }));
+    }
+    writeln!(out, "Queued delivery {} again", id)?;
+    Ok(())
+}
+
+/// `venue list`: one row per court
+pub fn venue_list(conn: &Connection, json: bool, out: &mut dyn Write) -> Result<()> {
+    let venues = VenueRepository::find_all_with_courts(conn)?;
+    if json {
+        return write_json(out, &venues);
+    }
+
+    let mut table = Table::new(&["COURT ID", "VENUE", "COURT", "ADDRESS"]);
+    for v in &venues {
+        if v.courts.is_empty() {
+            table.add_row(vec!["-".to_string(), v.venue.name.clone(), "-".to_string(), cell(v.venue.address.as_ref())]);
+        }
+        for court in &v.courts {
+            table.add_row(vec![
+                court.id.to_string(),
+                v.venue.name.clone(),
+                court.name.clone(),
+                cell(v.venue.address.as_ref()),
+            ]);
+        }
+    }
+    table.write_to(out)
+}
+
+/// `venue add`: the venue and its courts are added together, or not at all
+pub fn venue_add(
+    conn: &Connection,
+    name: &str,
+    address: Option<&str>,
+    courts: &[String],
+    json: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let tx = UnitOfWork::begin(conn)?;
+    let venue_id = VenueRepository::create(&tx, name, address)?;
+    let mut court_ids = Vec::new();
+    for court in courts {
+        court_ids.push(VenueRepository::add_court(&tx, venue_id, court)?);
+    }
+    tx.commit()?;
+    tracing::info!(target: AUDIT_TARGET, venue_id, name = %name.trim(), courts = court_ids.len(), "venue added via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "id": venue_id, "courts": court_ids }));
+    }
+    writeln!(out, "Added venue {} with {} court(s)", venue_id, court_ids.len())?;
+    Ok(())
+}
+
+/// `venue add-court`
+pub fn venue_add_court(conn: &Connection, venue: &str, name: &str, json: bool, out: &mut dyn Write) -> Result<()> {
+    let venue = VenueRepository::find_by_name(conn, venue)?
+        .ok_or_else(|| AppError::NotFound(format!("Venue '{}'", venue)))?;
+    let id = VenueRepository::add_court(conn, venue.id, name)?;
+    tracing::info!(target: AUDIT_TARGET, court_id = id, venue = %venue.name, name = %name.trim(), "court added via cli");
+
+    if json {
+        return write_json(out, &serde_json::json!({ "id": id }));
+    }
+    writeln!(out, "Added court {} to {}", id, venue.name)?;
+    Ok(())
+}
+
+/// `config show`: effective settings and the layer each one came from
+pub fn config_show(settings: &Settings, json: bool, out: &mut dyn Write) -> Result<()> {
+    let entries = settings.entries();
+    if json {
+        return write_json(out, &entries);
+    }
+
+    let mut table = Table::new(&["KEY", "VALUE", "SOURCE"]);
+    for entry in entries {
+        table.add_row(vec![entry.key.to_string(), entry.value, entry.source]);
+    }
+    table.write_to(out)
+}
+
+/// Look up a user by username and make sure they are a coach
+pub fn find_coach(conn: &Connection, username: &str) -> Result<User> {
+    let user = UserRepository::find_by_username(conn, username)?
+        .ok_or_else(|| AppError::NotFound(format!("User '{}'", username)))?;
+    if !user.is_coach() {
+        return Err(AppError::Validation(format!("'{}' is not a coach", username)));
+    }
+    Ok(user)
+}
+
+/// Look up a user by username and make sure they are a player
+fn find_player(conn: &Connection, username: &str) -> Result<User> {
+    let user = UserRepository::find_by_username(conn, username)?
+        .ok_or_else(|| AppError::NotFound(format!("User '{}'", username)))?;
+    if !user.is_player() {
+        return Err(AppError::Validation(format!("'{}' is not a player", username)));
+    }
+    Ok(user)
+}
+
+/// The player's subscription to a session, which quiz answers and homework
+/// are recorded against
+fn active_subscription(conn: &Connection, player: &User, session_id: i64) -> Result<i64> {
+    match SubscriptionRepository::find_by_user_and_session(conn, player.id, session_id)? {
+        Some(sub) if sub.status != SubscriptionStatus::Cancelled => Ok(sub.id),
+        _ => Err(AppError::Validation(format!(
+            "{} is not subscribed to session {}",
+            player.username, session_id
+        ))),
+    }
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| AppError::Validation(format!("Invalid date '{}', expected YYYY-MM-DD", value)))
+}
+
+/// (subscribed, completed) counts for a session, ignoring cancelled subscriptions
+fn active_subscriptions(conn: &Connection, session_id: i64) -> Result<(usize, usize)> {
+    let subscriptions = SubscriptionRepository::find_by_session(conn, session_id)?;
+    let subscribed = subscriptions
+        .iter()
+        .filter(|s| s.status != SubscriptionStatus::Cancelled)
+        .count();
+    let completed = subscriptions
+        .iter()
+        .filter(|s| s.status == SubscriptionStatus::Completed)
+        .count();
+    Ok((subscribed, completed))
+}
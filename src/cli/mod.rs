@@ -0,0 +1,128 @@
+pub mod args;
+pub mod commands;
+pub mod output;
+
+pub use args::{AuditArgs, Cli, Command, ConfigCommand, ExportFormat, HistoryArgs, KeyCommand, PlayerCommand, SessionCommand, TemplateCommand, TokenCommand, VenueCommand, WebhookCommand};
+
+use rusqlite::Connection;
+use std::io::Write;
+use std::path::Path;
+
+use crate::config::Settings;
+
+/// Run a non-interactive subcommand (everything except `run`)
+pub fn execute(
+    conn: &Connection,
+    settings: &Settings,
+    command: &Command,
+    json: bool,
+    out: &mut dyn Write,
+) -> crate::utils::Result<()> {
+    match command {
+        Command::Run { .. } => Err(crate::utils::AppError::Other(
+            "`run` starts the TUI and can't be executed headless".to_string(),
+        )),
+        Command::Config(ConfigCommand::Show) => Err(crate::utils::AppError::Other(
+            "`config show` runs before the database is opened".to_string(),
+        )),
+        Command::Init { sample_data } => commands::init(conn, *sample_data, out),
+        Command::Session(SessionCommand::List { coach }) => {
+            commands::session_list(conn, coach.as_deref(), json, out)
+        }
+        Command::Session(SessionCommand::Create(args)) => commands::session_create(conn, args, json, out),
+        Command::Session(SessionCommand::Delete { id }) => commands::session_delete(conn, *id, json, out),
+        Command::Session(SessionCommand::Cancel { id, reason }) => {
+            commands::session_cancel(conn, *id, reason, json, out)
+        }
+        Command::Session(SessionCommand::Quiz { id, question, answer, options }) => {
+            commands::session_quiz(conn, *id, question, answer, options.as_deref(), json, out)
+        }
+        Command::Session(SessionCommand::Homework { id, title, description, due }) => {
+            commands::session_homework(conn, *id, title, description, due.as_deref(), json, out)
+        }
+        Command::Session(SessionCommand::Plan { id, format, output }) => {
+            with_output(output.as_deref(), out, |out| commands::session_plan(conn, *id, *format, json, out))
+        }
+        Command::Template(TemplateCommand::List { coach, tags }) => {
+            commands::template_list(conn, coach.as_deref(), tags, json, out)
+        }
+        Command::Template(TemplateCommand::Import { file, coach }) => {
+            commands::template_import(conn, file, coach, json, out)
+        }
+        Command::Template(TemplateCommand::Delete { id }) => commands::template_delete(conn, *id, json, out),
+        Command::Template(TemplateCommand::Tag { id, tags }) => commands::template_tag(conn, *id, tags, json, out),
+        Command::Player(PlayerCommand::Import(args)) => commands::player_import(conn, args, json, out),
+        Command::Player(PlayerCommand::Comment { player, coach, text }) => {
+            commands::player_comment(conn, player, coach, text, json, out)
+        }
+        Command::Player(PlayerCommand::Skill { player, level }) => commands::player_skill(conn, player, level, json, out),
+        Command::Player(PlayerCommand::Answer { player, quiz, answer }) => {
+            commands::player_answer(conn, player, *quiz, answer, json, out)
+        }
+        Command::Player(PlayerCommand::Submit { player, homework, notes }) => {
+            commands::player_submit(conn, player, *homework, notes.as_deref(), json, out)
+        }
+        Command::Player(PlayerCommand::Email { player, email }) => {
+            commands::player_email(conn, player, email.as_deref(), json, out)
+        }
+        Command::Export { format, coach, output } => with_output(output.as_deref(), out, |out| match format {
+            ExportFormat::Csv => commands::export_sessions(conn, coach.as_deref(), out),
+            ExportFormat::Json if coach.is_some() => Err(crate::utils::AppError::Validation(
+                "--coach is only supported for CSV exports".to_string(),
+            )),
+            ExportFormat::Json => commands::export_json(conn, out),
+        }),
+        Command::Import { file, mode } => commands::import_json(conn, file, *mode, json, out),
+        Command::History(args) => with_output(args.output.as_deref(), out, |out| {
+            commands::history(conn, args.player.as_deref(), args.coach.as_deref(), json, out)
+        }),
+        Command::Report(args) => commands::report(conn, args, json, out),
+        Command::Progress(args) => with_output(args.output.as_deref(), out, |out| commands::progress(conn, args, json, out)),
+        Command::Audit(args) => commands::audit(conn, args, json, out),
+        Command::AuthorizedKeys { program } => {
+            let program = match program {
+                Some(program) => program.clone(),
+                None => std::env::current_exe()?,
+            };
+            commands::authorized_keys(conn, &program, out)
+        }
+        Command::Key(KeyCommand::Add { user, file, label }) => {
+            commands::key_add(conn, user, file, label.as_deref(), json, out)
+        }
+        Command::Key(KeyCommand::List { user }) => commands::key_list(conn, user.as_deref(), json, out),
+        Command::Key(KeyCommand::Revoke { key }) => commands::key_revoke(conn, key, json, out),
+        Command::Key(KeyCommand::Label { key, label }) => commands::key_label(conn, key, label, json, out),
+        Command::Serve { bind } => commands::serve(conn, bind, out),
+        Command::Token(TokenCommand::Create { user, label }) => {
+            commands::token_create(conn, user, label.as_deref(), json, out)
+        }
+        Command::Token(TokenCommand::List) => commands::token_list(conn, json, out),
+        Command::Token(TokenCommand::Revoke { id }) => commands::token_revoke(conn, *id, json, out),
+        Command::Remind { hours, dry_run } => commands::remind(conn, settings, *hours, *dry_run, json, out),
+        Command::Venue(VenueCommand::List) => commands::venue_list(conn, json, out),
+        Command::Venue(VenueCommand::Add { name, address, courts }) => {
+            commands::venue_add(conn, name, address.as_deref(), courts, json, out)
+        }
+        Command::Venue(VenueCommand::AddCourt { venue, name }) => commands::venue_add_court(conn, venue, name, json, out),
+        Command::Webhook(WebhookCommand::Add { url, events }) => commands::webhook_add(conn, url, events, json, out),
+        Command::Webhook(WebhookCommand::List) => commands::webhook_list(conn, json, out),
+        Command::Webhook(WebhookCommand::Remove { id }) => commands::webhook_remove(conn, *id, json, out),
+        Command::Webhook(WebhookCommand::Outbox { all, limit }) => {
+            commands::webhook_outbox(conn, *all, *limit, json, out)
+        }
+        Command::Webhook(WebhookCommand::Deliver { watch }) => commands::webhook_deliver(conn, *watch, json, out),
+        Command::Webhook(WebhookCommand::Retry { id }) => commands::webhook_retry(conn, *id, json, out),
+    }
+}
+
+/// Run `write` against `--output` if given, otherwise against `out`
+fn with_output(
+    output: Option<&Path>,
+    out: &mut dyn Write,
+    write: impl FnOnce(&mut dyn Write) -> crate::utils::Result<()>,
+) -> crate::utils::Result<()> {
+    match output {
+        Some(path) => write(&mut std::fs::File::create(path)?),
+        None => write(out),
+    }
+}
@@ -0,0 +1,66 @@
+use crate::utils::{AppError, Result};
+use serde::Serialize;
+use std::io::Write;
+
+/// Plain-text table with left-aligned, auto-sized columns
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn write_to(&self, out: &mut dyn Write) -> Result<()> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                if i < widths.len() {
+                    widths[i] = widths[i].max(cell.chars().count());
+                }
+            }
+        }
+
+        let format_row = |cells: &[String]| {
+            cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        writeln!(out, "{}", format_row(&self.headers))?;
+        let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        writeln!(out, "{}", format_row(&separator))?;
+        for row in &self.rows {
+            writeln!(out, "{}", format_row(row))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Write a value as pretty-printed JSON
+pub fn write_json<T: Serialize + ?Sized>(out: &mut dyn Write, value: &T) -> Result<()> {
+    serde_json::to_writer_pretty(&mut *out, value)
+        .map_err(|e| AppError::Other(format!("JSON serialization failed: {}", e)))?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Format an optional value for a table cell
+pub fn cell<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string())
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// Keys the screens use for their own actions. The global keys are checked
+/// first, so binding one of them to these would hide the action.
+pub const ACTION_KEYS: &str = "aAcCdDeEfFgGhHjklLmMpPrRsStTuUwWxX";
+
+/// Remappable global keys (`[keys]` in `config.toml`). Quit also answers to
+/// its other case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBindings {
+    pub quit: char,
+    pub help: char,
+    pub search: char,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            quit: 'q',
+            help: '?',
+            search: '/',
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Bindings with the config key they are set by
+    pub fn named(&self) -> [(&'static str, char); 3] {
+        [("keys.quit", self.quit), ("keys.help", self.help), ("keys.search", self.search)]
+    }
+
+    /// Rewrite the default key labels (`[q]`, `[?]`, `[/]`) in help text to
+    /// the configured keys
+    pub fn relabel(&self, text: &str) -> String {
+        let defaults = Self::default();
+        let labels = [
+            (format!("[{}]", defaults.quit), self.quit),
+            (format!("[{}]", defaults.help), self.help),
+            (format!("[{}]", defaults.search), self.search),
+        ];
+
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        'outer: while !rest.is_empty() {
+            for (label, key) in &labels {
+                if let Some(tail) = rest.strip_prefix(label.as_str()) {
+                    out.push_str(&format!("[{}]", key));
+                    rest = tail;
+                    continue 'outer;
+                }
+            }
+            let mut chars = rest.chars();
+            out.extend(chars.next());
+            rest = chars.as_str();
+        }
+        out
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        let named = self.named();
+        for (i, (name, key)) in named.iter().enumerate() {
+            if !key.is_ascii_graphic() || key.is_ascii_digit() {
+                return Err(format!(
+                    "{} must be a printable, non-digit ASCII character (got {:?})",
+                    name, key
+                ));
+            }
+            if let Some((other, _)) = named[..i].iter().find(|(other, k)| Self::overlaps(other, *k, name, *key)) {
+                return Err(format!("{} and {} are both bound to '{}'", other, name, key));
+            }
+            if let Some(action) = ACTION_KEYS.chars().find(|action| Self::overlaps(name, *key, "", *action)) {
+                return Err(format!("{} '{}' is already the '{}' action key", name, key, action));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether two bindings catch the same key press
+    fn overlaps(name: &str, key: char, other_name: &str, other: char) -> bool {
+        if name == "keys.quit" || other_name == "keys.quit" {
+            key.eq_ignore_ascii_case(&other)
+        } else {
+            key == other
+        }
+    }
+}
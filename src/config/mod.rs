@@ -0,0 +1,423 @@
+pub mod keys;
+pub mod theme;
+
+pub use keys::KeyBindings;
+pub use theme::Theme;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::utils::validate_email;
+use crate::utils::{AppError, Result};
+
+/// Machine-wide config file, read before the user's (DESIGN.md §9.1)
+pub const SYSTEM_CONFIG_PATH: &str = "/etc/tennis-tui/config.toml";
+pub const DEFAULT_DB_PATH: &str = "data/tennis.db";
+pub const DEFAULT_LOG_PATH: &str = "logs/tennis-tui.log";
+pub const DB_PATH_ENV: &str = "TENNIS_DB_PATH";
+pub const LOG_LEVEL_ENV: &str = "TENNIS_LOG_LEVEL";
+
+/// Every setting, in config file order, with the environment variable that
+/// overrides it (DESIGN.md §9.2)
+pub const SETTINGS: [(&str, &str); 14] = [
+    ("database.path", DB_PATH_ENV),
+    ("logging.level", LOG_LEVEL_ENV),
+    ("logging.path", "TENNIS_LOG_PATH"),
+    ("app.page_size", "TENNIS_PAGE_SIZE"),
+    ("ui.theme", "TENNIS_THEME"),
+    ("smtp.host", "TENNIS_SMTP_HOST"),
+    ("smtp.port", "TENNIS_SMTP_PORT"),
+    ("smtp.from", "TENNIS_SMTP_FROM"),
+    ("smtp.helo", "TENNIS_SMTP_HELO"),
+    ("reminders.hours", "TENNIS_REMINDERS_HOURS"),
+    ("reminders.location", "TENNIS_REMINDERS_LOCATION"),
+    ("keys.quit", "TENNIS_KEYS_QUIT"),
+    ("keys.help", "TENNIS_KEYS_HELP"),
+    ("keys.search", "TENNIS_KEYS_SEARCH"),
+];
+
+const LOG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+const MAX_PAGE_SIZE: usize = 500;
+/// Longest `reminders.hours` ahead, one week
+const MAX_REMINDER_HOURS: u32 = 7 * 24;
+
+/// Where an effective setting came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    Env(&'static str),
+    Cli(&'static str),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Env(name) => write!(f, "env {}", name),
+            Source::Cli(flag) => write!(f, "{}", flag),
+        }
+    }
+}
+
+/// One line of `config show`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SettingEntry {
+    pub key: &'static str,
+    pub value: String,
+    pub source: String,
+}
+
+/// Effective configuration. Layers are applied in order, later ones winning:
+/// built-in defaults, `/etc/tennis-tui/config.toml`, the user's config file,
+/// `TENNIS_*` environment variables, then command-line flags (`--db-path` and
+/// `--set key=value`). Every setting can be given in every layer.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub database_path: String,
+    pub log_level: String,
+    /// Main log file; rotated copies and `audit.log` live next to it
+    pub log_path: PathBuf,
+    /// Sessions shown per page in the session list
+    pub page_size: usize,
+    pub theme: Theme,
+    pub keys: KeyBindings,
+    /// SMTP relay that session reminders are sent through
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    /// Sender address of reminders
+    pub smtp_from: Option<String>,
+    /// Name this host gives in `EHLO`
+    pub smtp_helo: String,
+    /// How far ahead `remind` looks for sessions
+    pub reminder_hours: u32,
+    /// Where sessions take place, printed in reminders
+    pub club_location: Option<String>,
+    sources: HashMap<&'static str, Source>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            database_path: DEFAULT_DB_PATH.to_string(),
+            log_level: "info".to_string(),
+            log_path: PathBuf::from(DEFAULT_LOG_PATH),
+            page_size: 20,
+            theme: Theme::default(),
+            keys: KeyBindings::default(),
+            smtp_host: None,
+            smtp_port: 25,
+            smtp_from: None,
+            smtp_helo: "localhost".to_string(),
+            reminder_hours: 24,
+            club_location: None,
+            sources: HashMap::new(),
+        }
+    }
+}
+
+/// Contents of a `config.toml`; every value is optional so files can be partial
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct FileConfig {
+    database: DatabaseSection,
+    logging: LoggingSection,
+    app: AppSection,
+    ui: UiSection,
+    keys: KeysSection,
+    smtp: SmtpSection,
+    reminders: RemindersSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DatabaseSection {
+    path: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct LoggingSection {
+    level: Option<String>,
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct AppSection {
+    #[serde(alias = "max_sessions_per_page")]
+    page_size: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct UiSection {
+    theme: Option<Theme>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SmtpSection {
+    host: Option<String>,
+    port: Option<u16>,
+    from: Option<String>,
+    helo: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct RemindersSection {
+    hours: Option<u32>,
+    location: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct KeysSection {
+    quit: Option<char>,
+    help: Option<char>,
+    search: Option<char>,
+}
+
+impl Settings {
+    /// Load from the standard locations and the process environment.
+    /// `config_path` (from `--config`) replaces the user's config file and
+    /// must exist.
+    pub fn load(config_path: Option<&Path>, db_path: Option<&str>, overrides: &[String]) -> Result<Self> {
+        let mut files = vec![PathBuf::from(SYSTEM_CONFIG_PATH)];
+        match config_path {
+            Some(path) if !path.is_file() => {
+                return Err(AppError::NotFound(format!("Config file {}", path.display())));
+            }
+            Some(path) => files.push(path.to_path_buf()),
+            None => files.extend(user_config_path()),
+        }
+
+        Self::resolve(&files, |name| std::env::var(name).ok(), db_path, overrides)
+    }
+
+    /// Apply config files (missing ones are skipped), then variables from
+    /// `env`, then the `--db-path` flag and `--set key=value` overrides
+    pub fn resolve(
+        files: &[PathBuf],
+        env: impl Fn(&str) -> Option<String>,
+        db_path: Option<&str>,
+        overrides: &[String],
+    ) -> Result<Self> {
+        let mut settings = Self::default();
+
+        for path in files.iter().filter(|path| path.is_file()) {
+            let contents = std::fs::read_to_string(path)?;
+            let file: FileConfig = toml::from_str(&contents)
+                .map_err(|e| AppError::Validation(format!("{}: {}", path.display(), e)))?;
+            settings.apply_file(file, path);
+        }
+
+        for (key, var) in SETTINGS {
+            if let Some(value) = env(var).filter(|v| !v.is_empty()) {
+                settings.set(key, &value, Source::Env(var))?;
+            }
+        }
+        if let Some(value) = db_path {
+            settings.set("database.path", value, Source::Cli("--db-path"))?;
+        }
+        for item in overrides {
+            let (name, value) = item
+                .split_once('=')
+                .ok_or_else(|| AppError::Validation(format!("--set {}: expected key=value", item)))?;
+            let key = SETTINGS
+                .iter()
+                .map(|(key, _)| *key)
+                .find(|key| *key == name.trim())
+                .ok_or_else(|| AppError::Validation(format!("--set {}: unknown setting '{}'", item, name.trim())))?;
+            settings.set(key, value, Source::Cli("--set"))?;
+        }
+
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Where the effective value of `key` came from
+    pub fn source(&self, key: &str) -> &Source {
+        self.sources.get(key).unwrap_or(&Source::Default)
+    }
+
+    /// Every setting with its value and source, in config file order
+    pub fn entries(&self) -> Vec<SettingEntry> {
+        let mut values = vec![
+            ("database.path", self.database_path.clone()),
+            ("logging.level", self.log_level.clone()),
+            ("logging.path", self.log_path.display().to_string()),
+            ("app.page_size", self.page_size.to_string()),
+            ("ui.theme", self.theme.as_str().to_string()),
+            ("smtp.host", self.smtp_host.clone().unwrap_or_default()),
+            ("smtp.port", self.smtp_port.to_string()),
+            ("smtp.from", self.smtp_from.clone().unwrap_or_default()),
+            ("smtp.helo", self.smtp_helo.clone()),
+            ("reminders.hours", self.reminder_hours.to_string()),
+            ("reminders.location", self.club_location.clone().unwrap_or_default()),
+        ];
+        values.extend(self.keys.named().iter().map(|(name, key)| (*name, key.to_string())));
+
+        values
+            .into_iter()
+            .map(|(key, value)| SettingEntry {
+                key,
+                value,
+                source: self.source(key).to_string(),
+            })
+            .collect()
+    }
+
+    /// Set one value given as text, by an environment variable or a flag.
+    /// Empty text clears the optional SMTP and reminder settings.
+    fn set(&mut self, key: &'static str, value: &str, source: Source) -> Result<()> {
+        let invalid = |message: String| AppError::Validation(format!("{} (from {}): {}", key, source, message));
+        let number = |value: &str| format!("'{}' is not a number", value);
+        let optional = |value: &str| Some(value.to_string()).filter(|v| !v.is_empty());
+        let single = |value: &str| {
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c),
+                _ => Err(format!("'{}' is not a single character", value)),
+            }
+        };
+
+        match key {
+            "database.path" => self.database_path = value.to_string(),
+            "logging.level" => self.log_level = value.to_lowercase(),
+            "logging.path" => self.log_path = PathBuf::from(value),
+            "app.page_size" => self.page_size = value.parse().map_err(|_| invalid(number(value)))?,
+            "ui.theme" => self.theme = value.parse().map_err(invalid)?,
+            "smtp.host" => self.smtp_host = optional(value),
+            "smtp.port" => self.smtp_port = value.parse().map_err(|_| invalid(number(value)))?,
+            "smtp.from" => self.smtp_from = optional(value),
+            "smtp.helo" => self.smtp_helo = value.to_string(),
+            "reminders.hours" => self.reminder_hours = value.parse().map_err(|_| invalid(number(value)))?,
+            "reminders.location" => self.club_location = optional(value),
+            "keys.quit" => self.keys.quit = single(value).map_err(invalid)?,
+            "keys.help" => self.keys.help = single(value).map_err(invalid)?,
+            "keys.search" => self.keys.search = single(value).map_err(invalid)?,
+            _ => return Err(AppError::Validation(format!("Unknown setting '{}'", key))),
+        }
+        self.sources.insert(key, source);
+        Ok(())
+    }
+
+    fn apply_file(&mut self, file: FileConfig, path: &Path) {
+        let mut set = Vec::new();
+
+        if let Some(value) = file.database.path {
+            self.database_path = value;
+            set.push("database.path");
+        }
+        if let Some(value) = file.logging.level {
+            self.log_level = value.to_lowercase();
+            set.push("logging.level");
+        }
+        if let Some(value) = file.logging.path {
+            self.log_path = value;
+            set.push("logging.path");
+        }
+        if let Some(value) = file.app.page_size {
+            self.page_size = value;
+            set.push("app.page_size");
+        }
+        if let Some(value) = file.ui.theme {
+            self.theme = value;
+            set.push("ui.theme");
+        }
+        if let Some(value) = file.smtp.host {
+            self.smtp_host = Some(value);
+            set.push("smtp.host");
+        }
+        if let Some(value) = file.smtp.port {
+            self.smtp_port = value;
+            set.push("smtp.port");
+        }
+        if let Some(value) = file.smtp.from {
+            self.smtp_from = Some(value);
+            set.push("smtp.from");
+        }
+        if let Some(value) = file.smtp.helo {
+            self.smtp_helo = value;
+            set.push("smtp.helo");
+        }
+        if let Some(value) = file.reminders.hours {
+            self.reminder_hours = value;
+            set.push("reminders.hours");
+        }
+        if let Some(value) = file.reminders.location {
+            self.club_location = Some(value);
+            set.push("reminders.location");
+        }
+        if let Some(value) = file.keys.quit {
+            self.keys.quit = value;
+            set.push("keys.quit");
+        }
+        if let Some(value) = file.keys.help {
+            self.keys.help = value;
+            set.push("keys.help");
+        }
+        if let Some(value) = file.keys.search {
+            self.keys.search = value;
+            set.push("keys.search");
+        }
+
+        for key in set {
+            self.sources.insert(key, Source::File(path.to_path_buf()));
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let invalid = |key: &str, message: String| {
+            AppError::Validation(format!("{} (from {}): {}", key, self.source(key), message))
+        };
+
+        if self.database_path.trim().is_empty() {
+            return Err(invalid("database.path", "must not be empty".to_string()));
+        }
+        if !LOG_LEVELS.contains(&self.log_level.as_str()) {
+            return Err(invalid(
+                "logging.level",
+                format!("'{}' is not one of {}", self.log_level, LOG_LEVELS.join(", ")),
+            ));
+        }
+        if self.page_size == 0 || self.page_size > MAX_PAGE_SIZE {
+            return Err(invalid(
+                "app.page_size",
+                format!("must be between 1 and {}", MAX_PAGE_SIZE),
+            ));
+        }
+        if self.smtp_port == 0 {
+            return Err(invalid("smtp.port", "must not be 0".to_string()));
+        }
+        if let Some(from) = &self.smtp_from {
+            validate_email(from).map_err(|message| invalid("smtp.from", message))?;
+        }
+        if self.smtp_helo.is_empty() || !self.smtp_helo.chars().all(|c| c.is_ascii_graphic()) {
+            return Err(invalid("smtp.helo", "must be a host name without spaces".to_string()));
+        }
+        if self.reminder_hours == 0 || self.reminder_hours > MAX_REMINDER_HOURS {
+            return Err(invalid(
+                "reminders.hours",
+                format!("must be between 1 and {}", MAX_REMINDER_HOURS),
+            ));
+        }
+        self.keys
+            .validate()
+            .map_err(|message| AppError::Validation(format!("keys: {}", message)))
+    }
+}
+
+/// `$XDG_CONFIG_HOME/tennis-tui/config.toml`, falling back to `~/.config`
+pub fn user_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("tennis-tui").join("config.toml"))
+}
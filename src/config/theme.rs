@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Colour scheme of the TUI, chosen with `ui.theme` in `config.toml`; the
+/// colours themselves are in `ui::theme`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// Bright accents for dark terminal backgrounds
+    #[default]
+    Dark,
+    /// Darker accents that stay readable on light backgrounds
+    Light,
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dark" => Ok(Theme::Dark),
+            "light" => Ok(Theme::Light),
+            _ => Err(format!("'{}' is not one of dark, light", s)),
+        }
+    }
+}
+
+impl Theme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+        }
+    }
+}
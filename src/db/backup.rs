@@ -0,0 +1,1005 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OptionalExtension};
+use std::collections::{HashMap, HashSet};
+
+use crate::db::UnitOfWork;
+use crate::db::repositories::{
+    AuditRepository, GroupRepository, SessionRepository, SessionTrainingLinkRepository, SubscriptionRepository,
+    TagRepository, TrainingContentRepository, TrainingTemplateRepository, UserRepository, VenueRepository,
+};
+use crate::models::{
+    ApiTokenExport, ClubExport, GroupExport, HomeworkExport, HomeworkSubmissionExport, ImportSummary,
+    NotificationExport, PlayerCommentExport, QuizExport, QuizResponseExport, SentReminderExport, SessionTagExport,
+    SkillLevelChangeExport, TemplateTagExport, UserKeyExport, CLUB_EXPORT_VERSION,
+};
+use crate::utils::{AppError, Result};
+
+/// How an import treats data already in the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ImportMode {
+    /// Keep existing data; rows that already exist (matched by username,
+    /// session title/date/time, template title, group, venue and court name,
+    /// key fingerprint and token hash) are reused. A player's comments,
+    /// skill history and inbox are only imported with a new player.
+    Merge,
+    /// Delete all club data first, change history included (its IDs would
+    /// point at the wrong rows), then import everything. Refused while
+    /// anything is archived, since exports leave archived rows out.
+    Replace,
+}
+
+/// Collect the whole club database into a versioned export document.
+/// Archived sessions and content are left out, with their subscriptions and tags.
+pub fn export(conn: &Connection) -> Result<ClubExport> {
+    let sessions = SessionRepository::find_all(conn)?;
+    let session_ids: HashSet<i64> = sessions.iter().map(|s| s.id).collect();
+
+    let mut training_content = Vec::new();
+    let mut session_training_links = Vec::new();
+    for session in &sessions {
+        training_content.extend(TrainingContentRepository::find_by_session(conn, session.id)?);
+        session_training_links.extend(SessionTrainingLinkRepository::get_for_session(conn, session.id)?);
+    }
+
+    let mut session_tags: Vec<SessionTagExport> = TagRepository::find_all_session_tags(conn)?
+        .into_iter()
+        .filter(|(session_id, _)| session_ids.contains(session_id))
+        .flat_map(|(session_id, tags)| tags.into_iter().map(move |tag| SessionTagExport { session_id, tag }))
+        .collect();
+    session_tags.sort_by(|a, b| (a.session_id, &a.tag).cmp(&(b.session_id, &b.tag)));
+
+    let mut groups = Vec::new();
+    for group in GroupRepository::find_all(conn)? {
+        let member_ids = GroupRepository::find_members(conn, group.id)?
+            .into_iter()
+            .map(|u| u.id)
+            .collect();
+        groups.push(GroupExport { group, member_ids });
+    }
+
+    let templates = TrainingTemplateRepository::list_including_private(conn)?;
+    let template_ids: HashSet<i64> = templates.iter().map(|t| t.id).collect();
+    let template_tags: Vec<TemplateTagExport> = select_all(
+        conn,
+        "SELECT tt.template_id, t.name FROM template_tags tt JOIN tags t ON t.id = tt.tag_id
+         ORDER BY tt.template_id, t.name",
+        |row| Ok(TemplateTagExport { template_id: row.get(0)?, tag: row.get(1)? }),
+    )?
+    .into_iter()
+    .filter(|t| template_ids.contains(&t.template_id))
+    .collect();
+
+    let subscriptions: Vec<_> = SubscriptionRepository::find_all(conn)?
+        .into_iter()
+        .filter(|s| session_ids.contains(&s.session_id))
+        .collect();
+    let subscription_ids: HashSet<i64> = subscriptions.iter().map(|s| s.id).collect();
+
+    let quizzes: Vec<QuizExport> = select_all(
+        conn,
+        "SELECT id, session_id, question, correct_answer, options, order_index FROM quizzes ORDER BY id",
+        |row| {
+            Ok(QuizExport {
+                id: row.get(0)?,
+                session_id: row.get(1)?,
+                question: row.get(2)?,
+                correct_answer: row.get(3)?,
+                options: row.get(4)?,
+                order_index: row.get(5)?,
+            })
+        },
+    )?
+    .into_iter()
+    .filter(|q| session_ids.contains(&q.session_id))
+    .collect();
+    let quiz_ids: HashSet<i64> = quizzes.iter().map(|q| q.id).collect();
+
+    let homework: Vec<HomeworkExport> = select_all(
+        conn,
+        "SELECT id, session_id, title, description, due_date, order_index FROM homework ORDER BY id",
+        |row| {
+            Ok(HomeworkExport {
+                id: row.get(0)?,
+                session_id: row.get(1)?,
+                title: row.get(2)?,
+                description: row.get(3)?,
+                due_date: row.get(4)?,
+                order_index: row.get(5)?,
+            })
+        },
+    )?
+    .into_iter()
+    .filter(|h| session_ids.contains(&h.session_id))
+    .collect();
+    let homework_ids: HashSet<i64> = homework.iter().map(|h| h.id).collect();
+
+    let quiz_responses = select_all(
+        conn,
+        "SELECT id, subscription_id, quiz_id, answer, is_correct, answered_at FROM quiz_responses ORDER BY id",
+        |row| {
+            Ok(QuizResponseExport {
+                id: row.get(0)?,
+                subscription_id: row.get(1)?,
+                quiz_id: row.get(2)?,
+                answer: row.get(3)?,
+                is_correct: row.get(4)?,
+                answered_at: row.get(5)?,
+            })
+        },
+    )?
+    .into_iter()
+    .filter(|r| subscription_ids.contains(&r.subscription_id) && quiz_ids.contains(&r.quiz_id))
+    .collect();
+
+    let homework_submissions = select_all(
+        conn,
+        "SELECT id, subscription_id, homework_id, notes, submitted_at FROM homework_submissions ORDER BY id",
+        |row| {
+            Ok(HomeworkSubmissionExport {
+                id: row.get(0)?,
+                subscription_id: row.get(1)?,
+                homework_id: row.get(2)?,
+                notes: row.get(3)?,
+                submitted_at: row.get(4)?,
+            })
+        },
+    )?
+    .into_iter()
+    .filter(|s| subscription_ids.contains(&s.subscription_id) && homework_ids.contains(&s.homework_id))
+    .collect();
+
+    let sent_reminders = select_all(
+        conn,
+        "SELECT id, session_id, user_id, starts_at, email, sent_at FROM sent_reminders ORDER BY id",
+        |row| {
+            Ok(SentReminderExport {
+                id: row.get(0)?,
+                session_id: row.get(1)?,
+                user_id: row.get(2)?,
+                starts_at: row.get(3)?,
+                email: row.get(4)?,
+                sent_at: row.get(5)?,
+            })
+        },
+    )?
+    .into_iter()
+    .filter(|r| session_ids.contains(&r.session_id))
+    .collect();
+
+    // Notifications outlive their session, so one about a session that
+    // isn't exported keeps its message without the link
+    let notifications = select_all(
+        conn,
+        "SELECT id, user_id, session_id, kind, message, created_at, read_at FROM notifications ORDER BY id",
+        |row| {
+            Ok(NotificationExport {
+                id: row.get(0)?,
+                user_id: row.get(1)?,
+                session_id: row.get(2)?,
+                kind: row.get(3)?,
+                message: row.get(4)?,
+                created_at: row.get(5)?,
+                read_at: row.get(6)?,
+            })
+        },
+    )?
+    .into_iter()
+    .map(|mut n| {
+        n.session_id = n.session_id.filter(|id| session_ids.contains(id));
+        n
+    })
+    .collect();
+
+    Ok(ClubExport {
+        format_version: CLUB_EXPORT_VERSION,
+        exported_at: Utc::now(),
+        users: UserRepository::find_all(conn)?,
+        sessions,
+        training_content,
+        templates,
+        session_training_links,
+        subscriptions,
+        session_tags,
+        groups,
+        venues: VenueRepository::find_all_with_courts(conn)?,
+        template_tags,
+        quizzes,
+        homework,
+        quiz_responses,
+        homework_submissions,
+        player_comments: select_all(
+            conn,
+            "SELECT id, player_id, coach_id, body, created_at FROM player_comments ORDER BY id",
+            |row| {
+                Ok(PlayerCommentExport {
+                    id: row.get(0)?,
+                    player_id: row.get(1)?,
+                    coach_id: row.get(2)?,
+                    body: row.get(3)?,
+                    created_at: row.get(4)?,
+                })
+            },
+        )?,
+        skill_level_changes: select_all(
+            conn,
+            "SELECT id, user_id, old_level, new_level, changed_at FROM skill_level_changes ORDER BY id",
+            |row| {
+                Ok(SkillLevelChangeExport {
+                    id: row.get(0)?,
+                    user_id: row.get(1)?,
+                    old_level: row.get(2)?,
+                    new_level: row.get(3)?,
+                    changed_at: row.get(4)?,
+                })
+            },
+        )?,
+        user_keys: select_all(
+            conn,
+            "SELECT id, user_id, key_type, public_key, fingerprint, label, created_at, revoked_at
+             FROM user_keys ORDER BY id",
+            |row| {
+                Ok(UserKeyExport {
+                    id: row.get(0)?,
+                    user_id: row.get(1)?,
+                    key_type: row.get(2)?,
+                    public_key: row.get(3)?,
+                    fingerprint: row.get(4)?,
+                    label: row.get(5)?,
+                    created_at: row.get(6)?,
+                    revoked_at: row.get(7)?,
+                })
+            },
+        )?,
+        api_tokens: select_all(
+            conn,
+            "SELECT id, user_id, token_hash, label, created_at, revoked_at FROM api_tokens ORDER BY id",
+            |row| {
+                Ok(ApiTokenExport {
+                    id: row.get(0)?,
+                    user_id: row.get(1)?,
+                    token_hash: row.get(2)?,
+                    label: row.get(3)?,
+                    created_at: row.get(4)?,
+                    revoked_at: row.get(5)?,
+                })
+            },
+        )?,
+        sent_reminders,
+        notifications,
+    })
+}
+
+/// Check the document is self-consistent: supported version, unique IDs and
+/// usernames, and every reference pointing at an exported row
+pub fn validate(doc: &ClubExport) -> Result<()> {
+    if doc.format_version != CLUB_EXPORT_VERSION {
+        return Err(AppError::Validation(format!(
+            "Unsupported export format version {} (expected {})",
+            doc.format_version, CLUB_EXPORT_VERSION
+        )));
+    }
+
+    let users = unique_ids("users", doc.users.iter().map(|u| u.id))?;
+    let sessions = unique_ids("sessions", doc.sessions.iter().map(|s| s.id))?;
+    let templates = unique_ids("templates", doc.templates.iter().map(|t| t.id))?;
+    unique_ids("training_content", doc.training_content.iter().map(|c| c.id))?;
+    unique_ids("session_training_links", doc.session_training_links.iter().map(|l| l.id))?;
+    let subscriptions = unique_ids("subscriptions", doc.subscriptions.iter().map(|s| s.id))?;
+    let quizzes = unique_ids("quizzes", doc.quizzes.iter().map(|q| q.id))?;
+    let homework = unique_ids("homework", doc.homework.iter().map(|h| h.id))?;
+    unique_ids("quiz_responses", doc.quiz_responses.iter().map(|r| r.id))?;
+    unique_ids("homework_submissions", doc.homework_submissions.iter().map(|s| s.id))?;
+    unique_ids("player_comments", doc.player_comments.iter().map(|c| c.id))?;
+    unique_ids("skill_level_changes", doc.skill_level_changes.iter().map(|c| c.id))?;
+    unique_ids("user_keys", doc.user_keys.iter().map(|k| k.id))?;
+    unique_ids("api_tokens", doc.api_tokens.iter().map(|t| t.id))?;
+    unique_ids("sent_reminders", doc.sent_reminders.iter().map(|r| r.id))?;
+    unique_ids("notifications", doc.notifications.iter().map(|n| n.id))?;
+    unique_ids("groups", doc.groups.iter().map(|g| g.group.id))?;
+    unique_ids("venues", doc.venues.iter().map(|v| v.venue.id))?;
+    let courts = unique_ids("courts", doc.venues.iter().flat_map(|v| v.courts.iter().map(|c| c.id)))?;
+
+    let mut usernames = HashSet::new();
+    for user in &doc.users {
+        if !usernames.insert(user.username.as_str()) {
+            return Err(AppError::Validation(format!("users: duplicate username '{}'", user.username)));
+        }
+    }
+
+    for s in &doc.sessions {
+        check_ref("sessions", s.id, "created_by", s.created_by, &users)?;
+        if let Some(court_id) = s.court_id {
+            check_ref("sessions", s.id, "court_id", court_id, &courts)?;
+        }
+    }
+    for c in &doc.training_content {
+        check_ref("training_content", c.id, "session_id", c.session_id, &sessions)?;
+    }
+    for t in &doc.templates {
+        check_ref("templates", t.id, "coach_id", t.coach_id, &users)?;
+        check_ref("templates", t.id, "created_by", t.created_by, &users)?;
+        if let Some(editor) = t.last_edited_by {
+            check_ref("templates", t.id, "last_edited_by", editor, &users)?;
+        }
+    }
+    for l in &doc.session_training_links {
+        check_ref("session_training_links", l.id, "session_id", l.session_id, &sessions)?;
+        check_ref("session_training_links", l.id, "training_template_id", l.training_template_id, &templates)?;
+    }
+    for s in &doc.subscriptions {
+        check_ref("subscriptions", s.id, "user_id", s.user_id, &users)?;
+        check_ref("subscriptions", s.id, "session_id", s.session_id, &sessions)?;
+    }
+    for t in &doc.session_tags {
+        check_ref("session_tags", t.session_id, "session_id", t.session_id, &sessions)?;
+    }
+    for g in &doc.groups {
+        check_ref("groups", g.group.id, "created_by", g.group.created_by, &users)?;
+        for member in &g.member_ids {
+            check_ref("groups", g.group.id, "member_ids", *member, &users)?;
+        }
+    }
+    for t in &doc.template_tags {
+        check_ref("template_tags", t.template_id, "template_id", t.template_id, &templates)?;
+    }
+    for q in &doc.quizzes {
+        check_ref("quizzes", q.id, "session_id", q.session_id, &sessions)?;
+    }
+    for h in &doc.homework {
+        check_ref("homework", h.id, "session_id", h.session_id, &sessions)?;
+    }
+    for r in &doc.quiz_responses {
+        check_ref("quiz_responses", r.id, "subscription_id", r.subscription_id, &subscriptions)?;
+        check_ref("quiz_responses", r.id, "quiz_id", r.quiz_id, &quizzes)?;
+    }
+    for s in &doc.homework_submissions {
+        check_ref("homework_submissions", s.id, "subscription_id", s.subscription_id, &subscriptions)?;
+        check_ref("homework_submissions", s.id, "homework_id", s.homework_id, &homework)?;
+    }
+    for c in &doc.player_comments {
+        check_ref("player_comments", c.id, "player_id", c.player_id, &users)?;
+        check_ref("player_comments", c.id, "coach_id", c.coach_id, &users)?;
+    }
+    for c in &doc.skill_level_changes {
+        check_ref("skill_level_changes", c.id, "user_id", c.user_id, &users)?;
+    }
+    for k in &doc.user_keys {
+        check_ref("user_keys", k.id, "user_id", k.user_id, &users)?;
+    }
+    for t in &doc.api_tokens {
+        check_ref("api_tokens", t.id, "user_id", t.user_id, &users)?;
+    }
+    for r in &doc.sent_reminders {
+        check_ref("sent_reminders", r.id, "session_id", r.session_id, &sessions)?;
+        check_ref("sent_reminders", r.id, "user_id", r.user_id, &users)?;
+    }
+    for n in &doc.notifications {
+        check_ref("notifications", n.id, "user_id", n.user_id, &users)?;
+        if let Some(session_id) = n.session_id {
+            check_ref("notifications", n.id, "session_id", session_id, &sessions)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Import an export document in a single transaction, remapping every ID.
+///
+/// Nothing is written if validation or any insert fails.
+pub fn import(conn: &Connection, doc: &ClubExport, mode: ImportMode) -> Result<ImportSummary> {
+    validate(doc)?;
+
+    let tx = UnitOfWork::begin(conn)?;
+    if mode == ImportMode::Replace {
+        ensure_nothing_archived(&tx)?;
+    }
+    let suspended = suspend_triggers(&tx)?;
+    if mode == ImportMode::Replace {
+        clear_club_data(&tx)?;
+    }
+
+    let mut summary = ImportSummary::default();
+
+    // users: matched by username
+    let mut user_ids: HashMap<i64, i64> = HashMap::new();
+    let mut added_users: HashSet<i64> = HashSet::new();
+    for user in &doc.users {
+        let existing: Option<i64> = tx
+            .query_row("SELECT id FROM users WHERE username = ?", [&user.username], |row| row.get(0))
+            .optional()?;
+        let new_id = match existing {
+            Some(id) => {
+                summary.users.existing += 1;
+                id
+            }
+            None => {
+                tx.execute(
+                    "INSERT INTO users (username, display_name, role, skill_level, goals, email, created_at, updated_at)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![
+                        user.username,
+                        user.display_name,
+                        user.role.as_str(),
+                        user.skill_level.as_ref().map(|s| s.as_str()),
+                        user.goals,
+                        user.email,
+                        sql_datetime(&user.created_at),
+                        sql_datetime(&user.updated_at),
+                    ],
+                )?;
+                summary.users.added += 1;
+                added_users.insert(user.id);
+                tx.last_insert_rowid()
+            }
+        };
+        user_ids.insert(user.id, new_id);
+    }
+
+    // venues matched by name, their courts by name within the venue
+    let mut court_ids: HashMap<i64, i64> = HashMap::new();
+    for export in &doc.venues {
+        let venue = &export.venue;
+        let existing: Option<i64> = tx
+            .query_row("SELECT id FROM venues WHERE name = ?", [&venue.name], |row| row.get(0))
+            .optional()?;
+        let venue_id = match existing {
+            Some(id) => {
+                summary.venues.existing += 1;
+                id
+            }
+            None => {
+                tx.execute(
+                    "INSERT INTO venues (name, address, created_at) VALUES (?, ?, ?)",
+                    rusqlite::params![venue.name, venue.address, venue.created_at],
+                )?;
+                summary.venues.added += 1;
+                tx.last_insert_rowid()
+            }
+        };
+        for court in &export.courts {
+            let existing: Option<i64> = tx
+                .query_row(
+                    "SELECT id FROM courts WHERE venue_id = ? AND name = ?",
+                    rusqlite::params![venue_id, court.name],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let new_id = match existing {
+                Some(id) => {
+                    summary.courts.existing += 1;
+                    id
+                }
+                None => {
+                    tx.execute(
+                        "INSERT INTO courts (venue_id, name) VALUES (?, ?)",
+                        rusqlite::params![venue_id, court.name],
+                    )?;
+                    summary.courts.added += 1;
+                    tx.last_insert_rowid()
+                }
+            };
+            court_ids.insert(court.id, new_id);
+        }
+    }
+
+    // templates: matched by owner, title and content type
+    let mut template_ids: HashMap<i64, i64> = HashMap::new();
+    let mut added_templates: HashSet<i64> = HashSet::new();
+    for template in &doc.templates {
+        let coach_id = user_ids[&template.coach_id];
+        let existing: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM training_templates WHERE coach_id = ? AND title = ? AND content_type = ?",
+                rusqlite::params![coach_id, template.title, template.content_type],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let new_id = match existing {
+            Some(id) => {
+                summary.templates.existing += 1;
+                id
+            }
+            None => {
+                tx.execute(
+                    "INSERT INTO training_templates (coach_id, title, content_type, description, duration_minutes,
+                                                     created_at, created_by, last_edited_by, last_edited_at, is_public)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![
+                        coach_id,
+                        template.title,
+                        template.content_type,
+                        template.description,
+                        template.duration_minutes,
+                        template.created_at,
+                        user_ids[&template.created_by],
+                        template.last_edited_by.map(|id| user_ids[&id]),
+                        template.last_edited_at,
+                        template.is_public,
+                    ],
+                )?;
+                summary.templates.added += 1;
+                added_templates.insert(template.id);
+                tx.last_insert_rowid()
+            }
+        };
+        template_ids.insert(template.id, new_id);
+    }
+
+    // sessions: matched by creator, title, date and time; the training plan
+    // and tags of a matched session are left as they are
+    let mut session_ids: HashMap<i64, i64> = HashMap::new();
+    let mut added_sessions: HashSet<i64> = HashSet::new();
+    for session in &doc.sessions {
+        let created_by = user_ids[&session.created_by];
+        let date = session.scheduled_date.map(|d| d.format("%Y-%m-%d").to_string());
+        let time = session.scheduled_time.map(|t| t.format("%H:%M:%S").to_string());
+        let existing: Option<i64> = tx
+            .query_row(
+                "SELECT id FROM sessions
+                 WHERE created_by = ? AND title = ? AND scheduled_date IS ? AND scheduled_time IS ?",
+                rusqlite::params![created_by, session.title, date, time],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let new_id = match existing {
+            Some(id) => {
+                summary.sessions.existing += 1;
+                id
+            }
+            None => {
+                tx.execute(
+                    "INSERT INTO sessions (title, description, scheduled_date, scheduled_time, duration_minutes,
+                                           skill_level, created_by, created_at, updated_at,
+                                           cancelled_at, cancel_reason, court_id)
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![
+                        session.title,
+                        session.description,
+                        date,
+                        time,
+                        session.duration_minutes,
+                        session.skill_level.as_ref().map(|s| s.as_str()),
+                        created_by,
+                        sql_datetime(&session.created_at),
+                        sql_datetime(&session.updated_at),
+                        session.cancelled_at.as_ref().map(sql_datetime),
+                        session.cancel_reason,
+                        session.court_id.map(|id| court_ids[&id]),
+                    ],
+                )?;
+                let new_id = tx.last_insert_rowid();
+                // A replace import brings back a club that was already free of
+                // clashes; merged sessions must not double-book the ones here
+                if mode == ImportMode::Merge {
+                    SessionRepository::ensure_slot_free(&tx, new_id).map_err(|e| match e {
+                        AppError::Validation(msg) => AppError::Validation(format!("sessions (id {}): {}", session.id, msg)),
+                        e => e,
+                    })?;
+                }
+                summary.sessions.added += 1;
+                added_sessions.insert(session.id);
+                new_id
+            }
+        };
+        session_ids.insert(session.id, new_id);
+    }
+
+    for content in &doc.training_content {
+        if !added_sessions.contains(&content.session_id) {
+            summary.training_content.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO training_content (session_id, content_type, title, description, duration_minutes, order_index)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            rusqlite::params![
+                session_ids[&content.session_id],
+                content.content_type.as_str(),
+                content.title,
+                content.description,
+                content.duration_minutes,
+                content.order_index,
+            ],
+        )?;
+        summary.training_content.added += 1;
+    }
+
+    for link in &doc.session_training_links {
+        if !added_sessions.contains(&link.session_id) {
+            summary.session_training_links.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO session_training_links (session_id, training_template_id, order_index, custom_notes)
+             VALUES (?, ?, ?, ?)",
+            rusqlite::params![
+                session_ids[&link.session_id],
+                template_ids[&link.training_template_id],
+                link.order_index,
+                link.custom_notes,
+            ],
+        )?;
+        summary.session_training_links.added += 1;
+    }
+
+    for tag in &doc.session_tags {
+        if !added_sessions.contains(&tag.session_id) {
+            summary.session_tags.existing += 1;
+            continue;
+        }
+        let tag_id = TagRepository::find_or_create(&tx, &tag.tag)?;
+        tx.execute(
+            "INSERT OR IGNORE INTO session_tags (session_id, tag_id) VALUES (?, ?)",
+            rusqlite::params![session_ids[&tag.session_id], tag_id],
+        )?;
+        summary.session_tags.added += 1;
+    }
+
+    // subscriptions: one per player and session
+    let mut subscription_ids: HashMap<i64, i64> = HashMap::new();
+    let mut added_subscriptions: HashSet<i64> = HashSet::new();
+    for sub in &doc.subscriptions {
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO subscriptions (user_id, session_id, subscribed_at, completed_at, status, notes)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            rusqlite::params![
+                user_ids[&sub.user_id],
+                session_ids[&sub.session_id],
+                sql_datetime(&sub.subscribed_at),
+                sub.completed_at.as_ref().map(sql_datetime),
+                sub.status.as_str(),
+                sub.notes,
+            ],
+        )?;
+        let new_id = if inserted == 0 {
+            summary.subscriptions.existing += 1;
+            tx.query_row(
+                "SELECT id FROM subscriptions WHERE user_id = ? AND session_id = ?",
+                rusqlite::params![user_ids[&sub.user_id], session_ids[&sub.session_id]],
+                |row| row.get(0),
+            )?
+        } else {
+            summary.subscriptions.added += 1;
+            added_subscriptions.insert(sub.id);
+            tx.last_insert_rowid()
+        };
+        subscription_ids.insert(sub.id, new_id);
+    }
+
+    // groups: matched by name, members are added to existing groups
+    for export in &doc.groups {
+        let group = &export.group;
+        let existing: Option<i64> = tx
+            .query_row("SELECT id FROM player_groups WHERE name = ?", [&group.name], |row| row.get(0))
+            .optional()?;
+        let group_id = match existing {
+            Some(id) => {
+                summary.groups.existing += 1;
+                id
+            }
+            None => {
+                tx.execute(
+                    "INSERT INTO player_groups (name, description, created_by, created_at) VALUES (?, ?, ?, ?)",
+                    rusqlite::params![group.name, group.description, user_ids[&group.created_by], group.created_at],
+                )?;
+                summary.groups.added += 1;
+                tx.last_insert_rowid()
+            }
+        };
+        for member in &export.member_ids {
+            tx.execute(
+                "INSERT OR IGNORE INTO group_members (group_id, user_id) VALUES (?, ?)",
+                rusqlite::params![group_id, user_ids[member]],
+            )?;
+        }
+    }
+
+    for tag in &doc.template_tags {
+        if !added_templates.contains(&tag.template_id) {
+            summary.template_tags.existing += 1;
+            continue;
+        }
+        let tag_id = TagRepository::find_or_create(&tx, &tag.tag)?;
+        tx.execute(
+            "INSERT OR IGNORE INTO template_tags (template_id, tag_id) VALUES (?, ?)",
+            rusqlite::params![template_ids[&tag.template_id], tag_id],
+        )?;
+        summary.template_tags.added += 1;
+    }
+
+    // quizzes and homework come with their session, answers and submissions
+    // with their subscription
+    let mut quiz_ids: HashMap<i64, i64> = HashMap::new();
+    for quiz in &doc.quizzes {
+        if !added_sessions.contains(&quiz.session_id) {
+            summary.quizzes.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO quizzes (session_id, question, correct_answer, options, order_index) VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                session_ids[&quiz.session_id],
+                quiz.question,
+                quiz.correct_answer,
+                quiz.options,
+                quiz.order_index,
+            ],
+        )?;
+        summary.quizzes.added += 1;
+        quiz_ids.insert(quiz.id, tx.last_insert_rowid());
+    }
+
+    let mut homework_ids: HashMap<i64, i64> = HashMap::new();
+    for homework in &doc.homework {
+        if !added_sessions.contains(&homework.session_id) {
+            summary.homework.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO homework (session_id, title, description, due_date, order_index) VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                session_ids[&homework.session_id],
+                homework.title,
+                homework.description,
+                homework.due_date,
+                homework.order_index,
+            ],
+        )?;
+        summary.homework.added += 1;
+        homework_ids.insert(homework.id, tx.last_insert_rowid());
+    }
+
+    for response in &doc.quiz_responses {
+        let Some(quiz_id) = quiz_ids.get(&response.quiz_id) else {
+            summary.quiz_responses.existing += 1;
+            continue;
+        };
+        if !added_subscriptions.contains(&response.subscription_id) {
+            summary.quiz_responses.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO quiz_responses (subscription_id, quiz_id, answer, is_correct, answered_at) VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                subscription_ids[&response.subscription_id],
+                quiz_id,
+                response.answer,
+                response.is_correct,
+                response.answered_at,
+            ],
+        )?;
+        summary.quiz_responses.added += 1;
+    }
+
+    for submission in &doc.homework_submissions {
+        let Some(homework_id) = homework_ids.get(&submission.homework_id) else {
+            summary.homework_submissions.existing += 1;
+            continue;
+        };
+        if !added_subscriptions.contains(&submission.subscription_id) {
+            summary.homework_submissions.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO homework_submissions (subscription_id, homework_id, notes, submitted_at) VALUES (?, ?, ?, ?)",
+            rusqlite::params![
+                subscription_ids[&submission.subscription_id],
+                homework_id,
+                submission.notes,
+                submission.submitted_at,
+            ],
+        )?;
+        summary.homework_submissions.added += 1;
+    }
+
+    // A player's comments, skill history and inbox come with the player
+    for comment in &doc.player_comments {
+        if !added_users.contains(&comment.player_id) {
+            summary.player_comments.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO player_comments (player_id, coach_id, body, created_at) VALUES (?, ?, ?, ?)",
+            rusqlite::params![user_ids[&comment.player_id], user_ids[&comment.coach_id], comment.body, comment.created_at],
+        )?;
+        summary.player_comments.added += 1;
+    }
+
+    for change in &doc.skill_level_changes {
+        if !added_users.contains(&change.user_id) {
+            summary.skill_level_changes.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO skill_level_changes (user_id, old_level, new_level, changed_at) VALUES (?, ?, ?, ?)",
+            rusqlite::params![user_ids[&change.user_id], change.old_level, change.new_level, change.changed_at],
+        )?;
+        summary.skill_level_changes.added += 1;
+    }
+
+    for notification in &doc.notifications {
+        if !added_users.contains(&notification.user_id) {
+            summary.notifications.existing += 1;
+            continue;
+        }
+        tx.execute(
+            "INSERT INTO notifications (user_id, session_id, kind, message, created_at, read_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            rusqlite::params![
+                user_ids[&notification.user_id],
+                notification.session_id.map(|id| session_ids[&id]),
+                notification.kind,
+                notification.message,
+                notification.created_at,
+                notification.read_at,
+            ],
+        )?;
+        summary.notifications.added += 1;
+    }
+
+    // keys, tokens and reminders: matched by fingerprint, token hash and
+    // (session, player, start)
+    for key in &doc.user_keys {
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO user_keys (user_id, key_type, public_key, fingerprint, label, created_at, revoked_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            rusqlite::params![
+                user_ids[&key.user_id],
+                key.key_type,
+                key.public_key,
+                key.fingerprint,
+                key.label,
+                key.created_at,
+                key.revoked_at,
+            ],
+        )?;
+        if inserted == 0 {
+            summary.user_keys.existing += 1;
+        } else {
+            summary.user_keys.added += 1;
+        }
+    }
+
+    for token in &doc.api_tokens {
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO api_tokens (user_id, token_hash, label, created_at, revoked_at) VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![user_ids[&token.user_id], token.token_hash, token.label, token.created_at, token.revoked_at],
+        )?;
+        if inserted == 0 {
+            summary.api_tokens.existing += 1;
+        } else {
+            summary.api_tokens.added += 1;
+        }
+    }
+
+    for reminder in &doc.sent_reminders {
+        let inserted = tx.execute(
+            "INSERT OR IGNORE INTO sent_reminders (session_id, user_id, starts_at, email, sent_at) VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                session_ids[&reminder.session_id],
+                user_ids[&reminder.user_id],
+                reminder.starts_at,
+                reminder.email,
+                reminder.sent_at,
+            ],
+        )?;
+        if inserted == 0 {
+            summary.sent_reminders.existing += 1;
+        } else {
+            summary.sent_reminders.added += 1;
+        }
+    }
+
+    resume_triggers(&tx, &suspended)?;
+    tx.commit()?;
+    Ok(summary)
+}
+
+/// Switch off the triggers that would report imported rows as new activity:
+/// the change history (and the webhook events it feeds) and the inbox.
+/// Returns the dropped inbox and webhook triggers for [`resume_triggers`].
+///
+/// Trigger changes are part of the transaction, so a failed import that
+/// rolls back gets them back as well.
+fn suspend_triggers(conn: &Connection) -> Result<Vec<(String, String)>> {
+    let triggers = select_all(
+        conn,
+        "SELECT name, sql FROM main.sqlite_master
+         WHERE type = 'trigger' AND (name GLOB 'notify_*' OR name GLOB 'webhook_*')",
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+    for (name, _) in &triggers {
+        conn.execute_batch(&format!("DROP TRIGGER main.{}", name))?;
+    }
+    AuditRepository::drop_triggers(conn)?;
+    Ok(triggers)
+}
+
+fn resume_triggers(conn: &Connection, triggers: &[(String, String)]) -> Result<()> {
+    for (_, sql) in triggers {
+        conn.execute_batch(sql)?;
+    }
+    AuditRepository::install_triggers(conn)
+}
+
+/// A replace import deletes archived rows too, and the export it restores
+/// doesn't have them, so they would be lost for good
+fn ensure_nothing_archived(conn: &Connection) -> Result<()> {
+    let archived: i64 = conn.query_row(
+        "SELECT (SELECT COUNT(*) FROM sessions WHERE archived_at IS NOT NULL)
+              + (SELECT COUNT(*) FROM training_content WHERE archived_at IS NOT NULL)
+              + (SELECT COUNT(*) FROM training_templates WHERE archived_at IS NOT NULL)",
+        [],
+        |row| row.get(0),
+    )?;
+    if archived > 0 {
+        return Err(AppError::Validation(format!(
+            "{} archived item(s) would be lost; restore or purge them before a replace import",
+            archived
+        )));
+    }
+    Ok(())
+}
+
+/// Delete every row of club data, children before parents. The change
+/// history goes too: its row IDs would name different rows after the import.
+fn clear_club_data(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "DELETE FROM audit_log;
+         DELETE FROM notifications;
+         DELETE FROM player_comments;
+         DELETE FROM skill_level_changes;
+         DELETE FROM group_members;
+         DELETE FROM player_groups;
+         DELETE FROM session_tags;
+         DELETE FROM template_tags;
+         DELETE FROM tags;
+         DELETE FROM quiz_responses;
+         DELETE FROM homework_submissions;
+         DELETE FROM sent_reminders;
+         DELETE FROM subscriptions;
+         DELETE FROM session_training_links;
+         DELETE FROM training_content;
+         DELETE FROM quizzes;
+         DELETE FROM homework;
+         DELETE FROM sessions;
+         DELETE FROM courts;
+         DELETE FROM venues;
+         DELETE FROM training_templates;
+         DELETE FROM user_keys;
+         DELETE FROM api_tokens;
+         DELETE FROM users;",
+    )?;
+    Ok(())
+}
+
+fn unique_ids(table: &str, ids: impl Iterator<Item = i64>) -> Result<HashSet<i64>> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) {
+            return Err(AppError::Validation(format!("{}: duplicate id {}", table, id)));
+        }
+    }
+    Ok(seen)
+}
+
+fn check_ref(table: &str, id: i64, field: &str, value: i64, known: &HashSet<i64>) -> Result<()> {
+    if known.contains(&value) {
+        Ok(())
+    } else {
+        Err(AppError::Validation(format!(
+            "{} (id {}): {} {} does not match any exported row",
+            table, id, field, value
+        )))
+    }
+}
+
+fn select_all<T>(conn: &Connection, sql: &str, map: impl FnMut(&rusqlite::Row) -> rusqlite::Result<T>) -> Result<Vec<T>> {
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt.query_map([], map)?.collect::<rusqlite::Result<Vec<T>>>()?;
+    Ok(rows)
+}
+
+fn sql_datetime(value: &DateTime<Utc>) -> String {
+    value.format("%Y-%m-%d %H:%M:%S").to_string()
+}
@@ -1,6 +1,12 @@
+use crate::db::repositories::AuditRepository;
 use crate::utils::Result;
 use rusqlite::Connection;
 use std::path::Path;
+use std::time::Duration;
+
+/// How long a statement waits for another connection's write to finish
+/// before failing with "database is busy"
+pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
 
 pub fn establish_connection(db_path: &str) -> Result<Connection> {
     let db_path = Path::new(db_path);
@@ -14,5 +20,26 @@ pub fn establish_connection(db_path: &str) -> Result<Connection> {
     // Enable foreign key constraints
     conn.execute("PRAGMA foreign_keys = ON", [])?;
 
+    // Concurrent users: readers don't block the writer (WAL), and writers
+    // queue for each other instead of failing straight away
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
+
+    // Record changes in the audit log (once migrations have created it)
+    let has_audit_log: bool = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'audit_log')",
+        [],
+        |row| row.get(0),
+    )?;
+    if has_audit_log {
+        AuditRepository::install_triggers(&conn)?;
+    }
+
     Ok(conn)
 }
+
+/// Counter that changes whenever another connection commits to the database.
+/// Writes made through `conn` itself leave it unchanged.
+pub fn data_version(conn: &Connection) -> Result<i64> {
+    Ok(conn.query_row("PRAGMA data_version", [], |row| row.get(0))?)
+}
@@ -1,4 +1,6 @@
 use rusqlite::Connection;
+use crate::db::UnitOfWork;
+use crate::db::repositories::AuditRepository;
 use crate::utils::Result;
 
 pub fn run_migrations(conn: &Connection) -> Result<()> {
@@ -124,6 +126,163 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
 		[]
 	)?;
 
+	// create training templates and session links (global template library)
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-01-29-000001_create_training_templates.sql"
+	))?;
+
+	// create player groups table
+	conn.execute(
+		"CREATE TABLE IF NOT EXISTS player_groups (
+			id INTEGER PRIMARY KEY AUTOINCREMENT,
+			name TEXT UNIQUE NOT NULL,
+			description TEXT,
+			created_by INTEGER NOT NULL,
+			created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+			FOREIGN KEY (created_by) REFERENCES users(id)
+		)",
+		[]
+	)?;
+
+	// create group membership table
+	conn.execute(
+		"CREATE TABLE IF NOT EXISTS group_members (
+			id INTEGER PRIMARY KEY AUTOINCREMENT,
+			group_id INTEGER NOT NULL,
+			user_id INTEGER NOT NULL,
+			added_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+			FOREIGN KEY (group_id) REFERENCES player_groups(id) ON DELETE CASCADE,
+			FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+			UNIQUE(group_id, user_id)
+		)",
+		[]
+	)?;
+
+	// create tags table (shared by sessions and templates)
+	conn.execute(
+		"CREATE TABLE IF NOT EXISTS tags (
+			id INTEGER PRIMARY KEY AUTOINCREMENT,
+			name TEXT UNIQUE NOT NULL COLLATE NOCASE
+		)",
+		[]
+	)?;
+
+	// create session tags junction table
+	conn.execute(
+		"CREATE TABLE IF NOT EXISTS session_tags (
+			session_id INTEGER NOT NULL,
+			tag_id INTEGER NOT NULL,
+			PRIMARY KEY (session_id, tag_id),
+			FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE,
+			FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+		)",
+		[]
+	)?;
+
+	// create template tags junction table
+	conn.execute(
+		"CREATE TABLE IF NOT EXISTS template_tags (
+			template_id INTEGER NOT NULL,
+			tag_id INTEGER NOT NULL,
+			PRIMARY KEY (template_id, tag_id),
+			FOREIGN KEY (template_id) REFERENCES training_templates(id) ON DELETE CASCADE,
+			FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+		)",
+		[]
+	)?;
+
+	// create full-text search index and the triggers keeping it in sync
+	let search_index_exists: bool = conn.query_row(
+		"SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'search_index')",
+		[],
+		|row| row.get(0),
+	)?;
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000001_create_search_index.sql"
+	))?;
+	if !search_index_exists {
+		backfill_search_index(conn)?;
+	}
+
+	// create skill level history and coach comments for progress reports
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000002_create_progress_tracking.sql"
+	))?;
+
+	// soft-delete: archived rows are hidden until restored or purged
+	for table in ["sessions", "training_content", "training_templates"] {
+		add_column_if_missing(conn, table, "archived_at", "DATETIME")?;
+	}
+
+	// create the change history; its triggers are per connection
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000003_create_audit_log.sql"
+	))?;
+
+	// create SSH keys for AuthorizedKeysCommand logins
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000004_create_user_keys.sql"
+	))?;
+
+	// create bearer tokens for the REST API
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000005_create_api_tokens.sql"
+	))?;
+
+	// create webhooks; their outbox is filled from the change history
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000006_create_webhooks.sql"
+	))?;
+	// per-webhook secrets for signing deliveries; existing webhooks get a random one
+	add_column_if_missing(conn, "webhooks", "secret", "TEXT")?;
+	conn.execute(
+		"UPDATE webhooks SET secret = lower(hex(randomblob(32))) WHERE secret IS NULL",
+		[],
+	)?;
+
+	// email addresses and the log of sent session reminders
+	add_column_if_missing(conn, "users", "email", "TEXT")?;
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000007_create_sent_reminders.sql"
+	))?;
+
+	// create the notification inbox; its triggers fill it from session changes
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000008_create_notifications.sql"
+	))?;
+
+	// session cancellation: cancelled sessions keep their subscriptions
+	add_column_if_missing(conn, "sessions", "cancelled_at", "DATETIME")?;
+	add_column_if_missing(conn, "sessions", "cancel_reason", "TEXT")?;
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000009_cancel_sessions.sql"
+	))?;
+
+	// venues and courts; a session is booked on at most one court
+	conn.execute_batch(include_str!(
+		"../../migrations/2026-10-18-000010_create_venues.sql"
+	))?;
+	add_column_if_missing(
+		conn,
+		"sessions",
+		"court_id",
+		"INTEGER REFERENCES courts(id) ON DELETE SET NULL",
+	)?;
+
+	// notifications of cancelled sessions: rebuild the table for the wider CHECK
+	let notifications_sql: String = conn.query_row(
+		"SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'notifications'",
+		[],
+		|row| row.get(0),
+	)?;
+	if !notifications_sql.contains("'session_cancelled'") {
+		let tx = UnitOfWork::begin(conn)?;
+		tx.execute_batch(include_str!(
+			"../../migrations/2026-10-18-000011_notify_cancelled_sessions.sql"
+		))?;
+		tx.commit()?;
+	}
+
 	// create indexes
 	conn.execute(
 		"CREATE INDEX IF NOT EXISTS idx_sessions_created_by ON sessions(created_by)",
@@ -133,6 +292,10 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
 		"CREATE INDEX IF NOT EXISTS idx_sessions_date ON sessions(scheduled_date)",
 		[],
 	)?;
+	conn.execute(
+		"CREATE INDEX IF NOT EXISTS idx_sessions_court ON sessions(court_id)",
+		[],
+	)?;
 	conn.execute(
 		"CREATE INDEX IF NOT EXISTS idx_subscriptions_user ON subscriptions(user_id)",
 		[],
@@ -153,6 +316,57 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
 		"CREATE INDEX IF NOT EXISTS idx_homework_session ON homework(session_id)",
 		[],
 	)?;
+	conn.execute(
+		"CREATE INDEX IF NOT EXISTS idx_group_members_group ON group_members(group_id)",
+		[],
+	)?;
+	conn.execute(
+		"CREATE INDEX IF NOT EXISTS idx_group_members_user ON group_members(user_id)",
+		[],
+	)?;
+	conn.execute(
+		"CREATE INDEX IF NOT EXISTS idx_session_tags_tag ON session_tags(tag_id)",
+		[],
+	)?;
+	conn.execute(
+		"CREATE INDEX IF NOT EXISTS idx_template_tags_tag ON template_tags(tag_id)",
+		[],
+	)?;
+	conn.execute(
+		"CREATE INDEX IF NOT EXISTS idx_sessions_archived ON sessions(archived_at) WHERE archived_at IS NOT NULL",
+		[],
+	)?;
+
+	AuditRepository::install_triggers(conn)?;
+
+	Ok(())
+}
 
+/// `ALTER TABLE ... ADD COLUMN` for databases created before the column existed
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, definition: &str) -> Result<()> {
+	let exists: bool = conn.query_row(
+		"SELECT EXISTS(SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2)",
+		[table, column],
+		|row| row.get(0),
+	)?;
+	if !exists {
+		conn.execute(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition), [])?;
+	}
+	Ok(())
+}
+
+/// Index rows that existed before the search index was created
+fn backfill_search_index(conn: &Connection) -> Result<()> {
+	conn.execute_batch(
+		"INSERT INTO search_index (kind, ref_id, session_id, title, body)
+			SELECT 'session', id, id, title, COALESCE(description, '') FROM sessions;
+		INSERT INTO search_index (kind, ref_id, session_id, title, body)
+			SELECT 'content', id, session_id, title, COALESCE(description, '') FROM training_content;
+		INSERT INTO search_index (kind, ref_id, session_id, title, body)
+			SELECT 'template', id, NULL, title, COALESCE(description, '') FROM training_templates;
+		INSERT INTO search_index (kind, ref_id, session_id, title, body)
+			SELECT 'note', id, session_id, '', notes FROM subscriptions
+			WHERE notes IS NOT NULL AND notes != '';"
+	)?;
 	Ok(())
-}
\ No newline at end of file
+}
@@ -1,6 +1,10 @@
+pub mod backup;
 pub mod connection;
 pub mod migrations;
+pub mod player_import;
 pub mod repositories;
+pub mod unit_of_work;
 
 pub use connection::establish_connection;
 pub use migrations::run_migrations;
+pub use unit_of_work::{atomically, UnitOfWork};
@@ -0,0 +1,285 @@
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::io::Read;
+
+use crate::db::repositories::{GroupRepository, UserRepository};
+use crate::db::UnitOfWork;
+use crate::models::{ImportedPlayer, PlayerImportSummary, PlayerRow, RowError, SkillLevel, UserRole};
+use crate::utils::{validate_email, AppError, Result};
+
+/// Install path of the binary used in generated `authorized_keys` lines (DESIGN.md §8.3)
+pub const DEFAULT_BIN_PATH: &str = "/home/tennis-tui/bin/tennis-tui";
+
+const REQUIRED_COLUMNS: [&str; 2] = ["username", "display_name"];
+const OPTIONAL_COLUMNS: [&str; 6] = ["role", "skill_level", "goals", "email", "group", "ssh_key"];
+const SSH_KEY_TYPES: [&str; 7] = [
+    "ssh-ed25519",
+    "ssh-rsa",
+    "ecdsa-sha2-nistp256",
+    "ecdsa-sha2-nistp384",
+    "ecdsa-sha2-nistp521",
+    "sk-ssh-ed25519@openssh.com",
+    "sk-ecdsa-sha2-nistp256@openssh.com",
+];
+
+/// Parse and validate a player CSV against the database.
+///
+/// Returns the rows that passed validation together with every row-level
+/// error; nothing is written.
+pub fn check(conn: &Connection, input: impl Read) -> Result<(Vec<PlayerRow>, Vec<RowError>)> {
+    let (rows, mut errors) = parse(input);
+
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for row in &rows {
+        if let Some(first_line) = seen.insert(&row.username, row.line) {
+            errors.push(RowError {
+                line: row.line,
+                message: format!("username '{}' already used on line {}", row.username, first_line),
+            });
+        } else if UserRepository::find_by_username(conn, &row.username)?.is_some() {
+            errors.push(RowError {
+                line: row.line,
+                message: format!("username '{}' already exists", row.username),
+            });
+        }
+    }
+
+    errors.sort_by_key(|e| e.line);
+    Ok((rows, errors))
+}
+
+/// Import players from CSV. Any invalid row rejects the whole file.
+/// Groups named in the file are created (owned by `coach_id`) if missing.
+pub fn import(conn: &Connection, input: impl Read, coach_id: i64, bin_path: &str) -> Result<PlayerImportSummary> {
+    let (rows, errors) = check(conn, input)?;
+    if !errors.is_empty() {
+        let lines: Vec<String> = errors.iter().map(|e| format!("  {}", e)).collect();
+        return Err(AppError::Validation(format!(
+            "{} problem(s) found, nothing imported:\n{}",
+            errors.len(),
+            lines.join("\n")
+        )));
+    }
+    if rows.is_empty() {
+        return Err(AppError::Validation("No players in file".to_string()));
+    }
+
+    let tx = UnitOfWork::begin(conn)?;
+    let mut group_ids: HashMap<String, i64> = HashMap::new();
+    let mut groups_created = Vec::new();
+    let mut players = Vec::new();
+
+    for row in &rows {
+        let id = UserRepository::create(
+            &tx,
+            &row.username,
+            &row.display_name,
+            &row.role,
+            row.skill_level.as_ref(),
+            row.goals.as_deref(),
+        )?;
+        if let Some(email) = &row.email {
+            UserRepository::set_email(&tx, id, Some(email))?;
+        }
+
+        if let Some(name) = &row.group {
+            let group_id = match group_ids.get(name) {
+                Some(group_id) => *group_id,
+                None => {
+                    let group_id = match GroupRepository::find_by_name(&tx, name)? {
+                        Some(group) => group.id,
+                        None => {
+                            groups_created.push(name.clone());
+                            GroupRepository::create(&tx, name, None, coach_id)?
+                        }
+                    };
+                    group_ids.insert(name.clone(), group_id);
+                    group_id
+                }
+            };
+            GroupRepository::add_member(&tx, group_id, id)?;
+        }
+
+        players.push(ImportedPlayer {
+            id,
+            username: row.username.clone(),
+            display_name: row.display_name.clone(),
+            role: row.role.clone(),
+            skill_level: row.skill_level.clone(),
+            group: row.group.clone(),
+            authorized_keys_line: authorized_keys_line(&row.username, row.ssh_key.as_deref(), bin_path),
+        });
+    }
+
+    tx.commit()?;
+    Ok(PlayerImportSummary { players, groups_created })
+}
+
+/// `authorized_keys` entry forcing the TUI for `username` (DESIGN.md §8.3).
+/// Without a key, a placeholder is written for the admin to fill in.
+pub fn authorized_keys_line(username: &str, ssh_key: Option<&str>, bin_path: &str) -> String {
+    let key = match ssh_key {
+        Some(key) => key.split_whitespace().take(2).collect::<Vec<_>>().join(" "),
+        None => "ssh-ed25519 <public-key>".to_string(),
+    };
+    format!(
+        "command=\"{} --user={}\",no-pty,no-port-forwarding,no-X11-forwarding {} {}",
+        bin_path, username, key, username
+    )
+}
+
+/// Usernames end up in forced SSH commands, so only allow a conservative charset
+pub fn validate_username(username: &str) -> std::result::Result<(), String> {
+    if username.is_empty() {
+        return Err("username is required".to_string());
+    }
+    if username.len() > 32 {
+        return Err(format!("username '{}' is longer than 32 characters", username));
+    }
+    let valid_start = username.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit());
+    let valid_chars = username
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.'));
+    if !valid_start || !valid_chars {
+        return Err(format!(
+            "username '{}' may only contain lowercase letters, digits, '_', '-' and '.', and must start with a letter or digit",
+            username
+        ));
+    }
+    Ok(())
+}
+
+fn parse(input: impl Read) -> (Vec<PlayerRow>, Vec<RowError>) {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(input);
+
+    let header_error = |message: String| (Vec::new(), vec![RowError { line: 1, message }]);
+    let headers = match reader.headers() {
+        Ok(headers) => headers.clone(),
+        Err(e) => return header_error(format!("could not read header: {}", e)),
+    };
+
+    let mut columns: HashMap<String, usize> = HashMap::new();
+    for (index, header) in headers.iter().enumerate() {
+        let name = header.to_lowercase().replace(' ', "_");
+        if !REQUIRED_COLUMNS.contains(&name.as_str()) && !OPTIONAL_COLUMNS.contains(&name.as_str()) {
+            return header_error(format!("unknown column '{}'", header));
+        }
+        columns.insert(name, index);
+    }
+    for required in REQUIRED_COLUMNS {
+        if !columns.contains_key(required) {
+            return header_error(format!("missing column '{}'", required));
+        }
+    }
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|p| p.line() as usize).unwrap_or(index + 2);
+                errors.push(RowError { line, message: format!("unreadable row: {}", e) });
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line() as usize).unwrap_or(index + 2);
+        let field = |name: &str| {
+            columns
+                .get(name)
+                .and_then(|i| record.get(*i))
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string())
+        };
+
+        match parse_row(line, field) {
+            Ok(row) => rows.push(row),
+            Err(messages) => errors.extend(messages.into_iter().map(|message| RowError { line, message })),
+        }
+    }
+
+    (rows, errors)
+}
+
+fn parse_row(line: usize, field: impl Fn(&str) -> Option<String>) -> std::result::Result<PlayerRow, Vec<String>> {
+    let mut messages = Vec::new();
+
+    let username = field("username").unwrap_or_default();
+    if let Err(message) = validate_username(&username) {
+        messages.push(message);
+    }
+
+    let display_name = field("display_name").unwrap_or_default();
+    if display_name.is_empty() {
+        messages.push("display name is required".to_string());
+    } else if display_name.len() > 100 {
+        messages.push("display name must be less than 100 characters".to_string());
+    }
+
+    let role = match field("role") {
+        None => Some(UserRole::Player),
+        Some(value) => {
+            let role = UserRole::from_str(&value);
+            if role.is_none() {
+                messages.push(format!("invalid role '{}' (expected player or coach)", value));
+            }
+            role
+        }
+    };
+
+    let skill_level = field("skill_level").and_then(|value| {
+        let level = SkillLevel::from_str(&value);
+        if level.is_none() {
+            messages.push(format!(
+                "invalid skill level '{}' (expected beginner, intermediate or advanced)",
+                value
+            ));
+        }
+        level
+    });
+
+    let email = field("email");
+    if let Some(Err(message)) = email.as_deref().map(validate_email) {
+        messages.push(message);
+    }
+
+    let group = field("group");
+    if let Some(name) = &group {
+        if name.len() < 2 || name.len() > 50 {
+            messages.push(format!("group name '{}' must be 2 to 50 characters", name));
+        }
+        if role == Some(UserRole::Coach) {
+            messages.push("coaches can't be added to player groups".to_string());
+        }
+    }
+
+    let ssh_key = field("ssh_key");
+    if let Some(key) = &ssh_key {
+        let mut parts = key.split_whitespace();
+        let key_type = parts.next().unwrap_or_default();
+        let valid_data = parts
+            .next()
+            .is_some_and(|data| data.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=')));
+        if !SSH_KEY_TYPES.contains(&key_type) || !valid_data {
+            messages.push("ssh_key is not an OpenSSH public key".to_string());
+        }
+    }
+
+    match role {
+        Some(role) if messages.is_empty() => Ok(PlayerRow {
+            line,
+            username,
+            display_name,
+            role,
+            skill_level,
+            goals: field("goals"),
+            email,
+            group,
+            ssh_key,
+        }),
+        _ => Err(messages),
+    }
+}
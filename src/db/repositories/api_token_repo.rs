@@ -0,0 +1,79 @@
+use crate::auth::token;
+use crate::models::{ApiToken, User};
+use crate::utils::{AppError, Result};
+use rusqlite::{Connection, Row};
+
+use super::UserRepository;
+
+pub struct ApiTokenRepository;
+
+impl ApiTokenRepository {
+    /// Issue a token for a user. Returns its ID and the token, which can't
+    /// be recovered later.
+    pub fn create(conn: &Connection, user_id: i64, label: Option<&str>) -> Result<(i64, String)> {
+        let token = token::generate()?;
+        conn.execute(
+            "INSERT INTO api_tokens (user_id, token_hash, label, created_at) VALUES (?, ?, ?, datetime('now'))",
+            rusqlite::params![user_id, token::hash(&token), label],
+        )?;
+
+        Ok((conn.last_insert_rowid(), token))
+    }
+
+    /// The owner of an active token
+    pub fn authenticate(conn: &Connection, token: &str) -> Result<Option<User>> {
+        let user_id = match conn.query_row(
+            "SELECT user_id FROM api_tokens WHERE token_hash = ? AND revoked_at IS NULL",
+            [token::hash(token)],
+            |row| row.get::<_, i64>(0),
+        ) {
+            Ok(user_id) => user_id,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        UserRepository::find_by_id(conn, user_id)
+    }
+
+    /// Every token, revoked ones included, ordered by username
+    pub fn find_all(conn: &Connection) -> Result<Vec<ApiToken>> {
+        let mut stmt = conn.prepare(
+            "SELECT t.id, t.user_id, u.username, t.label, t.created_at, t.revoked_at
+             FROM api_tokens t
+             JOIN users u ON u.id = t.user_id
+             ORDER BY u.username ASC, t.id ASC",
+        )?;
+
+        let tokens = stmt.query_map([], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for token in tokens {
+            results.push(token?);
+        }
+
+        Ok(results)
+    }
+
+    /// Stop a token from authenticating
+    pub fn revoke(conn: &Connection, id: i64) -> Result<()> {
+        let revoked = conn.execute(
+            "UPDATE api_tokens SET revoked_at = datetime('now') WHERE id = ? AND revoked_at IS NULL",
+            [id],
+        )?;
+        if revoked == 0 {
+            return Err(AppError::NotFound(format!("Active token {}", id)));
+        }
+        Ok(())
+    }
+
+    fn map_row(row: &Row) -> rusqlite::Result<ApiToken> {
+        Ok(ApiToken {
+            id: row.get(0)?,
+            user_id: row.get(1)?,
+            username: row.get(2)?,
+            label: row.get(3)?,
+            created_at: row.get(4)?,
+            revoked_at: row.get(5)?,
+        })
+    }
+}
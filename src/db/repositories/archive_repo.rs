@@ -0,0 +1,112 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rusqlite::{Connection, Row};
+
+use crate::db::UnitOfWork;
+use crate::db::repositories::SessionRepository;
+use crate::models::{ArchiveKind, ArchivedItem};
+use crate::utils::{AppError, Result};
+
+/// Soft-delete of sessions, training content and templates.
+///
+/// Archived rows keep their data and relations (content, subscriptions,
+/// links) but are hidden from every other query until restored. Purging
+/// deletes them for good, cascading through foreign keys.
+pub struct ArchiveRepository;
+
+impl ArchiveRepository {
+    /// Move a row to the archive
+    pub fn archive(conn: &Connection, kind: ArchiveKind, id: i64) -> Result<()> {
+        let archived = conn.execute(
+            &format!(
+                "UPDATE {} SET archived_at = datetime('now') WHERE id = ? AND archived_at IS NULL",
+                kind.table()
+            ),
+            [id],
+        )?;
+        if archived == 0 {
+            return Err(AppError::NotFound(format!("{} {}", kind.label(), id)));
+        }
+        Ok(())
+    }
+
+    /// Bring an archived row back. A session is refused if its slot has been
+    /// booked again in the meantime.
+    pub fn restore(conn: &Connection, kind: ArchiveKind, id: i64) -> Result<()> {
+        let tx = UnitOfWork::begin(conn)?;
+        let restored = tx.execute(
+            &format!(
+                "UPDATE {} SET archived_at = NULL WHERE id = ? AND archived_at IS NOT NULL",
+                kind.table()
+            ),
+            [id],
+        )?;
+        if restored == 0 {
+            return Err(AppError::NotFound(format!("Archived {} {}", kind.as_str(), id)));
+        }
+        if kind == ArchiveKind::Session {
+            SessionRepository::ensure_slot_free(&tx, id)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Permanently delete an archived row (only archived rows can be purged)
+    pub fn purge(conn: &Connection, kind: ArchiveKind, id: i64) -> Result<()> {
+        let purged = conn.execute(
+            &format!("DELETE FROM {} WHERE id = ? AND archived_at IS NOT NULL", kind.table()),
+            [id],
+        )?;
+        if purged == 0 {
+            return Err(AppError::NotFound(format!("Archived {} {}", kind.as_str(), id)));
+        }
+        Ok(())
+    }
+
+    /// Everything a coach has archived: their sessions, content of their
+    /// sessions and their templates, most recently archived first
+    pub fn find_by_coach(conn: &Connection, coach_id: i64) -> Result<Vec<ArchivedItem>> {
+        let mut stmt = conn.prepare(
+            "SELECT 'session', id, title, NULL, archived_at
+             FROM sessions
+             WHERE archived_at IS NOT NULL AND created_by = ?1
+             UNION ALL
+             SELECT 'content', c.id, c.title, s.title, c.archived_at
+             FROM training_content c
+             JOIN sessions s ON s.id = c.session_id
+             WHERE c.archived_at IS NOT NULL AND s.created_by = ?1
+             UNION ALL
+             SELECT 'template', id, title, NULL, archived_at
+             FROM training_templates
+             WHERE archived_at IS NOT NULL AND coach_id = ?1
+             ORDER BY 5 DESC, 2 DESC",
+        )?;
+
+        let items = stmt.query_map([coach_id], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for item in items {
+            results.push(item?);
+        }
+
+        Ok(results)
+    }
+
+    fn map_row(row: &Row) -> rusqlite::Result<ArchivedItem> {
+        let kind = match row.get::<_, String>(0)?.as_str() {
+            "session" => ArchiveKind::Session,
+            "content" => ArchiveKind::Content,
+            _ => ArchiveKind::Template,
+        };
+        let archived_at: String = row.get(4)?;
+
+        Ok(ArchivedItem {
+            kind,
+            id: row.get(1)?,
+            title: row.get(2)?,
+            session_title: row.get(3)?,
+            archived_at: NaiveDateTime::parse_from_str(&archived_at, "%Y-%m-%d %H:%M:%S")
+                .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+                .unwrap_or_else(|_| Utc::now()),
+        })
+    }
+}
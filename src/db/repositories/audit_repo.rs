@@ -0,0 +1,203 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use rusqlite::{Connection, Row};
+
+use crate::models::{AuditAction, AuditEntry, AuditQuery};
+use crate::utils::Result;
+
+/// Tables whose changes are recorded in `audit_log`
+const AUDITED_TABLES: [&str; 18] = [
+    "users",
+    "sessions",
+    "training_content",
+    "quizzes",
+    "homework",
+    "subscriptions",
+    "quiz_responses",
+    "homework_submissions",
+    "training_templates",
+    "session_training_links",
+    "player_groups",
+    "group_members",
+    "session_tags",
+    "template_tags",
+    "player_comments",
+    "user_keys",
+    "venues",
+    "courts",
+];
+
+/// Change history of club data.
+///
+/// Every insert, update and delete on the audited tables is recorded by TEMP
+/// triggers installed on each application connection, with the row as JSON
+/// before and after the change. The acting user is per connection, set with
+/// [`AuditRepository::set_actor`]; writes from other SQLite clients are not
+/// recorded.
+pub struct AuditRepository;
+
+impl AuditRepository {
+    /// (Re)create the audit triggers on this connection from the current
+    /// schema. Tables that don't exist yet are skipped.
+    pub fn install_triggers(conn: &Connection) -> Result<()> {
+        let mut sql = String::from("CREATE TEMP TABLE IF NOT EXISTS audit_context (actor_id INTEGER);\n");
+
+        for table in AUDITED_TABLES {
+            let columns = Self::table_columns(conn, table)?;
+            if columns.is_empty() {
+                continue;
+            }
+
+            let row_json = |alias: &str| {
+                let pairs: Vec<String> = columns
+                    .iter()
+                    .map(|column| format!("'{}', {}.\"{}\"", column, alias, column))
+                    .collect();
+                format!("json_object({})", pairs.join(", "))
+            };
+            let session_id = |alias: &str| {
+                if table == "sessions" {
+                    format!("{}.id", alias)
+                } else if columns.iter().any(|c| c == "session_id") {
+                    format!("{}.session_id", alias)
+                } else if columns.iter().any(|c| c == "subscription_id") {
+                    format!("(SELECT session_id FROM main.subscriptions WHERE id = {}.subscription_id)", alias)
+                } else {
+                    "NULL".to_string()
+                }
+            };
+
+            let events = [
+                ("insert", "INSERT", "NEW", "NULL".to_string(), row_json("NEW"), String::new()),
+                (
+                    "update",
+                    "UPDATE",
+                    "NEW",
+                    row_json("OLD"),
+                    row_json("NEW"),
+                    format!(" WHEN {} IS NOT {}", row_json("OLD"), row_json("NEW")),
+                ),
+                ("delete", "DELETE", "OLD", row_json("OLD"), "NULL".to_string(), String::new()),
+            ];
+            for (action, event, alias, before, after, when) in events {
+                sql.push_str(&format!(
+                    "DROP TRIGGER IF EXISTS temp.audit_{table}_{action};
+                     CREATE TEMP TRIGGER audit_{table}_{action} AFTER {event} ON main.{table}{when}
+                     BEGIN
+                         INSERT INTO audit_log (actor_id, entity, entity_id, session_id, action, before_json, after_json)
+                         VALUES ((SELECT actor_id FROM temp.audit_context), '{table}', {alias}.rowid, {session_id}, '{action}', {before}, {after});
+                     END;\n",
+                    session_id = session_id(alias),
+                ));
+            }
+        }
+
+        conn.execute_batch(&sql)?;
+        Ok(())
+    }
+
+    /// Remove this connection's audit triggers; changes go unrecorded until
+    /// [`AuditRepository::install_triggers`] runs again
+    pub fn drop_triggers(conn: &Connection) -> Result<()> {
+        let mut stmt = conn.prepare("SELECT name FROM temp.sqlite_master WHERE type = 'trigger' AND name GLOB 'audit_*'")?;
+        let names = stmt.query_map([], |row| row.get::<_, String>(0))?.collect::<rusqlite::Result<Vec<_>>>()?;
+        for name in names {
+            conn.execute_batch(&format!("DROP TRIGGER temp.{}", name))?;
+        }
+        Ok(())
+    }
+
+    /// Attribute changes made on this connection to `actor_id` (None for
+    /// system changes)
+    pub fn set_actor(conn: &Connection, actor_id: Option<i64>) -> Result<()> {
+        conn.execute_batch(
+            "CREATE TEMP TABLE IF NOT EXISTS audit_context (actor_id INTEGER);
+             DELETE FROM temp.audit_context;",
+        )?;
+        conn.execute("INSERT INTO temp.audit_context (actor_id) VALUES (?)", [actor_id])?;
+        Ok(())
+    }
+
+    /// Entries matching `query`, newest first
+    pub fn find(conn: &Connection, query: &AuditQuery) -> Result<Vec<AuditEntry>> {
+        let mut stmt = conn.prepare(
+            "SELECT a.id, a.actor_id, u.display_name, a.entity, a.entity_id, a.session_id, a.action,
+                    a.before_json, a.after_json, a.created_at
+             FROM audit_log a
+             LEFT JOIN users u ON u.id = a.actor_id
+             WHERE (?1 IS NULL OR a.actor_id = ?1)
+               AND (?2 IS NULL OR a.session_id = ?2)
+               AND (?3 IS NULL OR date(a.created_at) >= ?3)
+               AND (?4 IS NULL OR date(a.created_at) <= ?4)
+             ORDER BY a.id DESC
+             LIMIT ?5",
+        )?;
+
+        let limit = query.limit.map(|l| l as i64).unwrap_or(-1);
+        let entries = stmt.query_map(
+            rusqlite::params![
+                query.actor_id,
+                query.session_id,
+                date_param(query.from),
+                date_param(query.to),
+                limit
+            ],
+            Self::map_row,
+        )?;
+
+        let mut results = Vec::new();
+        for entry in entries {
+            results.push(entry?);
+        }
+
+        Ok(results)
+    }
+
+    /// All changes belonging to a session (its row, content, links, tags,
+    /// subscriptions and answers), newest first
+    pub fn for_session(conn: &Connection, session_id: i64) -> Result<Vec<AuditEntry>> {
+        Self::find(
+            conn,
+            &AuditQuery {
+                session_id: Some(session_id),
+                ..AuditQuery::default()
+            },
+        )
+    }
+
+    fn table_columns(conn: &Connection, table: &str) -> Result<Vec<String>> {
+        let mut stmt = conn.prepare("SELECT name FROM pragma_table_info(?) ORDER BY cid")?;
+        let columns = stmt.query_map([table], |row| row.get(0))?;
+
+        let mut results = Vec::new();
+        for column in columns {
+            results.push(column?);
+        }
+
+        Ok(results)
+    }
+
+    fn map_row(row: &Row) -> rusqlite::Result<AuditEntry> {
+        let action: String = row.get(6)?;
+        let json = |value: Option<String>| value.and_then(|s| serde_json::from_str(&s).ok());
+        let created_at: String = row.get(9)?;
+
+        Ok(AuditEntry {
+            id: row.get(0)?,
+            actor_id: row.get(1)?,
+            actor_name: row.get(2)?,
+            entity: row.get(3)?,
+            entity_id: row.get(4)?,
+            session_id: row.get(5)?,
+            action: action.parse::<AuditAction>().map_err(|_| rusqlite::Error::InvalidQuery)?,
+            before: json(row.get(7)?),
+            after: json(row.get(8)?),
+            created_at: NaiveDateTime::parse_from_str(&created_at, "%Y-%m-%d %H:%M:%S")
+                .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+                .unwrap_or_else(|_| Utc::now()),
+        })
+    }
+}
+
+fn date_param(date: Option<NaiveDate>) -> Option<String> {
+    date.map(|d| d.format("%Y-%m-%d").to_string())
+}
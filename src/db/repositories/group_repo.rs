@@ -0,0 +1,178 @@
+use crate::db::repositories::{SessionRepository, UserRepository};
+use crate::db::UnitOfWork;
+use crate::models::{Group, GroupAssignment, User};
+use crate::utils::Result;
+use rusqlite::Connection;
+
+pub struct GroupRepository;
+
+impl GroupRepository {
+    /// Create a new group
+    pub fn create(
+        conn: &Connection,
+        name: &str,
+        description: Option<&str>,
+        created_by: i64,
+    ) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO player_groups (name, description, created_by, created_at)
+             VALUES (?, ?, ?, datetime('now'))",
+            rusqlite::params![name, description, created_by],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Get all groups, ordered by name
+    pub fn find_all(conn: &Connection) -> Result<Vec<Group>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, name, description, created_by, created_at
+             FROM player_groups
+             ORDER BY name ASC",
+        )?;
+
+        let groups = stmt.query_map([], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for group in groups {
+            results.push(group?);
+        }
+
+        Ok(results)
+    }
+
+    /// Get a single group by ID
+    pub fn find_by_id(conn: &Connection, id: i64) -> Result<Option<Group>> {
+        match conn.query_row(
+            "SELECT id, name, description, created_by, created_at
+             FROM player_groups
+             WHERE id = ?",
+            [id],
+            Self::map_row,
+        ) {
+            Ok(group) => Ok(Some(group)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Get a single group by its (unique) name
+    pub fn find_by_name(conn: &Connection, name: &str) -> Result<Option<Group>> {
+        match conn.query_row(
+            "SELECT id, name, description, created_by, created_at
+             FROM player_groups
+             WHERE name = ?",
+            [name],
+            Self::map_row,
+        ) {
+            Ok(group) => Ok(Some(group)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Delete a group (memberships cascade)
+    pub fn delete(conn: &Connection, id: i64) -> Result<()> {
+        conn.execute("DELETE FROM player_groups WHERE id = ?", [id])?;
+        Ok(())
+    }
+
+    /// Add a player to a group (no-op if already a member)
+    pub fn add_member(conn: &Connection, group_id: i64, user_id: i64) -> Result<()> {
+        conn.execute(
+            "INSERT OR IGNORE INTO group_members (group_id, user_id, added_at)
+             VALUES (?, ?, datetime('now'))",
+            rusqlite::params![group_id, user_id],
+        )?;
+        Ok(())
+    }
+
+    /// Remove a player from a group
+    pub fn remove_member(conn: &Connection, group_id: i64, user_id: i64) -> Result<()> {
+        conn.execute(
+            "DELETE FROM group_members WHERE group_id = ? AND user_id = ?",
+            rusqlite::params![group_id, user_id],
+        )?;
+        Ok(())
+    }
+
+    /// Check if a player belongs to a group
+    pub fn is_member(conn: &Connection, group_id: i64, user_id: i64) -> Result<bool> {
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM group_members WHERE group_id = ? AND user_id = ?",
+            rusqlite::params![group_id, user_id],
+            |row| row.get(0),
+        )?;
+
+        Ok(count > 0)
+    }
+
+    /// Get all members of a group, ordered by display name
+    pub fn find_members(conn: &Connection, group_id: i64) -> Result<Vec<User>> {
+        let mut stmt = conn.prepare(
+            "SELECT u.id, u.username, u.display_name, u.role, u.skill_level, u.goals,
+                    u.created_at, u.updated_at, u.email
+             FROM group_members gm
+             JOIN users u ON u.id = gm.user_id
+             WHERE gm.group_id = ?
+             ORDER BY u.display_name ASC",
+        )?;
+
+        let members = stmt.query_map([group_id], UserRepository::map_row)?;
+
+        let mut results = Vec::new();
+        for member in members {
+            results.push(member?);
+        }
+
+        Ok(results)
+    }
+
+    /// Subscribe every member of a group to a session in a single transaction.
+    ///
+    /// A cancelled subscription is made active again and counts as
+    /// subscribed; members already holding an active or completed subscription
+    /// are reported in `already_subscribed` and left untouched. A cancelled
+    /// session is refused.
+    pub fn assign_session(
+        conn: &Connection,
+        group_id: i64,
+        session_id: i64,
+    ) -> Result<GroupAssignment> {
+        let tx = UnitOfWork::begin(conn)?;
+        SessionRepository::ensure_not_cancelled(&tx, session_id)?;
+        let mut assignment = GroupAssignment::default();
+
+        for member in Self::find_members(&tx, group_id)? {
+            // Counts only rows inserted or reactivated
+            let changed = tx.execute(
+                "INSERT INTO subscriptions (user_id, session_id, status, subscribed_at)
+                 VALUES (?, ?, 'active', datetime('now'))
+                 ON CONFLICT (user_id, session_id) DO UPDATE
+                 SET status = 'active', subscribed_at = excluded.subscribed_at
+                 WHERE status = 'cancelled'",
+                rusqlite::params![member.id, session_id],
+            )?;
+
+            if changed > 0 {
+                assignment.subscribed.push(member);
+            } else {
+                assignment.already_subscribed.push(member);
+            }
+        }
+
+        tx.commit()?;
+        Ok(assignment)
+    }
+
+    // Helper to map a row to a Group
+    fn map_row(row: &rusqlite::Row) -> rusqlite::Result<Group> {
+        Ok(Group {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            description: row.get(2)?,
+            created_by: row.get(3)?,
+            created_at: row.get(4)?,
+        })
+    }
+}
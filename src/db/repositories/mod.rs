@@ -1,11 +1,37 @@
+pub mod api_token_repo;
+pub mod archive_repo;
+pub mod audit_repo;
+pub mod group_repo;
+pub mod notification_repo;
+pub mod progress_repo;
+pub mod reminder_repo;
+pub mod search_repo;
 pub mod session_repo;
 pub mod subscription_repo;
 pub mod training_content_repo;
 pub mod training_template_repo;
 pub mod session_training_link_repo;
+pub mod tag_repo;
+pub mod user_repo;
+pub mod user_key_repo;
+pub mod venue_repo;
+pub mod webhook_repo;
 
+pub use self::api_token_repo::ApiTokenRepository;
+pub use self::archive_repo::ArchiveRepository;
+pub use self::audit_repo::AuditRepository;
+pub use self::group_repo::GroupRepository;
+pub use self::notification_repo::NotificationRepository;
+pub use self::progress_repo::ProgressRepository;
+pub use self::reminder_repo::ReminderRepository;
+pub use self::search_repo::SearchRepository;
 pub use self::session_repo::SessionRepository;
 pub use self::subscription_repo::SubscriptionRepository;
 pub use self::training_content_repo::TrainingContentRepository;
 pub use self::training_template_repo::TrainingTemplateRepository;
-pub use self::session_training_link_repo::SessionTrainingLinkRepository;
\ No newline at end of file
+pub use self::session_training_link_repo::SessionTrainingLinkRepository;
+pub use self::tag_repo::TagRepository;
+pub use self::user_repo::UserRepository;
+pub use self::user_key_repo::UserKeyRepository;
+pub use self::venue_repo::VenueRepository;
+pub use self::webhook_repo::WebhookRepository;
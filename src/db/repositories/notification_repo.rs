@@ -0,0 +1,73 @@
+use rusqlite::{Connection, Row};
+
+use crate::models::{Notification, NotificationKind};
+use crate::utils::{AppError, Result};
+
+/// The notification inbox. Notifications are written by triggers on the
+/// tables they report on; this repository only reads them and marks them read.
+pub struct NotificationRepository;
+
+impl NotificationRepository {
+    /// A user's notifications, newest first
+    pub fn find_by_user(conn: &Connection, user_id: i64) -> Result<Vec<Notification>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, user_id, session_id, kind, message, created_at, read_at
+             FROM notifications
+             WHERE user_id = ?
+             ORDER BY id DESC",
+        )?;
+
+        let notifications = stmt.query_map([user_id], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for notification in notifications {
+            results.push(notification?);
+        }
+
+        Ok(results)
+    }
+
+    /// Number of notifications the user hasn't read, for the header badge
+    pub fn unread_count(conn: &Connection, user_id: i64) -> Result<i64> {
+        let count = conn.query_row(
+            "SELECT COUNT(*) FROM notifications WHERE user_id = ? AND read_at IS NULL",
+            [user_id],
+            |row| row.get(0),
+        )?;
+        Ok(count)
+    }
+
+    /// Mark one of the user's notifications read. Reading it again is a no-op.
+    pub fn mark_read(conn: &Connection, user_id: i64, id: i64) -> Result<()> {
+        let found = conn.execute(
+            "UPDATE notifications SET read_at = COALESCE(read_at, datetime('now')) WHERE id = ? AND user_id = ?",
+            [id, user_id],
+        )?;
+        if found == 0 {
+            return Err(AppError::NotFound(format!("Notification {}", id)));
+        }
+        Ok(())
+    }
+
+    /// Mark all of the user's notifications read; returns how many were unread
+    pub fn mark_all_read(conn: &Connection, user_id: i64) -> Result<usize> {
+        let updated = conn.execute(
+            "UPDATE notifications SET read_at = datetime('now') WHERE user_id = ? AND read_at IS NULL",
+            [user_id],
+        )?;
+        Ok(updated)
+    }
+
+    fn map_row(row: &Row) -> rusqlite::Result<Notification> {
+        let kind: String = row.get(3)?;
+        Ok(Notification {
+            id: row.get(0)?,
+            user_id: row.get(1)?,
+            session_id: row.get(2)?,
+            kind: kind.parse::<NotificationKind>().map_err(|_| rusqlite::Error::InvalidQuery)?,
+            message: row.get(4)?,
+            created_at: row.get(5)?,
+            read_at: row.get(6)?,
+        })
+    }
+}
@@ -0,0 +1,281 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::db::UnitOfWork;
+use crate::models::{HomeworkState, HomeworkStatus, PlayerComment, QuizScore, SkillLevel, SkillLevelChange};
+use crate::utils::{AppError, Result};
+
+/// Queries behind player progress reports. Every query takes an optional
+/// inclusive date range; session-based rows are filtered on the session date
+/// (undated sessions are left out once a bound is given), the rest on the
+/// date the row was recorded.
+pub struct ProgressRepository;
+
+impl ProgressRepository {
+    /// Quiz answers per session, oldest session first
+    pub fn quiz_scores(
+        conn: &Connection,
+        user_id: i64,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<QuizScore>> {
+        let mut stmt = conn.prepare(
+            "SELECT s.id, s.title, s.scheduled_date, COUNT(qr.id), COALESCE(SUM(qr.is_correct), 0)
+             FROM quiz_responses qr
+             JOIN subscriptions sub ON sub.id = qr.subscription_id
+             JOIN sessions s ON s.id = sub.session_id
+             WHERE sub.user_id = ?1
+               AND s.archived_at IS NULL
+               AND (?2 IS NULL OR s.scheduled_date >= ?2)
+               AND (?3 IS NULL OR s.scheduled_date <= ?3)
+             GROUP BY s.id
+             ORDER BY s.scheduled_date IS NULL, s.scheduled_date ASC, s.id ASC",
+        )?;
+
+        let scores = stmt.query_map(rusqlite::params![user_id, date_param(from), date_param(to)], |row| {
+            Ok(QuizScore {
+                session_id: row.get(0)?,
+                session_title: row.get(1)?,
+                session_date: parse_date(row.get(2)?),
+                answered: row.get(3)?,
+                correct: row.get(4)?,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for score in scores {
+            results.push(score?);
+        }
+
+        Ok(results)
+    }
+
+    /// Homework of the sessions a player is subscribed to (cancelled
+    /// subscriptions excluded). Unsubmitted homework due before `today` is overdue.
+    pub fn homework(
+        conn: &Connection,
+        user_id: i64,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        today: NaiveDate,
+    ) -> Result<Vec<HomeworkStatus>> {
+        let mut stmt = conn.prepare(
+            "SELECT h.id, h.title, s.title, h.due_date, MIN(hs.submitted_at)
+             FROM homework h
+             JOIN sessions s ON s.id = h.session_id
+             JOIN subscriptions sub ON sub.session_id = s.id AND sub.user_id = ?1 AND sub.status != 'cancelled'
+             LEFT JOIN homework_submissions hs ON hs.homework_id = h.id AND hs.subscription_id = sub.id
+             WHERE s.archived_at IS NULL
+               AND (?2 IS NULL OR s.scheduled_date >= ?2)
+               AND (?3 IS NULL OR s.scheduled_date <= ?3)
+             GROUP BY h.id
+             ORDER BY s.scheduled_date IS NULL, s.scheduled_date ASC, h.order_index ASC",
+        )?;
+
+        let homework = stmt.query_map(rusqlite::params![user_id, date_param(from), date_param(to)], |row| {
+            let due_date = parse_date(row.get(3)?);
+            let submitted_at = parse_datetime(row.get(4)?);
+            let state = match (submitted_at, due_date) {
+                (Some(_), _) => HomeworkState::Submitted,
+                (None, Some(due)) if due < today => HomeworkState::Overdue,
+                (None, _) => HomeworkState::Open,
+            };
+            Ok(HomeworkStatus {
+                homework_id: row.get(0)?,
+                title: row.get(1)?,
+                session_title: row.get(2)?,
+                due_date,
+                submitted_at,
+                state,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for item in homework {
+            results.push(item?);
+        }
+
+        Ok(results)
+    }
+
+    /// Skill level changes, oldest first
+    pub fn skill_level_changes(
+        conn: &Connection,
+        user_id: i64,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<SkillLevelChange>> {
+        let mut stmt = conn.prepare(
+            "SELECT old_level, new_level, changed_at
+             FROM skill_level_changes
+             WHERE user_id = ?1
+               AND (?2 IS NULL OR date(changed_at) >= ?2)
+               AND (?3 IS NULL OR date(changed_at) <= ?3)
+             ORDER BY changed_at ASC, id ASC",
+        )?;
+
+        let changes = stmt.query_map(rusqlite::params![user_id, date_param(from), date_param(to)], |row| {
+            let old_level: Option<String> = row.get(0)?;
+            let new_level: Option<String> = row.get(1)?;
+            Ok(SkillLevelChange {
+                old_level: old_level.and_then(|l| SkillLevel::from_str(&l)),
+                new_level: new_level.and_then(|l| SkillLevel::from_str(&l)),
+                changed_at: parse_datetime(row.get(2)?).unwrap_or_else(Utc::now),
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for change in changes {
+            results.push(change?);
+        }
+
+        Ok(results)
+    }
+
+    /// Add a quiz question at the end of a session's quiz
+    pub fn add_quiz(
+        conn: &Connection,
+        session_id: i64,
+        question: &str,
+        correct_answer: &str,
+        options: Option<&str>,
+    ) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO quizzes (session_id, question, correct_answer, options, order_index)
+             VALUES (?1, ?2, ?3, ?4, (SELECT COALESCE(MAX(order_index) + 1, 0) FROM quizzes WHERE session_id = ?1))",
+            rusqlite::params![session_id, question, correct_answer, options],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Set homework at the end of a session's homework list
+    pub fn add_homework(
+        conn: &Connection,
+        session_id: i64,
+        title: &str,
+        description: &str,
+        due_date: Option<NaiveDate>,
+    ) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO homework (session_id, title, description, due_date, order_index)
+             VALUES (?1, ?2, ?3, ?4, (SELECT COALESCE(MAX(order_index) + 1, 0) FROM homework WHERE session_id = ?1))",
+            rusqlite::params![session_id, title, description, date_param(due_date)],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Session a quiz question belongs to
+    pub fn find_quiz_session(conn: &Connection, quiz_id: i64) -> Result<Option<i64>> {
+        Ok(conn
+            .query_row("SELECT session_id FROM quizzes WHERE id = ?", [quiz_id], |row| row.get(0))
+            .optional()?)
+    }
+
+    /// Session a homework assignment belongs to
+    pub fn find_homework_session(conn: &Connection, homework_id: i64) -> Result<Option<i64>> {
+        Ok(conn
+            .query_row("SELECT session_id FROM homework WHERE id = ?", [homework_id], |row| row.get(0))
+            .optional()?)
+    }
+
+    /// Record a player's answer to a quiz question, replacing an earlier
+    /// answer. Answers are marked correct when they match the correct answer,
+    /// ignoring case and surrounding spaces. Returns whether it was correct.
+    pub fn record_answer(conn: &Connection, subscription_id: i64, quiz_id: i64, answer: &str) -> Result<bool> {
+        let tx = UnitOfWork::begin(conn)?;
+        let correct_answer: String = tx
+            .query_row("SELECT correct_answer FROM quizzes WHERE id = ?", [quiz_id], |row| row.get(0))
+            .optional()?
+            .ok_or_else(|| AppError::NotFound(format!("Quiz {}", quiz_id)))?;
+        let is_correct = answer.trim().eq_ignore_ascii_case(correct_answer.trim());
+
+        tx.execute(
+            "DELETE FROM quiz_responses WHERE subscription_id = ? AND quiz_id = ?",
+            rusqlite::params![subscription_id, quiz_id],
+        )?;
+        tx.execute(
+            "INSERT INTO quiz_responses (subscription_id, quiz_id, answer, is_correct, answered_at)
+             VALUES (?, ?, ?, ?, datetime('now'))",
+            rusqlite::params![subscription_id, quiz_id, answer.trim(), is_correct],
+        )?;
+        tx.commit()?;
+
+        Ok(is_correct)
+    }
+
+    /// Record that a player handed in homework
+    pub fn submit_homework(conn: &Connection, subscription_id: i64, homework_id: i64, notes: Option<&str>) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO homework_submissions (subscription_id, homework_id, notes, submitted_at)
+             VALUES (?, ?, ?, datetime('now'))",
+            rusqlite::params![subscription_id, homework_id, notes],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Record a coach's comment on a player
+    pub fn add_comment(conn: &Connection, player_id: i64, coach_id: i64, body: &str) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO player_comments (player_id, coach_id, body, created_at)
+             VALUES (?, ?, ?, datetime('now'))",
+            rusqlite::params![player_id, coach_id, body],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Coach comments on a player, oldest first
+    pub fn comments(
+        conn: &Connection,
+        player_id: i64,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<PlayerComment>> {
+        let mut stmt = conn.prepare(
+            "SELECT c.id, c.player_id, c.coach_id, u.display_name, c.body, c.created_at
+             FROM player_comments c
+             JOIN users u ON u.id = c.coach_id
+             WHERE c.player_id = ?1
+               AND (?2 IS NULL OR date(c.created_at) >= ?2)
+               AND (?3 IS NULL OR date(c.created_at) <= ?3)
+             ORDER BY c.created_at ASC, c.id ASC",
+        )?;
+
+        let comments = stmt.query_map(rusqlite::params![player_id, date_param(from), date_param(to)], |row| {
+            Ok(PlayerComment {
+                id: row.get(0)?,
+                player_id: row.get(1)?,
+                coach_id: row.get(2)?,
+                coach_name: row.get(3)?,
+                body: row.get(4)?,
+                created_at: parse_datetime(row.get(5)?).unwrap_or_else(Utc::now),
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for comment in comments {
+            results.push(comment?);
+        }
+
+        Ok(results)
+    }
+}
+
+fn date_param(date: Option<NaiveDate>) -> Option<String> {
+    date.map(|d| d.format("%Y-%m-%d").to_string())
+}
+
+fn parse_date(value: Option<String>) -> Option<NaiveDate> {
+    value.and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok())
+}
+
+fn parse_datetime(value: Option<String>) -> Option<DateTime<Utc>> {
+    value.and_then(|s| {
+        NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+            .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+            .ok()
+    })
+}
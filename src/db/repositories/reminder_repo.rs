@@ -0,0 +1,67 @@
+use chrono::NaiveDateTime;
+use rusqlite::{Connection, Row};
+
+use crate::models::DueReminder;
+use crate::utils::Result;
+
+/// Session start as `YYYY-MM-DD HH:MM`; `scheduled_time` is stored as HH:MM:SS
+const STARTS_AT: &str = "s.scheduled_date || ' ' || COALESCE(substr(s.scheduled_time, 1, 5), '00:00')";
+
+pub struct ReminderRepository;
+
+impl ReminderRepository {
+    /// Active subscriptions to sessions starting between `from` and `to`
+    /// (inclusive) that haven't been reminded of that start yet, soonest first
+    pub fn due(conn: &Connection, from: NaiveDateTime, to: NaiveDateTime) -> Result<Vec<DueReminder>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT s.id, u.id, u.username, u.display_name, u.email, {starts_at}
+             FROM subscriptions sub
+             JOIN sessions s ON s.id = sub.session_id
+             JOIN users u ON u.id = sub.user_id
+             WHERE sub.status = 'active'
+               AND s.archived_at IS NULL
+               AND s.scheduled_date IS NOT NULL
+               AND {starts_at} BETWEEN ?1 AND ?2
+               AND NOT EXISTS (
+                   SELECT 1 FROM sent_reminders r
+                   WHERE r.session_id = s.id AND r.user_id = u.id AND r.starts_at = {starts_at}
+               )
+             ORDER BY {starts_at} ASC, s.id ASC, u.display_name ASC",
+            starts_at = STARTS_AT
+        ))?;
+
+        let reminders = stmt.query_map([minutes(from), minutes(to)], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for reminder in reminders {
+            results.push(reminder?);
+        }
+
+        Ok(results)
+    }
+
+    /// Remember that `reminder` was sent to `email`
+    pub fn record_sent(conn: &Connection, reminder: &DueReminder, email: &str) -> Result<()> {
+        conn.execute(
+            "INSERT OR IGNORE INTO sent_reminders (session_id, user_id, starts_at, email, sent_at)
+             VALUES (?, ?, ?, ?, datetime('now'))",
+            rusqlite::params![reminder.session_id, reminder.user_id, reminder.starts_at, email],
+        )?;
+        Ok(())
+    }
+
+    fn map_row(row: &Row) -> rusqlite::Result<DueReminder> {
+        Ok(DueReminder {
+            session_id: row.get(0)?,
+            user_id: row.get(1)?,
+            username: row.get(2)?,
+            display_name: row.get(3)?,
+            email: row.get(4)?,
+            starts_at: row.get(5)?,
+        })
+    }
+}
+
+fn minutes(value: NaiveDateTime) -> String {
+    value.format("%Y-%m-%d %H:%M").to_string()
+}
@@ -0,0 +1,81 @@
+use crate::models::{SearchHit, SearchKind, User};
+use crate::utils::Result;
+use rusqlite::Connection;
+
+pub struct SearchRepository;
+
+impl SearchRepository {
+    /// Full-text search across sessions, training content, templates and
+    /// subscription notes.
+    ///
+    /// Results are grouped by kind (sessions, content, templates, notes) and
+    /// ranked by relevance within each group. Coaches only see their own
+    /// sessions; players only see their own notes and public templates.
+    /// Archived rows are never returned.
+    pub fn search(conn: &Connection, query: &str, viewer: &User, limit: usize) -> Result<Vec<SearchHit>> {
+        let Some(expression) = SearchHit::match_expression(query) else {
+            return Ok(Vec::new());
+        };
+
+        let mut stmt = conn.prepare(
+            "SELECT kind, ref_id,
+                    CASE WHEN kind = 'template' THEN (
+                        SELECT l.session_id FROM session_training_links l
+                        JOIN sessions s ON s.id = l.session_id
+                        WHERE l.training_template_id = search_index.ref_id
+                          AND (NOT ?3 OR s.created_by = ?2)
+                        ORDER BY s.scheduled_date DESC, s.id DESC
+                        LIMIT 1
+                    ) ELSE session_id END,
+                    CASE WHEN kind = 'note'
+                         THEN (SELECT title FROM sessions WHERE id = search_index.session_id)
+                         ELSE title END,
+                    snippet(search_index, -1, '[', ']', '...', 10)
+             FROM search_index
+             WHERE search_index MATCH ?1
+               AND CASE kind
+                   WHEN 'template' THEN ref_id IN (
+                       SELECT id FROM training_templates
+                       WHERE (is_public = 1 OR coach_id = ?2) AND archived_at IS NULL)
+                   WHEN 'note' THEN CASE WHEN ?3
+                       THEN session_id IN (SELECT id FROM sessions WHERE created_by = ?2)
+                       ELSE ref_id IN (SELECT id FROM subscriptions WHERE user_id = ?2) END
+                   ELSE (NOT ?3 OR session_id IN (SELECT id FROM sessions WHERE created_by = ?2))
+                   END
+               AND (session_id IS NULL
+                    OR session_id NOT IN (SELECT id FROM sessions WHERE archived_at IS NOT NULL))
+               AND NOT (kind = 'content'
+                        AND ref_id IN (SELECT id FROM training_content WHERE archived_at IS NOT NULL))
+             ORDER BY CASE kind
+                      WHEN 'session' THEN 0
+                      WHEN 'content' THEN 1
+                      WHEN 'template' THEN 2
+                      ELSE 3 END,
+                      rank
+             LIMIT ?4",
+        )?;
+
+        let hits = stmt.query_map(
+            rusqlite::params![expression, viewer.id, viewer.is_coach(), limit as i64],
+            |row| {
+                let kind_str: String = row.get(0)?;
+                let kind = kind_str.parse::<SearchKind>().map_err(|_| rusqlite::Error::InvalidQuery)?;
+
+                Ok(SearchHit {
+                    kind,
+                    ref_id: row.get(1)?,
+                    session_id: row.get(2)?,
+                    title: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                    snippet: row.get(4)?,
+                })
+            },
+        )?;
+
+        let mut results = Vec::new();
+        for hit in hits {
+            results.push(hit?);
+        }
+
+        Ok(results)
+    }
+}
@@ -1,17 +1,20 @@
-use crate::models::{Session, SkillLevel};
-use crate::utils::Result;
+use crate::db::UnitOfWork;
+use crate::models::{booked_span, Booking, Clash, ClashKind, Session, SkillLevel, DEFAULT_BOOKING_MINUTES};
+use crate::utils::{AppError, Result};
 use chrono::{NaiveDate, NaiveTime};
-use rusqlite::Connection;
+use rusqlite::{Connection, OptionalExtension};
 
 pub struct SessionRepository;
 
 impl SessionRepository {
-    /// Get all sessions
+    /// Get all sessions that are not archived
     pub fn find_all(conn: &Connection) -> Result<Vec<Session>> {
         let mut stmt = conn.prepare(
             "SELECT id, title, description, scheduled_date, scheduled_time,
-                    duration_minutes, skill_level, created_by, created_at, updated_at
+                    duration_minutes, skill_level, created_by, created_at, updated_at,
+                    cancelled_at, cancel_reason, court_id
              FROM sessions
+             WHERE archived_at IS NULL
              ORDER BY scheduled_date DESC, created_at DESC",
         )?;
 
@@ -27,9 +30,10 @@ impl SessionRepository {
     pub fn find_by_coach(conn: &Connection, coach_id: i64) -> Result<Vec<Session>> {
         let mut stmt = conn.prepare(
             "SELECT id, title, description, scheduled_date, scheduled_time,
-                    duration_minutes, skill_level, created_by, created_at, updated_at
+                    duration_minutes, skill_level, created_by, created_at, updated_at,
+                    cancelled_at, cancel_reason, court_id
              FROM sessions
-             WHERE created_by = ?
+             WHERE created_by = ? AND archived_at IS NULL
              ORDER BY scheduled_date DESC, created_at DESC",
         )?;
 
@@ -41,7 +45,8 @@ impl SessionRepository {
         Ok(sessions)
     }
 
-    /// Create a new session
+    /// Create a new session; refused if its coach already runs another
+    /// session at that time
     pub fn create(
         conn: &Connection,
         title: &str,
@@ -56,7 +61,8 @@ impl SessionRepository {
         let scheduled_date_str = scheduled_date.map(|d| d.format("%Y-%m-%d").to_string());
         let scheduled_time_str = scheduled_time.map(|t| t.format("%H:%M:%S").to_string());
 
-        conn.execute(
+        let tx = UnitOfWork::begin(conn)?;
+        tx.execute(
             "INSERT INTO sessions (title, description, scheduled_date, scheduled_time,
                                    duration_minutes, skill_level, created_by, created_at, updated_at)
              VALUES (?, ?, ?, ?, ?, ?, ?, datetime('now'), datetime('now'))",
@@ -70,17 +76,21 @@ impl SessionRepository {
                 created_by,
             ],
         )?;
+        let id = tx.last_insert_rowid();
+        Self::ensure_slot_free(&tx, id)?;
 
-        Ok(conn.last_insert_rowid())
+        tx.commit()?;
+        Ok(id)
     }
 
     /// Get a single session by ID
     pub fn find_by_id(conn: &Connection, id: i64) -> Result<Option<Session>> {
         let mut stmt = conn.prepare(
             "SELECT id, title, description, scheduled_date, scheduled_time,
-                    duration_minutes, skill_level, created_by, created_at, updated_at
+                    duration_minutes, skill_level, created_by, created_at, updated_at,
+                    cancelled_at, cancel_reason, court_id
              FROM sessions
-             WHERE id = ?",
+             WHERE id = ? AND archived_at IS NULL",
         )?;
 
         let session = stmt.query_row([id], |row| Self::map_row(row));
@@ -92,12 +102,254 @@ impl SessionRepository {
         }
     }
 
-    /// Delete a session
+    /// Duplicate a session with a new date/time, copying its training content,
+    /// template links (order and custom notes are preserved) and tags. The
+    /// copy keeps the court, so nothing is copied if its new slot clashes.
+    ///
+    /// Returns the ID of the new session.
+    pub fn duplicate(
+        conn: &Connection,
+        id: i64,
+        scheduled_date: Option<NaiveDate>,
+        scheduled_time: Option<NaiveTime>,
+        created_by: i64,
+    ) -> Result<i64> {
+        let scheduled_date_str = scheduled_date.map(|d| d.format("%Y-%m-%d").to_string());
+        let scheduled_time_str = scheduled_time.map(|t| t.format("%H:%M:%S").to_string());
+
+        let tx = UnitOfWork::begin(conn)?;
+
+        let copied = tx.execute(
+            "INSERT INTO sessions (title, description, scheduled_date, scheduled_time,
+                                   duration_minutes, skill_level, court_id, created_by, created_at, updated_at)
+             SELECT title, description, ?, ?, duration_minutes, skill_level, court_id, ?,
+                    datetime('now'), datetime('now')
+             FROM sessions
+             WHERE id = ? AND archived_at IS NULL",
+            rusqlite::params![scheduled_date_str, scheduled_time_str, created_by, id],
+        )?;
+        if copied == 0 {
+            return Err(crate::utils::AppError::NotFound(format!("Session {}", id)));
+        }
+        let new_id = tx.last_insert_rowid();
+
+        // The copy keeps the court, so it must not double-book it
+        Self::ensure_slot_free(&tx, new_id)?;
+
+        tx.execute(
+            "INSERT INTO training_content (session_id, content_type, title, description,
+                                           duration_minutes, order_index)
+             SELECT ?, content_type, title, description, duration_minutes, order_index
+             FROM training_content
+             WHERE session_id = ? AND archived_at IS NULL
+             ORDER BY order_index ASC",
+            rusqlite::params![new_id, id],
+        )?;
+
+        tx.execute(
+            "INSERT INTO session_training_links (session_id, training_template_id, order_index, custom_notes)
+             SELECT ?, training_template_id, order_index, custom_notes
+             FROM session_training_links
+             WHERE session_id = ?
+             ORDER BY order_index ASC",
+            rusqlite::params![new_id, id],
+        )?;
+
+        tx.execute(
+            "INSERT INTO session_tags (session_id, tag_id)
+             SELECT ?, tag_id FROM session_tags WHERE session_id = ?",
+            rusqlite::params![new_id, id],
+        )?;
+
+        tx.commit()?;
+        Ok(new_id)
+    }
+
+    /// Permanently delete a session with its content and subscriptions; the
+    /// TUI archives sessions instead (see `ArchiveRepository`)
     pub fn delete(conn: &Connection, id: i64) -> Result<()> {
         conn.execute("DELETE FROM sessions WHERE id = ?", [id])?;
         Ok(())
     }
 
+    /// Cancel a session, keeping it and its history. Active subscriptions
+    /// become cancelled (completed ones are left alone) and their players are
+    /// notified by the `notify_session_cancelled` trigger.
+    ///
+    /// Returns the number of subscriptions cancelled.
+    pub fn cancel(conn: &Connection, id: i64, reason: &str) -> Result<usize> {
+        let reason = reason.trim();
+        if reason.is_empty() {
+            return Err(AppError::Validation(
+                "A reason is required to cancel a session".to_string(),
+            ));
+        }
+
+        let tx = UnitOfWork::begin(conn)?;
+
+        let session = Self::find_by_id(&tx, id)?
+            .ok_or_else(|| AppError::NotFound(format!("Session {}", id)))?;
+        if session.is_cancelled() {
+            return Err(AppError::Validation(format!(
+                "Session \"{}\" is already cancelled",
+                session.title
+            )));
+        }
+
+        // The session goes first so the trigger still sees who was subscribed
+        tx.execute(
+            "UPDATE sessions SET cancelled_at = datetime('now'), cancel_reason = ?, updated_at = datetime('now') WHERE id = ?",
+            rusqlite::params![reason, id],
+        )?;
+        let cancelled = tx.execute(
+            "UPDATE subscriptions SET status = 'cancelled' WHERE session_id = ? AND status = 'active'",
+            [id],
+        )?;
+
+        tx.commit()?;
+        Ok(cancelled)
+    }
+
+    /// Book a session on a court, or clear its court with `None`; refused if
+    /// the court is already taken at that time
+    pub fn set_court(conn: &Connection, id: i64, court_id: Option<i64>) -> Result<()> {
+        let tx = UnitOfWork::begin(conn)?;
+        let updated = tx.execute(
+            "UPDATE sessions SET court_id = ?, updated_at = datetime('now') WHERE id = ? AND archived_at IS NULL",
+            rusqlite::params![court_id, id],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound(format!("Session {}", id)));
+        }
+        Self::ensure_slot_free(&tx, id)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Sessions overlapping a booking on the same court or run by the same
+    /// coach. Archived and cancelled sessions don't hold their slot, and
+    /// neither do sessions without a date and time. A session clashing on
+    /// both counts is reported once, as a court clash.
+    pub fn find_clashes(conn: &Connection, booking: &Booking) -> Result<Vec<Clash>> {
+        let Some((starts_at, ends_at)) = booking.span() else {
+            return Ok(Vec::new());
+        };
+
+        // Compared as intervals rather than by date, so sessions running
+        // past midnight (or for days) are caught whenever they started
+        let mut stmt = conn.prepare(
+            "SELECT s.id, s.title, s.scheduled_date, s.scheduled_time, s.duration_minutes,
+                    s.court_id, c.name, v.name
+             FROM sessions s
+             LEFT JOIN courts c ON c.id = s.court_id
+             LEFT JOIN venues v ON v.id = c.venue_id
+             WHERE s.archived_at IS NULL AND s.cancelled_at IS NULL
+               AND s.scheduled_date IS NOT NULL AND s.scheduled_time IS NOT NULL
+               AND datetime(s.scheduled_date || ' ' || s.scheduled_time) < ?
+               AND datetime(s.scheduled_date || ' ' || s.scheduled_time,
+                            '+' || MAX(COALESCE(s.duration_minutes, ?), 1) || ' minutes') > ?
+               AND (s.created_by = ? OR s.court_id = ?)
+               AND s.id IS NOT ?
+             ORDER BY s.scheduled_date ASC, s.scheduled_time ASC",
+        )?;
+
+        let rows = stmt.query_map(
+            rusqlite::params![
+                ends_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                DEFAULT_BOOKING_MINUTES,
+                starts_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                booking.coach_id,
+                booking.court_id,
+                booking.session_id,
+            ],
+            |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, Option<i32>>(4)?,
+                    row.get::<_, Option<i64>>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                    row.get::<_, Option<String>>(7)?,
+                ))
+            },
+        )?;
+
+        let mut clashes = Vec::new();
+        for row in rows {
+            let (session_id, title, date, time, duration, court_id, court, venue) = row?;
+            let (Ok(date), Ok(time)) = (
+                NaiveDate::parse_from_str(&date, "%Y-%m-%d"),
+                NaiveTime::parse_from_str(&time, "%H:%M:%S"),
+            ) else {
+                continue;
+            };
+            let (other_start, other_end) = booked_span(date, time, duration);
+
+            let kind = if booking.court_id.is_some() && court_id == booking.court_id {
+                ClashKind::Court
+            } else {
+                ClashKind::Coach
+            };
+            clashes.push(Clash {
+                kind,
+                session_id,
+                title,
+                starts_at: other_start,
+                ends_at: other_end,
+                court: match kind {
+                    ClashKind::Court => court.zip(venue).map(|(court, venue)| format!("{} @ {}", court, venue)),
+                    ClashKind::Coach => None,
+                },
+            });
+        }
+
+        Ok(clashes)
+    }
+
+    /// Refuse a booking that double-books its court or its coach
+    pub fn ensure_no_clash(conn: &Connection, booking: &Booking) -> Result<()> {
+        match Self::find_clashes(conn, booking)?.first() {
+            Some(clash) => Err(AppError::Validation(clash.describe())),
+            None => Ok(()),
+        }
+    }
+
+    /// Refuse a saved session that double-books its court or its coach.
+    /// Called after every write that can move a session, inside the same
+    /// transaction; archived and cancelled sessions hold no slot and pass.
+    pub(crate) fn ensure_slot_free(conn: &Connection, id: i64) -> Result<()> {
+        let Some(session) = Self::find_by_id(conn, id)? else {
+            return Ok(());
+        };
+        if session.is_cancelled() {
+            return Ok(());
+        }
+        Self::ensure_no_clash(
+            conn,
+            &Booking {
+                date: session.scheduled_date,
+                time: session.scheduled_time,
+                duration_minutes: session.duration_minutes,
+                court_id: session.court_id,
+                coach_id: session.created_by,
+                session_id: Some(id),
+            },
+        )
+    }
+
+    /// Refuse to add subscribers to a cancelled session
+    pub fn ensure_not_cancelled(conn: &Connection, id: i64) -> Result<()> {
+        let cancelled: Option<bool> = conn
+            .query_row("SELECT cancelled_at IS NOT NULL FROM sessions WHERE id = ?", [id], |row| row.get(0))
+            .optional()?;
+        if cancelled == Some(true) {
+            return Err(AppError::Validation(format!("Session {} is cancelled", id)));
+        }
+        Ok(())
+    }
+
     // Helper to map a row to a Session
     fn map_row(row: &rusqlite::Row) -> rusqlite::Result<Session> {
         let skill_level_str: Option<String> = row.get(6)?;
@@ -127,6 +379,13 @@ impl SessionRepository {
                 })
                 .unwrap_or_else(|_| chrono::Utc::now());
 
+        let cancelled_at_str: Option<String> = row.get(10)?;
+        let cancelled_at = cancelled_at_str.and_then(|s| {
+            chrono::NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|dt| chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(dt, chrono::Utc))
+        });
+
         Ok(Session {
             id: row.get(0)?,
             title: row.get(1)?,
@@ -138,10 +397,14 @@ impl SessionRepository {
             created_by: row.get(7)?,
             created_at,
             updated_at,
+            cancelled_at,
+            cancel_reason: row.get(11)?,
+            court_id: row.get(12)?,
         })
     }
 
-    /// Update an existing session
+    /// Update an existing session, including its court; refused if the new
+    /// slot double-books the court or the session's coach
     pub fn update(
         conn: &Connection,
         id: i64,
@@ -151,13 +414,15 @@ impl SessionRepository {
         scheduled_time: Option<NaiveTime>,
         duration_minutes: Option<i32>,
         skill_level: Option<&SkillLevel>,
+        court_id: Option<i64>,
     ) -> Result<()> {
         let skill_level_str = skill_level.map(|s| s.as_str());
         let scheduled_date_str = scheduled_date.map(|d| d.format("%Y-%m-%d").to_string());
         let scheduled_time_str = scheduled_time.map(|t| t.format("%H:%M:%S").to_string());
 
-        conn.execute(
-            "UPDATE sessions SET title = ?, description = ?, scheduled_date = ?, scheduled_time = ?, duration_minutes = ?, skill_level = ?, updated_at = datetime('now') WHERE id = ?",
+        let tx = UnitOfWork::begin(conn)?;
+        let updated = tx.execute(
+            "UPDATE sessions SET title = ?, description = ?, scheduled_date = ?, scheduled_time = ?, duration_minutes = ?, skill_level = ?, court_id = ?, updated_at = datetime('now') WHERE id = ?",
             rusqlite::params![
                 title,
                 description,
@@ -165,9 +430,14 @@ impl SessionRepository {
                 scheduled_time_str,
                 duration_minutes,
                 skill_level_str,
+                court_id,
                 id,
             ],
         )?;
+        if updated > 0 {
+            Self::ensure_slot_free(&tx, id)?;
+        }
+        tx.commit()?;
         Ok(())
     }
 }
\ No newline at end of file
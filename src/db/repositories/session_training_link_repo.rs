@@ -1,5 +1,6 @@
+use crate::db::UnitOfWork;
 use crate::models::{SessionTrainingLink, SessionTrainingLinkWithTemplate, TrainingTemplate};
-use crate::utils::error::Result;
+use crate::utils::error::{AppError, Result};
 use rusqlite::Connection;
 
 pub struct SessionTrainingLinkRepository;
@@ -129,17 +130,50 @@ impl SessionTrainingLinkRepository {
         Ok(())
     }
 
+    /// Put the session's templates in the order of `template_ids`, which must
+    /// list every linked template once (a template linked twice, twice).
+    ///
+    /// Runs as one unit of work: links are first moved to negative positions
+    /// so no intermediate state collides on `UNIQUE(session_id, order_index)`,
+    /// and a failure part-way leaves the previous order intact.
     pub fn reorder_in_session(
         conn: &Connection,
         session_id: i64,
         template_ids: &[i64],
     ) -> Result<()> {
-        for (order_index, template_id) in template_ids.iter().enumerate() {
-            let mut stmt = conn.prepare(
-                "UPDATE session_training_links SET order_index = ? WHERE session_id = ? AND training_template_id = ?"
-            )?;
-            stmt.execute(rusqlite::params![order_index as i32, session_id, template_id])?;
+        let uow = UnitOfWork::begin(conn)?;
+
+        let mut unplaced = Self::get_for_session(&uow, session_id)?;
+        let mut link_ids = Vec::with_capacity(template_ids.len());
+        for template_id in template_ids {
+            let position = unplaced
+                .iter()
+                .position(|link| link.training_template_id == *template_id)
+                .ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "Template {} is not linked to session {} (or is listed too often)",
+                        template_id, session_id
+                    ))
+                })?;
+            link_ids.push(unplaced.remove(position).id);
         }
-        Ok(())
+        if let Some(missing) = unplaced.first() {
+            return Err(AppError::Validation(format!(
+                "Template {} of session {} is missing from the new order",
+                missing.training_template_id, session_id
+            )));
+        }
+
+        uow.execute(
+            "UPDATE session_training_links SET order_index = -1 - order_index WHERE session_id = ?",
+            [session_id],
+        )?;
+        let mut stmt = uow.prepare("UPDATE session_training_links SET order_index = ? WHERE id = ?")?;
+        for (order_index, link_id) in link_ids.iter().enumerate() {
+            stmt.execute(rusqlite::params![order_index as i32, link_id])?;
+        }
+        drop(stmt);
+
+        uow.commit()
     }
 }
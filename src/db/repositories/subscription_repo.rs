@@ -1,12 +1,16 @@
 use rusqlite::Connection;
-use crate::models::{Subscription, SubscriptionStatus};
+use chrono::NaiveDate;
+use crate::db::repositories::SessionRepository;
+use crate::db::UnitOfWork;
+use crate::models::{HistoryEntry, Subscription, SubscriptionStatus};
 use crate::utils::Result;
 
 pub struct SubscriptionRepository;
 
 impl SubscriptionRepository {
-    /// Subscribe a user to a session
+    /// Subscribe a user to a session; cancelled sessions are refused
     pub fn create(conn: &Connection, user_id:i64, session_id: i64) -> Result<i64> {
+        SessionRepository::ensure_not_cancelled(conn, session_id)?;
         conn.execute(
             "INSERT INTO subscriptions (user_id, session_id, status, subscribed_at)
              VALUES (?, ?, 'active', datetime('now'))",
@@ -16,6 +20,20 @@ impl SubscriptionRepository {
         Ok(conn.last_insert_rowid())
     }
 
+    /// Subscribe several users to a session, all or none: if any of them is
+    /// already subscribed nobody is. Returns the new subscription IDs in order.
+    pub fn create_many(conn: &Connection, user_ids: &[i64], session_id: i64) -> Result<Vec<i64>> {
+        let uow = UnitOfWork::begin(conn)?;
+
+        let mut ids = Vec::with_capacity(user_ids.len());
+        for user_id in user_ids {
+            ids.push(Self::create(&uow, *user_id, session_id)?);
+        }
+
+        uow.commit()?;
+        Ok(ids)
+    }
+
     /// Check if a user is subscribed to a session
     pub fn is_subscribed(conn: &Connection, user_id: i64, session_id: i64) -> Result<bool> {
         let count: i64 = conn.query_row(
@@ -43,6 +61,24 @@ impl SubscriptionRepository {
         }
     }
 
+    /// Get all subscriptions, ordered by ID
+    pub fn find_all(conn: &Connection) -> Result<Vec<Subscription>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, user_id, session_id, subscribed_at, completed_at, status, notes
+             FROM subscriptions
+             ORDER BY id ASC",
+        )?;
+
+        let subscriptions_iter = stmt.query_map([], Self::map_row)?;
+
+        let mut subscriptions = Vec::new();
+        for subscription_result in subscriptions_iter {
+            subscriptions.push(subscription_result?)
+        }
+
+        Ok(subscriptions)
+    }
+
     /// Get all subscriptions for a user
     pub fn find_by_user(conn: &Connection, user_id: i64) -> Result<Vec<Subscription>> {
         let mut stmt = conn.prepare(
@@ -81,6 +117,51 @@ impl SubscriptionRepository {
         Ok(subscriptions)
     }
 
+    /// Training history of one player, oldest session first
+    pub fn history_for_player(conn: &Connection, user_id: i64) -> Result<Vec<HistoryEntry>> {
+        Self::query_history(conn, "sub.user_id = ?", user_id)
+    }
+
+    /// Training history of every player subscribed to a coach's sessions
+    pub fn history_for_coach(conn: &Connection, coach_id: i64) -> Result<Vec<HistoryEntry>> {
+        Self::query_history(conn, "s.created_by = ?", coach_id)
+    }
+
+    fn query_history(conn: &Connection, condition: &str, id: i64) -> Result<Vec<HistoryEntry>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT sub.id, sub.user_id, sub.session_id, sub.subscribed_at, sub.completed_at,
+                    sub.status, sub.notes,
+                    u.username, u.display_name, s.title, s.scheduled_date, s.duration_minutes,
+                    s.cancel_reason
+             FROM subscriptions sub
+             JOIN sessions s ON s.id = sub.session_id
+             JOIN users u ON u.id = sub.user_id
+             WHERE {} AND s.archived_at IS NULL
+             ORDER BY s.scheduled_date IS NULL, s.scheduled_date ASC, s.id ASC, u.username ASC",
+            condition
+        ))?;
+
+        let history_iter = stmt.query_map([id], |row| {
+            let session_date: Option<String> = row.get(10)?;
+            Ok(HistoryEntry {
+                subscription: Self::map_row(row)?,
+                username: row.get(7)?,
+                display_name: row.get(8)?,
+                session_title: row.get(9)?,
+                session_date: session_date.and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()),
+                duration_minutes: row.get(11)?,
+                cancel_reason: row.get(12)?,
+            })
+        })?;
+
+        let mut history = Vec::new();
+        for entry_result in history_iter {
+            history.push(entry_result?)
+        }
+
+        Ok(history)
+    }
+
     /// Makr a subscription as completed
     pub fn mark_completed(conn: &Connection, subscription_id: i64) -> Result<()> {
         conn.execute(
@@ -0,0 +1,127 @@
+use crate::db::UnitOfWork;
+use crate::models::Tag;
+use crate::utils::Result;
+use rusqlite::Connection;
+use std::collections::HashMap;
+
+pub struct TagRepository;
+
+impl TagRepository {
+    /// Get all known tags, ordered by name
+    pub fn find_all(conn: &Connection) -> Result<Vec<Tag>> {
+        let mut stmt = conn.prepare("SELECT id, name FROM tags ORDER BY name ASC")?;
+
+        let tags = stmt.query_map([], |row| {
+            Ok(Tag {
+                id: row.get(0)?,
+                name: row.get(1)?,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for tag in tags {
+            results.push(tag?);
+        }
+
+        Ok(results)
+    }
+
+    /// Get the ID of a tag, creating it if it doesn't exist yet
+    pub fn find_or_create(conn: &Connection, name: &str) -> Result<i64> {
+        conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?)", [name])?;
+
+        let id = conn.query_row("SELECT id FROM tags WHERE name = ?", [name], |row| row.get(0))?;
+        Ok(id)
+    }
+
+    /// Get tag names for a session
+    pub fn find_by_session(conn: &Connection, session_id: i64) -> Result<Vec<String>> {
+        let mut stmt = conn.prepare(
+            "SELECT t.name FROM session_tags st
+             JOIN tags t ON t.id = st.tag_id
+             WHERE st.session_id = ?
+             ORDER BY t.name ASC",
+        )?;
+
+        let names = stmt.query_map([session_id], |row| row.get(0))?;
+
+        let mut results = Vec::new();
+        for name in names {
+            results.push(name?);
+        }
+
+        Ok(results)
+    }
+
+    /// Get tag names for every tagged session, keyed by session ID
+    pub fn find_all_session_tags(conn: &Connection) -> Result<HashMap<i64, Vec<String>>> {
+        let mut stmt = conn.prepare(
+            "SELECT st.session_id, t.name FROM session_tags st
+             JOIN tags t ON t.id = st.tag_id
+             ORDER BY t.name ASC",
+        )?;
+
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?;
+
+        let mut results: HashMap<i64, Vec<String>> = HashMap::new();
+        for row in rows {
+            let (session_id, name) = row?;
+            results.entry(session_id).or_default().push(name);
+        }
+
+        Ok(results)
+    }
+
+    /// Replace the tags of a session
+    pub fn set_session_tags(conn: &Connection, session_id: i64, tags: &[String]) -> Result<()> {
+        let tx = UnitOfWork::begin(conn)?;
+
+        tx.execute("DELETE FROM session_tags WHERE session_id = ?", [session_id])?;
+        for tag in tags {
+            let tag_id = Self::find_or_create(&tx, tag)?;
+            tx.execute(
+                "INSERT OR IGNORE INTO session_tags (session_id, tag_id) VALUES (?, ?)",
+                rusqlite::params![session_id, tag_id],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Get tag names for a training template
+    pub fn find_by_template(conn: &Connection, template_id: i64) -> Result<Vec<String>> {
+        let mut stmt = conn.prepare(
+            "SELECT t.name FROM template_tags tt
+             JOIN tags t ON t.id = tt.tag_id
+             WHERE tt.template_id = ?
+             ORDER BY t.name ASC",
+        )?;
+
+        let names = stmt.query_map([template_id], |row| row.get(0))?;
+
+        let mut results = Vec::new();
+        for name in names {
+            results.push(name?);
+        }
+
+        Ok(results)
+    }
+
+    /// Replace the tags of a training template
+    pub fn set_template_tags(conn: &Connection, template_id: i64, tags: &[String]) -> Result<()> {
+        let tx = UnitOfWork::begin(conn)?;
+
+        tx.execute("DELETE FROM template_tags WHERE template_id = ?", [template_id])?;
+        for tag in tags {
+            let tag_id = Self::find_or_create(&tx, tag)?;
+            tx.execute(
+                "INSERT OR IGNORE INTO template_tags (template_id, tag_id) VALUES (?, ?)",
+                rusqlite::params![template_id, tag_id],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+}
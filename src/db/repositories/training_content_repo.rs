@@ -11,7 +11,7 @@ impl TrainingContentRepository {
         let mut stmt = conn.prepare(
             "SELECT id, session_id, content_type, title, description, duration_minutes, order_index
              FROM training_content
-             WHERE session_id = ?
+             WHERE session_id = ? AND archived_at IS NULL
              ORDER BY order_index ASC",
         )?;
 
@@ -28,7 +28,7 @@ impl TrainingContentRepository {
         let mut stmt = conn.prepare(
             "SELECT id, session_id, content_type, title, description, duration_minutes, order_index
              FROM training_content
-             WHERE id = ?",
+             WHERE id = ? AND archived_at IS NULL",
         )?;
 
         let result = stmt
@@ -92,7 +92,7 @@ impl TrainingContentRepository {
         Ok(())
     }
 
-    /// Delete training content by ID
+    /// Permanently delete training content by ID
     pub fn delete(conn: &Connection, id: i64) -> Result<()> {
         conn.execute("DELETE FROM training_content WHERE id = ?", [id])?;
         Ok(())
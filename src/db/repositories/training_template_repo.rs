@@ -55,7 +55,40 @@ impl TrainingTemplateRepository {
         let mut stmt = conn.prepare(
             "SELECT id, coach_id, title, content_type, description, duration_minutes, 
                     created_at, created_by, last_edited_by, last_edited_at, is_public 
-             FROM training_templates WHERE is_public = 1 ORDER BY created_at DESC"
+             FROM training_templates WHERE is_public = 1 AND archived_at IS NULL ORDER BY created_at DESC"
+        )?;
+
+        let templates = stmt.query_map([], |row| {
+            Ok(TrainingTemplate {
+                id: row.get(0)?,
+                coach_id: row.get(1)?,
+                title: row.get(2)?,
+                content_type: row.get(3)?,
+                description: row.get(4)?,
+                duration_minutes: row.get(5)?,
+                created_at: row.get(6)?,
+                created_by: row.get(7)?,
+                last_edited_by: row.get(8)?,
+                last_edited_at: row.get(9)?,
+                is_public: row.get::<_, i32>(10)? != 0,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for template in templates {
+            results.push(template?);
+        }
+
+        Ok(results)
+    }
+
+    /// Every template, public or private and including archived ones (sessions
+    /// may still link to them), ordered by ID (used for backups)
+    pub fn list_including_private(conn: &Connection) -> Result<Vec<TrainingTemplate>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, coach_id, title, content_type, description, duration_minutes, 
+                    created_at, created_by, last_edited_by, last_edited_at, is_public 
+             FROM training_templates ORDER BY id ASC"
         )?;
 
         let templates = stmt.query_map([], |row| {
@@ -86,7 +119,7 @@ impl TrainingTemplateRepository {
         let mut stmt = conn.prepare(
             "SELECT id, coach_id, title, content_type, description, duration_minutes, 
                     created_at, created_by, last_edited_by, last_edited_at, is_public 
-             FROM training_templates WHERE coach_id = ? ORDER BY created_at DESC"
+             FROM training_templates WHERE coach_id = ? AND archived_at IS NULL ORDER BY created_at DESC"
         )?;
 
         let templates = stmt.query_map(rusqlite::params![coach_id], |row| {
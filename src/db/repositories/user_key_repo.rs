@@ -0,0 +1,126 @@
+use crate::auth::PublicKey;
+use crate::models::UserKey;
+use crate::utils::{AppError, Result};
+use rusqlite::{Connection, Row};
+
+const SELECT_KEYS: &str = "SELECT k.id, k.user_id, u.username, k.key_type, k.public_key, k.fingerprint,
+                                  k.label, k.created_at, k.revoked_at
+                           FROM user_keys k
+                           JOIN users u ON u.id = k.user_id";
+
+pub struct UserKeyRepository;
+
+impl UserKeyRepository {
+    /// Register a public key for a user. A key can belong to one user only.
+    pub fn add(conn: &Connection, user_id: i64, key: &PublicKey, label: Option<&str>) -> Result<i64> {
+        let fingerprint = key.fingerprint();
+        if let Some(existing) = Self::find_by_fingerprint(conn, &fingerprint)? {
+            return Err(AppError::Validation(format!(
+                "Key {} is already registered for {}",
+                fingerprint, existing.username
+            )));
+        }
+
+        conn.execute(
+            "INSERT INTO user_keys (user_id, key_type, public_key, fingerprint, label, created_at)
+             VALUES (?, ?, ?, ?, ?, datetime('now'))",
+            rusqlite::params![user_id, key.key_type, key.data, fingerprint, label],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Get a single key by ID, revoked or not
+    pub fn find_by_id(conn: &Connection, id: i64) -> Result<Option<UserKey>> {
+        match conn.query_row(&format!("{} WHERE k.id = ?", SELECT_KEYS), [id], Self::map_row) {
+            Ok(key) => Ok(Some(key)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Look up a key, revoked or not
+    pub fn find_by_fingerprint(conn: &Connection, fingerprint: &str) -> Result<Option<UserKey>> {
+        match conn.query_row(
+            &format!("{} WHERE k.fingerprint = ?", SELECT_KEYS),
+            [fingerprint],
+            Self::map_row,
+        ) {
+            Ok(key) => Ok(Some(key)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Keys that may log in, ordered by username
+    pub fn find_active(conn: &Connection) -> Result<Vec<UserKey>> {
+        Self::query(conn, "WHERE k.revoked_at IS NULL", None)
+    }
+
+    /// Every key, revoked ones included, ordered by username
+    pub fn find_all(conn: &Connection) -> Result<Vec<UserKey>> {
+        Self::query(conn, "", None)
+    }
+
+    /// Every key of one user, revoked ones included
+    pub fn find_by_user(conn: &Connection, user_id: i64) -> Result<Vec<UserKey>> {
+        Self::query(conn, "WHERE k.user_id = ?", Some(user_id))
+    }
+
+    /// Stop a key from logging in. It stays listed, marked as revoked.
+    pub fn revoke(conn: &Connection, id: i64) -> Result<()> {
+        let revoked = conn.execute(
+            "UPDATE user_keys SET revoked_at = datetime('now') WHERE id = ? AND revoked_at IS NULL",
+            [id],
+        )?;
+        if revoked == 0 {
+            return Err(AppError::NotFound(format!("Active key {}", id)));
+        }
+        Ok(())
+    }
+
+    /// Change the label shown for a key (None clears it)
+    pub fn set_label(conn: &Connection, id: i64, label: Option<&str>) -> Result<()> {
+        let updated = conn.execute(
+            "UPDATE user_keys SET label = ? WHERE id = ?",
+            rusqlite::params![label, id],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound(format!("Key {}", id)));
+        }
+        Ok(())
+    }
+
+    fn query(conn: &Connection, filter: &str, user_id: Option<i64>) -> Result<Vec<UserKey>> {
+        let mut stmt = conn.prepare(&format!(
+            "{} {} ORDER BY u.username ASC, k.id ASC",
+            SELECT_KEYS, filter
+        ))?;
+
+        let keys = match user_id {
+            Some(user_id) => stmt.query_map([user_id], Self::map_row)?,
+            None => stmt.query_map([], Self::map_row)?,
+        };
+
+        let mut results = Vec::new();
+        for key in keys {
+            results.push(key?);
+        }
+
+        Ok(results)
+    }
+
+    fn map_row(row: &Row) -> rusqlite::Result<UserKey> {
+        Ok(UserKey {
+            id: row.get(0)?,
+            user_id: row.get(1)?,
+            username: row.get(2)?,
+            key_type: row.get(3)?,
+            public_key: row.get(4)?,
+            fingerprint: row.get(5)?,
+            label: row.get(6)?,
+            created_at: row.get(7)?,
+            revoked_at: row.get(8)?,
+        })
+    }
+}
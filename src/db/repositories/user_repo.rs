@@ -0,0 +1,154 @@
+use crate::models::{SkillLevel, User, UserRole};
+use crate::utils::{AppError, Result};
+use rusqlite::Connection;
+
+pub struct UserRepository;
+
+impl UserRepository {
+    /// Create a new user
+    pub fn create(
+        conn: &Connection,
+        username: &str,
+        display_name: &str,
+        role: &UserRole,
+        skill_level: Option<&SkillLevel>,
+        goals: Option<&str>,
+    ) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO users (username, display_name, role, skill_level, goals, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, datetime('now'), datetime('now'))",
+            rusqlite::params![username, display_name, role.as_str(), skill_level.map(|s| s.as_str()), goals],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Set or clear the address session reminders are sent to
+    pub fn set_email(conn: &Connection, id: i64, email: Option<&str>) -> Result<()> {
+        let updated = conn.execute(
+            "UPDATE users SET email = ?, updated_at = datetime('now') WHERE id = ?",
+            rusqlite::params![email, id],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound(format!("User {}", id)));
+        }
+        Ok(())
+    }
+
+    /// Change a user's skill level; the change is kept in
+    /// `skill_level_changes` for progress reports
+    pub fn set_skill_level(conn: &Connection, id: i64, skill_level: Option<&SkillLevel>) -> Result<()> {
+        let updated = conn.execute(
+            "UPDATE users SET skill_level = ?, updated_at = datetime('now') WHERE id = ?",
+            rusqlite::params![skill_level.map(|s| s.as_str()), id],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound(format!("User {}", id)));
+        }
+        Ok(())
+    }
+
+    /// Get a single user by ID
+    pub fn find_by_id(conn: &Connection, id: i64) -> Result<Option<User>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, username, display_name, role, skill_level, goals, created_at, updated_at, email
+             FROM users
+             WHERE id = ?",
+        )?;
+
+        match stmt.query_row([id], Self::map_row) {
+            Ok(user) => Ok(Some(user)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Get a single user by username
+    pub fn find_by_username(conn: &Connection, username: &str) -> Result<Option<User>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, username, display_name, role, skill_level, goals, created_at, updated_at, email
+             FROM users
+             WHERE username = ?",
+        )?;
+
+        match stmt.query_row([username], Self::map_row) {
+            Ok(user) => Ok(Some(user)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Get all users, ordered by ID
+    pub fn find_all(conn: &Connection) -> Result<Vec<User>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, username, display_name, role, skill_level, goals, created_at, updated_at, email
+             FROM users
+             ORDER BY id ASC",
+        )?;
+
+        let users = stmt.query_map([], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for user in users {
+            results.push(user?);
+        }
+
+        Ok(results)
+    }
+
+    /// Get all players, ordered by display name
+    pub fn find_players(conn: &Connection) -> Result<Vec<User>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, username, display_name, role, skill_level, goals, created_at, updated_at, email
+             FROM users
+             WHERE role = 'player'
+             ORDER BY display_name ASC",
+        )?;
+
+        let users = stmt.query_map([], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for user in users {
+            results.push(user?);
+        }
+
+        Ok(results)
+    }
+
+    // Helper to map a row to a User
+    pub(crate) fn map_row(row: &rusqlite::Row) -> rusqlite::Result<User> {
+        let role_str: String = row.get(3)?;
+        let role = UserRole::from_str(&role_str).ok_or(rusqlite::Error::InvalidQuery)?;
+
+        let skill_level_str: Option<String> = row.get(4)?;
+        let skill_level = skill_level_str.and_then(|s| SkillLevel::from_str(&s));
+
+        let created_at_str: String = row.get(6)?;
+        let created_at =
+            chrono::NaiveDateTime::parse_from_str(&created_at_str, "%Y-%m-%d %H:%M:%S")
+                .map(|dt| {
+                    chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(dt, chrono::Utc)
+                })
+                .unwrap_or_else(|_| chrono::Utc::now());
+
+        let updated_at_str: String = row.get(7)?;
+        let updated_at =
+            chrono::NaiveDateTime::parse_from_str(&updated_at_str, "%Y-%m-%d %H:%M:%S")
+                .map(|dt| {
+                    chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(dt, chrono::Utc)
+                })
+                .unwrap_or_else(|_| chrono::Utc::now());
+
+        Ok(User {
+            id: row.get(0)?,
+            username: row.get(1)?,
+            display_name: row.get(2)?,
+            role,
+            skill_level,
+            goals: row.get(5)?,
+            email: row.get(8)?,
+            created_at,
+            updated_at,
+        })
+    }
+}
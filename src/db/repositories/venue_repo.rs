@@ -0,0 +1,146 @@
+use rusqlite::{Connection, Row};
+
+use crate::models::{Court, Venue, VenueWithCourts};
+use crate::utils::{AppError, Result};
+
+/// Venues and the courts sessions are booked on
+pub struct VenueRepository;
+
+impl VenueRepository {
+    /// Create a venue; names are unique
+    pub fn create(conn: &Connection, name: &str, address: Option<&str>) -> Result<i64> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(AppError::Validation("Venue name cannot be empty".to_string()));
+        }
+        if Self::find_by_name(conn, name)?.is_some() {
+            return Err(AppError::Validation(format!("Venue \"{}\" already exists", name)));
+        }
+
+        conn.execute(
+            "INSERT INTO venues (name, address, created_at) VALUES (?, ?, datetime('now'))",
+            rusqlite::params![name, address],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Add a court to a venue; court names are unique within a venue
+    pub fn add_court(conn: &Connection, venue_id: i64, name: &str) -> Result<i64> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(AppError::Validation("Court name cannot be empty".to_string()));
+        }
+        if Self::find_courts(conn)?
+            .iter()
+            .any(|c| c.venue_id == venue_id && c.name == name)
+        {
+            return Err(AppError::Validation(format!("Court \"{}\" already exists", name)));
+        }
+
+        conn.execute(
+            "INSERT INTO courts (venue_id, name) VALUES (?, ?)",
+            rusqlite::params![venue_id, name],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// All venues, ordered by name
+    pub fn find_all(conn: &Connection) -> Result<Vec<Venue>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, name, address, created_at
+             FROM venues
+             ORDER BY name ASC",
+        )?;
+
+        let venues = stmt.query_map([], Self::map_venue)?;
+
+        let mut results = Vec::new();
+        for venue in venues {
+            results.push(venue?);
+        }
+
+        Ok(results)
+    }
+
+    /// All venues with their courts, ordered by name
+    pub fn find_all_with_courts(conn: &Connection) -> Result<Vec<VenueWithCourts>> {
+        let courts = Self::find_courts(conn)?;
+        Ok(Self::find_all(conn)?
+            .into_iter()
+            .map(|venue| VenueWithCourts {
+                courts: courts.iter().filter(|c| c.venue_id == venue.id).cloned().collect(),
+                venue,
+            })
+            .collect())
+    }
+
+    /// Get a venue by its (unique) name
+    pub fn find_by_name(conn: &Connection, name: &str) -> Result<Option<Venue>> {
+        match conn.query_row(
+            "SELECT id, name, address, created_at
+             FROM venues
+             WHERE name = ?",
+            [name],
+            Self::map_venue,
+        ) {
+            Ok(venue) => Ok(Some(venue)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// All courts with their venue, ordered by venue and court name
+    pub fn find_courts(conn: &Connection) -> Result<Vec<Court>> {
+        let mut stmt = conn.prepare(
+            "SELECT c.id, c.venue_id, c.name, v.name
+             FROM courts c
+             JOIN venues v ON v.id = c.venue_id
+             ORDER BY v.name ASC, c.name ASC",
+        )?;
+
+        let courts = stmt.query_map([], Self::map_court)?;
+
+        let mut results = Vec::new();
+        for court in courts {
+            results.push(court?);
+        }
+
+        Ok(results)
+    }
+
+    /// Get a single court by ID
+    pub fn find_court(conn: &Connection, id: i64) -> Result<Option<Court>> {
+        match conn.query_row(
+            "SELECT c.id, c.venue_id, c.name, v.name
+             FROM courts c
+             JOIN venues v ON v.id = c.venue_id
+             WHERE c.id = ?",
+            [id],
+            Self::map_court,
+        ) {
+            Ok(court) => Ok(Some(court)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn map_venue(row: &Row) -> rusqlite::Result<Venue> {
+        Ok(Venue {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            address: row.get(2)?,
+            created_at: row.get(3)?,
+        })
+    }
+
+    fn map_court(row: &Row) -> rusqlite::Result<Court> {
+        Ok(Court {
+            id: row.get(0)?,
+            venue_id: row.get(1)?,
+            name: row.get(2)?,
+            venue_name: row.get(3)?,
+        })
+    }
+}
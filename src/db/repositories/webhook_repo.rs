@@ -0,0 +1,188 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OptionalExtension, Row};
+
+use crate::auth::token;
+use crate::db::UnitOfWork;
+use crate::models::{Webhook, WebhookDelivery};
+use crate::utils::{AppError, Result};
+
+/// Webhook endpoints and their outbox.
+///
+/// Outbox rows are written by the `webhook_session_events` trigger on
+/// `audit_log`, so events are only queued for changes made through the
+/// application, like the change history itself.
+pub struct WebhookRepository;
+
+impl WebhookRepository {
+    /// Register an endpoint for `events`, or for every event if empty.
+    /// Returns its ID and the secret its deliveries are signed with (see
+    /// `webhooks::signature`).
+    pub fn create(conn: &Connection, url: &str, events: &[String]) -> Result<(i64, String)> {
+        let events = if events.is_empty() { "*".to_string() } else { events.join(",") };
+        let secret = token::generate()?;
+        conn.execute(
+            "INSERT INTO webhooks (url, events, secret, created_at) VALUES (?, ?, ?, datetime('now'))",
+            [url, events.as_str(), secret.as_str()],
+        )?;
+
+        Ok((conn.last_insert_rowid(), secret))
+    }
+
+    pub fn find_all(conn: &Connection) -> Result<Vec<Webhook>> {
+        let mut stmt = conn.prepare("SELECT id, url, events, created_at FROM webhooks ORDER BY id ASC")?;
+
+        let webhooks = stmt.query_map([], |row| {
+            let events: String = row.get(2)?;
+            Ok(Webhook {
+                id: row.get(0)?,
+                url: row.get(1)?,
+                events: events
+                    .split(',')
+                    .filter(|event| *event != "*")
+                    .map(str::to_string)
+                    .collect(),
+                created_at: row.get(3)?,
+            })
+        })?;
+
+        let mut results = Vec::new();
+        for webhook in webhooks {
+            results.push(webhook?);
+        }
+
+        Ok(results)
+    }
+
+    /// Remove an endpoint along with its undelivered events
+    pub fn delete(conn: &Connection, id: i64) -> Result<()> {
+        let deleted = conn.execute("DELETE FROM webhooks WHERE id = ?", [id])?;
+        if deleted == 0 {
+            return Err(AppError::NotFound(format!("Webhook {}", id)));
+        }
+        Ok(())
+    }
+
+    /// Claim the oldest pending delivery due at `now` for one attempt.
+    ///
+    /// Its next attempt is moved to `claimed_until` in the same transaction,
+    /// so a `webhook deliver` running alongside doesn't send it too. If the
+    /// attempt's outcome is never recorded (the process died), the delivery
+    /// is due again once the claim runs out.
+    pub fn claim_due(
+        conn: &Connection,
+        now: DateTime<Utc>,
+        claimed_until: DateTime<Utc>,
+    ) -> Result<Option<WebhookDelivery>> {
+        let tx = UnitOfWork::begin(conn)?;
+        let delivery = tx
+            .query_row(
+                &format!(
+                    "{} WHERE o.status = 'pending' AND o.next_attempt_at <= ? ORDER BY o.id ASC LIMIT 1",
+                    Self::SELECT_DELIVERIES
+                ),
+                [sql_time(now)],
+                Self::map_row,
+            )
+            .optional()?;
+        if let Some(delivery) = &delivery {
+            tx.execute(
+                "UPDATE webhook_outbox SET next_attempt_at = ? WHERE id = ?",
+                rusqlite::params![sql_time(claimed_until), delivery.id],
+            )?;
+        }
+        tx.commit()?;
+
+        Ok(delivery)
+    }
+
+    /// Most recent deliveries first; delivered ones only if `include_delivered`
+    pub fn find_deliveries(conn: &Connection, include_delivered: bool, limit: usize) -> Result<Vec<WebhookDelivery>> {
+        let filter = if include_delivered { "" } else { "WHERE o.status != 'delivered'" };
+        let mut stmt = conn.prepare(&format!(
+            "{} {} ORDER BY o.id DESC LIMIT ?",
+            Self::SELECT_DELIVERIES,
+            filter
+        ))?;
+
+        let deliveries = stmt.query_map([limit as i64], Self::map_row)?;
+
+        let mut results = Vec::new();
+        for delivery in deliveries {
+            results.push(delivery?);
+        }
+
+        Ok(results)
+    }
+
+    pub fn mark_delivered(conn: &Connection, id: i64, now: DateTime<Utc>) -> Result<()> {
+        conn.execute(
+            "UPDATE webhook_outbox
+             SET status = 'delivered', attempts = attempts + 1, delivered_at = ?, last_error = NULL
+             WHERE id = ?",
+            rusqlite::params![sql_time(now), id],
+        )?;
+        Ok(())
+    }
+
+    /// Record a failed attempt; the delivery is retried at `retry_at`, or
+    /// given up on if there is none
+    pub fn record_failure(conn: &Connection, id: i64, error: &str, retry_at: Option<DateTime<Utc>>) -> Result<()> {
+        match retry_at {
+            Some(retry_at) => conn.execute(
+                "UPDATE webhook_outbox
+                 SET attempts = attempts + 1, last_error = ?, next_attempt_at = ?
+                 WHERE id = ?",
+                rusqlite::params![error, sql_time(retry_at), id],
+            )?,
+            None => conn.execute(
+                "UPDATE webhook_outbox
+                 SET status = 'failed', attempts = attempts + 1, last_error = ?
+                 WHERE id = ?",
+                rusqlite::params![error, id],
+            )?,
+        };
+        Ok(())
+    }
+
+    /// Queue a failed delivery again, with a fresh set of attempts
+    pub fn retry(conn: &Connection, id: i64) -> Result<()> {
+        let queued = conn.execute(
+            "UPDATE webhook_outbox
+             SET status = 'pending', attempts = 0, next_attempt_at = datetime('now')
+             WHERE id = ? AND status = 'failed'",
+            [id],
+        )?;
+        if queued == 0 {
+            return Err(AppError::NotFound(format!("Failed delivery {}", id)));
+        }
+        Ok(())
+    }
+
+    const SELECT_DELIVERIES: &'static str =
+        "SELECT o.id, o.webhook_id, w.url, o.event, o.payload, o.status, o.attempts, o.next_attempt_at,
+                o.last_error, o.created_at, o.delivered_at, w.secret
+         FROM webhook_outbox o
+         JOIN webhooks w ON w.id = o.webhook_id";
+
+    fn map_row(row: &Row) -> rusqlite::Result<WebhookDelivery> {
+        Ok(WebhookDelivery {
+            id: row.get(0)?,
+            webhook_id: row.get(1)?,
+            url: row.get(2)?,
+            event: row.get(3)?,
+            payload: row.get(4)?,
+            status: row.get(5)?,
+            attempts: row.get(6)?,
+            next_attempt_at: row.get(7)?,
+            last_error: row.get(8)?,
+            created_at: row.get(9)?,
+            delivered_at: row.get(10)?,
+            secret: row.get(11)?,
+        })
+    }
+}
+
+/// A timestamp in SQLite's `datetime()` format, so it compares as text
+fn sql_time(value: DateTime<Utc>) -> String {
+    value.format("%Y-%m-%d %H:%M:%S").to_string()
+}
@@ -0,0 +1,93 @@
+use rusqlite::Connection;
+use std::ops::Deref;
+
+use crate::utils::Result;
+
+const SAVEPOINT: &str = "unit_of_work";
+
+/// Writes that are committed together or not at all.
+///
+/// The outermost unit of work is a `BEGIN IMMEDIATE` transaction, which takes
+/// the write lock up front: a check followed by a write (a clash check, an
+/// "already subscribed" check) can't be interleaved with another
+/// connection's write, and a busy database is reported at `begin` instead of
+/// halfway through. Units of work nest: one begun while another is open
+/// becomes a savepoint inside it, so repository functions that are atomic on their own (reorder,
+/// duplicate a session, subscribe a group) can be composed into one larger
+/// atomic operation. Only the outermost commit makes the changes durable.
+///
+/// Dropping a unit of work without committing it, whether through an early
+/// `?` return or a panic, rolls back everything written since it began.
+pub struct UnitOfWork<'c> {
+    conn: &'c Connection,
+    nested: bool,
+    finished: bool,
+}
+
+impl<'c> UnitOfWork<'c> {
+    /// Start a unit of work: an immediate transaction, or a savepoint inside the
+    /// current one
+    pub fn begin(conn: &'c Connection) -> Result<Self> {
+        let nested = !conn.is_autocommit();
+        if nested {
+            conn.execute_batch(&format!("SAVEPOINT {}", SAVEPOINT))?;
+        } else {
+            conn.execute_batch("BEGIN IMMEDIATE")?;
+        }
+        Ok(Self { conn, nested, finished: false })
+    }
+
+    /// Keep the changes (durable once the outermost unit of work commits)
+    pub fn commit(mut self) -> Result<()> {
+        if self.nested {
+            self.conn.execute_batch(&format!("RELEASE {}", SAVEPOINT))?;
+        } else {
+            self.conn.execute_batch("COMMIT")?;
+        }
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Undo every change made since `begin`
+    pub fn rollback(mut self) -> Result<()> {
+        self.finished = true;
+        self.undo()
+    }
+
+    fn undo(&self) -> Result<()> {
+        if self.nested {
+            self.conn
+                .execute_batch(&format!("ROLLBACK TO {0}; RELEASE {0}", SAVEPOINT))?;
+        } else {
+            self.conn.execute_batch("ROLLBACK")?;
+        }
+        Ok(())
+    }
+}
+
+impl Deref for UnitOfWork<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn
+    }
+}
+
+impl Drop for UnitOfWork<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            // Errors can't be reported from drop; a failed rollback leaves
+            // the savepoint open and the outer transaction uncommitted
+            let _ = self.undo();
+        }
+    }
+}
+
+/// Run `work` in a unit of work, committing if it returns `Ok` and rolling
+/// back if it returns an error
+pub fn atomically<T>(conn: &Connection, work: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+    let uow = UnitOfWork::begin(conn)?;
+    let value = work(&uow)?;
+    uow.commit()?;
+    Ok(value)
+}
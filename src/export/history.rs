@@ -0,0 +1,57 @@
+use chrono::NaiveDate;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::csv_error;
+use crate::models::HistoryEntry;
+use crate::utils::Result;
+
+pub const HEADERS: [&str; 10] = [
+    "username", "display_name", "session_id", "date", "title", "duration_minutes", "status",
+    "completed_at", "notes", "cancel_reason",
+];
+
+/// Write training history as CSV, one row per subscription
+pub fn write_csv(entries: &[HistoryEntry], out: &mut dyn Write) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record(HEADERS).map_err(csv_error)?;
+
+    for entry in entries {
+        let subscription = &entry.subscription;
+        writer
+            .write_record([
+                entry.username.clone(),
+                entry.display_name.clone(),
+                subscription.session_id.to_string(),
+                entry.session_date.map(|d| d.to_string()).unwrap_or_default(),
+                entry.session_title.clone(),
+                entry.duration_minutes.map(|d| d.to_string()).unwrap_or_default(),
+                subscription.status.as_str().to_string(),
+                subscription
+                    .completed_at
+                    .map(|c| c.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_default(),
+                subscription.notes.clone().unwrap_or_default(),
+                entry.cancel_reason.clone().unwrap_or_default(),
+            ])
+            .map_err(csv_error)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Default file name for a history export: `history-<username>-<date>.csv`
+/// for one player, `history-all-<date>.csv` for a coach-wide export
+pub fn file_name(username: Option<&str>, date: NaiveDate) -> String {
+    format!("history-{}-{}.csv", username.unwrap_or("all"), date)
+}
+
+/// Write history to `dir/file_name(..)`, creating the directory if needed
+pub fn export_to_dir(entries: &[HistoryEntry], dir: &Path, username: Option<&str>, date: NaiveDate) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(file_name(username, date));
+    let mut file = std::fs::File::create(&path)?;
+    write_csv(entries, &mut file)?;
+    Ok(path)
+}
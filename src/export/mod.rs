@@ -0,0 +1,64 @@
+//! File exports shared by the CLI and the TUI
+pub mod history;
+pub mod plan;
+pub mod progress;
+
+use crate::utils::AppError;
+
+/// Output format of printable documents (session plans, progress reports)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DocumentFormat {
+    Markdown,
+    Html,
+}
+
+impl DocumentFormat {
+    pub fn extension(&self) -> &str {
+        match self {
+            DocumentFormat::Markdown => "md",
+            DocumentFormat::Html => "html",
+        }
+    }
+}
+
+pub(crate) fn csv_error(e: csv::Error) -> AppError {
+    AppError::Other(format!("CSV error: {}", e))
+}
+
+pub(crate) const HTML_STYLE: &str = "<style>
+body { font-family: sans-serif; max-width: 50em; margin: 2em auto; color: #222; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #999; padding: 0.4em; text-align: left; vertical-align: top; }
+th { background: #eee; }
+.summary { list-style: none; padding: 0; }
+.notes { font-style: italic; margin-top: 0.3em; }
+.cancelled td { text-decoration: line-through; color: #777; }
+@media print { body { margin: 0; } }
+</style>
+";
+
+pub(crate) fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|v| !v.trim().is_empty())
+}
+
+pub(crate) fn single_line(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub(crate) fn markdown_cell(value: &str) -> String {
+    single_line(value).replace('|', "\\|")
+}
+
+pub(crate) fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Escaped text with line breaks preserved
+pub(crate) fn html_text(value: &str) -> String {
+    escape_html(value.trim()).replace('\n', "<br>")
+}
@@ -0,0 +1,264 @@
+use chrono::{Duration, NaiveTime};
+use rusqlite::Connection;
+use serde::Serialize;
+
+use super::{escape_html, html_text, markdown_cell, non_empty, single_line, DocumentFormat, HTML_STYLE};
+use crate::db::repositories::{SessionRepository, SessionTrainingLinkRepository, TrainingContentRepository, VenueRepository};
+use crate::models::{Court, Session, SessionTrainingLinkWithTemplate, TrainingContent};
+use crate::utils::{AppError, Result};
+
+/// One activity of a session plan with its timing
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlanItem {
+    pub content_type: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub duration_minutes: Option<i32>,
+    /// Custom notes of a linked template
+    pub notes: Option<String>,
+    /// Minutes from the start of the session
+    pub start_offset_minutes: i32,
+    /// Wall-clock start, when the session has a scheduled time
+    pub start_time: Option<NaiveTime>,
+}
+
+/// A session with its training content and linked templates, in plan order
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionPlan {
+    pub session: Session,
+    pub items: Vec<PlanItem>,
+    /// The court the session is booked on
+    pub court: Option<Court>,
+}
+
+impl SessionPlan {
+    /// Merge content and template links by `order_index` (content first on
+    /// ties) and compute each item's start from the durations before it.
+    /// Items without a duration take no time.
+    pub fn build(session: Session, content: Vec<TrainingContent>, links: Vec<SessionTrainingLinkWithTemplate>) -> Self {
+        let mut ordered: Vec<(i32, PlanItem)> = Vec::new();
+        for item in content {
+            ordered.push((
+                item.order_index,
+                PlanItem {
+                    content_type: item.content_type.as_str().to_string(),
+                    title: item.title,
+                    description: item.description,
+                    duration_minutes: item.duration_minutes,
+                    notes: None,
+                    start_offset_minutes: 0,
+                    start_time: None,
+                },
+            ));
+        }
+        for link in links {
+            let Some(template) = link.template else { continue };
+            ordered.push((
+                link.link.order_index,
+                PlanItem {
+                    content_type: template.content_type,
+                    title: template.title,
+                    description: template.description,
+                    duration_minutes: template.duration_minutes,
+                    notes: link.link.custom_notes,
+                    start_offset_minutes: 0,
+                    start_time: None,
+                },
+            ));
+        }
+        ordered.sort_by_key(|(order_index, _)| *order_index);
+
+        let mut offset = 0;
+        let items = ordered
+            .into_iter()
+            .map(|(_, mut item)| {
+                item.start_offset_minutes = offset;
+                item.start_time = session
+                    .scheduled_time
+                    .map(|time| time + Duration::minutes(offset as i64));
+                offset += item.duration_minutes.unwrap_or(0).max(0);
+                item
+            })
+            .collect();
+
+        Self { session, items, court: None }
+    }
+
+    /// Load the plan of a session from the database
+    pub fn load(conn: &Connection, session_id: i64) -> Result<Self> {
+        let session = SessionRepository::find_by_id(conn, session_id)?
+            .ok_or_else(|| AppError::NotFound(format!("Session {}", session_id)))?;
+        let content = TrainingContentRepository::find_by_session(conn, session_id)?;
+        let links = SessionTrainingLinkRepository::get_for_session_with_templates(conn, session_id)?;
+        let court = match session.court_id {
+            Some(court_id) => VenueRepository::find_court(conn, court_id)?,
+            None => None,
+        };
+        Ok(Self { court, ..Self::build(session, content, links) })
+    }
+
+    /// Sum of all item durations
+    pub fn total_minutes(&self) -> i32 {
+        self.items.iter().map(|i| i.duration_minutes.unwrap_or(0).max(0)).sum()
+    }
+
+    /// Minutes the plan runs past the session's scheduled duration
+    pub fn overrun_minutes(&self) -> Option<i32> {
+        let scheduled = self.session.duration_minutes?;
+        let total = self.total_minutes();
+        (total > scheduled).then_some(total - scheduled)
+    }
+
+    pub fn render(&self, format: DocumentFormat) -> String {
+        match format {
+            DocumentFormat::Markdown => self.to_markdown(),
+            DocumentFormat::Html => self.to_html(),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let session = &self.session;
+        let mut out = format!("# {}\n\n", single_line(&session.title));
+        for (label, value) in self.summary() {
+            out.push_str(&format!("- **{}:** {}\n", label, value));
+        }
+        if let Some(description) = non_empty(&session.description) {
+            out.push_str(&format!("\n{}\n", description.trim()));
+        }
+
+        out.push_str("\n## Plan\n\n");
+        if self.items.is_empty() {
+            out.push_str("_No training content yet._\n");
+            return out;
+        }
+
+        out.push_str("| # | Start | Minutes | Type | Activity |\n");
+        out.push_str("|---|-------|---------|------|----------|\n");
+        for (i, item) in self.items.iter().enumerate() {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                i + 1,
+                self.start_label(item),
+                item.duration_minutes.map(|d| d.to_string()).unwrap_or_else(|| "-".to_string()),
+                markdown_cell(&item.content_type),
+                markdown_cell(&item.title),
+            ));
+        }
+
+        out.push_str("\n## Details\n");
+        for (i, item) in self.items.iter().enumerate() {
+            out.push_str(&format!(
+                "\n### {}. {} ({})\n\n",
+                i + 1,
+                single_line(&item.title),
+                self.start_label(item)
+            ));
+            if let Some(description) = non_empty(&item.description) {
+                out.push_str(&format!("{}\n\n", description.trim()));
+            }
+            if let Some(notes) = non_empty(&item.notes) {
+                out.push_str(&format!("> **Notes:** {}\n\n", notes.trim().replace('\n', "\n> ")));
+            }
+        }
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+        out
+    }
+
+    /// Self-contained HTML page (inline CSS, no external resources)
+    pub fn to_html(&self) -> String {
+        let session = &self.session;
+        let mut out = String::new();
+        out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        out.push_str(&format!("<title>{}</title>\n", escape_html(&session.title)));
+        out.push_str(HTML_STYLE);
+        out.push_str("</head>\n<body>\n");
+        out.push_str(&format!("<h1>{}</h1>\n<ul class=\"summary\">\n", escape_html(&session.title)));
+        for (label, value) in self.summary() {
+            out.push_str(&format!(
+                "<li><strong>{}:</strong> {}</li>\n",
+                label,
+                escape_html(&value)
+            ));
+        }
+        out.push_str("</ul>\n");
+        if let Some(description) = non_empty(&session.description) {
+            out.push_str(&format!("<p>{}</p>\n", html_text(description)));
+        }
+
+        out.push_str("<h2>Plan</h2>\n");
+        if self.items.is_empty() {
+            out.push_str("<p><em>No training content yet.</em></p>\n");
+        } else {
+            out.push_str("<table>\n<thead><tr><th>#</th><th>Start</th><th>Minutes</th><th>Type</th><th>Activity</th></tr></thead>\n<tbody>\n");
+            for (i, item) in self.items.iter().enumerate() {
+                out.push_str(&format!("<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td><strong>{}</strong>",
+                    i + 1,
+                    escape_html(&self.start_label(item)),
+                    item.duration_minutes.map(|d| d.to_string()).unwrap_or_else(|| "-".to_string()),
+                    escape_html(&item.content_type),
+                    escape_html(&item.title),
+                ));
+                if let Some(description) = non_empty(&item.description) {
+                    out.push_str(&format!("<div>{}</div>", html_text(description)));
+                }
+                if let Some(notes) = non_empty(&item.notes) {
+                    out.push_str(&format!("<div class=\"notes\">Notes: {}</div>", html_text(notes)));
+                }
+                out.push_str("</td></tr>\n");
+            }
+            out.push_str("</tbody>\n</table>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// Default file name: `plan-<session id>-<date or "undated">.<ext>`
+    pub fn file_name(&self, format: DocumentFormat) -> String {
+        format!(
+            "plan-{}-{}.{}",
+            self.session.id,
+            self.session
+                .scheduled_date
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "undated".to_string()),
+            format.extension()
+        )
+    }
+
+    fn summary(&self) -> Vec<(&'static str, String)> {
+        let session = &self.session;
+        let mut summary = Vec::new();
+        if session.is_cancelled() {
+            summary.push(("Cancelled", session.cancel_reason.clone().unwrap_or_else(|| "-".to_string())));
+        }
+        if let Some(date) = session.scheduled_date {
+            summary.push(("Date", date.format("%A %Y-%m-%d").to_string()));
+        }
+        if let Some(time) = session.scheduled_time {
+            summary.push(("Time", time.format("%H:%M").to_string()));
+        }
+        let mut duration = format!("{} min planned", self.total_minutes());
+        if let Some(scheduled) = session.duration_minutes {
+            duration = format!("{} min scheduled, {}", scheduled, duration);
+        }
+        if let Some(overrun) = self.overrun_minutes() {
+            duration.push_str(&format!(" ({} min over)", overrun));
+        }
+        summary.push(("Duration", duration));
+        if let Some(court) = &self.court {
+            summary.push(("Court", court.label()));
+        }
+        if let Some(level) = &session.skill_level {
+            summary.push(("Skill level", level.as_str().to_string()));
+        }
+        summary
+    }
+
+    fn start_label(&self, item: &PlanItem) -> String {
+        match item.start_time {
+            Some(time) => format!("{} (+{})", time.format("%H:%M"), item.start_offset_minutes),
+            None => format!("+{} min", item.start_offset_minutes),
+        }
+    }
+}
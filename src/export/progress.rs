@@ -0,0 +1,374 @@
+use chrono::NaiveDate;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use super::{escape_html, html_text, markdown_cell, single_line, DocumentFormat, HTML_STYLE};
+use crate::db::repositories::{ProgressRepository, SubscriptionRepository, UserRepository};
+use crate::models::{
+    HistoryEntry, HomeworkStatus, PlayerComment, QuizScore, SkillLevelChange, SubscriptionStatus, User,
+};
+use crate::utils::{AppError, Result};
+
+/// Everything known about one player's progress over a date range
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgressReport {
+    pub player: User,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub sessions: Vec<HistoryEntry>,
+    pub quiz_scores: Vec<QuizScore>,
+    pub homework: Vec<HomeworkStatus>,
+    pub skill_level_changes: Vec<SkillLevelChange>,
+    pub comments: Vec<PlayerComment>,
+}
+
+impl ProgressReport {
+    /// Collect a player's report; `today` decides which homework is overdue
+    pub fn load(
+        conn: &Connection,
+        player_id: i64,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        today: NaiveDate,
+    ) -> Result<Self> {
+        let player = UserRepository::find_by_id(conn, player_id)?
+            .ok_or_else(|| AppError::NotFound(format!("User {}", player_id)))?;
+
+        let in_range = |date: Option<NaiveDate>| match date {
+            Some(date) => from.is_none_or(|from| date >= from) && to.is_none_or(|to| date <= to),
+            None => from.is_none() && to.is_none(),
+        };
+        let sessions = SubscriptionRepository::history_for_player(conn, player_id)?
+            .into_iter()
+            .filter(|entry| in_range(entry.session_date))
+            .collect();
+
+        Ok(Self {
+            sessions,
+            quiz_scores: ProgressRepository::quiz_scores(conn, player_id, from, to)?,
+            homework: ProgressRepository::homework(conn, player_id, from, to, today)?,
+            skill_level_changes: ProgressRepository::skill_level_changes(conn, player_id, from, to)?,
+            comments: ProgressRepository::comments(conn, player_id, from, to)?,
+            player,
+            from,
+            to,
+        })
+    }
+
+    /// Subscriptions that were not cancelled
+    pub fn subscribed(&self) -> usize {
+        self.sessions
+            .iter()
+            .filter(|e| e.subscription.status != SubscriptionStatus::Cancelled)
+            .count()
+    }
+
+    /// Sessions marked as completed
+    pub fn attended(&self) -> usize {
+        self.sessions
+            .iter()
+            .filter(|e| e.subscription.status == SubscriptionStatus::Completed)
+            .count()
+    }
+
+    pub fn completion_rate(&self) -> f64 {
+        percentage(self.attended() as u32, self.subscribed() as u32)
+    }
+
+    /// (correct, answered) over all quizzes
+    pub fn quiz_totals(&self) -> (u32, u32) {
+        self.quiz_scores
+            .iter()
+            .fold((0, 0), |(correct, answered), s| (correct + s.correct, answered + s.answered))
+    }
+
+    pub fn period_label(&self) -> String {
+        match (self.from, self.to) {
+            (Some(from), Some(to)) => format!("{} to {}", from, to),
+            (Some(from), None) => format!("from {}", from),
+            (None, Some(to)) => format!("until {}", to),
+            (None, None) => "all time".to_string(),
+        }
+    }
+
+    /// `progress-<username>[-<from>][_<to>].<ext>`
+    pub fn file_name(&self, format: DocumentFormat) -> String {
+        let mut name = format!("progress-{}", self.player.username);
+        if let Some(from) = self.from {
+            name.push_str(&format!("-{}", from));
+        }
+        if let Some(to) = self.to {
+            name.push_str(&format!("_{}", to));
+        }
+        format!("{}.{}", name, format.extension())
+    }
+
+    pub fn render(&self, format: DocumentFormat) -> String {
+        let title = format!("Progress report: {}", self.player.display_name);
+        let blocks = self.blocks();
+        match format {
+            DocumentFormat::Markdown => to_markdown(&title, &blocks),
+            DocumentFormat::Html => to_html(&title, &blocks),
+        }
+    }
+
+    fn blocks(&self) -> Vec<Block> {
+        let player = &self.player;
+        let mut blocks = vec![Block::Summary(vec![
+            ("Player", format!("{} (@{})", player.display_name, player.username)),
+            ("Period", self.period_label()),
+            (
+                "Skill level",
+                player.skill_level.as_ref().map(|s| s.as_str()).unwrap_or("not set").to_string(),
+            ),
+            ("Goals", player.goals.clone().unwrap_or_else(|| "-".to_string())),
+        ])];
+
+        blocks.push(Block::Heading("Attendance"));
+        blocks.push(Block::Text(format!(
+            "{} sessions subscribed, {} completed ({:.0}%).",
+            self.subscribed(),
+            self.attended(),
+            self.completion_rate()
+        )));
+        if !self.sessions.is_empty() {
+            blocks.push(Block::Table {
+                headers: &["Date", "Session", "Status"],
+                rows: self
+                    .sessions
+                    .iter()
+                    .map(|e| {
+                        let status = match (&e.subscription.status, &e.cancel_reason) {
+                            (SubscriptionStatus::Cancelled, Some(reason)) => format!("cancelled: {}", reason),
+                            (status, _) => status.as_str().to_string(),
+                        };
+                        vec![date_cell(e.session_date), e.session_title.clone(), status]
+                    })
+                    .collect(),
+                struck: self
+                    .sessions
+                    .iter()
+                    .map(|e| e.subscription.status == SubscriptionStatus::Cancelled)
+                    .collect(),
+            });
+        }
+
+        blocks.push(Block::Heading("Quiz scores"));
+        if self.quiz_scores.is_empty() {
+            blocks.push(Block::Empty("No quiz answers in this period."));
+        } else {
+            blocks.push(Block::Table {
+                headers: &["Date", "Session", "Correct", "Answered", "Score"],
+                rows: self
+                    .quiz_scores
+                    .iter()
+                    .map(|s| {
+                        vec![
+                            date_cell(s.session_date),
+                            s.session_title.clone(),
+                            s.correct.to_string(),
+                            s.answered.to_string(),
+                            format!("{:.0}%", percentage(s.correct, s.answered)),
+                        ]
+                    })
+                    .collect(),
+                struck: Vec::new(),
+            });
+            let (correct, answered) = self.quiz_totals();
+            blocks.push(Block::Text(format!(
+                "Overall: {} of {} correct ({:.0}%).",
+                correct,
+                answered,
+                percentage(correct, answered)
+            )));
+        }
+
+        blocks.push(Block::Heading("Homework"));
+        if self.homework.is_empty() {
+            blocks.push(Block::Empty("No homework in this period."));
+        } else {
+            blocks.push(Block::Table {
+                headers: &["Due", "Homework", "Session", "Status"],
+                rows: self
+                    .homework
+                    .iter()
+                    .map(|h| {
+                        vec![
+                            date_cell(h.due_date),
+                            h.title.clone(),
+                            h.session_title.clone(),
+                            h.state.as_str().to_string(),
+                        ]
+                    })
+                    .collect(),
+                struck: Vec::new(),
+            });
+        }
+
+        blocks.push(Block::Heading("Skill level"));
+        if self.skill_level_changes.is_empty() {
+            blocks.push(Block::Empty("No changes in this period."));
+        } else {
+            for change in &self.skill_level_changes {
+                let level = |l: &Option<crate::models::SkillLevel>| {
+                    l.as_ref().map(|l| l.as_str()).unwrap_or("not set").to_string()
+                };
+                blocks.push(Block::Item(format!(
+                    "{}: {} → {}",
+                    change.changed_at.format("%Y-%m-%d"),
+                    level(&change.old_level),
+                    level(&change.new_level)
+                )));
+            }
+        }
+
+        blocks.push(Block::Heading("Coach comments"));
+        if self.comments.is_empty() {
+            blocks.push(Block::Empty("No comments in this period."));
+        } else {
+            for comment in &self.comments {
+                blocks.push(Block::Comment {
+                    byline: format!("{}, {}", comment.created_at.format("%Y-%m-%d"), comment.coach_name),
+                    body: comment.body.clone(),
+                });
+            }
+        }
+
+        blocks
+    }
+}
+
+/// Report content, rendered either as Markdown or HTML
+enum Block {
+    Summary(Vec<(&'static str, String)>),
+    Heading(&'static str),
+    Text(String),
+    Empty(&'static str),
+    Item(String),
+    /// `struck` marks rows (by index) shown struck through, like cancelled sessions
+    Table { headers: &'static [&'static str], rows: Vec<Vec<String>>, struck: Vec<bool> },
+    Comment { byline: String, body: String },
+}
+
+fn to_markdown(title: &str, blocks: &[Block]) -> String {
+    let mut out = format!("# {}\n", single_line(title));
+    for block in blocks {
+        match block {
+            Block::Summary(entries) => {
+                out.push('\n');
+                for (label, value) in entries {
+                    out.push_str(&format!("- **{}:** {}\n", label, single_line(value)));
+                }
+            }
+            Block::Heading(heading) => out.push_str(&format!("\n## {}\n", heading)),
+            Block::Text(text) => out.push_str(&format!("\n{}\n", text)),
+            Block::Empty(text) => out.push_str(&format!("\n_{}_\n", text)),
+            Block::Item(text) => {
+                // Blank line before the first item of a list only
+                if !out.lines().last().is_some_and(|l| l.starts_with("- ")) {
+                    out.push('\n');
+                }
+                out.push_str(&format!("- {}\n", text));
+            }
+            Block::Table { headers, rows, struck } => {
+                out.push_str(&format!("\n| {} |\n", headers.join(" | ")));
+                out.push_str(&format!("|{}\n", "---|".repeat(headers.len())));
+                for (i, row) in rows.iter().enumerate() {
+                    let strike = struck.get(i).copied().unwrap_or(false);
+                    let cells: Vec<String> = row
+                        .iter()
+                        .map(|c| match markdown_cell(c) {
+                            cell if strike && !cell.is_empty() => format!("~~{}~~", cell),
+                            cell => cell,
+                        })
+                        .collect();
+                    out.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+            }
+            Block::Comment { byline, body } => {
+                out.push_str(&format!("\n**{}**\n\n> {}\n", byline, body.trim().replace('\n', "\n> ")));
+            }
+        }
+    }
+    out
+}
+
+fn to_html(title: &str, blocks: &[Block]) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!("<title>{}</title>\n", escape_html(title)));
+    out.push_str(HTML_STYLE);
+    out.push_str("</head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", escape_html(title)));
+
+    let mut in_list = false;
+    for block in blocks {
+        if in_list && !matches!(block, Block::Item(_)) {
+            out.push_str("</ul>\n");
+            in_list = false;
+        }
+        match block {
+            Block::Summary(entries) => {
+                out.push_str("<ul class=\"summary\">\n");
+                for (label, value) in entries {
+                    out.push_str(&format!("<li><strong>{}:</strong> {}</li>\n", label, escape_html(value)));
+                }
+                out.push_str("</ul>\n");
+            }
+            Block::Heading(heading) => out.push_str(&format!("<h2>{}</h2>\n", heading)),
+            Block::Text(text) => out.push_str(&format!("<p>{}</p>\n", escape_html(text))),
+            Block::Empty(text) => out.push_str(&format!("<p><em>{}</em></p>\n", escape_html(text))),
+            Block::Item(text) => {
+                if !in_list {
+                    out.push_str("<ul>\n");
+                    in_list = true;
+                }
+                out.push_str(&format!("<li>{}</li>\n", escape_html(text)));
+            }
+            Block::Table { headers, rows, struck } => {
+                out.push_str("<table>\n<thead><tr>");
+                for header in headers.iter() {
+                    out.push_str(&format!("<th>{}</th>", header));
+                }
+                out.push_str("</tr></thead>\n<tbody>\n");
+                for (i, row) in rows.iter().enumerate() {
+                    if struck.get(i).copied().unwrap_or(false) {
+                        out.push_str("<tr class=\"cancelled\">");
+                    } else {
+                        out.push_str("<tr>");
+                    }
+                    for cell in row {
+                        out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</tbody>\n</table>\n");
+            }
+            Block::Comment { byline, body } => {
+                out.push_str(&format!(
+                    "<blockquote><strong>{}</strong><br>{}</blockquote>\n",
+                    escape_html(byline),
+                    html_text(body)
+                ));
+            }
+        }
+    }
+    if in_list {
+        out.push_str("</ul>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn date_cell(date: Option<NaiveDate>) -> String {
+    date.map(|d| d.to_string()).unwrap_or_else(|| "-".to_string())
+}
+
+fn percentage(part: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 * 100.0 / total as f64
+    }
+}
@@ -1,6 +1,12 @@
 // Library exports for testing and external use
+pub mod api;
 pub mod auth;
+pub mod cli;
+pub mod config;
 pub mod db;
+pub mod export;
 pub mod models;
+pub mod reminders;
 pub mod ui;
 pub mod utils;
+pub mod webhooks;
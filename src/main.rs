@@ -1,9 +1,3 @@
-mod auth;
-mod db;
-mod models;
-mod ui;
-mod utils;
-
 use clap::Parser;
 use crossterm::{
     execute,
@@ -11,58 +5,79 @@ use crossterm::{
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::io;
-use tracing_subscriber;
-
-use auth::UserContext;
-use db::{establish_connection, run_migrations};
-use models::{User, UserRole};
-use ui::App;
-use utils::AppError;
-
-#[derive(Parser, Debug)]
-#[command(name = "tennis-tui")]
-#[command(about = "Tennis Training TUI Application", long_about = None)]
-struct Args {
-    // Username to authenticate as
-    #[arg(long)]
-    user: Option<String>,
-
-    /// Path to SQLite database
-    #[arg(long, default_value = "data/tennis.db")]
-    db_path: String,
-
-    /// Initialize database with sample data
-    #[arg(long)]
-    init_db: bool,
-}
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize logging
-    tracing_subscriber::fmt::init();
+use tui_coach::auth::{ssh_key, UserContext};
+use tui_coach::cli::{self, commands, Cli, Command, ConfigCommand};
+use tui_coach::config::Settings;
+use tui_coach::db::{establish_connection, run_migrations};
+use tui_coach::db::repositories::{UserKeyRepository, UserRepository};
+use tui_coach::models::User;
+use tui_coach::ui::App;
+use tui_coach::utils::logging::{self, AUDIT_TARGET};
+use tui_coach::utils::AppError;
 
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Parse command line arguments
-    let args = Args::parse();
+    let args = Cli::parse();
+
+    // Resolve defaults, config files, environment and flags
+    let settings = match Settings::load(args.config.as_deref(), args.db_path.as_deref(), &args.set) {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(Command::Config(ConfigCommand::Show)) = &args.command {
+        commands::config_show(&settings, args.json, &mut io::stdout().lock())?;
+        return Ok(());
+    }
+
+    // Initialize logging; logs go to files only so they never draw over the TUI
+    if let Err(err) = logging::init(&settings) {
+        eprintln!("Warning: logging disabled: {}", err);
+    }
 
     // Establish database connection
-    let conn = establish_connection(&args.db_path)?;
+    let conn = establish_connection(&settings.database_path)?;
 
     // Run migrations
     run_migrations(&conn)?;
 
-    // Init database with sample data if requested
-    if args.init_db {
-        init_sample_data(&conn)?;
-        println!("Database initialized with sample data.");
-        return Ok(());
-    }
+    // Legacy top-level flags map onto `init --sample-data` and `run --user`
+    let command = match args.command {
+        Some(command) => command,
+        None if args.init_db => Command::Init { sample_data: true },
+        None => Command::Run {
+            // User is required for running the application
+            user: Some(args.user.ok_or_else(|| {
+                Box::new(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "Error: --user is required",
+                )) as Box<dyn std::error::Error>
+            })?),
+            user_from_key: false,
+        },
+    };
+
+    let username = match command {
+        Command::Run { user: Some(user), .. } => user,
+        Command::Run { user: None, .. } => user_from_key(&conn)?,
+        command => {
+            let _span = tracing::info_span!("cli").entered();
+            let mut stdout = io::stdout().lock();
+            if let Err(err) = cli::execute(&conn, &settings, &command, args.json, &mut stdout) {
+                tracing::error!(command = ?command, error = %err, "command failed");
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+    };
 
-    // User is required for running the application
-    let username = args.user.ok_or_else(|| {
-        Box::new(std::io::Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "Error: --user is required",
-        )) as Box<dyn std::error::Error>
-    })?;
+    // Every log line of this TUI session carries the username
+    let _span = tracing::info_span!("tui", user = %username).entered();
 
     // Load user from database
     let user = load_user(&conn, &username)?;
@@ -76,7 +91,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     // Create and run app
-    let mut app = App::new(user_context, args.db_path);
+    let mut app = App::new(user_context, &settings);
     let res = app.run(&mut terminal);
 
     // Restore terminal
@@ -85,6 +100,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     terminal.show_cursor()?;
 
     if let Err(err) = res {
+        tracing::error!(error = %err, "TUI exited with an error");
         eprintln!("Error: {:?}", err);
     }
 
@@ -92,93 +108,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 fn load_user(conn: &rusqlite::Connection, username: &str) -> Result<User, AppError> {
-    eprintln!("DEBUG: Attempting to load user: {}", username);
-
-    let mut stmt = conn.prepare(
-        "SELECT id, username, display_name, role, skill_level, goals, created_at, updated_at
-         FROM users WHERE username = ?",
-    )?;
-
-    let user = stmt.query_row([username], |row| {
-        let role_str: String = row.get(3)?;
-        eprintln!("DEBUG: role_str = {}", role_str);
-
-        let skill_level_str: Option<String> = row.get(4)?;
-        eprintln!("DEBUG: skill_level_str = {:?}", skill_level_str);
-
-        let role = match UserRole::from_str(&role_str) {
-            Some(r) => {
-                eprintln!("DEBUG: Parsed role successfully: {:?}", r);
-                r
-            }
-            None => {
-                eprintln!("DEBUG: Failed to parse role from: '{}'", role_str);
-                return Err(rusqlite::Error::InvalidQuery);
-            }
-        };
-
-        let skill_level = skill_level_str.and_then(|s| models::SkillLevel::from_str(&s));
-        eprintln!("DEBUG: skill_level parsed: {:?}", skill_level);
-
-        let created_at_str: String = row.get(6)?;
-        eprintln!("DEBUG: created_at_str = {}", created_at_str);
-        let updated_at_str: String = row.get(7)?;
-        eprintln!("DEBUG: updated_at_str = {}", updated_at_str);
-
-        // Parse SQLite datetime format (YYYY-MM-DD HH:MM:SS)
-        let created_at =
-            chrono::NaiveDateTime::parse_from_str(&created_at_str, "%Y-%m-%d %H:%M:%S")
-                .map(|dt| {
-                    chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(dt, chrono::Utc)
-                })
-                .unwrap_or_else(|_| chrono::Utc::now());
-        eprintln!("DEBUG: created_at parsed successfully");
-
-        let updated_at =
-            chrono::NaiveDateTime::parse_from_str(&updated_at_str, "%Y-%m-%d %H:%M:%S")
-                .map(|dt| {
-                    chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(dt, chrono::Utc)
-                })
-                .unwrap_or_else(|_| chrono::Utc::now());
-        eprintln!("DEBUG: updated_at parsed successfully");
-
-        eprintln!("DEBUG: About to create User struct");
-
-        Ok(User {
-            id: row.get(0)?,
-            username: row.get(1)?,
-            display_name: row.get(2)?,
-            role,
-            skill_level,
-            goals: row.get(5)?,
-            created_at,
-            updated_at,
-        })
-    })?;
-
-    Ok(user)
+    match UserRepository::find_by_username(conn, username)? {
+        Some(user) => {
+            tracing::info!(target: AUDIT_TARGET, user = %user.username, role = user.role.as_str(), "login");
+            Ok(user)
+        }
+        None => {
+            tracing::warn!(target: AUDIT_TARGET, user = %username, "login failed: unknown user");
+            Err(AppError::NotFound(format!("User {}", username)))
+        }
+    }
 }
 
-fn init_sample_data(conn: &rusqlite::Connection) -> Result<(), AppError> {
-    // Insert coach
-    conn.execute(
-        "INSERT OR IGNORE INTO users (username, display_name, role, created_at, updated_at)
-         VALUES (?, ?, ?, datetime('now'), datetime('now'))",
-        ["coach", "Coach Peter", "coach"],
-    )?;
-
-    // Insert sample players
-    conn.execute(
-        "INSERT OR IGNORE INTO users (username, display_name, role, skill_level, goals, created_at, updated_at)
-         VALUES (?, ?, ?, ?, ?, datetime('now'), datetime('now'))",
-        ["alice", "Alice Smith", "player", "beginner", "Improve serve and backhand"],
-    )?;
-
-    conn.execute(
-        "INSERT OR IGNORE INTO users (username, display_name, role, skill_level, goals, created_at, updated_at)
-         VALUES (?, ?, ?, ?, ?, datetime('now'), datetime('now'))",
-        ["bob", "Bob Jones", "player", "intermediate", "Prepare for tournament"],
-    )?;
-
-    Ok(())
+/// `run --user-from-key`: the owner of the SSH key sshd authenticated this login with
+fn user_from_key(conn: &rusqlite::Connection) -> Result<String, AppError> {
+    let fingerprint = ssh_key::login_fingerprint(|name| std::env::var(name).ok())?;
+    match UserKeyRepository::find_by_fingerprint(conn, &fingerprint)? {
+        Some(key) if !key.is_revoked() => Ok(key.username),
+        Some(key) => {
+            tracing::warn!(target: AUDIT_TARGET, user = %key.username, %fingerprint, "login failed: revoked key");
+            Err(AppError::Unauthorized(format!("Key {} has been revoked", fingerprint)))
+        }
+        None => {
+            tracing::warn!(target: AUDIT_TARGET, %fingerprint, "login failed: unknown key");
+            Err(AppError::Unauthorized(format!("Key {} is not registered", fingerprint)))
+        }
+    }
 }
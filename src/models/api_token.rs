@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// A bearer token for the REST API; the token itself is never stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiToken {
+    pub id: i64,
+    pub user_id: i64,
+    /// Username of the token's owner
+    pub username: String,
+    pub label: Option<String>,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+impl ApiToken {
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
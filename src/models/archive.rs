@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Kinds of rows that are archived (soft-deleted) instead of deleted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchiveKind {
+    Session,
+    Content,
+    Template,
+}
+
+impl ArchiveKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ArchiveKind::Session => "session",
+            ArchiveKind::Content => "content",
+            ArchiveKind::Template => "template",
+        }
+    }
+
+    /// Name shown in messages and on the archive screen
+    pub fn label(&self) -> &str {
+        match self {
+            ArchiveKind::Session => "Session",
+            ArchiveKind::Content => "Training content",
+            ArchiveKind::Template => "Template",
+        }
+    }
+
+    /// Table holding rows of this kind
+    pub fn table(&self) -> &'static str {
+        match self {
+            ArchiveKind::Session => "sessions",
+            ArchiveKind::Content => "training_content",
+            ArchiveKind::Template => "training_templates",
+        }
+    }
+}
+
+/// A row waiting in the archive to be restored or purged
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ArchivedItem {
+    pub kind: ArchiveKind,
+    pub id: i64,
+    pub title: String,
+    /// Session the content belongs to (content only)
+    pub session_title: Option<String>,
+    pub archived_at: DateTime<Utc>,
+}
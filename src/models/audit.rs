@@ -0,0 +1,142 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Insert,
+    Update,
+    Delete,
+}
+
+impl FromStr for AuditAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "insert" => Ok(AuditAction::Insert),
+            "update" => Ok(AuditAction::Update),
+            "delete" => Ok(AuditAction::Delete),
+            _ => Err(format!("Unknown audit action '{}'", s)),
+        }
+    }
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AuditAction::Insert => "insert",
+            AuditAction::Update => "update",
+            AuditAction::Delete => "delete",
+        }
+    }
+
+    /// Past tense for display ("created", "updated", "deleted")
+    pub fn label(&self) -> &str {
+        match self {
+            AuditAction::Insert => "created",
+            AuditAction::Update => "updated",
+            AuditAction::Delete => "deleted",
+        }
+    }
+}
+
+/// One recorded change of a database row
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEntry {
+    pub id: i64,
+    pub actor_id: Option<i64>,
+    /// Display name of the actor, if the user still exists
+    pub actor_name: Option<String>,
+    /// Table the row belongs to
+    pub entity: String,
+    pub entity_id: Option<i64>,
+    pub session_id: Option<i64>,
+    pub action: AuditAction,
+    /// Row as JSON before the change (updates and deletes)
+    pub before: Option<Value>,
+    /// Row as JSON after the change (inserts and updates)
+    pub after: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditEntry {
+    /// Who made the change; changes without an actor come from the CLI or imports
+    pub fn actor_label(&self) -> String {
+        match (&self.actor_name, self.actor_id) {
+            (Some(name), _) => name.clone(),
+            (None, Some(id)) => format!("user #{}", id),
+            (None, None) => "system".to_string(),
+        }
+    }
+
+    /// Columns whose value differs between before and after, in column order
+    pub fn changed_fields(&self) -> Vec<String> {
+        let empty = serde_json::Map::new();
+        let before = self.before.as_ref().and_then(Value::as_object).unwrap_or(&empty);
+        let after = self.after.as_ref().and_then(Value::as_object).unwrap_or(&empty);
+
+        let mut fields: Vec<String> = after
+            .iter()
+            .filter(|(key, value)| before.get(*key) != Some(*value))
+            .map(|(key, _)| key.clone())
+            .collect();
+        fields.extend(before.keys().filter(|key| !after.contains_key(*key)).cloned());
+        fields
+    }
+
+    /// `(field, before, after)` for every changed column, values rendered as text
+    pub fn changes(&self) -> Vec<(String, String, String)> {
+        let value = |row: &Option<Value>, field: &str| match row.as_ref().and_then(|row| row.get(field)) {
+            None | Some(Value::Null) => "-".to_string(),
+            Some(Value::String(s)) => s.clone(),
+            Some(other) => other.to_string(),
+        };
+
+        self.changed_fields()
+            .into_iter()
+            .map(|field| {
+                let before = value(&self.before, &field);
+                let after = value(&self.after, &field);
+                (field, before, after)
+            })
+            .collect()
+    }
+
+    /// `title` or `name` of the row, when it has one
+    pub fn subject(&self) -> Option<String> {
+        let row = self.after.as_ref().or(self.before.as_ref())?;
+        ["title", "name", "username"]
+            .iter()
+            .find_map(|key| row.get(*key).and_then(Value::as_str))
+            .map(|s| s.to_string())
+    }
+
+    /// One-line summary, e.g. `updated sessions #3 "Serve clinic": title, duration_minutes`
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{} {}", self.action.label(), self.entity);
+        if let Some(id) = self.entity_id {
+            summary.push_str(&format!(" #{}", id));
+        }
+        if let Some(subject) = self.subject() {
+            summary.push_str(&format!(" \"{}\"", subject));
+        }
+        if self.action == AuditAction::Update {
+            summary.push_str(&format!(": {}", self.changed_fields().join(", ")));
+        }
+        summary
+    }
+}
+
+/// Filters for browsing the audit log; all are optional
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditQuery {
+    pub actor_id: Option<i64>,
+    pub session_id: Option<i64>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    /// Maximum number of entries, newest first
+    pub limit: Option<usize>,
+}
@@ -0,0 +1,239 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::group::Group;
+use super::session::{Session, Subscription, TrainingContent};
+use super::training_template::{SessionTrainingLink, TrainingTemplate};
+use super::user::User;
+use super::venue::VenueWithCourts;
+
+/// Version written to `format_version`; bump when the document layout changes
+pub const CLUB_EXPORT_VERSION: u32 = 1;
+
+/// Versioned JSON document holding the whole club database.
+///
+/// IDs are the ones from the exporting database; importing remaps them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClubExport {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub users: Vec<User>,
+    pub sessions: Vec<Session>,
+    pub training_content: Vec<TrainingContent>,
+    pub templates: Vec<TrainingTemplate>,
+    pub session_training_links: Vec<SessionTrainingLink>,
+    pub subscriptions: Vec<Subscription>,
+    #[serde(default)]
+    pub session_tags: Vec<SessionTagExport>,
+    #[serde(default)]
+    pub groups: Vec<GroupExport>,
+    #[serde(default)]
+    pub venues: Vec<VenueWithCourts>,
+    #[serde(default)]
+    pub template_tags: Vec<TemplateTagExport>,
+    #[serde(default)]
+    pub quizzes: Vec<QuizExport>,
+    #[serde(default)]
+    pub homework: Vec<HomeworkExport>,
+    #[serde(default)]
+    pub quiz_responses: Vec<QuizResponseExport>,
+    #[serde(default)]
+    pub homework_submissions: Vec<HomeworkSubmissionExport>,
+    #[serde(default)]
+    pub player_comments: Vec<PlayerCommentExport>,
+    #[serde(default)]
+    pub skill_level_changes: Vec<SkillLevelChangeExport>,
+    #[serde(default)]
+    pub user_keys: Vec<UserKeyExport>,
+    #[serde(default)]
+    pub api_tokens: Vec<ApiTokenExport>,
+    #[serde(default)]
+    pub sent_reminders: Vec<SentReminderExport>,
+    #[serde(default)]
+    pub notifications: Vec<NotificationExport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionTagExport {
+    pub session_id: i64,
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateTagExport {
+    pub template_id: i64,
+    pub tag: String,
+}
+
+// The rows below are exported as stored, timestamps included, so a replace
+// import gives back the same history
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuizExport {
+    pub id: i64,
+    pub session_id: i64,
+    pub question: String,
+    pub correct_answer: String,
+    pub options: Option<String>,
+    pub order_index: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HomeworkExport {
+    pub id: i64,
+    pub session_id: i64,
+    pub title: String,
+    pub description: String,
+    pub due_date: Option<String>,
+    pub order_index: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuizResponseExport {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub quiz_id: i64,
+    pub answer: String,
+    pub is_correct: bool,
+    pub answered_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HomeworkSubmissionExport {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub homework_id: i64,
+    pub notes: Option<String>,
+    pub submitted_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerCommentExport {
+    pub id: i64,
+    pub player_id: i64,
+    pub coach_id: i64,
+    pub body: String,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillLevelChangeExport {
+    pub id: i64,
+    pub user_id: i64,
+    pub old_level: Option<String>,
+    pub new_level: Option<String>,
+    pub changed_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserKeyExport {
+    pub id: i64,
+    pub user_id: i64,
+    pub key_type: String,
+    pub public_key: String,
+    pub fingerprint: String,
+    pub label: Option<String>,
+    pub created_at: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+/// Only the token hash is stored, so tokens keep working after a restore
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiTokenExport {
+    pub id: i64,
+    pub user_id: i64,
+    pub token_hash: String,
+    pub label: Option<String>,
+    pub created_at: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SentReminderExport {
+    pub id: i64,
+    pub session_id: i64,
+    pub user_id: i64,
+    pub starts_at: String,
+    pub email: String,
+    pub sent_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationExport {
+    pub id: i64,
+    pub user_id: i64,
+    pub session_id: Option<i64>,
+    pub kind: String,
+    pub message: String,
+    pub created_at: Option<String>,
+    pub read_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupExport {
+    #[serde(flatten)]
+    pub group: Group,
+    pub member_ids: Vec<i64>,
+}
+
+/// Rows written vs. rows that already existed, for one table
+#[derive(Debug, Clone, Copy, Default, Serialize, PartialEq)]
+pub struct ImportCount {
+    pub added: usize,
+    pub existing: usize,
+}
+
+/// What an import did, per table
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportSummary {
+    pub users: ImportCount,
+    pub venues: ImportCount,
+    pub courts: ImportCount,
+    pub sessions: ImportCount,
+    pub training_content: ImportCount,
+    pub templates: ImportCount,
+    pub session_training_links: ImportCount,
+    pub subscriptions: ImportCount,
+    pub session_tags: ImportCount,
+    pub groups: ImportCount,
+    pub template_tags: ImportCount,
+    pub quizzes: ImportCount,
+    pub homework: ImportCount,
+    pub quiz_responses: ImportCount,
+    pub homework_submissions: ImportCount,
+    pub player_comments: ImportCount,
+    pub skill_level_changes: ImportCount,
+    pub user_keys: ImportCount,
+    pub api_tokens: ImportCount,
+    pub sent_reminders: ImportCount,
+    pub notifications: ImportCount,
+}
+
+impl ImportSummary {
+    /// (table name, counts) pairs in import order
+    pub fn rows(&self) -> Vec<(&'static str, ImportCount)> {
+        vec![
+            ("users", self.users),
+            ("user_keys", self.user_keys),
+            ("api_tokens", self.api_tokens),
+            ("skill_level_changes", self.skill_level_changes),
+            ("player_comments", self.player_comments),
+            ("venues", self.venues),
+            ("courts", self.courts),
+            ("templates", self.templates),
+            ("template_tags", self.template_tags),
+            ("sessions", self.sessions),
+            ("training_content", self.training_content),
+            ("quizzes", self.quizzes),
+            ("homework", self.homework),
+            ("session_training_links", self.session_training_links),
+            ("session_tags", self.session_tags),
+            ("subscriptions", self.subscriptions),
+            ("quiz_responses", self.quiz_responses),
+            ("homework_submissions", self.homework_submissions),
+            ("sent_reminders", self.sent_reminders),
+            ("notifications", self.notifications),
+            ("groups", self.groups),
+        ]
+    }
+}
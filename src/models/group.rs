@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use super::user::User;
+
+/// A named squad of players managed by a coach (e.g. "U14 Tuesday group")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Group {
+    pub id: i64,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_by: i64,
+    pub created_at: String,
+}
+
+/// Outcome of assigning a session to every member of a group
+#[derive(Debug, Clone, Default)]
+pub struct GroupAssignment {
+    /// Players that received a new subscription
+    pub subscribed: Vec<User>,
+    /// Players that were already subscribed and were left untouched
+    pub already_subscribed: Vec<User>,
+}
+
+impl GroupAssignment {
+    /// Short human-readable summary for the message bar / CLI output
+    pub fn summary(&self) -> String {
+        let mut summary = format!("Subscribed {} player(s)", self.subscribed.len());
+        if !self.already_subscribed.is_empty() {
+            let names: Vec<&str> = self
+                .already_subscribed
+                .iter()
+                .map(|u| u.display_name.as_str())
+                .collect();
+            summary.push_str(&format!("; already subscribed: {}", names.join(", ")));
+        }
+        summary
+    }
+}
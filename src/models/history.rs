@@ -0,0 +1,17 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use super::session::Subscription;
+
+/// A player's subscription joined with its session, as shown in training history
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryEntry {
+    pub username: String,
+    pub display_name: String,
+    pub session_title: String,
+    pub session_date: Option<NaiveDate>,
+    pub duration_minutes: Option<i32>,
+    /// Why the session was cancelled, if it was
+    pub cancel_reason: Option<String>,
+    pub subscription: Subscription,
+}
@@ -1,9 +1,43 @@
+pub mod api_token;
+pub mod archive;
+pub mod audit;
+pub mod backup;
+pub mod group;
+pub mod history;
+pub mod notification;
+pub mod player_import;
+pub mod progress;
+pub mod reminder;
+pub mod search;
 pub mod session;
 pub mod session_with_subscription;
+pub mod tag;
 pub mod user;
+pub mod user_key;
+pub mod venue;
+pub mod webhook;
 pub mod training_template;
 
+pub use api_token::ApiToken;
+pub use archive::{ArchiveKind, ArchivedItem};
+pub use audit::{AuditAction, AuditEntry, AuditQuery};
+pub use backup::{
+    ApiTokenExport, ClubExport, GroupExport, HomeworkExport, HomeworkSubmissionExport, ImportCount, ImportSummary,
+    NotificationExport, PlayerCommentExport, QuizExport, QuizResponseExport, SentReminderExport, SessionTagExport,
+    SkillLevelChangeExport, TemplateTagExport, UserKeyExport, CLUB_EXPORT_VERSION,
+};
+pub use group::{Group, GroupAssignment};
+pub use history::HistoryEntry;
+pub use notification::{Notification, NotificationKind};
+pub use player_import::{ImportedPlayer, PlayerImportSummary, PlayerRow, RowError};
+pub use progress::{HomeworkState, HomeworkStatus, PlayerComment, QuizScore, SkillLevelChange};
+pub use reminder::{DueReminder, ReminderOutcome, ReminderStatus};
+pub use search::{SearchHit, SearchKind};
 pub use session::{ContentType, Session, TrainingContent, Subscription, SubscriptionStatus};
 pub use session_with_subscription::SessionWithSubscription;
+pub use tag::Tag;
 pub use user::{SkillLevel, User, UserRole};
+pub use user_key::UserKey;
+pub use venue::{booked_span, Booking, Clash, ClashKind, Court, Venue, VenueWithCourts, DEFAULT_BOOKING_MINUTES};
+pub use webhook::{Webhook, WebhookDelivery, WEBHOOK_EVENTS};
 pub use training_template::{SessionTrainingLink, SessionTrainingLinkWithTemplate, TemplateAuditInfo, TrainingTemplate};
\ No newline at end of file
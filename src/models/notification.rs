@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// What a notification is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    SessionChanged,
+    SessionCancelled,
+    SessionDeleted,
+    SessionRestored,
+    Feedback,
+}
+
+impl FromStr for NotificationKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "session_changed" => Ok(NotificationKind::SessionChanged),
+            "session_cancelled" => Ok(NotificationKind::SessionCancelled),
+            "session_deleted" => Ok(NotificationKind::SessionDeleted),
+            "session_restored" => Ok(NotificationKind::SessionRestored),
+            "feedback" => Ok(NotificationKind::Feedback),
+            _ => Err(format!("Unknown notification kind '{}'", s)),
+        }
+    }
+}
+
+impl NotificationKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            NotificationKind::SessionChanged => "session_changed",
+            NotificationKind::SessionCancelled => "session_cancelled",
+            NotificationKind::SessionDeleted => "session_deleted",
+            NotificationKind::SessionRestored => "session_restored",
+            NotificationKind::Feedback => "feedback",
+        }
+    }
+
+    /// Name shown in the inbox
+    pub fn label(&self) -> &str {
+        match self {
+            NotificationKind::SessionChanged => "Changed",
+            NotificationKind::SessionCancelled => "Cancelled",
+            NotificationKind::SessionDeleted => "Deleted",
+            NotificationKind::SessionRestored => "Restored",
+            NotificationKind::Feedback => "Feedback",
+        }
+    }
+}
+
+/// A message in a user's inbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: i64,
+    pub user_id: i64,
+    /// The session it is about, while that session exists
+    pub session_id: Option<i64>,
+    pub kind: NotificationKind,
+    pub message: String,
+    pub created_at: String,
+    pub read_at: Option<String>,
+}
+
+impl Notification {
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+}
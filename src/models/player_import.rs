@@ -0,0 +1,53 @@
+use serde::Serialize;
+use std::fmt;
+
+use super::user::{SkillLevel, UserRole};
+
+/// One validated row of a player import file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerRow {
+    /// Line number in the CSV file (the header is line 1)
+    pub line: usize,
+    pub username: String,
+    pub display_name: String,
+    pub role: UserRole,
+    pub skill_level: Option<SkillLevel>,
+    pub goals: Option<String>,
+    pub email: Option<String>,
+    pub group: Option<String>,
+    /// OpenSSH public key, used for the printed `authorized_keys` line
+    pub ssh_key: Option<String>,
+}
+
+/// A problem with one row of a player import file
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RowError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// A user created by a player import
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedPlayer {
+    pub id: i64,
+    pub username: String,
+    pub display_name: String,
+    pub role: UserRole,
+    pub skill_level: Option<SkillLevel>,
+    pub group: Option<String>,
+    pub authorized_keys_line: String,
+}
+
+/// Result of a successful player import
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerImportSummary {
+    pub players: Vec<ImportedPlayer>,
+    /// Groups that did not exist before the import
+    pub groups_created: Vec<String>,
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+use super::user::SkillLevel;
+
+/// Quiz answers given by a player in one session
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct QuizScore {
+    pub session_id: i64,
+    pub session_title: String,
+    pub session_date: Option<NaiveDate>,
+    pub answered: u32,
+    pub correct: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HomeworkState {
+    Submitted,
+    Open,
+    Overdue,
+}
+
+impl HomeworkState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            HomeworkState::Submitted => "submitted",
+            HomeworkState::Open => "open",
+            HomeworkState::Overdue => "overdue",
+        }
+    }
+}
+
+/// Homework of a session the player is subscribed to
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HomeworkStatus {
+    pub homework_id: i64,
+    pub title: String,
+    pub session_title: String,
+    pub due_date: Option<NaiveDate>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub state: HomeworkState,
+}
+
+/// One change of a player's skill level
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SkillLevelChange {
+    pub old_level: Option<SkillLevel>,
+    pub new_level: Option<SkillLevel>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// A coach's comment on a player
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerComment {
+    pub id: i64,
+    pub player_id: i64,
+    pub coach_id: i64,
+    pub coach_name: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,47 @@
+use serde::Serialize;
+
+/// A subscribed player to remind of an upcoming session
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DueReminder {
+    pub session_id: i64,
+    pub user_id: i64,
+    pub username: String,
+    pub display_name: String,
+    /// None if the player has no address on file
+    pub email: Option<String>,
+    /// Session start, `YYYY-MM-DD HH:MM`; sessions without a time start at 00:00
+    pub starts_at: String,
+}
+
+/// What `remind` did for one player and session
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderStatus {
+    Sent,
+    /// Would have been sent (`--dry-run`)
+    Pending,
+    /// The player has no email address
+    NoEmail,
+    /// The relay refused or couldn't be reached; retried on the next run
+    Failed,
+}
+
+impl ReminderStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReminderStatus::Sent => "sent",
+            ReminderStatus::Pending => "pending",
+            ReminderStatus::NoEmail => "no email",
+            ReminderStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReminderOutcome {
+    #[serde(flatten)]
+    pub reminder: DueReminder,
+    pub title: String,
+    pub status: ReminderStatus,
+    pub error: Option<String>,
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// What a search hit points at
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SearchKind {
+    Session,
+    Content,
+    Template,
+    Note,
+}
+
+impl FromStr for SearchKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "session" => Ok(SearchKind::Session),
+            "content" => Ok(SearchKind::Content),
+            "template" => Ok(SearchKind::Template),
+            "note" => Ok(SearchKind::Note),
+            _ => Err(format!("Unknown search kind '{}'", s)),
+        }
+    }
+}
+
+impl SearchKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SearchKind::Session => "session",
+            SearchKind::Content => "content",
+            SearchKind::Template => "template",
+            SearchKind::Note => "note",
+        }
+    }
+
+    /// Heading used when results are grouped by kind
+    pub fn label(&self) -> &str {
+        match self {
+            SearchKind::Session => "Sessions",
+            SearchKind::Content => "Training Content",
+            SearchKind::Template => "Templates",
+            SearchKind::Note => "Notes",
+        }
+    }
+}
+
+/// A ranked full-text search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    pub ref_id: i64,
+    /// Session to open for this hit. For templates this is the most recent
+    /// session using the template, or None if it isn't used anywhere.
+    pub session_id: Option<i64>,
+    pub title: String,
+    pub snippet: String,
+}
+
+impl SearchHit {
+    /// Turn user input into an FTS5 match expression.
+    ///
+    /// Every word must match (as a prefix); punctuation is dropped so the
+    /// input can never be an invalid FTS5 query. Returns None if nothing
+    /// searchable is left.
+    pub fn match_expression(input: &str) -> Option<String> {
+        let terms: Vec<String> = input
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .map(|t| format!("\"{}\"*", t.to_lowercase()))
+            .collect();
+
+        if terms.is_empty() {
+            None
+        } else {
+            Some(terms.join(" "))
+        }
+    }
+}
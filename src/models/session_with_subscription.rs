@@ -1,7 +1,9 @@
+use serde::Serialize;
+
 use super::{Session, Subscription};
 
 /// Session combined with subscription details (for player views)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SessionWithSubscription {
     pub session: Session,
     pub subscription: Option<Subscription>,
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// Free-form label attached to sessions and templates (e.g. "serve", "footwork")
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Tag {
+    pub id: i64,
+    pub name: String,
+}
+
+impl Tag {
+    /// Parse a comma-separated tag list as typed by the user.
+    ///
+    /// Tags are trimmed and lowercased; empty entries and duplicates are dropped
+    /// while keeping the original order.
+    pub fn parse_list(input: &str) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for raw in input.split(',') {
+            let tag = raw.trim().to_lowercase();
+            if !tag.is_empty() && !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        tags
+    }
+
+    /// Validate a comma-separated tag list
+    pub fn validate_list(input: &str) -> Result<(), String> {
+        let tags = Self::parse_list(input);
+        if tags.len() > 10 {
+            return Err("At most 10 tags are allowed".to_string());
+        }
+        if let Some(tag) = tags.iter().find(|t| t.len() > 30) {
+            return Err(format!("Tag '{}' must be less than 30 characters", tag));
+        }
+        Ok(())
+    }
+}
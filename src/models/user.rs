@@ -50,7 +50,7 @@ impl SkillLevel {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: i64,
     pub username: String,
@@ -58,6 +58,9 @@ pub struct User {
     pub role: UserRole,
     pub skill_level: Option<SkillLevel>,
     pub goals: Option<String>,
+    /// Where session reminders are sent
+    #[serde(default)]
+    pub email: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// An SSH public key that logs a user in (see `authorized-keys`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserKey {
+    pub id: i64,
+    pub user_id: i64,
+    /// Username of the key's owner
+    pub username: String,
+    pub key_type: String,
+    /// Base64 key blob
+    pub public_key: String,
+    /// `SHA256:...`, as printed by `ssh-keygen -l`
+    pub fingerprint: String,
+    pub label: Option<String>,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+impl UserKey {
+    /// `<type> <base64>`, as used in `authorized_keys`
+    pub fn openssh(&self) -> String {
+        format!("{} {}", self.key_type, self.public_key)
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
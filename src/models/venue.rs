@@ -0,0 +1,109 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// Sessions without a duration are taken to last this long when checking for
+/// double-booking
+pub const DEFAULT_BOOKING_MINUTES: i32 = 60;
+
+/// A place with courts sessions can be booked on (see `venue add`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Venue {
+    pub id: i64,
+    pub name: String,
+    pub address: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Court {
+    pub id: i64,
+    pub venue_id: i64,
+    pub name: String,
+    /// Name of the venue the court belongs to, for display
+    pub venue_name: String,
+}
+
+impl Court {
+    /// `Court 1 @ Riverside`
+    pub fn label(&self) -> String {
+        format!("{} @ {}", self.name, self.venue_name)
+    }
+}
+
+/// A venue with its courts, as listed by `venue list --json` and exported
+/// with the club data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueWithCourts {
+    #[serde(flatten)]
+    pub venue: Venue,
+    #[serde(default)]
+    pub courts: Vec<Court>,
+}
+
+/// The slot a session is about to take, checked against the sessions already
+/// booked before it is saved
+#[derive(Debug, Clone)]
+pub struct Booking {
+    pub date: Option<NaiveDate>,
+    pub time: Option<NaiveTime>,
+    pub duration_minutes: Option<i32>,
+    pub court_id: Option<i64>,
+    pub coach_id: i64,
+    /// The session being edited, which can't clash with itself
+    pub session_id: Option<i64>,
+}
+
+impl Booking {
+    /// Start and end, if the session has both a date and a time
+    pub fn span(&self) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        Some(booked_span(self.date?, self.time?, self.duration_minutes))
+    }
+}
+
+/// Start and end of a session starting at `date` and `time`
+pub fn booked_span(date: NaiveDate, time: NaiveTime, duration_minutes: Option<i32>) -> (NaiveDateTime, NaiveDateTime) {
+    let start = date.and_time(time);
+    let minutes = duration_minutes.unwrap_or(DEFAULT_BOOKING_MINUTES).max(1);
+    (start, start + Duration::minutes(minutes as i64))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClashKind {
+    /// Another session is booked on the same court
+    Court,
+    /// The coach already runs another session at that time
+    Coach,
+}
+
+/// An existing session overlapping a booking
+#[derive(Debug, Clone, Serialize)]
+pub struct Clash {
+    pub kind: ClashKind,
+    pub session_id: i64,
+    pub title: String,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    /// Label of the shared court, for court clashes
+    pub court: Option<String>,
+}
+
+impl Clash {
+    pub fn describe(&self) -> String {
+        let when = format!(
+            "{} {}-{}",
+            self.starts_at.format("%Y-%m-%d"),
+            self.starts_at.format("%H:%M"),
+            self.ends_at.format("%H:%M")
+        );
+        match self.kind {
+            ClashKind::Court => format!(
+                "{} is already booked for \"{}\" ({})",
+                self.court.as_deref().unwrap_or("The court"),
+                self.title,
+                when
+            ),
+            ClashKind::Coach => format!("The coach already runs \"{}\" ({})", self.title, when),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// Session events a webhook can receive
+pub const WEBHOOK_EVENTS: [&str; 7] = [
+    "session.created",
+    "session.updated",
+    "session.deleted",
+    "session.restored",
+    "session.subscribed",
+    "session.unsubscribed",
+    "session.completed",
+];
+
+/// An endpoint that is sent session events (see `webhook add`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: i64,
+    pub url: String,
+    /// Events sent to this endpoint; empty means all of them
+    pub events: Vec<String>,
+    pub created_at: String,
+}
+
+impl Webhook {
+    pub fn events_label(&self) -> String {
+        if self.events.is_empty() {
+            "all".to_string()
+        } else {
+            self.events.join(", ")
+        }
+    }
+}
+
+/// One event for one webhook, waiting in or delivered from the outbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub url: String,
+    pub event: String,
+    /// JSON body
+    pub payload: String,
+    /// `pending`, `delivered` or `failed` (given up after too many attempts)
+    pub status: String,
+    pub attempts: i64,
+    pub next_attempt_at: String,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+    /// The webhook's signing secret; never listed
+    #[serde(skip)]
+    pub secret: Option<String>,
+}
@@ -0,0 +1,126 @@
+//! Email reminders for upcoming sessions (`tennis-tui remind`), meant to run
+//! from cron.
+//!
+//! Each run looks for active subscriptions to sessions starting within the
+//! next `reminders.hours`, emails each player through the configured SMTP
+//! relay, and records what was sent in `sent_reminders` so that later runs
+//! don't repeat it. A failed send is not recorded and is tried again next run.
+
+pub mod smtp;
+
+use chrono::{Duration, NaiveDateTime};
+use rusqlite::Connection;
+use std::collections::hash_map::{Entry, HashMap};
+
+use crate::db::repositories::{ReminderRepository, UserRepository};
+use crate::export::plan::SessionPlan;
+use crate::models::{DueReminder, ReminderOutcome, ReminderStatus};
+use crate::utils::Result;
+use smtp::Relay;
+
+/// A rendered reminder
+#[derive(Debug, Clone, PartialEq)]
+pub struct Email {
+    pub subject: String,
+    pub body: String,
+}
+
+/// Remind every player of sessions starting between `now` and `hours` later.
+/// Without a relay nothing is sent or recorded (a dry run).
+pub fn send_due(
+    conn: &Connection,
+    relay: Option<&Relay>,
+    now: NaiveDateTime,
+    hours: u32,
+    location: Option<&str>,
+) -> Result<Vec<ReminderOutcome>> {
+    let due = ReminderRepository::due(conn, now, now + Duration::hours(hours as i64))?;
+
+    let mut plans: HashMap<i64, (SessionPlan, Option<String>)> = HashMap::new();
+    let mut outcomes = Vec::new();
+    for reminder in due {
+        if let Entry::Vacant(entry) = plans.entry(reminder.session_id) {
+            let plan = SessionPlan::load(conn, reminder.session_id)?;
+            let coach = UserRepository::find_by_id(conn, plan.session.created_by)?.map(|coach| coach.display_name);
+            entry.insert((plan, coach));
+        }
+        let (plan, coach) = &plans[&reminder.session_id];
+
+        let (status, error) = match (&reminder.email, relay) {
+            (None, _) => (ReminderStatus::NoEmail, None),
+            (Some(_), None) => (ReminderStatus::Pending, None),
+            (Some(email), Some(relay)) => {
+                let message = render(plan, &reminder, coach.as_deref(), location);
+                match relay.send(email, &message.subject, &message.body) {
+                    Ok(()) => {
+                        ReminderRepository::record_sent(conn, &reminder, email)?;
+                        (ReminderStatus::Sent, None)
+                    }
+                    Err(e) => {
+                        tracing::warn!(session_id = reminder.session_id, user = %reminder.username, error = %e, "reminder not sent");
+                        (ReminderStatus::Failed, Some(e.to_string()))
+                    }
+                }
+            }
+        };
+        outcomes.push(ReminderOutcome { title: plan.session.title.clone(), reminder, status, error });
+    }
+
+    Ok(outcomes)
+}
+
+/// Subject and plain-text body of one player's reminder
+pub fn render(plan: &SessionPlan, reminder: &DueReminder, coach: Option<&str>, location: Option<&str>) -> Email {
+    let session = &plan.session;
+    let title = session.title.replace(['\r', '\n'], " ");
+
+    let mut when = session
+        .scheduled_date
+        .map(|date| date.format("%A %-d %B %Y").to_string())
+        .unwrap_or_default();
+    let mut subject = format!("Reminder: {}", title);
+    if let Some(date) = session.scheduled_date {
+        subject.push_str(&format!(" on {}", date.format("%a %-d %b")));
+    }
+    if let Some(time) = session.scheduled_time {
+        subject.push_str(&format!(" at {}", time.format("%H:%M")));
+        when.push_str(&format!(", {}", time.format("%H:%M")));
+        if let Some(minutes) = session.duration_minutes {
+            when.push_str(&format!("-{}", (time + Duration::minutes(minutes as i64)).format("%H:%M")));
+        }
+    }
+
+    let mut body = format!("Hi {},\n\nThis is a reminder of your upcoming session.\n\n", reminder.display_name);
+    body.push_str(&format!("{}\n", title));
+    body.push_str(&format!("  When:  {}\n", when));
+    // The booked court says more than the club-wide `reminders.location`
+    let court = plan.court.as_ref().map(|court| court.label());
+    if let Some(location) = court.as_deref().or(location) {
+        body.push_str(&format!("  Where: {}\n", location));
+    }
+    if let Some(level) = &session.skill_level {
+        body.push_str(&format!("  Level: {}\n", level.as_str()));
+    }
+    if let Some(description) = session.description.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        body.push_str(&format!("\n{}\n", description));
+    }
+
+    if !plan.items.is_empty() {
+        body.push_str("\nPlan:\n");
+        for item in &plan.items {
+            let start = match item.start_time {
+                Some(time) => time.format("%H:%M").to_string(),
+                None => format!("+{}m", item.start_offset_minutes),
+            };
+            let length = item.duration_minutes.map(|d| format!(", {} min", d)).unwrap_or_default();
+            body.push_str(&format!("  {:>5}  {} ({}{})\n", start, item.title, item.content_type, length));
+        }
+    }
+
+    body.push_str("\nSee you on court!\n");
+    if let Some(coach) = coach {
+        body.push_str(&format!("{}\n", coach));
+    }
+
+    Email { subject, body }
+}
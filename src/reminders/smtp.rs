@@ -0,0 +1,126 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::auth::digest::base64_encode;
+use crate::config::Settings;
+use crate::utils::{AppError, Result};
+
+/// How long to wait for the relay to connect and to answer each command
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// An SMTP relay that accepts mail without authentication, such as the
+/// local MTA. It takes care of TLS and onward delivery.
+///
+/// The client speaks plain SMTP with neither STARTTLS nor AUTH, so the relay
+/// must accept mail from this host unauthenticated: run it on the same
+/// machine or a trusted network, and let it relay only for trusted hosts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relay {
+    pub host: String,
+    pub port: u16,
+    /// Sender address
+    pub from: String,
+    /// Name given in `EHLO`
+    pub helo: String,
+}
+
+impl Relay {
+    /// The relay from `[smtp]` in the config file
+    pub fn from_settings(settings: &Settings) -> Result<Self> {
+        let (Some(host), Some(from)) = (&settings.smtp_host, &settings.smtp_from) else {
+            return Err(AppError::Validation(
+                "Set smtp.host and smtp.from in config.toml to send reminders".to_string(),
+            ));
+        };
+        Ok(Self {
+            host: host.clone(),
+            port: settings.smtp_port,
+            from: from.clone(),
+            helo: settings.smtp_helo.clone(),
+        })
+    }
+
+    /// Send one plain-text message
+    pub fn send(&self, to: &str, subject: &str, body: &str) -> Result<()> {
+        let addr = (self.host.as_str(), self.port)
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| AppError::Other(format!("Can't resolve {}", self.host)))?;
+        let mut stream = TcpStream::connect_timeout(&addr, TIMEOUT)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        expect(&mut reader, 220)?;
+        command(&mut stream, &mut reader, &format!("EHLO {}", self.helo), 250)?;
+        command(&mut stream, &mut reader, &format!("MAIL FROM:<{}>", self.from), 250)?;
+        command(&mut stream, &mut reader, &format!("RCPT TO:<{}>", to), 250)?;
+        command(&mut stream, &mut reader, "DATA", 354)?;
+
+        let mut data = String::new();
+        for line in self.message(to, subject, body).lines() {
+            // Dot-stuffing, so a line of "." doesn't end the message early
+            if line.starts_with('.') {
+                data.push('.');
+            }
+            data.push_str(line);
+            data.push_str("\r\n");
+        }
+        data.push_str(".\r\n");
+        stream.write_all(data.as_bytes())?;
+        expect(&mut reader, 250)?;
+
+        // The message is accepted; a failed goodbye doesn't matter
+        let _ = command(&mut stream, &mut reader, "QUIT", 221);
+        Ok(())
+    }
+
+    fn message(&self, to: &str, subject: &str, body: &str) -> String {
+        let now = chrono::Utc::now();
+        format!(
+            "Date: {}\nFrom: {}\nTo: {}\nSubject: {}\nMessage-ID: <{}.{}@tennis-tui>\nMIME-Version: 1.0\nContent-Type: text/plain; charset=utf-8\nContent-Transfer-Encoding: 8bit\n\n{}",
+            now.to_rfc2822(),
+            self.from,
+            to,
+            encode_header(subject),
+            now.timestamp_nanos_opt().unwrap_or_default(),
+            std::process::id(),
+            body
+        )
+    }
+}
+
+/// RFC 2047 encoded-word for non-ASCII header values
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", base64_encode(value.as_bytes()))
+    }
+}
+
+fn command(stream: &mut TcpStream, reader: &mut impl BufRead, line: &str, code: u16) -> Result<()> {
+    stream.write_all(format!("{}\r\n", line).as_bytes())?;
+    expect(reader, code)
+}
+
+/// Read a (possibly multi-line) reply and check it is in the same class
+/// (2xx, 3xx) as `code`
+fn expect(reader: &mut impl BufRead, code: u16) -> Result<()> {
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(AppError::Other("SMTP relay closed the connection".to_string()));
+        }
+        let reply = line.trim_end();
+        let continued = reply.as_bytes().get(3) == Some(&b'-');
+        if continued {
+            continue;
+        }
+        return match reply.get(..3).and_then(|c| c.parse::<u16>().ok()) {
+            Some(received) if received / 100 == code / 100 => Ok(()),
+            _ => Err(AppError::Other(format!("SMTP relay answered '{}'", reply))),
+        };
+    }
+}
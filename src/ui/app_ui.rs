@@ -7,19 +7,52 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame, Terminal,
 };
+use rusqlite::Connection;
+use std::cell::OnceCell;
+use std::collections::HashMap;
 use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 use crate::auth::UserContext;
-use crate::db::repositories::{SessionRepository, SubscriptionRepository, TrainingContentRepository};
-use crate::models::{SessionWithSubscription, TrainingContent};
+use crate::config::{KeyBindings, Settings};
+use crate::db::atomically;
+use crate::db::connection::data_version;
+use crate::db::repositories::{
+    ArchiveRepository, AuditRepository, SessionRepository, SubscriptionRepository, TagRepository, TrainingContentRepository, VenueRepository,
+};
+use crate::export::plan::SessionPlan;
+use crate::export::DocumentFormat;
+use crate::models::{ArchiveKind, ArchivedItem, AuditEntry, Court, Group, HistoryEntry, Notification, SearchHit, SessionWithSubscription, TrainingContent, User, UserKey};
+use crate::ui::group_form::GroupForm;
+use crate::ui::key_form::KeyForm;
 use crate::ui::navigation::Screen;
-use crate::ui::session_filter::SessionFilter;
+use crate::ui::session_filter::{SessionFilter, SessionFilterBar};
 use crate::ui::session_form::SessionForm;
 use crate::ui::session_edit_form::SessionEditForm;
 use crate::ui::text_editor::TextEditor;
 use crate::ui::help::HelpScreen;
+use crate::ui::theme::Theme;
 use crate::ui::training_content_form::TrainingContentForm;
+use crate::utils::logging::{self, AUDIT_TARGET};
+
+mod archive;
+mod audit;
+mod cancel;
+mod groups;
+mod inbox;
+mod profile;
+mod search;
+mod user_keys;
+mod venues;
+
+/// How long the last deletion can be undone with `u`
+const UNDO_WINDOW: Duration = Duration::from_secs(30);
+
+/// How often the app checks for changes made by other users while idle
+const TICK_RATE: Duration = Duration::from_millis(500);
 
 pub struct App {
     pub user_context: UserContext,
@@ -37,17 +70,56 @@ pub struct App {
     pub training_content_form: TrainingContentForm,
     pub training_content_selected_index: usize,
     pub home_menu_selected_index: usize,
+    pub groups: Vec<Group>,
+    pub group_selected_index: usize,
+    pub group_form: GroupForm,
+    pub group_roster: Vec<(User, bool)>,
+    pub group_roster_selected_index: usize,
+    pub session_tags: HashMap<i64, Vec<String>>,
+    /// Courts sessions can be booked on, for the session forms
+    pub courts: Vec<Court>,
+    pub filter_bar: SessionFilterBar,
+    pub filter_bar_form: SessionFilterBar,
+    pub search_query: String,
+    pub cancel_reason: String,
+    pub search_results: Vec<SearchHit>,
+    pub search_selected_index: usize,
+    pub search_return_screen: Screen,
+    pub profile_user: Option<User>,
+    pub profile_history: Vec<HistoryEntry>,
+    pub profile_selected_index: usize,
+    pub profile_return_screen: Screen,
+    pub audit_entries: Vec<AuditEntry>,
+    pub audit_selected_index: usize,
+    pub archived_items: Vec<ArchivedItem>,
+    pub archive_selected_index: usize,
+    /// Last archived row and when, for undo
+    pub last_archived: Option<(ArchiveKind, i64, Instant)>,
+    pub user_keys: Vec<UserKey>,
+    pub user_key_selected_index: usize,
+    pub key_form: KeyForm,
+    pub notifications: Vec<Notification>,
+    pub notification_selected_index: usize,
+    /// Shown as a badge in the header
+    pub unread_notifications: i64,
+    pub theme: Theme,
+    pub keys: KeyBindings,
+    pub page_size: usize,
+    /// Opened on first use and kept for the whole session
+    conn: OnceCell<Rc<Connection>>,
+    /// `PRAGMA data_version` when the current screen was last refreshed
+    data_version: Option<i64>,
 }
 
 impl App {
-    pub fn new(user_context: UserContext, db_path: String) -> Self {
+    pub fn new(user_context: UserContext, settings: &Settings) -> Self {
         Self {
             user_context,
             should_quit: false,
             current_screen: Screen::Home,
             sessions: Vec::new(),
             selected_index: 0,
-            db_path,
+            db_path: settings.database_path.clone(),
             session_filter: SessionFilter::MySubscriptions,
             message: None,
             session_form: SessionForm::new(),
@@ -57,20 +129,64 @@ impl App {
             training_content_form: TrainingContentForm::new(),
             training_content_selected_index: 0,
             home_menu_selected_index: 0,
+            groups: Vec::new(),
+            group_selected_index: 0,
+            group_form: GroupForm::new(),
+            group_roster: Vec::new(),
+            group_roster_selected_index: 0,
+            session_tags: HashMap::new(),
+            courts: Vec::new(),
+            filter_bar: SessionFilterBar::new(),
+            filter_bar_form: SessionFilterBar::new(),
+            search_query: String::new(),
+            cancel_reason: String::new(),
+            search_results: Vec::new(),
+            search_selected_index: 0,
+            search_return_screen: Screen::Home,
+            profile_user: None,
+            profile_history: Vec::new(),
+            profile_selected_index: 0,
+            profile_return_screen: Screen::Home,
+            audit_entries: Vec::new(),
+            audit_selected_index: 0,
+            archived_items: Vec::new(),
+            archive_selected_index: 0,
+            last_archived: None,
+            user_keys: Vec::new(),
+            user_key_selected_index: 0,
+            key_form: KeyForm::new(),
+            notifications: Vec::new(),
+            notification_selected_index: 0,
+            unread_notifications: 0,
+            theme: settings.theme,
+            keys: settings.keys,
+            page_size: settings.page_size,
+            conn: OnceCell::new(),
+            data_version: None,
         }
     }
 
     pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        self.load_unread_count();
+        let mut last_tick = Instant::now();
         loop {
             terminal.draw(|f| self.render(f))?;
 
-            if let Event::Key(key) = event::read()? {
+            // Wait for a key until the next tick is due
+            let timeout = TICK_RATE.saturating_sub(last_tick.elapsed());
+            if event::poll(timeout)? && let Event::Key(key) = event::read()? {
                 // Only handle KeyPress events (ignore KeyRelease and KeyRepeat)
                 if key.kind == KeyEventKind::Press {
+                    let _span = logging::screen_span(&self.current_screen).entered();
                     self.handle_key_event(key);
                 }
             }
 
+            if last_tick.elapsed() >= TICK_RATE {
+                self.on_tick();
+                last_tick = Instant::now();
+            }
+
             if self.should_quit {
                 break;
             }
@@ -91,19 +207,71 @@ impl App {
             return;
         }
 
+        // Handle filter bar input
+        if self.current_screen == Screen::SessionFilterEdit {
+            self.handle_filter_bar_key_event(key);
+            return;
+        }
+
+        // Handle search prompt input
+        if self.current_screen == Screen::Search {
+            self.handle_search_key_event(key);
+            return;
+        }
+
+        // Handle cancellation reason input
+        if let Screen::SessionCancel(session_id) = self.current_screen {
+            self.handle_cancel_key_event(key, session_id);
+            return;
+        }
+
+        // Handle group form input
+        if self.current_screen == Screen::GroupCreate {
+            self.handle_group_form_key_event(key);
+            return;
+        }
+
+        // Handle SSH key form input
+        if matches!(self.current_screen, Screen::UserKeyCreate | Screen::UserKeyLabel(_)) {
+            self.handle_key_form_key_event(key);
+            return;
+        }
+
         // Handle delete confirmation
-        if matches!(self.current_screen, Screen::SessionDelete(_) | Screen::TrainingContentDelete(_)) {
+        if matches!(
+            self.current_screen,
+            Screen::SessionDelete(_)
+                | Screen::TrainingContentDelete(_)
+                | Screen::GroupDelete(_)
+                | Screen::ArchivePurge
+                | Screen::UserKeyRevoke(_)
+        ) {
             match key.code {
                 KeyCode::Char('y') | KeyCode::Char('Y') => {
                     if let Screen::SessionDelete(session_id) = self.current_screen {
                         self.delete_session(session_id);
                     } else if let Screen::TrainingContentDelete(content_id) = self.current_screen {
                         self.delete_training_content(content_id);
+                    } else if let Screen::GroupDelete(group_id) = self.current_screen {
+                        self.delete_group(group_id);
+                    } else if self.current_screen == Screen::ArchivePurge {
+                        self.purge_archived_item();
+                    } else if let Screen::UserKeyRevoke(key_id) = self.current_screen {
+                        self.revoke_user_key(key_id);
                     }
                 }
                 KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
-                    self.current_screen = Screen::SessionList;
-                    self.load_sessions();
+                    if matches!(self.current_screen, Screen::GroupDelete(_)) {
+                        self.current_screen = Screen::GroupList;
+                        self.load_groups();
+                    } else if self.current_screen == Screen::ArchivePurge {
+                        self.current_screen = Screen::Archive;
+                    } else if matches!(self.current_screen, Screen::UserKeyRevoke(_)) {
+                        self.current_screen = Screen::UserKeys;
+                    } else {
+                        self.current_screen = Screen::SessionList;
+                        self.load_sessions();
+                    }
                 }
                 _ => {}
             }
@@ -114,11 +282,19 @@ impl App {
         self.message = None;
 
         match key.code {
-            KeyCode::Char('?') => {
+            KeyCode::Char(c) if c == self.keys.help => {
                 // Show help screen (available from any screen)
                 self.current_screen = Screen::Help;
             }
-            KeyCode::Char('q') | KeyCode::Char('Q') => {
+            KeyCode::Char(c) if c == self.keys.search => {
+                // Open full-text search (available from any screen)
+                self.search_return_screen = self.current_screen.clone();
+                self.search_query.clear();
+                self.search_results.clear();
+                self.search_selected_index = 0;
+                self.current_screen = Screen::Search;
+            }
+            KeyCode::Char(c) if c.eq_ignore_ascii_case(&self.keys.quit) => {
                 // Always quit the app
                 self.should_quit = true;
             }
@@ -129,6 +305,16 @@ impl App {
                 } else if self.current_screen == Screen::Help {
                     self.current_screen = Screen::Home;
                     self.home_menu_selected_index = 0;
+                } else if matches!(self.current_screen, Screen::GroupMembers(_)) {
+                    self.current_screen = Screen::GroupList;
+                    self.load_groups();
+                } else if matches!(self.current_screen, Screen::GroupAssign(_)) {
+                    self.current_screen = Screen::SessionList;
+                } else if matches!(self.current_screen, Screen::PlayerProfile(_)) {
+                    self.current_screen = self.profile_return_screen.clone();
+                    self.home_menu_selected_index = 0;
+                } else if let Screen::SessionAudit(session_id) = self.current_screen {
+                    self.current_screen = Screen::SessionDetail(session_id);
                 } else {
                     self.current_screen = Screen::Home;
                     self.home_menu_selected_index = 0;
@@ -148,10 +334,30 @@ impl App {
                         }
                     }
                     // Session list navigation
-                    Screen::SessionList => {
-                        if self.selected_index > 0 {
-                            self.selected_index -= 1;
-                        }
+                    Screen::SessionList if self.selected_index > 0 => {
+                        self.selected_index -= 1;
+                    }
+                    // Group list navigation
+                    Screen::GroupList | Screen::GroupAssign(_) if self.group_selected_index > 0 => {
+                        self.group_selected_index -= 1;
+                    }
+                    Screen::GroupMembers(_) if self.group_roster_selected_index > 0 => {
+                        self.group_roster_selected_index -= 1;
+                    }
+                    Screen::PlayerProfile(_) if self.profile_selected_index > 0 => {
+                        self.profile_selected_index -= 1;
+                    }
+                    Screen::SessionAudit(_) if self.audit_selected_index > 0 => {
+                        self.audit_selected_index -= 1;
+                    }
+                    Screen::Archive if self.archive_selected_index > 0 => {
+                        self.archive_selected_index -= 1;
+                    }
+                    Screen::UserKeys if self.user_key_selected_index > 0 => {
+                        self.user_key_selected_index -= 1;
+                    }
+                    Screen::Inbox if self.notification_selected_index > 0 => {
+                        self.notification_selected_index -= 1;
                     }
                     _ => {}
                 }
@@ -168,14 +374,40 @@ impl App {
                         }
                     }
                     // Session list navigation
-                    Screen::SessionList => {
-                        if !self.sessions.is_empty() && self.selected_index < self.sessions.len() - 1 {
-                            self.selected_index += 1;
-                        }
+                    Screen::SessionList if !self.sessions.is_empty() && self.selected_index < self.sessions.len() - 1 => {
+                        self.selected_index += 1;
+                    }
+                    // Group list navigation
+                    Screen::GroupList | Screen::GroupAssign(_) if !self.groups.is_empty() && self.group_selected_index < self.groups.len() - 1 => {
+                        self.group_selected_index += 1;
+                    }
+                    Screen::GroupMembers(_) if !self.group_roster.is_empty() && self.group_roster_selected_index < self.group_roster.len() - 1 => {
+                        self.group_roster_selected_index += 1;
+                    }
+                    Screen::PlayerProfile(_) if !self.profile_history.is_empty() && self.profile_selected_index < self.profile_history.len() - 1 => {
+                        self.profile_selected_index += 1;
+                    }
+                    Screen::SessionAudit(_) if !self.audit_entries.is_empty() && self.audit_selected_index < self.audit_entries.len() - 1 => {
+                        self.audit_selected_index += 1;
+                    }
+                    Screen::Archive if !self.archived_items.is_empty() && self.archive_selected_index < self.archived_items.len() - 1 => {
+                        self.archive_selected_index += 1;
+                    }
+                    Screen::UserKeys if !self.user_keys.is_empty() && self.user_key_selected_index < self.user_keys.len() - 1 => {
+                        self.user_key_selected_index += 1;
+                    }
+                    Screen::Inbox if !self.notifications.is_empty() && self.notification_selected_index < self.notifications.len() - 1 => {
+                        self.notification_selected_index += 1;
                     }
                     _ => {}
                 }
             }
+            KeyCode::PageUp if self.current_screen == Screen::SessionList => {
+                self.selected_index = self.selected_index.saturating_sub(self.page_size);
+            }
+            KeyCode::PageDown if self.current_screen == Screen::SessionList && !self.sessions.is_empty() => {
+                self.selected_index = (self.selected_index + self.page_size).min(self.sessions.len() - 1);
+            }
             KeyCode::Enter => {
                 match self.current_screen {
                     // Menu selection on Home screen
@@ -192,24 +424,64 @@ impl App {
                                     self.current_screen = Screen::SessionList;
                                     self.selected_index = 0;
                                 }
+                                Screen::GroupList => {
+                                    self.load_groups();
+                                    self.current_screen = Screen::GroupList;
+                                }
+                                Screen::PlayerProfile(user_id) => {
+                                    self.open_profile(*user_id);
+                                }
+                                Screen::Archive => {
+                                    self.load_archive();
+                                    self.current_screen = Screen::Archive;
+                                }
+                                Screen::UserKeys => {
+                                    self.load_user_keys();
+                                    self.current_screen = Screen::UserKeys;
+                                }
+                                Screen::Inbox => {
+                                    self.load_notifications();
+                                    self.notification_selected_index = 0;
+                                    self.current_screen = Screen::Inbox;
+                                }
                                 _ => {}
                             }
                         }
                     }
                     // Session selection on SessionList
-                    Screen::SessionList => {
-                        if !self.sessions.is_empty() {
-                            let session_id = self.sessions[self.selected_index].session.id;
-                            self.current_screen = Screen::SessionDetail(session_id);
-                            
-                            // Load training content for this session
-                            if let Ok(conn) = crate::db::connection::establish_connection(&self.db_path) {
-                                if let Ok(content) = TrainingContentRepository::find_by_session(&conn, session_id) {
-                                    self.training_content = content;
-                                }
-                            }
+                    Screen::SessionList if !self.sessions.is_empty() => {
+                        let session_id = self.sessions[self.selected_index].session.id;
+                        self.current_screen = Screen::SessionDetail(session_id);
+                        
+                        // Load training content for this session
+                        if let Ok(conn) = self.connect()
+                            && let Ok(content) = TrainingContentRepository::find_by_session(&conn, session_id)
+                        {
+                            self.training_content = content;
                         }
                     }
+                    // Open membership editor for the selected group
+                    Screen::GroupList if !self.groups.is_empty() => {
+                        let group_id = self.groups[self.group_selected_index].id;
+                        self.load_group_roster(group_id);
+                        self.current_screen = Screen::GroupMembers(group_id);
+                    }
+                    // Open the selected player's profile (coach)
+                    Screen::GroupMembers(_) => {
+                        if let Some((player, _)) = self.group_roster.get(self.group_roster_selected_index) {
+                            let player_id = player.id;
+                            self.open_profile(player_id);
+                        }
+                    }
+                    // Subscribe the selected group to the session
+                    Screen::GroupAssign(session_id) if !self.groups.is_empty() => {
+                        let group_id = self.groups[self.group_selected_index].id;
+                        self.assign_session_to_group(group_id, session_id);
+                    }
+                    // Read the selected notification and open its session
+                    Screen::Inbox => {
+                        self.open_notification();
+                    }
                     _ => {}
                 }
             }
@@ -218,46 +490,95 @@ impl App {
                 if self.user_context.is_coach() && self.current_screen == Screen::SessionList {
                     self.session_form = SessionForm::new();
                     self.current_screen = Screen::SessionCreate;
+                } else if self.user_context.is_coach() && self.current_screen == Screen::GroupList {
+                    self.group_form = GroupForm::new();
+                    self.current_screen = Screen::GroupCreate;
+                } else if self.user_context.is_coach() && self.current_screen == Screen::UserKeys {
+                    self.key_form = KeyForm::new();
+                    self.current_screen = Screen::UserKeyCreate;
                 }
             }
-            KeyCode::Char('f') | KeyCode::Char('F') => {
-                // Toggle filter (player only, on session list)
-                if self.user_context.is_player() && self.current_screen == Screen::SessionList {
-                    self.session_filter = self.session_filter.toggle();
-                    self.load_sessions();
+            KeyCode::Char('g') | KeyCode::Char('G') if self.user_context.is_coach() => {
+                // Assign session to a group (coach only, on session list or detail)
+                let session_id = match self.current_screen {
+                    Screen::SessionList if !self.sessions.is_empty() => {
+                        Some(self.sessions[self.selected_index].session.id)
+                    }
+                    Screen::SessionDetail(session_id) => Some(session_id),
+                    _ => None,
+                };
+                if let Some(session_id) = session_id {
+                    self.load_groups();
+                    if self.is_session_cancelled(session_id) {
+                        self.message = Some("Error: this session was cancelled".to_string());
+                    } else if self.groups.is_empty() {
+                        self.message = Some("No groups yet. Create one from Manage Groups".to_string());
+                    } else {
+                        self.current_screen = Screen::GroupAssign(session_id);
+                    }
                 }
             }
-            KeyCode::Char('s') | KeyCode::Char('S') => {
-                // Subscribe/Unsubscribe (player only, on session list)
-                if self.user_context.is_player() && self.current_screen == Screen::SessionList {
-                    self.toggle_subscription();
+            KeyCode::Char('p') | KeyCode::Char('P') if self.user_context.is_coach() => {
+                // Duplicate session (coach only, on session list or detail)
+                let session_id = match self.current_screen {
+                    Screen::SessionList if !self.sessions.is_empty() => {
+                        Some(self.sessions[self.selected_index].session.id)
+                    }
+                    Screen::SessionDetail(session_id) => Some(session_id),
+                    _ => None,
+                };
+                if let Some(session_id) = session_id {
+                    self.duplicate_session(session_id);
                 }
             }
-            KeyCode::Char('m') | KeyCode::Char('M') => {
+            KeyCode::Char(' ') if self.user_context.is_coach() => {
+                // Toggle membership (coach only, on group members)
+                if let Screen::GroupMembers(group_id) = self.current_screen {
+                    self.toggle_group_membership(group_id);
+                }
+            }
+            KeyCode::Char('f') if self.user_context.is_player() && self.current_screen == Screen::SessionList => {
+                // Toggle filter (player only, on session list)
+                self.session_filter = self.session_filter.toggle();
+                self.load_sessions();
+            }
+            KeyCode::Char('F') if self.current_screen == Screen::SessionList => {
+                // Edit tag / skill level / date range filter bar (on session list)
+                self.filter_bar_form = self.filter_bar.clone();
+                self.filter_bar_form.focus_field = crate::ui::session_filter::FilterField::Tags;
+                self.current_screen = Screen::SessionFilterEdit;
+            }
+            KeyCode::Char('s') | KeyCode::Char('S') if self.user_context.is_player() && self.current_screen == Screen::SessionList => {
+                // Subscribe/Unsubscribe (player only, on session list)
+                self.toggle_subscription();
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') if self.user_context.is_player() => {
                 // Mark complete (player only, on session detail)
-                if self.user_context.is_player() {
-                    if let Screen::SessionDetail(session_id) = self.current_screen {
-                        self.mark_session_complete(session_id);
-                    }
+                if let Screen::SessionDetail(session_id) = self.current_screen {
+                    self.mark_session_complete(session_id);
                 }
             }
-            KeyCode::Char('t') | KeyCode::Char('T') => {
+            KeyCode::Char('t') | KeyCode::Char('T') if self.user_context.is_coach() => {
                 // Training content management (coach only, on session detail)
-                if self.user_context.is_coach() {
-                    if let Screen::SessionDetail(session_id) = self.current_screen {
-                        self.training_content_form = TrainingContentForm::new();
-                        self.current_screen = Screen::TrainingContentCreate(session_id);
-                    }
+                if let Screen::SessionDetail(session_id) = self.current_screen {
+                    self.training_content_form = TrainingContentForm::new();
+                    self.current_screen = Screen::TrainingContentCreate(session_id);
+                }
+            }
+            KeyCode::Char('h') | KeyCode::Char('H') if self.user_context.is_coach() => {
+                // Change history (coach only, on session detail)
+                if let Screen::SessionDetail(session_id) = self.current_screen {
+                    self.open_session_audit(session_id);
                 }
             }
-            KeyCode::Char('e') | KeyCode::Char('E') => {
+            KeyCode::Char('e') | KeyCode::Char('E') if self.user_context.is_coach() && self.current_screen == Screen::SessionList && !self.sessions.is_empty() => {
                 // Edit session (coach only, on session list)
-                if self.user_context.is_coach() && self.current_screen == Screen::SessionList && !self.sessions.is_empty() {
-                    let session_id = self.sessions[self.selected_index].session.id;
-                    if let Some(session_with_sub) = self.sessions.iter().find(|s| s.session.id == session_id) {
-                        self.session_edit_form = Some(SessionEditForm::from_session(&session_with_sub.session));
-                        self.current_screen = Screen::SessionEdit(session_id);
-                    }
+                let session_id = self.sessions[self.selected_index].session.id;
+                if let Some(session_with_sub) = self.sessions.iter().find(|s| s.session.id == session_id) {
+                    let mut form = SessionEditForm::from_session(&session_with_sub.session);
+                    form.tags = self.session_tags.get(&session_id).map(|t| t.join(", ")).unwrap_or_default();
+                    self.session_edit_form = Some(form);
+                    self.current_screen = Screen::SessionEdit(session_id);
                 }
             }
             KeyCode::Char('d') | KeyCode::Char('D') => {
@@ -265,8 +586,78 @@ impl App {
                 if self.user_context.is_coach() && self.current_screen == Screen::SessionList && !self.sessions.is_empty() {
                     let session_id = self.sessions[self.selected_index].session.id;
                     self.current_screen = Screen::SessionDelete(session_id);
+                } else if self.user_context.is_coach() && self.current_screen == Screen::GroupList && !self.groups.is_empty() {
+                    let group_id = self.groups[self.group_selected_index].id;
+                    self.current_screen = Screen::GroupDelete(group_id);
+                } else if self.current_screen == Screen::Archive && !self.archived_items.is_empty() {
+                    self.current_screen = Screen::ArchivePurge;
+                } else if self.user_context.is_coach() && self.current_screen == Screen::UserKeys {
+                    match self.user_keys.get(self.user_key_selected_index) {
+                        Some(key) if key.is_revoked() => {
+                            self.message = Some("This key is already revoked".to_string());
+                        }
+                        Some(key) => self.current_screen = Screen::UserKeyRevoke(key.id),
+                        None => {}
+                    }
+                }
+            }
+            KeyCode::Char('w') | KeyCode::Char('W') => {
+                // Write a printable session plan: w = Markdown, W = HTML (on session detail)
+                if let Screen::SessionDetail(session_id) = self.current_screen {
+                    let format = if key.code == KeyCode::Char('W') { DocumentFormat::Html } else { DocumentFormat::Markdown };
+                    self.export_session_plan(session_id, format);
                 }
             }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                // Write a progress report for the shown player (on player profile)
+                if matches!(self.current_screen, Screen::PlayerProfile(_)) {
+                    self.export_progress_report();
+                } else if self.current_screen == Screen::Archive {
+                    self.restore_archived_item();
+                }
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') if self.user_context.is_coach() && self.current_screen == Screen::UserKeys => {
+                // Change the selected key's label (coach only, on SSH keys)
+                if let Some(key) = self.user_keys.get(self.user_key_selected_index) {
+                    self.key_form = KeyForm::for_label(key);
+                    self.current_screen = Screen::UserKeyLabel(key.id);
+                }
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') if self.current_screen == Screen::Inbox => {
+                // Mark every notification read (on inbox)
+                self.mark_all_notifications_read();
+            }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                // Undo the last deletion (shortly after deleting)
+                self.undo_last_archive();
+            }
+            KeyCode::Char('x') => {
+                // Export the shown player's training history (on player profile)
+                if matches!(self.current_screen, Screen::PlayerProfile(_)) {
+                    self.export_profile_history(false);
+                } else if self.user_context.is_coach() {
+                    // Cancel session, keeping its history (coach only, on session list or detail)
+                    let session_id = match self.current_screen {
+                        Screen::SessionList if !self.sessions.is_empty() => {
+                            Some(self.sessions[self.selected_index].session.id)
+                        }
+                        Screen::SessionDetail(session_id) => Some(session_id),
+                        _ => None,
+                    };
+                    if let Some(session_id) = session_id {
+                        if self.is_session_cancelled(session_id) {
+                            self.message = Some("Session already cancelled".to_string());
+                        } else {
+                            self.cancel_reason.clear();
+                            self.current_screen = Screen::SessionCancel(session_id);
+                        }
+                    }
+                }
+            }
+            KeyCode::Char('X') if self.user_context.is_coach() && matches!(self.current_screen, Screen::PlayerProfile(_)) => {
+                // Export the history of every player in the coach's sessions (coach only)
+                self.export_profile_history(true);
+            }
             _ => {}
         }
     }
@@ -279,83 +670,75 @@ impl App {
             KeyCode::Tab => {
                 if is_create {
                     self.session_form.next_field();
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        form.next_field();
-                    }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    form.next_field();
                 }
             }
             KeyCode::BackTab => {
                 if is_create {
                     self.session_form.prev_field();
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        form.prev_field();
-                    }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    form.prev_field();
                 }
             }
             KeyCode::Char(c) => {
                 if is_create {
                     self.session_form.add_char(c);
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        form.add_char(c);
-                    }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    form.add_char(c);
                 }
             }
             KeyCode::Backspace => {
                 if is_create {
                     self.session_form.backspace();
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        form.backspace();
-                    }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    form.backspace();
                 }
             }
             KeyCode::Left => {
-                // For skill level navigation
+                // For court and skill level navigation
                 if is_create {
                     if self.session_form.focus_field == crate::ui::session_form::FormField::SkillLevel {
-                        self.session_form.focus_field = crate::ui::session_form::FormField::DurationMinutes;
+                        self.session_form.focus_field = crate::ui::session_form::FormField::Court;
+                    } else if self.session_form.focus_field == crate::ui::session_form::FormField::Court {
+                        self.session_form.cycle_court(&self.courts, false);
                     }
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        if form.focus_field == crate::ui::session_edit_form::FormField::SkillLevel {
-                            form.focus_field = crate::ui::session_edit_form::FormField::DurationMinutes;
-                        }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    if form.focus_field == crate::ui::session_edit_form::FormField::SkillLevel {
+                        form.focus_field = crate::ui::session_edit_form::FormField::Court;
+                    } else if form.focus_field == crate::ui::session_edit_form::FormField::Court {
+                        form.cycle_court(&self.courts, false);
                     }
                 }
             }
             KeyCode::Right => {
-                // For skill level navigation
+                // For court and skill level navigation
                 if is_create {
                     if self.session_form.focus_field == crate::ui::session_form::FormField::SkillLevel {
                         self.session_form.cycle_skill_level_forward();
+                    } else if self.session_form.focus_field == crate::ui::session_form::FormField::Court {
+                        self.session_form.cycle_court(&self.courts, true);
                     }
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        if form.focus_field == crate::ui::session_edit_form::FormField::SkillLevel {
-                            form.cycle_skill_level_forward();
-                        }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    if form.focus_field == crate::ui::session_edit_form::FormField::SkillLevel {
+                        form.cycle_skill_level_forward();
+                    } else if form.focus_field == crate::ui::session_edit_form::FormField::Court {
+                        form.cycle_court(&self.courts, true);
                     }
                 }
             }
             KeyCode::Up => {
                 if is_create {
                     self.session_form.prev_field();
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        form.prev_field();
-                    }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    form.prev_field();
                 }
             }
             KeyCode::Down => {
                 if is_create {
                     self.session_form.next_field();
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        form.next_field();
-                    }
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    form.next_field();
                 }
             }
             KeyCode::Enter => {
@@ -369,18 +752,16 @@ impl App {
                             self.message = Some(format!("Error: {}", e));
                         }
                     }
-                } else if is_edit {
-                    if let Some(form) = &mut self.session_edit_form {
-                        match form.validate() {
-                            Ok(()) => {
-                                if let Screen::SessionEdit(session_id) = self.current_screen {
-                                    self.update_session(session_id);
-                                }
-                            }
-                            Err(e) => {
-                                self.message = Some(format!("Error: {}", e));
+                } else if is_edit && let Some(form) = &mut self.session_edit_form {
+                    match form.validate() {
+                        Ok(()) => {
+                            if let Screen::SessionEdit(session_id) = self.current_screen {
+                                self.update_session(session_id);
                             }
                         }
+                        Err(e) => {
+                            self.message = Some(format!("Error: {}", e));
+                        }
                     }
                 }
             }
@@ -446,7 +827,7 @@ impl App {
     }
 
     fn save_session(&mut self) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if let Ok(conn) = self.connect() {
             let (title, description, date, time, duration, skill_level_str) = self.session_form.as_db_values();
             
             // Parse skill level
@@ -460,23 +841,38 @@ impl App {
                 chrono::NaiveTime::parse_from_str(t, "%H:%M").ok()
             });
             
-            match SessionRepository::create(
-                &conn,
-                &title,
-                if description.is_empty() { None } else { Some(description.as_str()) },
-                date_parsed,
-                time_parsed,
-                duration,
-                skill_level.as_ref(),
-                self.user_context.user.id,
-            ) {
-                Ok(_) => {
+            let court_id = self.session_form.court_id;
+            let tags = self.session_form.tag_list();
+            let coach_id = self.user_context.user.id;
+
+            // The repository refuses double-bookings; the session, its court
+            // and tags are saved together or not at all
+            let saved = atomically(&conn, |conn| {
+                let session_id = SessionRepository::create(
+                    conn,
+                    &title,
+                    if description.is_empty() { None } else { Some(description.as_str()) },
+                    date_parsed,
+                    time_parsed,
+                    duration,
+                    skill_level.as_ref(),
+                    coach_id,
+                )?;
+                SessionRepository::set_court(conn, session_id, court_id)?;
+                TagRepository::set_session_tags(conn, session_id, &tags)?;
+                Ok(session_id)
+            });
+
+            match saved {
+                Ok(session_id) => {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, title = %title, "session created");
                     self.message = Some("Session created successfully!".to_string());
                     self.current_screen = Screen::SessionList;
                     self.load_sessions();
                 }
                 Err(e) => {
-                    self.message = Some(format!("Error saving session: {:?}", e));
+                    tracing::error!(error = %e, "creating session failed");
+                    self.message = Some(format!("Error saving session: {}", e));
                 }
             }
         } else {
@@ -485,7 +881,7 @@ impl App {
     }
 
     fn update_session(&mut self, session_id: i64) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if let Ok(conn) = self.connect() {
             if let Some(form) = &self.session_edit_form {
                 let (title, description, date, time, duration, skill_level_str) = form.as_db_values();
                 
@@ -500,24 +896,37 @@ impl App {
                     chrono::NaiveTime::parse_from_str(t, "%H:%M").ok()
                 });
                 
-                match SessionRepository::update(
-                    &conn,
-                    session_id,
-                    &title,
-                    if description.is_empty() { None } else { Some(description.as_str()) },
-                    date_parsed,
-                    time_parsed,
-                    duration,
-                    skill_level.as_ref(),
-                ) {
-                    Ok(_) => {
+                let court_id = form.court_id;
+                let tags = form.tag_list();
+
+                // As in save_session
+                let saved = atomically(&conn, |conn| {
+                    SessionRepository::update(
+                        conn,
+                        session_id,
+                        &title,
+                        if description.is_empty() { None } else { Some(description.as_str()) },
+                        date_parsed,
+                        time_parsed,
+                        duration,
+                        skill_level.as_ref(),
+                        court_id,
+                    )?;
+                    TagRepository::set_session_tags(conn, session_id, &tags)?;
+                    Ok(())
+                });
+
+                match saved {
+                    Ok(()) => {
+                        tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, "session updated");
                         self.message = Some("Session updated successfully!".to_string());
                         self.current_screen = Screen::SessionList;
                         self.session_edit_form = None;
                         self.load_sessions();
                     }
                     Err(e) => {
-                        self.message = Some(format!("Error updating session: {:?}", e));
+                        tracing::error!(session_id, error = %e, "updating session failed");
+                        self.message = Some(format!("Error updating session: {}", e));
                     }
                 }
             }
@@ -527,14 +936,17 @@ impl App {
     }
 
     fn delete_session(&mut self, session_id: i64) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
-            match SessionRepository::delete(&conn, session_id) {
+        if let Ok(conn) = self.connect() {
+            match ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id) {
                 Ok(_) => {
-                    self.message = Some("Session deleted successfully!".to_string());
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, "session archived");
+                    self.last_archived = Some((ArchiveKind::Session, session_id, Instant::now()));
+                    self.message = Some("Session deleted. Press [u] to undo".to_string());
                     self.current_screen = Screen::SessionList;
                     self.load_sessions();
                 }
                 Err(e) => {
+                    tracing::error!(session_id, error = %e, "deleting session failed");
                     self.message = Some(format!("Error deleting session: {:?}", e));
                 }
             }
@@ -543,8 +955,50 @@ impl App {
         }
     }
 
+    /// Copy a session (one week later by default) and open the copy for editing
+    fn duplicate_session(&mut self, session_id: i64) {
+        if let Ok(conn) = self.connect() {
+            let original = match SessionRepository::find_by_id(&conn, session_id) {
+                Ok(Some(session)) => session,
+                _ => {
+                    self.message = Some("Error: session not found".to_string());
+                    return;
+                }
+            };
+
+            let new_date = original
+                .scheduled_date
+                .map(|d| d + chrono::Duration::days(7));
+
+            match SessionRepository::duplicate(
+                &conn,
+                session_id,
+                new_date,
+                original.scheduled_time,
+                self.user_context.user.id,
+            ) {
+                Ok(new_id) => {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id = new_id, source_session_id = session_id, "session duplicated");
+                    self.load_sessions();
+                    if let Ok(Some(copy)) = SessionRepository::find_by_id(&conn, new_id) {
+                        let mut form = SessionEditForm::from_session(&copy);
+                        form.tags = TagRepository::find_by_session(&conn, new_id).unwrap_or_default().join(", ");
+                        self.session_edit_form = Some(form);
+                        self.current_screen = Screen::SessionEdit(new_id);
+                        self.message = Some("Session duplicated - adjust date/time and save".to_string());
+                    }
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error duplicating session: {}", e));
+                }
+            }
+        } else {
+            self.message = Some("Error connecting to database".to_string());
+        }
+    }
+
     fn save_training_content(&mut self, session_id: i64) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if let Ok(conn) = self.connect() {
             let (title, description, duration, content_type_str) = self.training_content_form.as_db_values();
             
             // Parse content type
@@ -584,7 +1038,7 @@ impl App {
     }
 
     fn update_training_content(&mut self, content_id: i64) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if let Ok(conn) = self.connect() {
             let (title, description, duration, content_type_str) = self.training_content_form.as_db_values();
             
             // Parse content type
@@ -626,14 +1080,16 @@ impl App {
     }
 
     fn delete_training_content(&mut self, content_id: i64) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if let Ok(conn) = self.connect() {
             // Find the session_id for this content
             if let Some(current) = self.training_content.iter().find(|c| c.id == content_id) {
                 let session_id = current.session_id;
                 
-                match TrainingContentRepository::delete(&conn, content_id) {
+                match ArchiveRepository::archive(&conn, ArchiveKind::Content, content_id) {
                     Ok(_) => {
-                        self.message = Some("Training content deleted successfully!".to_string());
+                        tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, content_id, "training content archived");
+                        self.last_archived = Some((ArchiveKind::Content, content_id, Instant::now()));
+                        self.message = Some("Training content deleted. Press [u] to undo".to_string());
                         self.current_screen = Screen::SessionDetail(session_id);
                         // Reload training content
                         if let Ok(content) = TrainingContentRepository::find_by_session(&conn, session_id) {
@@ -650,23 +1106,261 @@ impl App {
         }
     }
 
-    /// Get home menu items based on user role
+    fn handle_filter_bar_key_event(&mut self, key: KeyEvent) {
+        use crate::ui::session_filter::FilterField;
+
+        match key.code {
+            KeyCode::Tab | KeyCode::Down => {
+                self.filter_bar_form.next_field();
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                self.filter_bar_form.prev_field();
+            }
+            KeyCode::Left | KeyCode::Right if self.filter_bar_form.focus_field == FilterField::SkillLevel => {
+                self.filter_bar_form.cycle_skill_level_forward();
+            }
+            KeyCode::Char(c) => {
+                self.filter_bar_form.add_char(c);
+            }
+            KeyCode::Backspace => {
+                self.filter_bar_form.backspace();
+            }
+            KeyCode::Delete => {
+                // Clear every criterion
+                self.filter_bar_form = SessionFilterBar::new();
+            }
+            KeyCode::Enter => {
+                match self.filter_bar_form.validate() {
+                    Ok(()) => {
+                        self.filter_bar = self.filter_bar_form.clone();
+                        self.current_screen = Screen::SessionList;
+                        self.load_sessions();
+                    }
+                    Err(e) => {
+                        self.message = Some(format!("Error: {}", e));
+                    }
+                }
+            }
+            KeyCode::Esc => {
+                self.current_screen = Screen::SessionList;
+            }
+            _ => {}
+        }
+    }
+
+    /// Show a session's details, even if the list filters hide it
+    fn open_session(&mut self, session_id: i64) {
+        if let Ok(conn) = self.connect() {
+            self.load_sessions();
+
+            // The session may be hidden by the current list filters
+            if !self.sessions.iter().any(|s| s.session.id == session_id) {
+                match SessionRepository::find_by_id(&conn, session_id) {
+                    Ok(Some(session)) => {
+                        let subscription = SubscriptionRepository::find_by_user_and_session(&conn, self.user_context.user.id, session_id)
+                            .unwrap_or(None);
+                        self.sessions.push(SessionWithSubscription::new(session, subscription));
+                    }
+                    _ => {
+                        self.message = Some("Session no longer exists".to_string());
+                        return;
+                    }
+                }
+            }
+
+            self.selected_index = self.sessions.iter().position(|s| s.session.id == session_id).unwrap_or(0);
+            self.training_content = TrainingContentRepository::find_by_session(&conn, session_id).unwrap_or_default();
+            self.current_screen = Screen::SessionDetail(session_id);
+        }
+    }
+
+    /// Periodic work: expire the undo hint and pick up other users' changes
+    fn on_tick(&mut self) {
+        if self.last_archived.is_some() && !self.can_undo() {
+            self.last_archived = None;
+        }
+
+        if self.shows_live_data() && self.data_changed_elsewhere() {
+            self.refresh_current_screen();
+        }
+    }
+
+    /// Screens listing data that other users may change, and Home for the
+    /// inbox badge. Forms and
+    /// confirmations are never refreshed so typing isn't disturbed; changes
+    /// made meanwhile show up once the user is back on a list.
+    fn shows_live_data(&self) -> bool {
+        matches!(
+            self.current_screen,
+            Screen::SessionList
+                | Screen::SessionDetail(_)
+                | Screen::GroupList
+                | Screen::GroupMembers(_)
+                | Screen::Search
+                | Screen::PlayerProfile(_)
+                | Screen::SessionAudit(_)
+                | Screen::Archive
+                | Screen::UserKeys
+                | Screen::Home
+                | Screen::Inbox
+        )
+    }
+
+    /// Whether another connection committed since the last check. Our own
+    /// writes don't count: the handler that made them reloads the screen.
+    fn data_changed_elsewhere(&mut self) -> bool {
+        let Ok(version) = self.connect().and_then(|conn| data_version(&conn)) else {
+            return false;
+        };
+        let changed = self.data_version.is_some_and(|seen| seen != version);
+        self.data_version = Some(version);
+        changed
+    }
+
+    /// Reload what the current screen shows, keeping the same item selected
+    /// if it still exists
+    fn refresh_current_screen(&mut self) {
+        self.load_unread_count();
+        match self.current_screen.clone() {
+            Screen::SessionList => self.reload_sessions(),
+            Screen::SessionDetail(session_id) => {
+                self.reload_sessions();
+                if !self.sessions.iter().any(|s| s.session.id == session_id) {
+                    self.current_screen = Screen::SessionList;
+                    self.message = Some("This session is no longer available".to_string());
+                    return;
+                }
+
+                let selected = self.training_content.get(self.training_content_selected_index).map(|c| c.id);
+                if let Ok(conn) = self.connect() {
+                    self.training_content =
+                        TrainingContentRepository::find_by_session(&conn, session_id).unwrap_or_default();
+                }
+                self.training_content_selected_index =
+                    reselect(&self.training_content, selected, self.training_content_selected_index, |c| c.id);
+            }
+            Screen::GroupList => {
+                let selected = self.groups.get(self.group_selected_index).map(|g| g.id);
+                let index = self.group_selected_index;
+                self.load_groups();
+                self.group_selected_index = reselect(&self.groups, selected, index, |g| g.id);
+            }
+            Screen::GroupMembers(group_id) => {
+                let selected = self.group_roster.get(self.group_roster_selected_index).map(|(p, _)| p.id);
+                self.load_group_roster(group_id);
+                self.group_roster_selected_index =
+                    reselect(&self.group_roster, selected, self.group_roster_selected_index, |(p, _)| p.id);
+            }
+            Screen::Search if !self.search_query.trim().is_empty() => {
+                let selected = self.search_results.get(self.search_selected_index).map(|h| (h.kind, h.ref_id));
+                let index = self.search_selected_index;
+                self.run_search();
+                self.search_selected_index = reselect(&self.search_results, selected, index, |h| (h.kind, h.ref_id));
+            }
+            Screen::PlayerProfile(user_id) => {
+                let selected = self.profile_history.get(self.profile_selected_index).map(|e| e.subscription.id);
+                if let Ok(conn) = self.connect() {
+                    self.profile_history = SubscriptionRepository::history_for_player(&conn, user_id).unwrap_or_default();
+                }
+                self.profile_selected_index =
+                    reselect(&self.profile_history, selected, self.profile_selected_index, |e| e.subscription.id);
+            }
+            Screen::SessionAudit(session_id) => {
+                let selected = self.audit_entries.get(self.audit_selected_index).map(|e| e.id);
+                if let Ok(conn) = self.connect() {
+                    self.audit_entries = AuditRepository::for_session(&conn, session_id).unwrap_or_default();
+                }
+                self.audit_selected_index = reselect(&self.audit_entries, selected, self.audit_selected_index, |e| e.id);
+            }
+            Screen::Archive => {
+                let selected = self.archived_items.get(self.archive_selected_index).map(|i| (i.kind, i.id));
+                let index = self.archive_selected_index;
+                self.load_archive();
+                self.archive_selected_index = reselect(&self.archived_items, selected, index, |i| (i.kind, i.id));
+            }
+            Screen::UserKeys => {
+                let selected = self.user_keys.get(self.user_key_selected_index).map(|k| k.id);
+                let index = self.user_key_selected_index;
+                self.load_user_keys();
+                self.user_key_selected_index = reselect(&self.user_keys, selected, index, |k| k.id);
+            }
+            Screen::Inbox => {
+                let selected = self.notifications.get(self.notification_selected_index).map(|n| n.id);
+                let index = self.notification_selected_index;
+                self.load_notifications();
+                self.notification_selected_index = reselect(&self.notifications, selected, index, |n| n.id);
+            }
+            _ => {}
+        }
+    }
+
+    /// `load_sessions`, keeping the selected session selected
+    fn reload_sessions(&mut self) {
+        let selected = self.sessions.get(self.selected_index).map(|s| s.session.id);
+        let index = self.selected_index;
+        self.load_sessions();
+        self.selected_index = reselect(&self.sessions, selected, index, |s| s.session.id);
+    }
+
+    /// The session's database connection, with changes attributed to the
+    /// logged-in user. Opened on first use; a failed open is retried next time.
+    fn connect(&self) -> crate::utils::Result<Rc<Connection>> {
+        if let Some(conn) = self.conn.get() {
+            return Ok(Rc::clone(conn));
+        }
+
+        let conn = crate::db::establish_connection(&self.db_path)?;
+        AuditRepository::set_actor(&conn, Some(self.user_context.user.id))?;
+        Ok(Rc::clone(self.conn.get_or_init(|| Rc::new(conn))))
+    }
+
+    /// Exports are written to an `exports` directory next to the database
+    fn export_dir(&self) -> PathBuf {
+        Path::new(&self.db_path)
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("exports")
+    }
+
+    /// Write a session plan into the export directory
+    fn export_session_plan(&mut self, session_id: i64, format: DocumentFormat) {
+        let dir = self.export_dir();
+        let result = self.connect()
+            .and_then(|conn| SessionPlan::load(&conn, session_id))
+            .and_then(|plan| {
+                std::fs::create_dir_all(&dir)?;
+                let path = dir.join(plan.file_name(format));
+                std::fs::write(&path, plan.render(format))?;
+                Ok(path)
+            });
+
+        self.message = Some(match result {
+            Ok(path) => format!("Plan written to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
     fn get_home_menu_items(&self) -> Vec<(&'static str, Screen)> {
         if self.user_context.is_coach() {
             vec![
                 ("Help", Screen::Help),
                 ("Manage Sessions", Screen::SessionList),
+                ("Manage Groups", Screen::GroupList),
+                ("Archive", Screen::Archive),
+                ("SSH Keys", Screen::UserKeys),
             ]
         } else {
             vec![
                 ("Help", Screen::Help),
                 ("My Sessions", Screen::SessionList),
+                ("Inbox", Screen::Inbox),
+                ("My Profile", Screen::PlayerProfile(self.user_context.user.id)),
             ]
         }
     }
 
     fn load_sessions(&mut self) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if let Ok(conn) = self.connect() {
             if self.user_context.is_coach() {
                 // Coach sees all their created sessions
                 let sessions = SessionRepository::find_by_coach(&conn, self.user_context.user.id)
@@ -699,6 +1393,19 @@ impl App {
                     })
                     .collect();
             }
+
+            self.courts = VenueRepository::find_courts(&conn).unwrap_or_default();
+
+            // Apply the tag / skill level / date range filter bar
+            self.session_tags = TagRepository::find_all_session_tags(&conn).unwrap_or_default();
+            if self.filter_bar.is_active() {
+                let session_tags = &self.session_tags;
+                let filter_bar = &self.filter_bar;
+                self.sessions.retain(|sws| {
+                    let tags = session_tags.get(&sws.session.id).map(|t| t.as_slice()).unwrap_or(&[]);
+                    filter_bar.matches(&sws.session, tags)
+                });
+            }
             self.selected_index = 0;
         }
     }
@@ -710,8 +1417,12 @@ impl App {
 
         let session_with_sub = &self.sessions[self.selected_index];
         let session_id = session_with_sub.session.id;
+        if session_with_sub.session.is_cancelled() {
+            self.message = Some("Error: this session was cancelled".to_string());
+            return;
+        }
 
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if let Ok(conn) = self.connect() {
             if session_with_sub.is_subscribed() {
                 // Unsubscribe
                 if let Err(e) = SubscriptionRepository::delete_by_user_and_session(
@@ -719,8 +1430,10 @@ impl App {
                     self.user_context.user.id,
                     session_id,
                 ) {
+                    tracing::error!(session_id, error = %e, "unsubscribing failed");
                     self.message = Some(format!("Error unsubscribing: {}", e));
                 } else {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, "unsubscribed");
                     self.message = Some("Unsubscribed successfully".to_string());
                     self.load_sessions();
                 }
@@ -731,8 +1444,10 @@ impl App {
                     self.user_context.user.id,
                     session_id,
                 ) {
+                    tracing::error!(session_id, error = %e, "subscribing failed");
                     self.message = Some(format!("Error subscribing: {}", e));
                 } else {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, "subscribed");
                     self.message = Some("Subscribed successfully".to_string());
                     self.load_sessions();
                 }
@@ -741,7 +1456,11 @@ impl App {
     }
 
     fn mark_session_complete(&mut self, session_id: i64) {
-        if let Ok(conn) = crate::db::establish_connection(&self.db_path) {
+        if self.is_session_cancelled(session_id) {
+            self.message = Some("Error: this session was cancelled".to_string());
+            return;
+        }
+        if let Ok(conn) = self.connect() {
             // Find the subscription
             if let Ok(Some(subscription)) = SubscriptionRepository::find_by_user_and_session(
                 &conn,
@@ -752,8 +1471,10 @@ impl App {
                     self.message = Some("Session already marked as complete".to_string());
                 } else {
                     if let Err(e) = SubscriptionRepository::mark_completed(&conn, subscription.id) {
+                        tracing::error!(session_id, error = %e, "marking session complete failed");
                         self.message = Some(format!("Error marking complete: {}", e));
                     } else {
+                        tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, "session completed");
                         self.message = Some("Session marked as complete!".to_string());
                         self.load_sessions();
                     }
@@ -800,10 +1521,26 @@ impl App {
             Screen::SessionCreate => self.render_session_create(frame, chunks[2]),
             Screen::SessionEdit(_) => self.render_session_edit(frame),
             Screen::SessionDelete(_) => self.render_session_delete(frame),
+            Screen::SessionCancel(session_id) => self.render_session_cancel(frame, chunks[2], *session_id),
             Screen::TrainingContentCreate(session_id) => self.render_training_content_create(frame, chunks[2], *session_id),
             Screen::TrainingContentEdit(content_id) => self.render_training_content_edit(frame, chunks[2], *content_id),
             Screen::TrainingContentDelete(content_id) => self.render_training_content_delete(frame, *content_id),
             Screen::Help => self.render_help(frame, chunks[2]),
+            Screen::SessionFilterEdit => self.render_filter_bar_edit(frame, chunks[2]),
+            Screen::GroupList => self.render_group_list(frame, chunks[2]),
+            Screen::GroupCreate => self.render_group_create(frame, chunks[2]),
+            Screen::GroupMembers(group_id) => self.render_group_members(frame, chunks[2], *group_id),
+            Screen::GroupDelete(group_id) => self.render_group_delete(frame, chunks[2], *group_id),
+            Screen::GroupAssign(session_id) => self.render_group_assign(frame, chunks[2], *session_id),
+            Screen::Search => self.render_search(frame, chunks[2]),
+            Screen::PlayerProfile(_) => self.render_player_profile(frame, chunks[2]),
+            Screen::SessionAudit(session_id) => self.render_session_audit(frame, chunks[2], *session_id),
+            Screen::Archive => self.render_archive(frame, chunks[2]),
+            Screen::ArchivePurge => self.render_archive_purge(frame, chunks[2]),
+            Screen::UserKeys => self.render_user_keys(frame, chunks[2]),
+            Screen::UserKeyCreate | Screen::UserKeyLabel(_) => self.render_key_form(frame, chunks[2]),
+            Screen::UserKeyRevoke(key_id) => self.render_user_key_revoke(frame, chunks[2], *key_id),
+            Screen::Inbox => self.render_inbox(frame, chunks[2]),
         }
 
         // Footer with dynamic help text
@@ -811,11 +1548,11 @@ impl App {
     }
 
     fn render_header(&self, frame: &mut Frame, area: Rect) {
-        let header = Paragraph::new(vec![Line::from(vec![
+        let mut spans = vec![
             Span::styled(
                 "Tennis Training App",
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(self.theme.accent())
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" | "),
@@ -826,11 +1563,20 @@ impl App {
             Span::raw(" | "),
             Span::styled(
                 format!("Role: {}", self.user_context.user.role.as_str()),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(self.theme.highlight()),
             ),
-        ])])
-        .block(Block::default().borders(Borders::ALL))
-        .alignment(Alignment::Center);
+        ];
+        if self.unread_notifications > 0 {
+            spans.push(Span::raw(" | "));
+            spans.push(Span::styled(
+                format!("Inbox: {} unread", self.unread_notifications),
+                Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        let header = Paragraph::new(vec![Line::from(spans)])
+            .block(Block::default().borders(Borders::ALL))
+            .alignment(Alignment::Center);
 
         frame.render_widget(header, area);
     }
@@ -839,11 +1585,14 @@ impl App {
         use crate::ui::help::HelpScreen;
 
         // Get dynamic footer help based on current screen
-        let commands = HelpScreen::get_footer_help(&self.current_screen, &self.user_context);
-        
+        let mut commands = HelpScreen::get_footer_help(&self.current_screen, &self.user_context);
+        if self.can_undo() {
+            commands.insert(0, ("[u]", "Undo delete"));
+        }
+
         let footer_text = commands
             .iter()
-            .map(|(key, desc)| format!("{} {} ", key, desc))
+            .map(|(key, desc)| format!("{} {} ", self.keys.relabel(key), desc))
             .collect::<String>();
 
         let footer = Paragraph::new(footer_text)
@@ -902,13 +1651,13 @@ impl App {
                     Span::styled(
                         "> ",
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(self.theme.highlight())
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(
                         label.to_string(),
                         Style::default()
-                            .fg(Color::Yellow)
+                            .fg(self.theme.highlight())
                             .add_modifier(Modifier::BOLD),
                     ),
                 ];
@@ -935,6 +1684,25 @@ impl App {
     }
 
     fn render_session_list(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        // Filter bar
+        let filter_style = if self.filter_bar.is_active() {
+            Style::default().fg(self.theme.highlight())
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let filter_bar = Paragraph::new(Line::from(vec![
+            Span::styled(self.filter_bar.describe(), filter_style),
+            Span::styled("  [F] edit filters", Style::default().fg(Color::DarkGray)),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title("Filters"));
+        frame.render_widget(filter_bar, chunks[0]);
+
+        let area = chunks[1];
         let title = if self.user_context.is_coach() {
             "Manage Sessions".to_string()
         } else {
@@ -947,10 +1715,12 @@ impl App {
                 Line::from(""),
                 Line::from(Span::styled(
                     "No sessions found",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 )),
                 Line::from(""),
-                Line::from(if self.user_context.is_coach() {
+                Line::from(if self.filter_bar.is_active() {
+                    "No sessions match the current filters. Press [F] to change them."
+                } else if self.user_context.is_coach() {
                     "Press [c] to create your first session"
                 } else if self.session_filter == SessionFilter::MySubscriptions {
                     "You haven't subscribed to any sessions yet. Press [f] to view all available sessions."
@@ -967,10 +1737,21 @@ impl App {
             return;
         }
 
+        // Only the page holding the selection is shown (`app.page_size`)
+        let page = self.selected_index / self.page_size;
+        let page_count = self.sessions.len().div_ceil(self.page_size);
+        let title = if page_count > 1 {
+            format!("{} (page {}/{}, PgUp/PgDn)", title, page + 1, page_count)
+        } else {
+            title
+        };
+
         let items: Vec<ListItem> = self
             .sessions
             .iter()
             .enumerate()
+            .skip(page * self.page_size)
+            .take(self.page_size)
             .map(|(i, session_with_sub)| {
                 let session = &session_with_sub.session;
                 
@@ -989,11 +1770,25 @@ impl App {
                     .map(|d| format!(" ({}min)", d))
                     .unwrap_or_default();
 
+                let tags_str = self
+                    .session_tags
+                    .get(&session.id)
+                    .map(|tags| tags.iter().map(|t| format!(" #{}", t)).collect::<String>())
+                    .unwrap_or_default();
+
+                // Cancelled sessions stay listed, struck through
+                let struck = if session.is_cancelled() {
+                    Style::default().fg(Color::DarkGray).add_modifier(Modifier::CROSSED_OUT)
+                } else {
+                    Style::default()
+                };
+                let cancelled_str = if session.is_cancelled() { " (cancelled)" } else { "" };
+
                 // Add subscription indicators for players
                 let status_indicator = if self.user_context.is_player() {
                     if session_with_sub.is_completed() {
                         " ✓"
-                    } else if session_with_sub.is_subscribed() {
+                    } else if session_with_sub.is_subscribed() && !session.is_cancelled() {
                         " ●"
                     } else {
                         ""
@@ -1007,22 +1802,30 @@ impl App {
                         Span::styled(
                             "► ",
                             Style::default()
-                                .fg(Color::Cyan)
+                                .fg(self.theme.accent())
                                 .add_modifier(Modifier::BOLD),
                         ),
                         Span::styled(
                             &session.title,
                             Style::default()
-                                .fg(Color::White)
-                                .add_modifier(Modifier::BOLD),
+                                .fg(self.theme.text())
+                                .add_modifier(Modifier::BOLD)
+                                .patch(struck),
                         ),
-                        Span::raw(format!(" - {} {}{}{}", date_str, time_str, duration_str, status_indicator)),
+                        Span::styled(format!(" - {} {}{}{}", date_str, time_str, duration_str, status_indicator), struck),
+                        Span::styled(cancelled_str, Style::default().fg(Color::Red)),
+                        Span::styled(tags_str, Style::default().fg(Color::Magenta)),
                     ])
                 } else {
-                    Line::from(format!(
-                        "  {} - {} {}{}{}",
-                        session.title, date_str, time_str, duration_str, status_indicator
-                    ))
+                    Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(
+                            format!("{} - {} {}{}{}", session.title, date_str, time_str, duration_str, status_indicator),
+                            struck,
+                        ),
+                        Span::styled(cancelled_str, Style::default().fg(Color::Red)),
+                        Span::styled(tags_str, Style::default().fg(Color::Magenta)),
+                    ])
                 };
 
                 ListItem::new(line)
@@ -1046,14 +1849,22 @@ impl App {
                 Line::from(Span::styled(
                     &session.title,
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(self.theme.accent())
                         .add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
             ];
 
-            // Show subscription status for players
-            if self.user_context.is_player() {
+            if session.is_cancelled() {
+                lines.push(Line::from(Span::styled(
+                    format!("Cancelled: {}", session.cancel_reason.as_deref().unwrap_or("-")),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                )));
+                lines.push(Line::from(""));
+            }
+
+            // Show subscription status for players (none left to change once cancelled)
+            if self.user_context.is_player() && (sws.is_completed() || !session.is_cancelled()) {
                 if sws.is_completed() {
                     lines.push(Line::from(Span::styled(
                         "Status: ✓ Completed",
@@ -1062,7 +1873,7 @@ impl App {
                 } else if sws.is_subscribed() {
                     lines.push(Line::from(Span::styled(
                         "Status: ● Subscribed",
-                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                        Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD),
                     )));
                 } else {
                     lines.push(Line::from(Span::styled(
@@ -1092,6 +1903,7 @@ impl App {
                     "Duration: {} minutes",
                     session.duration_minutes.unwrap_or(0)
                 )),
+                Line::from(format!("Court: {}", self.court_label(session.court_id))),
                 Line::from(format!(
                     "Skill Level: {}",
                     session
@@ -1100,13 +1912,19 @@ impl App {
                         .map(|s| s.as_str())
                         .unwrap_or("Any")
                 )),
+                Line::from(format!(
+                    "Tags: {}",
+                    self.session_tags
+                        .get(&session.id)
+                        .map(|tags| tags.join(", "))
+                        .unwrap_or_else(|| "None".to_string())
+                )),
                 Line::from(""),
                 Line::from("Description:"),
                 Line::from(
                     session
                         .description
-                        .as_ref()
-                        .map(|s| s.as_str())
+                        .as_deref()
                         .unwrap_or("No description")
                 ),
                 Line::from(""),
@@ -1148,6 +1966,8 @@ impl App {
                 lines.push(Line::from(Span::styled(
                     if sws.is_completed() {
                         "This session is already marked as complete"
+                    } else if session.is_cancelled() {
+                        "This session was cancelled"
                     } else if sws.is_subscribed() {
                         "Press [m] to mark this session as complete"
                     } else {
@@ -1203,7 +2023,7 @@ impl App {
             Line::from(vec![
                 Span::styled(
                     "Title: ",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 ),
                 Span::raw(&form.title),
                 if form.focus_field == crate::ui::session_form::FormField::Title {
@@ -1215,7 +2035,7 @@ impl App {
             Line::from(vec![
                 Span::styled(
                     "Description: ",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 ),
                 Span::raw(&form.description),
                 if form.focus_field == crate::ui::session_form::FormField::Description {
@@ -1227,7 +2047,7 @@ impl App {
             Line::from(vec![
                 Span::styled(
                     "Date (YYYY-MM-DD): ",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 ),
                 Span::raw(&form.scheduled_date),
                 if form.focus_field == crate::ui::session_form::FormField::ScheduledDate {
@@ -1239,7 +2059,7 @@ impl App {
             Line::from(vec![
                 Span::styled(
                     "Time (HH:MM): ",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 ),
                 Span::raw(&form.scheduled_time),
                 if form.focus_field == crate::ui::session_form::FormField::ScheduledTime {
@@ -1251,7 +2071,7 @@ impl App {
             Line::from(vec![
                 Span::styled(
                     "Duration (minutes): ",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 ),
                 Span::raw(&form.duration_minutes),
                 if form.focus_field == crate::ui::session_form::FormField::DurationMinutes {
@@ -1260,10 +2080,22 @@ impl App {
                     Span::raw("")
                 },
             ]),
+            Line::from(vec![
+                Span::styled(
+                    "Court (←/→ to pick): ",
+                    Style::default().fg(self.theme.highlight()),
+                ),
+                Span::raw(self.court_label(form.court_id)),
+                if form.focus_field == crate::ui::session_form::FormField::Court {
+                    Span::styled("▼", Style::default().fg(Color::Green))
+                } else {
+                    Span::raw("")
+                },
+            ]),
             Line::from(vec![
                 Span::styled(
                     "Skill Level: ",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 ),
                 Span::raw(&form.skill_level),
                 if form.focus_field == crate::ui::session_form::FormField::SkillLevel {
@@ -1272,6 +2104,18 @@ impl App {
                     Span::raw("")
                 },
             ]),
+            Line::from(vec![
+                Span::styled(
+                    "Tags (comma separated): ",
+                    Style::default().fg(self.theme.highlight()),
+                ),
+                Span::raw(&form.tags),
+                if form.focus_field == crate::ui::session_form::FormField::Tags {
+                    Span::styled("▼", Style::default().fg(Color::Green))
+                } else {
+                    Span::raw("")
+                },
+            ]),
             Line::from(""),
         ];
 
@@ -1280,7 +2124,7 @@ impl App {
                 Block::default()
                     .borders(Borders::ALL)
                     .title(title)
-                    .style(Style::default().fg(Color::White)),
+                    .style(Style::default().fg(self.theme.text())),
             )
             .alignment(Alignment::Left);
 
@@ -1290,13 +2134,13 @@ impl App {
         let help_text = vec![
             Line::from(""),
             Line::from(vec![
-                Span::styled("[Tab]", Style::default().fg(Color::Cyan)),
+                Span::styled("[Tab]", Style::default().fg(self.theme.accent())),
                 Span::raw(" Next field  "),
-                Span::styled("[Shift+Tab]", Style::default().fg(Color::Cyan)),
+                Span::styled("[Shift+Tab]", Style::default().fg(self.theme.accent())),
                 Span::raw(" Prev field  "),
-                Span::styled("[Enter]", Style::default().fg(Color::Cyan)),
+                Span::styled("[Enter]", Style::default().fg(self.theme.accent())),
                 Span::raw(" Save  "),
-                Span::styled("[Esc]", Style::default().fg(Color::Cyan)),
+                Span::styled("[Esc]", Style::default().fg(self.theme.accent())),
                 Span::raw(" Cancel"),
             ]),
         ];
@@ -1329,6 +2173,8 @@ impl App {
                     Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
                 ])
                 .margin(2)
                 .split(chunks[1]);
@@ -1339,7 +2185,7 @@ impl App {
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .style(if form.focus_field == crate::ui::session_edit_form::FormField::Title {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 });
@@ -1352,7 +2198,7 @@ impl App {
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .style(if form.focus_field == crate::ui::session_edit_form::FormField::Description {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 });
@@ -1365,7 +2211,7 @@ impl App {
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .style(if form.focus_field == crate::ui::session_edit_form::FormField::ScheduledDate {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 });
@@ -1378,7 +2224,7 @@ impl App {
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .style(if form.focus_field == crate::ui::session_edit_form::FormField::ScheduledTime {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 });
@@ -1391,25 +2237,51 @@ impl App {
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .style(if form.focus_field == crate::ui::session_edit_form::FormField::DurationMinutes {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 });
             let duration_para = Paragraph::new(form.duration_minutes.as_str()).block(duration_block);
             frame.render_widget(duration_para, form_chunks[4]);
 
+            // Court field
+            let court_block = Block::default()
+                .title("Court (←/→ to pick, Backspace to clear)")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .style(if form.focus_field == crate::ui::session_edit_form::FormField::Court {
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                });
+            let court_para = Paragraph::new(self.court_label(form.court_id)).block(court_block);
+            frame.render_widget(court_para, form_chunks[5]);
+
             // Skill level field
             let skill_block = Block::default()
                 .title("Skill Level (←/→ to cycle)")
                 .borders(Borders::ALL)
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .style(if form.focus_field == crate::ui::session_edit_form::FormField::SkillLevel {
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default()
                 });
             let skill_para = Paragraph::new(form.skill_level.as_str()).block(skill_block);
-            frame.render_widget(skill_para, form_chunks[5]);
+            frame.render_widget(skill_para, form_chunks[6]);
+
+            // Tags field
+            let tags_block = Block::default()
+                .title("Tags (comma separated, e.g. serve, footwork)")
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .style(if form.focus_field == crate::ui::session_edit_form::FormField::Tags {
+                    Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                });
+            let tags_para = Paragraph::new(form.tags.as_str()).block(tags_block);
+            frame.render_widget(tags_para, form_chunks[7]);
         }
 
         // Footer with help
@@ -1453,7 +2325,7 @@ impl App {
             Line::from(""),
             Line::from(Span::styled("Delete this session?", Style::default().add_modifier(Modifier::BOLD))),
             Line::from(""),
-            Line::from(Span::raw("All associated subscriptions will also be deleted.")),
+            Line::from(Span::raw("It can be undone with [u] or restored from the Archive.")),
             Line::from(""),
         ];
 
@@ -1487,14 +2359,17 @@ impl App {
         
         let text_lines: Vec<Line> = help_text
             .iter()
+            .map(|line| self.keys.relabel(line))
             .map(|line| {
                 if line.starts_with("═") {
-                    Line::from(Span::styled(line.clone(), Style::default().fg(Color::Cyan)))
+                    Line::from(Span::styled(line.clone(), Style::default().fg(self.theme.accent())))
                 } else if line.starts_with("GLOBAL") || line.starts_with("NAVIGATION") 
                     || line.starts_with("SESSION") || line.starts_with("DELETION") 
                     || line.starts_with("FORM") || line.starts_with("TEXT") 
-                    || line.starts_with("FIELD") {
-                    Line::from(Span::styled(line.clone(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+                    || line.starts_with("FIELD") || line.starts_with("GROUP")
+                    || line.starts_with("SEARCH") || line.starts_with("PROFILE")
+                    || line.starts_with("ARCHIVE") || line.starts_with("SSH") {
+                    Line::from(Span::styled(line.clone(), Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)))
                 } else {
                     Line::from(line.clone())
                 }
@@ -1530,7 +2405,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::Title {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1543,7 +2418,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::Description {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1556,7 +2431,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::DurationMinutes {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1569,7 +2444,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::ContentType {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1615,7 +2490,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::Title {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1628,7 +2503,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::Description {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1641,7 +2516,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::DurationMinutes {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1654,7 +2529,7 @@ impl App {
             .borders(Borders::ALL)
             .border_type(ratatui::widgets::BorderType::Rounded)
             .style(if form.focus_field == crate::ui::training_content_form::FormField::ContentType {
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             });
@@ -1691,9 +2566,11 @@ impl App {
                 Line::from(format!("Title: {}", content.title)),
                 Line::from(format!("Type: {:?}", content.content_type)),
                 Line::from(""),
+                Line::from("It can be undone with [u] or restored from the Archive."),
+                Line::from(""),
                 Line::from(Span::styled(
                     "Press [y] to confirm or [n] to cancel",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.highlight()),
                 )),
             ];
             let para = Paragraph::new(lines)
@@ -1707,4 +2584,78 @@ impl App {
             frame.render_widget(msg, frame.size());
         }
     }
-}
\ No newline at end of file
+
+    fn render_filter_bar_edit(&self, frame: &mut Frame, area: Rect) {
+        use crate::ui::session_filter::FilterField;
+
+        let form = &self.filter_bar_form;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ])
+            .split(area);
+
+        let field_style = |field: FilterField| {
+            if form.focus_field == field {
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            }
+        };
+
+        let fields = [
+            (FilterField::Tags, "Tags (all must match, comma separated)", form.tags.clone()),
+            (
+                FilterField::SkillLevel,
+                "Skill Level (←/→ to cycle)",
+                form.skill_level
+                    .as_ref()
+                    .map(|s| s.as_str().to_string())
+                    .unwrap_or_else(|| "any".to_string()),
+            ),
+            (FilterField::DateFrom, "From date (YYYY-MM-DD)", form.date_from.clone()),
+            (FilterField::DateTo, "To date (YYYY-MM-DD)", form.date_to.clone()),
+        ];
+
+        for (i, (field, label, value)) in fields.into_iter().enumerate() {
+            let block = Block::default()
+                .title(label)
+                .borders(Borders::ALL)
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .style(field_style(field));
+            frame.render_widget(Paragraph::new(value).block(block), chunks[i + 1]);
+        }
+
+        // Footer with help
+        let help_text = vec![
+            Line::from(vec![
+                Span::styled("[Tab] ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("Next field  "),
+                Span::styled("[Enter] ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("Apply  "),
+                Span::styled("[Del] ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("Clear all  "),
+                Span::styled("[Esc] ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("Cancel"),
+            ]),
+        ];
+        let help_para = Paragraph::new(help_text);
+        frame.render_widget(help_para, chunks[5]);
+    }
+
+}
+
+/// Index of the previously selected item in a reloaded list, or the old index
+/// clamped to the new length if the item is gone
+fn reselect<T, K: PartialEq>(items: &[T], selected: Option<K>, index: usize, key: impl Fn(&T) -> K) -> usize {
+    selected
+        .and_then(|selected| items.iter().position(|item| key(item) == selected))
+        .unwrap_or_else(|| index.min(items.len().saturating_sub(1)))
+}
@@ -0,0 +1,186 @@
+//! Archive screen: undo, restore and purge
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::db::repositories::{ArchiveRepository, TrainingContentRepository};
+use crate::models::ArchiveKind;
+use crate::ui::navigation::Screen;
+use crate::utils::logging::AUDIT_TARGET;
+use super::{App, UNDO_WINDOW};
+
+impl App {
+    pub(super) fn load_archive(&mut self) {
+        if let Ok(conn) = self.connect() {
+            match ArchiveRepository::find_by_coach(&conn, self.user_context.user.id) {
+                Ok(items) => {
+                    self.archived_items = items;
+                    self.archive_selected_index = 0;
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error loading archive: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Restore the last deleted session or content while the undo window is open
+    pub(super) fn undo_last_archive(&mut self) {
+        let Some((kind, id, archived_at)) = self.last_archived.take() else {
+            self.message = Some("Nothing to undo".to_string());
+            return;
+        };
+        if archived_at.elapsed() > UNDO_WINDOW {
+            self.message = Some("Too late to undo; restore it from the Archive".to_string());
+            return;
+        }
+
+        let Ok(conn) = self.connect() else {
+            self.message = Some("Error connecting to database".to_string());
+            return;
+        };
+        match ArchiveRepository::restore(&conn, kind, id) {
+            Ok(_) => {
+                tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, kind = kind.as_str(), id, "deletion undone");
+                self.message = Some(format!("{} restored", kind.label()));
+                match self.current_screen {
+                    Screen::SessionList => self.load_sessions(),
+                    Screen::SessionDetail(session_id) => {
+                        self.training_content = TrainingContentRepository::find_by_session(&conn, session_id).unwrap_or_default();
+                    }
+                    Screen::Archive => self.load_archive(),
+                    _ => {}
+                }
+            }
+            Err(e) => {
+                self.message = Some(format!("Error undoing: {}", e));
+            }
+        }
+    }
+
+    /// Whether `u` would still undo the last deletion
+    pub(super) fn can_undo(&self) -> bool {
+        self.last_archived
+            .is_some_and(|(_, _, archived_at)| archived_at.elapsed() <= UNDO_WINDOW)
+    }
+
+    pub(super) fn restore_archived_item(&mut self) {
+        let Some(item) = self.archived_items.get(self.archive_selected_index).cloned() else { return };
+        if let Ok(conn) = self.connect() {
+            match ArchiveRepository::restore(&conn, item.kind, item.id) {
+                Ok(_) => {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, kind = item.kind.as_str(), id = item.id, "restored from archive");
+                    self.message = Some(format!("{} \"{}\" restored", item.kind.label(), item.title));
+                    self.load_archive();
+                    self.archive_selected_index = self.archive_selected_index.min(self.archived_items.len().saturating_sub(1));
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error restoring: {}", e));
+                }
+            }
+        }
+    }
+
+    pub(super) fn purge_archived_item(&mut self) {
+        self.current_screen = Screen::Archive;
+        let Some(item) = self.archived_items.get(self.archive_selected_index).cloned() else { return };
+        if let Ok(conn) = self.connect() {
+            match ArchiveRepository::purge(&conn, item.kind, item.id) {
+                Ok(_) => {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, kind = item.kind.as_str(), id = item.id, "purged from archive");
+                    if self.last_archived.is_some_and(|(kind, id, _)| kind == item.kind && id == item.id) {
+                        self.last_archived = None;
+                    }
+                    self.message = Some(format!("{} \"{}\" permanently deleted", item.kind.label(), item.title));
+                    self.load_archive();
+                    self.archive_selected_index = self.archive_selected_index.min(self.archived_items.len().saturating_sub(1));
+                }
+                Err(e) => {
+                    tracing::error!(kind = item.kind.as_str(), id = item.id, error = %e, "purging failed");
+                    self.message = Some(format!("Error deleting: {}", e));
+                }
+            }
+        }
+    }
+
+    pub(super) fn render_archive(&self, frame: &mut Frame, area: Rect) {
+        if self.archived_items.is_empty() {
+            let empty = Paragraph::new("The archive is empty")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title("Archive"))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .archived_items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let (marker, style) = if i == self.archive_selected_index {
+                    (
+                        Span::styled("► ", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD)),
+                        Style::default().fg(self.theme.text()).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    (Span::raw("  "), Style::default())
+                };
+                let session = item
+                    .session_title
+                    .as_deref()
+                    .map(|title| format!("  (in {})", title))
+                    .unwrap_or_default();
+
+                ListItem::new(Line::from(vec![
+                    marker,
+                    Span::styled(
+                        format!("{}  {:<18}", item.archived_at.format("%Y-%m-%d %H:%M"), item.kind.label()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(item.title.clone(), style),
+                    Span::styled(session, Style::default().fg(Color::DarkGray)),
+                ]))
+            })
+            .collect();
+
+        let title = format!("Archive ({} items) - [r] restore, [d] delete permanently", self.archived_items.len());
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(list, area);
+    }
+
+    pub(super) fn render_archive_purge(&self, frame: &mut Frame, area: Rect) {
+        let Some(item) = self.archived_items.get(self.archive_selected_index) else { return };
+        let consequence = match item.kind {
+            ArchiveKind::Session => "Its training content, subscriptions and answers are deleted too.",
+            ArchiveKind::Content => "It is removed from its session.",
+            ArchiveKind::Template => "Sessions using it lose the link.",
+        };
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("Permanently delete {} \"{}\"?", item.kind.label().to_lowercase(), item.title),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(consequence),
+            Line::from("This cannot be undone."),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press [y] to confirm or [n] to cancel",
+                Style::default().fg(self.theme.highlight()),
+            )),
+        ];
+
+        let para = Paragraph::new(lines)
+            .block(Block::default().title("Delete Permanently").borders(Borders::ALL))
+            .alignment(Alignment::Center);
+        frame.render_widget(para, area);
+    }
+}
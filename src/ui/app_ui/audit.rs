@@ -0,0 +1,101 @@
+//! Session change history screen
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::db::repositories::AuditRepository;
+use crate::models::AuditAction;
+use crate::ui::navigation::Screen;
+use super::App;
+
+impl App {
+    /// Show who changed a session, its content and its subscriptions
+    pub(super) fn open_session_audit(&mut self, session_id: i64) {
+        if let Ok(conn) = self.connect() {
+            match AuditRepository::for_session(&conn, session_id) {
+                Ok(entries) => {
+                    self.audit_entries = entries;
+                    self.audit_selected_index = 0;
+                    self.current_screen = Screen::SessionAudit(session_id);
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error loading change history: {}", e));
+                }
+            }
+        }
+    }
+
+    pub(super) fn render_session_audit(&self, frame: &mut Frame, area: Rect, session_id: i64) {
+        let title = format!("Change History - Session #{}", session_id);
+        if self.audit_entries.is_empty() {
+            let empty = Paragraph::new("No recorded changes for this session")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(area);
+
+        let items: Vec<ListItem> = self
+            .audit_entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let action_color = match entry.action {
+                    AuditAction::Insert => Color::Green,
+                    AuditAction::Update => self.theme.highlight(),
+                    AuditAction::Delete => Color::Red,
+                };
+                let (marker, style) = if i == self.audit_selected_index {
+                    (
+                        Span::styled("► ", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD)),
+                        Style::default().fg(self.theme.text()).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    (Span::raw("  "), Style::default())
+                };
+
+                ListItem::new(Line::from(vec![
+                    marker,
+                    Span::styled(
+                        format!("{}  {:<20}  ", entry.created_at.format("%Y-%m-%d %H:%M"), entry.actor_label()),
+                        style,
+                    ),
+                    Span::styled(entry.summary(), Style::default().fg(action_color)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(list, chunks[0]);
+
+        let Some(selected) = self.audit_entries.get(self.audit_selected_index) else { return };
+        let lines: Vec<Line> = selected
+            .changes()
+            .into_iter()
+            .map(|(field, before, after)| {
+                Line::from(vec![
+                    Span::styled(format!("{:<20} ", field), Style::default().fg(self.theme.accent())),
+                    Span::styled(before.replace('\n', " "), Style::default().fg(Color::DarkGray)),
+                    Span::raw(" → "),
+                    Span::styled(after.replace('\n', " "), Style::default().fg(self.theme.text())),
+                ])
+            })
+            .collect();
+
+        let details = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title("Before → After"));
+        frame.render_widget(details, chunks[1]);
+    }
+}
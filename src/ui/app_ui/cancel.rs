@@ -0,0 +1,97 @@
+//! Cancel dialog: the reason a coach gives for calling a session off
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::db::repositories::SessionRepository;
+use crate::ui::navigation::Screen;
+use crate::utils::logging::AUDIT_TARGET;
+use super::App;
+
+impl App {
+    /// Cancel a session with the typed reason; it stays listed, struck through
+    fn cancel_session(&mut self, session_id: i64) {
+        if let Ok(conn) = self.connect() {
+            match SessionRepository::cancel(&conn, session_id, &self.cancel_reason) {
+                Ok(cancelled) => {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, session_id, reason = %self.cancel_reason.trim(), "session cancelled");
+                    self.message = Some(format!("Session cancelled. {} subscribed player(s) notified", cancelled));
+                    self.current_screen = Screen::SessionList;
+                    self.load_sessions();
+                }
+                Err(e) => {
+                    tracing::error!(session_id, error = %e, "cancelling session failed");
+                    self.message = Some(format!("Error cancelling session: {}", e));
+                }
+            }
+        } else {
+            self.message = Some("Error connecting to database".to_string());
+        }
+    }
+
+    pub(super) fn is_session_cancelled(&self, session_id: i64) -> bool {
+        self.sessions
+            .iter()
+            .any(|s| s.session.id == session_id && s.session.is_cancelled())
+    }
+
+    pub(super) fn handle_cancel_key_event(&mut self, key: KeyEvent, session_id: i64) {
+        match key.code {
+            KeyCode::Char(c) => {
+                self.cancel_reason.push(c);
+            }
+            KeyCode::Backspace => {
+                self.cancel_reason.pop();
+            }
+            KeyCode::Enter => {
+                self.cancel_session(session_id);
+            }
+            KeyCode::Esc => {
+                self.current_screen = Screen::SessionList;
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn render_session_cancel(&self, frame: &mut Frame, area: Rect, session_id: i64) {
+        let title = self
+            .sessions
+            .iter()
+            .find(|s| s.session.id == session_id)
+            .map(|s| s.session.title.as_str())
+            .unwrap_or("this session");
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("Cancel \"{}\"?", title),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from("It stays listed, struck through. Subscribed players are notified with the reason."),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Reason: ", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD)),
+                Span::raw(&self.cancel_reason),
+                Span::styled("█", Style::default().fg(self.theme.highlight())),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press [Enter] to cancel the session or [Esc] to go back",
+                Style::default().fg(self.theme.highlight()),
+            )),
+        ];
+
+        let para = Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().title("Cancel Session").borders(Borders::ALL))
+            .alignment(Alignment::Center);
+        frame.render_widget(para, area);
+    }
+}
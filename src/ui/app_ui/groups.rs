@@ -0,0 +1,403 @@
+//! Group screens: list, create, members, delete and assign
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::db::repositories::{GroupRepository, UserRepository};
+use crate::ui::navigation::Screen;
+use super::App;
+
+impl App {
+    pub(super) fn handle_group_form_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                self.group_form.next_field();
+            }
+            KeyCode::Char(c) => {
+                self.group_form.add_char(c);
+            }
+            KeyCode::Backspace => {
+                self.group_form.backspace();
+            }
+            KeyCode::Enter => {
+                match self.group_form.validate() {
+                    Ok(()) => {
+                        self.save_group();
+                    }
+                    Err(e) => {
+                        self.message = Some(format!("Error: {}", e));
+                    }
+                }
+            }
+            KeyCode::Esc => {
+                self.current_screen = Screen::GroupList;
+                self.load_groups();
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn load_groups(&mut self) {
+        if let Ok(conn) = self.connect() {
+            self.groups = GroupRepository::find_all(&conn).unwrap_or_default();
+            self.group_selected_index = 0;
+        }
+    }
+
+    /// Load every player together with their membership flag for a group
+    pub(super) fn load_group_roster(&mut self, group_id: i64) {
+        if let Ok(conn) = self.connect() {
+            let players = UserRepository::find_players(&conn).unwrap_or_default();
+            let members = GroupRepository::find_members(&conn, group_id).unwrap_or_default();
+
+            self.group_roster = players
+                .into_iter()
+                .map(|player| {
+                    let is_member = members.iter().any(|m| m.id == player.id);
+                    (player, is_member)
+                })
+                .collect();
+        }
+    }
+
+    fn save_group(&mut self) {
+        if let Ok(conn) = self.connect() {
+            let (name, description) = self.group_form.as_db_values();
+
+            match GroupRepository::create(
+                &conn,
+                &name,
+                description.as_deref(),
+                self.user_context.user.id,
+            ) {
+                Ok(_) => {
+                    self.message = Some("Group created successfully!".to_string());
+                    self.current_screen = Screen::GroupList;
+                    self.load_groups();
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error saving group: {}", e));
+                }
+            }
+        } else {
+            self.message = Some("Error connecting to database".to_string());
+        }
+    }
+
+    pub(super) fn delete_group(&mut self, group_id: i64) {
+        if let Ok(conn) = self.connect() {
+            match GroupRepository::delete(&conn, group_id) {
+                Ok(_) => {
+                    self.message = Some("Group deleted successfully!".to_string());
+                    self.current_screen = Screen::GroupList;
+                    self.load_groups();
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error deleting group: {}", e));
+                }
+            }
+        } else {
+            self.message = Some("Error connecting to database".to_string());
+        }
+    }
+
+    pub(super) fn toggle_group_membership(&mut self, group_id: i64) {
+        if self.group_roster.is_empty() {
+            return;
+        }
+
+        let (player, is_member) = &self.group_roster[self.group_roster_selected_index];
+        let player_id = player.id;
+        let is_member = *is_member;
+
+        if let Ok(conn) = self.connect() {
+            let result = if is_member {
+                GroupRepository::remove_member(&conn, group_id, player_id)
+            } else {
+                GroupRepository::add_member(&conn, group_id, player_id)
+            };
+
+            match result {
+                Ok(()) => {
+                    self.group_roster[self.group_roster_selected_index].1 = !is_member;
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error updating group: {}", e));
+                }
+            }
+        }
+    }
+
+    pub(super) fn assign_session_to_group(&mut self, group_id: i64, session_id: i64) {
+        if let Ok(conn) = self.connect() {
+            match GroupRepository::assign_session(&conn, group_id, session_id) {
+                Ok(assignment) => {
+                    self.message = Some(assignment.summary());
+                    self.current_screen = Screen::SessionList;
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error assigning group: {}", e));
+                }
+            }
+        } else {
+            self.message = Some("Error connecting to database".to_string());
+        }
+    }
+
+    pub(super) fn render_group_list(&self, frame: &mut Frame, area: Rect) {
+        let title = "Manage Groups";
+
+        if self.groups.is_empty() {
+            let empty_text = vec![
+                Line::from(""),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "No groups found",
+                    Style::default().fg(self.theme.highlight()),
+                )),
+                Line::from(""),
+                Line::from("Press [c] to create your first group"),
+            ];
+
+            let content = Paragraph::new(empty_text)
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .alignment(Alignment::Center);
+
+            frame.render_widget(content, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(i, group)| {
+                let description = group
+                    .description
+                    .as_ref()
+                    .map(|d| format!(" - {}", d))
+                    .unwrap_or_default();
+
+                let line = if i == self.group_selected_index {
+                    Line::from(vec![
+                        Span::styled(
+                            "► ",
+                            Style::default()
+                                .fg(self.theme.accent())
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            &group.name,
+                            Style::default()
+                                .fg(self.theme.text())
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw(description),
+                    ])
+                } else {
+                    Line::from(format!("  {}{}", group.name, description))
+                };
+
+                ListItem::new(line)
+            })
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+
+        frame.render_widget(list, area);
+    }
+
+    pub(super) fn render_group_create(&self, frame: &mut Frame, area: Rect) {
+        let form = &self.group_form;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ])
+            .split(area);
+
+        // Name field
+        let name_block = Block::default()
+            .title("Group Name (required, 2-50 chars)")
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .style(if form.focus_field == crate::ui::group_form::FormField::Name {
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            });
+        let name_para = Paragraph::new(form.name.as_str()).block(name_block);
+        frame.render_widget(name_para, chunks[1]);
+
+        // Description field
+        let desc_block = Block::default()
+            .title("Description (optional, max 200 chars)")
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .style(if form.focus_field == crate::ui::group_form::FormField::Description {
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            });
+        let desc_para = Paragraph::new(form.description.as_str()).block(desc_block);
+        frame.render_widget(desc_para, chunks[2]);
+
+        // Footer with help
+        let help_text = vec![
+            Line::from(vec![
+                Span::styled("[Tab] ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("Next field  "),
+                Span::styled("[Enter] ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("Save  "),
+                Span::styled("[Esc] ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::raw("Cancel"),
+            ]),
+        ];
+        let help_para = Paragraph::new(help_text);
+        frame.render_widget(help_para, chunks[3]);
+    }
+
+    pub(super) fn render_group_members(&self, frame: &mut Frame, area: Rect, group_id: i64) {
+        let title = self
+            .groups
+            .iter()
+            .find(|g| g.id == group_id)
+            .map(|g| format!("Members - {}", g.name))
+            .unwrap_or_else(|| "Members".to_string());
+
+        if self.group_roster.is_empty() {
+            let content = Paragraph::new(vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    "No players registered yet",
+                    Style::default().fg(self.theme.highlight()),
+                )),
+            ])
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .alignment(Alignment::Center);
+
+            frame.render_widget(content, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .group_roster
+            .iter()
+            .enumerate()
+            .map(|(i, (player, is_member))| {
+                let checkbox = if *is_member { "[x]" } else { "[ ]" };
+                let skill = player
+                    .skill_level
+                    .as_ref()
+                    .map(|s| format!(" ({})", s.as_str()))
+                    .unwrap_or_default();
+
+                let line = if i == self.group_roster_selected_index {
+                    Line::from(vec![
+                        Span::styled(
+                            "► ",
+                            Style::default()
+                                .fg(self.theme.accent())
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!("{} {}", checkbox, player.display_name),
+                            Style::default()
+                                .fg(self.theme.text())
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw(skill),
+                    ])
+                } else {
+                    Line::from(format!("  {} {}{}", checkbox, player.display_name, skill))
+                };
+
+                ListItem::new(line)
+            })
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+
+        frame.render_widget(list, area);
+    }
+
+    pub(super) fn render_group_delete(&self, frame: &mut Frame, area: Rect, group_id: i64) {
+        let name = self
+            .groups
+            .iter()
+            .find(|g| g.id == group_id)
+            .map(|g| g.name.as_str())
+            .unwrap_or("Unknown group");
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("Delete group \"{}\"?", name),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from("Players keep their existing subscriptions."),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press [y] to confirm or [n] to cancel",
+                Style::default().fg(self.theme.highlight()),
+            )),
+        ];
+
+        let para = Paragraph::new(lines)
+            .block(Block::default().title("Delete Group").borders(Borders::ALL))
+            .alignment(Alignment::Center);
+        frame.render_widget(para, area);
+    }
+
+    pub(super) fn render_group_assign(&self, frame: &mut Frame, area: Rect, session_id: i64) {
+        let title = self
+            .sessions
+            .iter()
+            .find(|s| s.session.id == session_id)
+            .map(|s| format!("Assign \"{}\" to group", s.session.title))
+            .unwrap_or_else(|| "Assign session to group".to_string());
+
+        let items: Vec<ListItem> = self
+            .groups
+            .iter()
+            .enumerate()
+            .map(|(i, group)| {
+                let line = if i == self.group_selected_index {
+                    Line::from(vec![
+                        Span::styled(
+                            "► ",
+                            Style::default()
+                                .fg(self.theme.accent())
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            &group.name,
+                            Style::default()
+                                .fg(self.theme.text())
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ])
+                } else {
+                    Line::from(format!("  {}", group.name))
+                };
+
+                ListItem::new(line)
+            })
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+
+        frame.render_widget(list, area);
+    }
+}
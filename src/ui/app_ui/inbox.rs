@@ -0,0 +1,125 @@
+//! Inbox screen: player notifications
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::db::repositories::NotificationRepository;
+use super::App;
+
+impl App {
+    pub(super) fn load_notifications(&mut self) {
+        if let Ok(conn) = self.connect() {
+            match NotificationRepository::find_by_user(&conn, self.user_context.user.id) {
+                Ok(notifications) => self.notifications = notifications,
+                Err(e) => {
+                    self.message = Some(format!("Error loading inbox: {}", e));
+                }
+            }
+        }
+        self.load_unread_count();
+    }
+
+    pub(super) fn load_unread_count(&mut self) {
+        if let Ok(conn) = self.connect() {
+            self.unread_notifications =
+                NotificationRepository::unread_count(&conn, self.user_context.user.id).unwrap_or(self.unread_notifications);
+        }
+    }
+
+    /// Mark the selected notification read and open the session it is about
+    pub(super) fn open_notification(&mut self) {
+        let Some(notification) = self.notifications.get(self.notification_selected_index).cloned() else { return };
+        if !notification.is_read() {
+            if let Ok(conn) = self.connect()
+                && let Err(e) = NotificationRepository::mark_read(&conn, self.user_context.user.id, notification.id)
+            {
+                self.message = Some(format!("Error: {}", e));
+                return;
+            }
+            self.load_notifications();
+        }
+
+        if let Some(session_id) = notification.session_id {
+            self.open_session(session_id);
+        }
+    }
+
+    pub(super) fn mark_all_notifications_read(&mut self) {
+        if let Ok(conn) = self.connect() {
+            match NotificationRepository::mark_all_read(&conn, self.user_context.user.id) {
+                Ok(0) => self.message = Some("No unread notifications".to_string()),
+                Ok(_) => self.message = Some("All notifications marked read".to_string()),
+                Err(e) => self.message = Some(format!("Error: {}", e)),
+            }
+        }
+        self.load_notifications();
+    }
+
+    pub(super) fn render_inbox(&self, frame: &mut Frame, area: Rect) {
+        if self.notifications.is_empty() {
+            let empty = Paragraph::new("No notifications yet. Changes to your sessions and feedback from your coach show up here.")
+                .style(Style::default().fg(Color::DarkGray))
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL).title("Inbox"))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(5), Constraint::Length(5)])
+            .split(area);
+
+        let items: Vec<ListItem> = self
+            .notifications
+            .iter()
+            .enumerate()
+            .map(|(i, notification)| {
+                let marker = if i == self.notification_selected_index {
+                    Span::styled("► ", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD))
+                } else {
+                    Span::raw("  ")
+                };
+                let (unread, style) = if notification.is_read() {
+                    (Span::raw("  "), Style::default().fg(Color::DarkGray))
+                } else {
+                    (
+                        Span::styled("● ", Style::default().fg(self.theme.highlight())),
+                        Style::default().fg(self.theme.text()).add_modifier(Modifier::BOLD),
+                    )
+                };
+
+                ListItem::new(Line::from(vec![
+                    marker,
+                    unread,
+                    Span::styled(
+                        format!("{}  {:<9}", notification.created_at, notification.kind.label()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(notification.message.clone(), style),
+                ]))
+            })
+            .collect();
+
+        let title = format!("Inbox ({} unread) - [Enter] read and open session, [a] mark all read", self.unread_notifications);
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(list, chunks[0]);
+
+        // The selected message in full, since long ones are cut off in the list
+        let selected = self
+            .notifications
+            .get(self.notification_selected_index)
+            .map(|n| n.message.as_str())
+            .unwrap_or_default();
+        let details = Paragraph::new(selected)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title("Message"));
+        frame.render_widget(details, chunks[1]);
+    }
+}
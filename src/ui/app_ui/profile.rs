@@ -0,0 +1,182 @@
+//! Player profile screen and its exports
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::db::repositories::{SubscriptionRepository, UserRepository};
+use crate::export;
+use crate::export::DocumentFormat;
+use crate::export::progress::ProgressReport;
+use crate::models::{SkillLevel, SubscriptionStatus};
+use crate::ui::navigation::Screen;
+use super::App;
+
+impl App {
+    /// Get home menu items based on user role
+    /// Show a player's profile, returning to the current screen on Esc
+    pub(super) fn open_profile(&mut self, user_id: i64) {
+        if let Ok(conn) = self.connect() {
+            match UserRepository::find_by_id(&conn, user_id) {
+                Ok(Some(user)) => {
+                    self.profile_history = SubscriptionRepository::history_for_player(&conn, user_id).unwrap_or_default();
+                    self.profile_user = Some(user);
+                    self.profile_selected_index = 0;
+                    self.profile_return_screen = self.current_screen.clone();
+                    self.current_screen = Screen::PlayerProfile(user_id);
+                }
+                Ok(None) => {
+                    self.message = Some("Player not found".to_string());
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error loading profile: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Write the profile's history (or, for `coach_wide`, the history of every
+    /// player in the coach's sessions) to a CSV file
+    pub(super) fn export_profile_history(&mut self, coach_wide: bool) {
+        let Some(player) = &self.profile_user else { return };
+        let today = chrono::Local::now().date_naive();
+
+        let result = if coach_wide {
+            self.connect()
+                .and_then(|conn| SubscriptionRepository::history_for_coach(&conn, self.user_context.user.id))
+                .and_then(|history| export::history::export_to_dir(&history, &self.export_dir(), None, today))
+        } else {
+            export::history::export_to_dir(&self.profile_history, &self.export_dir(), Some(&player.username), today)
+        };
+
+        self.message = Some(match result {
+            Ok(path) => format!("Exported history to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Write an all-time Markdown progress report for the profile's player
+    pub(super) fn export_progress_report(&mut self) {
+        let Some(player) = &self.profile_user else { return };
+        let dir = self.export_dir();
+        let today = chrono::Local::now().date_naive();
+        let result = self.connect()
+            .and_then(|conn| ProgressReport::load(&conn, player.id, None, None, today))
+            .and_then(|report| {
+                std::fs::create_dir_all(&dir)?;
+                let path = dir.join(report.file_name(DocumentFormat::Markdown));
+                std::fs::write(&path, report.render(DocumentFormat::Markdown))?;
+                Ok(path)
+            });
+
+        self.message = Some(match result {
+            Ok(path) => format!("Progress report written to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    pub(super) fn render_player_profile(&self, frame: &mut Frame, area: Rect) {
+        let Some(player) = &self.profile_user else { return };
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(7), Constraint::Min(0)])
+            .split(area);
+
+        let completed = self
+            .profile_history
+            .iter()
+            .filter(|e| e.subscription.status == SubscriptionStatus::Completed)
+            .count();
+        let profile = Paragraph::new(vec![
+            Line::from(Span::styled(
+                format!("{} (@{})", player.display_name, player.username),
+                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(format!(
+                "Skill Level: {}",
+                player.skill_level.as_ref().map(SkillLevel::as_str).unwrap_or("Not set")
+            )),
+            Line::from(format!("Goals: {}", player.goals.as_deref().unwrap_or("-"))),
+            Line::from(format!(
+                "Sessions: {} subscribed, {} completed",
+                self.profile_history.len(),
+                completed
+            )),
+        ])
+        .block(Block::default().borders(Borders::ALL).title("Profile"));
+        frame.render_widget(profile, chunks[0]);
+
+        if self.profile_history.is_empty() {
+            let empty = Paragraph::new("No training history yet")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title("Training History"))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[1]);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .profile_history
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let status = entry.subscription.status.as_str();
+                let status_color = match entry.subscription.status {
+                    SubscriptionStatus::Completed => Color::Green,
+                    SubscriptionStatus::Active => self.theme.highlight(),
+                    SubscriptionStatus::Cancelled => Color::DarkGray,
+                };
+                let details = format!(
+                    "{}  {:<30}  {:>7}  ",
+                    entry.session_date.map(|d| d.to_string()).unwrap_or_else(|| "No date   ".to_string()),
+                    entry.session_title,
+                    entry.duration_minutes.map(|d| format!("{} min", d)).unwrap_or_default(),
+                );
+
+                let (marker, style) = if i == self.profile_selected_index {
+                    (
+                        Span::styled("► ", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD)),
+                        Style::default().fg(self.theme.text()).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    (Span::raw("  "), Style::default())
+                };
+
+                let style = if entry.subscription.status == SubscriptionStatus::Cancelled {
+                    style.add_modifier(Modifier::CROSSED_OUT)
+                } else {
+                    style
+                };
+
+                let mut lines = vec![Line::from(vec![
+                    marker,
+                    Span::styled(details, style),
+                    Span::styled(status, Style::default().fg(status_color)),
+                ])];
+                if entry.subscription.status == SubscriptionStatus::Cancelled
+                    && let Some(reason) = &entry.cancel_reason
+                {
+                    lines.push(Line::from(Span::styled(
+                        format!("    Cancelled: {}", reason),
+                        Style::default().fg(Color::DarkGray),
+                    )));
+                }
+                if let Some(notes) = entry.subscription.notes.as_deref().filter(|n| !n.is_empty()) {
+                    lines.push(Line::from(Span::styled(
+                        format!("    {}", notes.replace('\n', " ")),
+                        Style::default().fg(Color::DarkGray),
+                    )));
+                }
+                ListItem::new(lines)
+            })
+            .collect();
+
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title("Training History"));
+        frame.render_widget(list, chunks[1]);
+    }
+}
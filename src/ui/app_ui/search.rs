@@ -0,0 +1,130 @@
+//! Search screen: the prompt and its hits
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::db::repositories::SearchRepository;
+use crate::models::SearchHit;
+use super::App;
+
+impl App {
+    pub(super) fn handle_search_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) => {
+                self.search_query.push(c);
+                self.run_search();
+            }
+            KeyCode::Backspace => {
+                self.search_query.pop();
+                self.run_search();
+            }
+            KeyCode::Up if self.search_selected_index > 0 => {
+                self.search_selected_index -= 1;
+            }
+            KeyCode::Down if !self.search_results.is_empty() && self.search_selected_index < self.search_results.len() - 1 => {
+                self.search_selected_index += 1;
+            }
+            KeyCode::Enter => {
+                if let Some(hit) = self.search_results.get(self.search_selected_index).cloned() {
+                    self.open_search_hit(&hit);
+                }
+            }
+            KeyCode::Esc => {
+                self.current_screen = self.search_return_screen.clone();
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn run_search(&mut self) {
+        self.search_selected_index = 0;
+        if let Ok(conn) = self.connect() {
+            match SearchRepository::search(&conn, &self.search_query, &self.user_context.user, 20) {
+                Ok(results) => self.search_results = results,
+                Err(e) => {
+                    self.search_results.clear();
+                    self.message = Some(format!("Search failed: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Jump to the session a search hit belongs to
+    fn open_search_hit(&mut self, hit: &SearchHit) {
+        let Some(session_id) = hit.session_id else {
+            self.message = Some(format!("Template '{}' is not used in any session yet", hit.title));
+            return;
+        };
+
+        self.open_session(session_id);
+    }
+
+    pub(super) fn render_search(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let prompt = Paragraph::new(Line::from(vec![
+            Span::styled("/", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD)),
+            Span::raw(&self.search_query),
+            Span::styled("█", Style::default().fg(self.theme.highlight())),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title("Search"));
+        frame.render_widget(prompt, chunks[0]);
+
+        if self.search_results.is_empty() {
+            let text = if self.search_query.trim().is_empty() {
+                "Type to search sessions, training content, templates and notes"
+            } else {
+                "No matches"
+            };
+            let empty = Paragraph::new(text)
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title("Results"))
+                .alignment(Alignment::Center);
+            frame.render_widget(empty, chunks[1]);
+            return;
+        }
+
+        // Results arrive grouped by kind; emit a heading whenever the kind changes
+        let mut items: Vec<ListItem> = Vec::new();
+        let mut current_kind = None;
+        for (i, hit) in self.search_results.iter().enumerate() {
+            if current_kind != Some(hit.kind) {
+                current_kind = Some(hit.kind);
+                items.push(ListItem::new(Line::from(Span::styled(
+                    hit.kind.label(),
+                    Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                ))));
+            }
+
+            let (marker, title_style) = if i == self.search_selected_index {
+                (
+                    Span::styled("► ", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD)),
+                    Style::default().fg(self.theme.text()).add_modifier(Modifier::BOLD),
+                )
+            } else {
+                (Span::raw("  "), Style::default())
+            };
+
+            items.push(ListItem::new(vec![
+                Line::from(vec![marker, Span::styled(hit.title.clone(), title_style)]),
+                Line::from(Span::styled(
+                    format!("    {}", hit.snippet.replace('\n', " ")),
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ]));
+        }
+
+        let title = format!("Results ({})", self.search_results.len());
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(list, chunks[1]);
+    }
+}
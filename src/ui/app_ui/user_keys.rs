@@ -0,0 +1,257 @@
+//! SSH key screens: list, register, relabel and revoke
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::db::repositories::{UserKeyRepository, UserRepository};
+use crate::ui::navigation::Screen;
+use crate::utils::logging::AUDIT_TARGET;
+use super::App;
+
+impl App {
+    pub(super) fn handle_key_form_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                self.key_form.next_field();
+            }
+            KeyCode::Char(c) => {
+                self.key_form.add_char(c);
+            }
+            KeyCode::Backspace => {
+                self.key_form.backspace();
+            }
+            KeyCode::Enter => {
+                match self.key_form.validate() {
+                    Ok(()) => {
+                        self.save_user_key();
+                    }
+                    Err(e) => {
+                        self.message = Some(format!("Error: {}", e));
+                    }
+                }
+            }
+            KeyCode::Esc => {
+                self.current_screen = Screen::UserKeys;
+            }
+            _ => {}
+        }
+    }
+
+    pub(super) fn load_user_keys(&mut self) {
+        if let Ok(conn) = self.connect() {
+            self.user_keys = UserKeyRepository::find_all(&conn).unwrap_or_default();
+            self.user_key_selected_index = self.user_key_selected_index.min(self.user_keys.len().saturating_sub(1));
+        }
+    }
+
+    /// Add the key in the form, or save the label of the key being relabelled
+    fn save_user_key(&mut self) {
+        let (username, label) = self.key_form.as_db_values();
+        let result = self.connect().and_then(|conn| match self.key_form.editing {
+            Some(key_id) => UserKeyRepository::set_label(&conn, key_id, label.as_deref()).map(|_| key_id),
+            None => {
+                let user = UserRepository::find_by_username(&conn, &username)?
+                    .ok_or_else(|| crate::utils::AppError::NotFound(format!("User '{}'", username)))?;
+                UserKeyRepository::add(&conn, user.id, &self.key_form.parsed_key()?, label.as_deref())
+            }
+        });
+
+        match result {
+            Ok(key_id) => {
+                if self.key_form.editing.is_none() {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, key_id, owner = %username, "ssh key added");
+                }
+                self.message = Some(if self.key_form.editing.is_some() {
+                    "Key label saved".to_string()
+                } else {
+                    format!("Key added for {}", username)
+                });
+                self.current_screen = Screen::UserKeys;
+                self.load_user_keys();
+                if let Some(index) = self.user_keys.iter().position(|k| k.id == key_id) {
+                    self.user_key_selected_index = index;
+                }
+            }
+            Err(e) => {
+                self.message = Some(format!("Error saving key: {}", e));
+            }
+        }
+    }
+
+    pub(super) fn revoke_user_key(&mut self, key_id: i64) {
+        self.current_screen = Screen::UserKeys;
+        if let Ok(conn) = self.connect() {
+            match UserKeyRepository::revoke(&conn, key_id) {
+                Ok(_) => {
+                    tracing::info!(target: AUDIT_TARGET, user = %self.user_context.user.username, key_id, "ssh key revoked");
+                    self.message = Some("Key revoked".to_string());
+                    self.load_user_keys();
+                }
+                Err(e) => {
+                    self.message = Some(format!("Error revoking key: {}", e));
+                }
+            }
+        }
+    }
+
+    pub(super) fn render_user_keys(&self, frame: &mut Frame, area: Rect) {
+        let title = "SSH Keys";
+
+        if self.user_keys.is_empty() {
+            let empty_text = vec![
+                Line::from(""),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "No SSH keys registered",
+                    Style::default().fg(self.theme.highlight()),
+                )),
+                Line::from(""),
+                Line::from("Press [c] to add a user's public key"),
+            ];
+
+            let content = Paragraph::new(empty_text)
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .alignment(Alignment::Center);
+
+            frame.render_widget(content, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .user_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let (marker, style) = if i == self.user_key_selected_index {
+                    (
+                        Span::styled("► ", Style::default().fg(self.theme.accent()).add_modifier(Modifier::BOLD)),
+                        Style::default().fg(self.theme.text()).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    (Span::raw("  "), Style::default())
+                };
+                let status = match &key.revoked_at {
+                    Some(revoked_at) => Span::styled(format!("  revoked {}", revoked_at), Style::default().fg(Color::Red)),
+                    None => Span::raw(""),
+                };
+
+                ListItem::new(Line::from(vec![
+                    marker,
+                    Span::styled(format!("{:<12}", key.username), style),
+                    Span::styled(format!("{:<24}", key.label.as_deref().unwrap_or("-")), style),
+                    Span::styled(
+                        format!("{}  {}", key.fingerprint, key.key_type),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    status,
+                ]))
+            })
+            .collect();
+
+        let title = format!("{} ({}) - [c] add, [l] label, [d] revoke", title, self.user_keys.len());
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+        frame.render_widget(list, area);
+    }
+
+    pub(super) fn render_key_form(&self, frame: &mut Frame, area: Rect) {
+        use crate::ui::key_form::FormField;
+        let form = &self.key_form;
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(5),
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ])
+            .split(area);
+
+        let field_style = |field: FormField| {
+            if form.focus_field == field {
+                Style::default().fg(self.theme.highlight()).add_modifier(Modifier::BOLD)
+            } else if form.editing.is_some() {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default()
+            }
+        };
+
+        // Username field
+        let user_block = Block::default()
+            .title("Username (required)")
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .style(field_style(FormField::Username));
+        frame.render_widget(Paragraph::new(form.username.as_str()).block(user_block), chunks[1]);
+
+        // Public key field
+        let key_block = Block::default()
+            .title("Public Key (required, the line from the user's .pub file)")
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .style(field_style(FormField::PublicKey));
+        let key_para = Paragraph::new(form.public_key.as_str())
+            .wrap(Wrap { trim: false })
+            .block(key_block);
+        frame.render_widget(key_para, chunks[2]);
+
+        // Label field
+        let label_title = if form.editing.is_some() {
+            "Label (empty clears it)"
+        } else {
+            "Label (optional, defaults to the key's comment)"
+        };
+        let label_block = Block::default()
+            .title(label_title)
+            .borders(Borders::ALL)
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .style(field_style(FormField::Label));
+        frame.render_widget(Paragraph::new(form.label.as_str()).block(label_block), chunks[3]);
+
+        // Footer with help
+        let mut help = Vec::new();
+        if form.editing.is_none() {
+            help.push(Span::styled("[Tab] ", Style::default().add_modifier(Modifier::BOLD)));
+            help.push(Span::raw("Next field  "));
+        }
+        help.push(Span::styled("[Enter] ", Style::default().add_modifier(Modifier::BOLD)));
+        help.push(Span::raw("Save  "));
+        help.push(Span::styled("[Esc] ", Style::default().add_modifier(Modifier::BOLD)));
+        help.push(Span::raw("Cancel"));
+        frame.render_widget(Paragraph::new(Line::from(help)), chunks[4]);
+    }
+
+    pub(super) fn render_user_key_revoke(&self, frame: &mut Frame, area: Rect, key_id: i64) {
+        let Some(key) = self.user_keys.iter().find(|k| k.id == key_id) else { return };
+        let label = key.label.as_deref().map(|label| format!(" ({})", label)).unwrap_or_default();
+
+        let lines = vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                format!("Revoke {}'s key{}?", key.username, label),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(key.fingerprint.clone()),
+            Line::from("It stays listed but can no longer be used to log in."),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press [y] to confirm or [n] to cancel",
+                Style::default().fg(self.theme.highlight()),
+            )),
+        ];
+
+        let para = Paragraph::new(lines)
+            .block(Block::default().title("Revoke Key").borders(Borders::ALL))
+            .alignment(Alignment::Center);
+        frame.render_widget(para, area);
+    }
+}
@@ -0,0 +1,13 @@
+//! Courts and venues shown on session screens
+
+use super::App;
+
+impl App {
+    /// `Court 1 @ Riverside`, or `(none)` for sessions not booked on a court
+    pub(super) fn court_label(&self, court_id: Option<i64>) -> String {
+        court_id
+            .and_then(|id| self.courts.iter().find(|c| c.id == id))
+            .map(|c| c.label())
+            .unwrap_or_else(|| "(none)".to_string())
+    }
+}
@@ -0,0 +1,85 @@
+/// Group creation form state
+#[derive(Debug, Clone)]
+pub struct GroupForm {
+    pub name: String,
+    pub description: String,
+    pub focus_field: FormField,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormField {
+    Name,
+    Description,
+}
+
+impl Default for GroupForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GroupForm {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            focus_field: FormField::Name,
+        }
+    }
+
+    /// Move focus to the other field
+    pub fn next_field(&mut self) {
+        self.focus_field = match self.focus_field {
+            FormField::Name => FormField::Description,
+            FormField::Description => FormField::Name,
+        };
+    }
+
+    /// Add character to current field
+    pub fn add_char(&mut self, c: char) {
+        match self.focus_field {
+            FormField::Name => self.name.push(c),
+            FormField::Description => self.description.push(c),
+        }
+    }
+
+    /// Remove last character from current field
+    pub fn backspace(&mut self) {
+        match self.focus_field {
+            FormField::Name => {
+                self.name.pop();
+            }
+            FormField::Description => {
+                self.description.pop();
+            }
+        }
+    }
+
+    /// Validate form fields
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("Group name is required".to_string());
+        }
+        if name.len() < 2 {
+            return Err("Group name must be at least 2 characters".to_string());
+        }
+        if name.len() > 50 {
+            return Err("Group name must be less than 50 characters".to_string());
+        }
+
+        if self.description.len() > 200 {
+            return Err("Description must be less than 200 characters".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Get all fields as a tuple for database insertion
+    pub fn as_db_values(&self) -> (String, Option<String>) {
+        (
+            self.name.trim().to_string(),
+            if self.description.is_empty() { None } else { Some(self.description.clone()) },
+        )
+    }
+}
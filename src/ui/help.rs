@@ -20,7 +20,12 @@ impl HelpScreen {
                         ("[c]", "Create new session"),
                         ("[e]", "Edit selected session"),
                         ("[d]", "Delete selected session"),
+                        ("[x]", "Cancel selected session"),
+                        ("[g]", "Assign session to a group"),
+                        ("[p]", "Duplicate selected session"),
+                        ("[F]", "Filter by tags/level/date"),
                         ("[↑↓]", "Navigate sessions"),
+                        ("[PgUp/PgDn]", "Previous/next page"),
                         ("[Enter]", "View session details"),
                         ("[1]", "Go to Home"),
                         ("[q]", "Go to Home"),
@@ -30,7 +35,9 @@ impl HelpScreen {
                     vec![
                         ("[s]", "Subscribe/Unsubscribe"),
                         ("[f]", "Toggle filter (My/All)"),
+                        ("[F]", "Filter by tags/level/date"),
                         ("[↑↓]", "Navigate sessions"),
+                        ("[PgUp/PgDn]", "Previous/next page"),
                         ("[Enter]", "View session details"),
                         ("[1]", "Go to Home"),
                         ("[q]", "Go to Home"),
@@ -43,7 +50,13 @@ impl HelpScreen {
                     vec![
                         ("[e]", "Edit this session"),
                         ("[d]", "Delete this session"),
+                        ("[x]", "Cancel this session"),
                         ("[t]", "Manage training content"),
+                        ("[g]", "Assign session to a group"),
+                        ("[p]", "Duplicate this session"),
+                        ("[w]", "Write plan as Markdown"),
+                        ("[W]", "Write plan as HTML"),
+                        ("[h]", "Show change history"),
                         ("[1]", "Go to Home"),
                         ("[2]", "Back to Session List"),
                         ("[q]", "Go to Home"),
@@ -53,6 +66,8 @@ impl HelpScreen {
                     vec![
                         ("[s]", "Subscribe/Unsubscribe"),
                         ("[m]", "Mark as completed"),
+                        ("[w]", "Write plan as Markdown"),
+                        ("[W]", "Write plan as HTML"),
                         ("[1]", "Go to Home"),
                         ("[2]", "Back to Session List"),
                         ("[q]", "Go to Home"),
@@ -73,6 +88,11 @@ impl HelpScreen {
                 ("[Esc]", "Cancel deletion"),
                 ("[?]", "Show this help"),
             ],
+            Screen::SessionCancel(_) => vec![
+                ("[type]", "Reason for cancelling"),
+                ("[Enter]", "Cancel the session"),
+                ("[Esc]", "Back to Session List"),
+            ],
             Screen::TrainingContentCreate(_) | Screen::TrainingContentEdit(_) => vec![
                 ("[Tab]", "Next field"),
                 ("[Shift+Tab]", "Previous field"),
@@ -94,6 +114,111 @@ impl HelpScreen {
                 ("[Space]", "Page down"),
                 ("[b]", "Page up"),
             ],
+            Screen::SessionFilterEdit => vec![
+                ("[Tab]", "Next field"),
+                ("[← →]", "Cycle skill level"),
+                ("[Enter]", "Apply filters"),
+                ("[Del]", "Clear all filters"),
+                ("[Esc]", "Cancel"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::GroupList => vec![
+                ("[c]", "Create new group"),
+                ("[d]", "Delete selected group"),
+                ("[↑↓]", "Navigate groups"),
+                ("[Enter]", "Manage group members"),
+                ("[Esc]", "Back to Home"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::GroupCreate => vec![
+                ("[Tab]", "Next field"),
+                ("[Enter]", "Save group"),
+                ("[Esc]", "Cancel"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::GroupMembers(_) => vec![
+                ("[Space]", "Add/remove selected player"),
+                ("[Enter]", "Open player profile"),
+                ("[↑↓]", "Navigate players"),
+                ("[Esc]", "Back to Group List"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::GroupDelete(_) => vec![
+                ("[y]", "Confirm deletion"),
+                ("[n]", "Cancel deletion"),
+                ("[Esc]", "Cancel deletion"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::GroupAssign(_) => vec![
+                ("[↑↓]", "Navigate groups"),
+                ("[Enter]", "Subscribe all group members"),
+                ("[Esc]", "Back to Session List"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::Search => vec![
+                ("[type]", "Search sessions, content, templates, notes"),
+                ("[↑↓]", "Navigate results"),
+                ("[Enter]", "Open selected result"),
+                ("[Esc]", "Close search"),
+            ],
+            Screen::PlayerProfile(_) => {
+                let mut commands = vec![
+                    ("[↑↓]", "Navigate training history"),
+                    ("[x]", "Export this player's history as CSV"),
+                    ("[r]", "Write progress report (Markdown)"),
+                ];
+                if user_context.is_coach() {
+                    commands.push(("[X]", "Export history of all your players as CSV"));
+                }
+                commands.push(("[Esc]", "Back"));
+                commands.push(("[?]", "Show this help"));
+                commands
+            }
+            Screen::SessionAudit(_) => vec![
+                ("[↑↓]", "Navigate changes"),
+                ("[Esc]", "Back to session"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::Archive => vec![
+                ("[↑↓]", "Navigate archived items"),
+                ("[r]", "Restore selected item"),
+                ("[d]", "Delete selected item permanently"),
+                ("[Esc]", "Back to Home"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::ArchivePurge => vec![
+                ("[y]", "Confirm permanent deletion"),
+                ("[n]", "Cancel"),
+                ("[Esc]", "Cancel"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::UserKeys => vec![
+                ("[↑↓]", "Navigate keys"),
+                ("[c]", "Add a key for a user"),
+                ("[l]", "Change selected key's label"),
+                ("[d]", "Revoke selected key"),
+                ("[Esc]", "Back to Home"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::UserKeyCreate | Screen::UserKeyLabel(_) => vec![
+                ("[Tab]", "Next field"),
+                ("[Enter]", "Save key"),
+                ("[Esc]", "Cancel"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::UserKeyRevoke(_) => vec![
+                ("[y]", "Confirm revocation"),
+                ("[n]", "Cancel"),
+                ("[Esc]", "Cancel"),
+                ("[?]", "Show this help"),
+            ],
+            Screen::Inbox => vec![
+                ("[↑↓]", "Navigate notifications"),
+                ("[Enter]", "Mark read and open its session"),
+                ("[a]", "Mark all as read"),
+                ("[Esc]", "Back to Home"),
+                ("[?]", "Show this help"),
+            ],
         }
     }
 
@@ -111,6 +236,7 @@ impl HelpScreen {
                         ("[c]", "Create"),
                         ("[e]", "Edit"),
                         ("[d]", "Delete"),
+                        ("[g]", "Group"),
                         ("[?]", "Help"),
                     ]
                 } else {
@@ -127,6 +253,7 @@ impl HelpScreen {
                     vec![
                         ("[t]", "Training"),
                         ("[e]", "Edit"),
+                        ("[h]", "History"),
                         ("[2]", "Back"),
                     ]
                 } else {
@@ -151,6 +278,10 @@ impl HelpScreen {
                 ("[y]", "Delete"),
                 ("[n]", "Cancel"),
             ],
+            Screen::SessionCancel(_) => vec![
+                ("[Enter]", "Cancel session"),
+                ("[Esc]", "Back"),
+            ],
             Screen::TrainingContentCreate(_) | Screen::TrainingContentEdit(_) => vec![
                 ("[Tab]", "Next"),
                 ("[← →]", "Content type"),
@@ -165,6 +296,88 @@ impl HelpScreen {
                 ("[q]", "Close"),
                 ("[↑↓]", "Scroll"),
             ],
+            Screen::SessionFilterEdit => vec![
+                ("[Tab]", "Next"),
+                ("[Enter]", "Apply"),
+                ("[Del]", "Clear"),
+                ("[Esc]", "Cancel"),
+            ],
+            Screen::GroupList => vec![
+                ("[c]", "Create"),
+                ("[d]", "Delete"),
+                ("[Enter]", "Members"),
+                ("[?]", "Help"),
+            ],
+            Screen::GroupCreate => vec![
+                ("[Tab]", "Next"),
+                ("[Enter]", "Save"),
+                ("[Esc]", "Cancel"),
+            ],
+            Screen::GroupMembers(_) => vec![
+                ("[Space]", "Toggle member"),
+                ("[Esc]", "Back"),
+            ],
+            Screen::GroupDelete(_) => vec![
+                ("[y]", "Delete"),
+                ("[n]", "Cancel"),
+            ],
+            Screen::GroupAssign(_) => vec![
+                ("[Enter]", "Assign"),
+                ("[Esc]", "Cancel"),
+            ],
+            Screen::Search => vec![
+                ("[↑↓]", "Results"),
+                ("[Enter]", "Open"),
+                ("[Esc]", "Close"),
+            ],
+            Screen::PlayerProfile(_) => {
+                if user_context.is_coach() {
+                    vec![
+                        ("[x]", "Export player"),
+                        ("[X]", "Export all"),
+                        ("[Esc]", "Back"),
+                    ]
+                } else {
+                    vec![
+                        ("[x]", "Export CSV"),
+                        ("[r]", "Report"),
+                        ("[Esc]", "Back"),
+                    ]
+                }
+            }
+            Screen::SessionAudit(_) => vec![
+                ("[↑↓]", "Navigate"),
+                ("[Esc]", "Back"),
+            ],
+            Screen::Archive => vec![
+                ("[r]", "Restore"),
+                ("[d]", "Delete forever"),
+                ("[Esc]", "Back"),
+            ],
+            Screen::ArchivePurge => vec![
+                ("[y]", "Delete forever"),
+                ("[n]", "Cancel"),
+            ],
+            Screen::UserKeys => vec![
+                ("[c]", "Add"),
+                ("[l]", "Label"),
+                ("[d]", "Revoke"),
+                ("[Esc]", "Back"),
+            ],
+            Screen::UserKeyCreate | Screen::UserKeyLabel(_) => vec![
+                ("[Tab]", "Next"),
+                ("[Enter]", "Save"),
+                ("[Esc]", "Cancel"),
+            ],
+            Screen::UserKeyRevoke(_) => vec![
+                ("[y]", "Revoke"),
+                ("[n]", "Cancel"),
+            ],
+            Screen::Inbox => vec![
+                ("[Enter]", "Open"),
+                ("[a]", "Mark all read"),
+                ("[Esc]", "Back"),
+            ],
         }
     }
 
@@ -180,25 +393,51 @@ impl HelpScreen {
             "  [q]        Return to home screen (or quit if on home)".to_string(),
             "  [1]        Jump to home screen".to_string(),
             "  [Esc]      Go back to previous screen".to_string(),
+            "  [/]        Search sessions, training content, templates and notes".to_string(),
             "".to_string(),
             "NAVIGATION COMMANDS:".to_string(),
             "  [2]        Go to Session Management".to_string(),
             "  [↑] [k]    Navigate up / Previous item (vi-style)".to_string(),
             "  [↓] [j]    Navigate down / Next item (vi-style)".to_string(),
+            "  [PgUp/PgDn] Previous / next page of the session list".to_string(),
             "  [Enter]    Select/View item or confirm action".to_string(),
             "".to_string(),
             "SESSION LIST COMMANDS (Coach):".to_string(),
             "  [c]        Create new session".to_string(),
             "  [e]        Edit selected session".to_string(),
             "  [d]        Delete selected session".to_string(),
+            "  [x]        Cancel session with a reason (list/detail); it stays listed,".to_string(),
+            "             struck through, and subscribed players are notified".to_string(),
+            "  [p]        Duplicate session with its training plan (list/detail)".to_string(),
+            "".to_string(),
+            "GROUP COMMANDS (Coach):".to_string(),
+            "  [g]        Assign selected session to a group (session list/detail)".to_string(),
+            "  [c]        Create new group (group list)".to_string(),
+            "  [d]        Delete selected group (group list)".to_string(),
+            "  [Enter]    Manage members of selected group".to_string(),
+            "  [Space]    Add/remove player from group (members screen)".to_string(),
+            "  [Enter]    Open selected player's profile (members screen)".to_string(),
             "".to_string(),
             "SESSION LIST COMMANDS (Player):".to_string(),
             "  [s]        Subscribe/Unsubscribe to session".to_string(),
             "  [f]        Toggle filter: My Sessions vs All Available".to_string(),
             "".to_string(),
+            "SESSION FILTER BAR (All users):".to_string(),
+            "  [F]        Filter session list by tags, skill level and date range".to_string(),
+            "  Tags       Comma separated; a session must carry every listed tag".to_string(),
+            "  [Del]      Clear all filters (in filter editor)".to_string(),
+            "".to_string(),
             "SESSION DETAIL COMMANDS (Player):".to_string(),
             "  [m]        Mark session as completed".to_string(),
             "".to_string(),
+            "SESSION PLAN (All users, on session detail):".to_string(),
+            "  [w]        Write printable plan as Markdown to exports/".to_string(),
+            "  [W]        Write printable plan as self-contained HTML to exports/".to_string(),
+            "".to_string(),
+            "SESSION HISTORY (Coach, on session detail):".to_string(),
+            "  [h]        Show who changed the session, its content and subscriptions".to_string(),
+            "  [↑] [↓]    Select a change to see its values before and after".to_string(),
+            "".to_string(),
             "SESSION DETAIL DISPLAY:".to_string(),
             "  View all session information including:".to_string(),
             "  - Session title, description, date, time, duration".to_string(),
@@ -210,14 +449,44 @@ impl HelpScreen {
             "  [Tab]      Move to next field".to_string(),
             "  [Shift+Tab] Move to previous field".to_string(),
             "  [↑] [↓]    Move up/down between fields".to_string(),
-            "  [← →]      Cycle skill level (when on skill level field)".to_string(),
+            "  [← →]      Cycle court or skill level (when on that field)".to_string(),
+            "  [Backspace] Clear the court (when on the court field)".to_string(),
             "  [Enter]    Save form".to_string(),
             "  [Esc]      Cancel without saving".to_string(),
             "".to_string(),
+            "PROFILE COMMANDS:".to_string(),
+            "  [x]        Export the player's training history as CSV".to_string(),
+            "  [X]        Export the history of all your players as CSV (Coach)".to_string(),
+            "  [r]        Write the player's progress report as Markdown".to_string(),
+            "             Files are written to exports/ next to the database".to_string(),
+            "".to_string(),
+            "SEARCH COMMANDS:".to_string(),
+            "  [/]        Open search; results update as you type".to_string(),
+            "  [↑] [↓]    Move between results (grouped by kind)".to_string(),
+            "  [Enter]    Open the session the result belongs to".to_string(),
+            "  [Esc]      Close search".to_string(),
+            "".to_string(),
             "DELETION COMMANDS:".to_string(),
             "  [y]        Confirm deletion".to_string(),
             "  [n]        Cancel deletion".to_string(),
             "  [Esc]      Cancel deletion".to_string(),
+            "  [u]        Undo the last deletion (within 30 seconds)".to_string(),
+            "             Deleted sessions and content are kept in the Archive".to_string(),
+            "".to_string(),
+            "ARCHIVE COMMANDS (Coach, from the Home menu):".to_string(),
+            "  [r]        Restore the selected session, content or template".to_string(),
+            "  [d]        Delete the selected item permanently (asks to confirm)".to_string(),
+            "".to_string(),
+            "INBOX COMMANDS (Player, from the Home menu):".to_string(),
+            "  [Enter]    Mark the selected notification read and open its session".to_string(),
+            "  [a]        Mark all notifications read".to_string(),
+            "             The header shows how many are unread".to_string(),
+            "".to_string(),
+            "SSH KEY COMMANDS (Coach, from the Home menu):".to_string(),
+            "  [c]        Add a public key for a user (paste the .pub line)".to_string(),
+            "  [l]        Change the selected key's label".to_string(),
+            "  [d]        Revoke the selected key (asks to confirm)".to_string(),
+            "             Revoked keys stay listed but can no longer log in".to_string(),
             "".to_string(),
             "TEXT EDITOR (Vim-style):".to_string(),
             "  [i]        Enter insert mode (when in normal mode)".to_string(),
@@ -237,7 +506,9 @@ impl HelpScreen {
             "  Date               Format: YYYY-MM-DD (optional)".to_string(),
             "  Time               Format: HH:MM (optional)".to_string(),
             "  Duration           Range: 5-480 minutes (optional)".to_string(),
+            "  Court              Optional: a court no other session holds then".to_string(),
             "  Skill Level        Choose: Beginner, Intermediate, or Advanced".to_string(),
+            "  Tags               Optional: comma separated, max 10 tags".to_string(),
             "".to_string(),
             "═══════════════════════════════════════════════════════════════════".to_string(),
             "Press [q] or [Esc] to close help and return to application".to_string(),
@@ -0,0 +1,119 @@
+use crate::auth::PublicKey;
+use crate::models::UserKey;
+
+/// SSH key form state: adding a key, or relabelling one (`editing`)
+#[derive(Debug, Clone)]
+pub struct KeyForm {
+    pub username: String,
+    pub public_key: String,
+    pub label: String,
+    pub focus_field: FormField,
+    /// Key being relabelled; only the label can change
+    pub editing: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FormField {
+    Username,
+    PublicKey,
+    Label,
+}
+
+impl Default for KeyForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyForm {
+    pub fn new() -> Self {
+        Self {
+            username: String::new(),
+            public_key: String::new(),
+            label: String::new(),
+            focus_field: FormField::Username,
+            editing: None,
+        }
+    }
+
+    /// Form for changing an existing key's label
+    pub fn for_label(key: &UserKey) -> Self {
+        Self {
+            username: key.username.clone(),
+            public_key: key.openssh(),
+            label: key.label.clone().unwrap_or_default(),
+            focus_field: FormField::Label,
+            editing: Some(key.id),
+        }
+    }
+
+    /// Move focus to the next field
+    pub fn next_field(&mut self) {
+        if self.editing.is_some() {
+            return;
+        }
+        self.focus_field = match self.focus_field {
+            FormField::Username => FormField::PublicKey,
+            FormField::PublicKey => FormField::Label,
+            FormField::Label => FormField::Username,
+        };
+    }
+
+    /// Add character to current field
+    pub fn add_char(&mut self, c: char) {
+        match self.focus_field {
+            FormField::Username => self.username.push(c),
+            FormField::PublicKey => self.public_key.push(c),
+            FormField::Label => self.label.push(c),
+        }
+    }
+
+    /// Remove last character from current field
+    pub fn backspace(&mut self) {
+        match self.focus_field {
+            FormField::Username => {
+                self.username.pop();
+            }
+            FormField::PublicKey => {
+                self.public_key.pop();
+            }
+            FormField::Label => {
+                self.label.pop();
+            }
+        }
+    }
+
+    /// Validate form fields
+    pub fn validate(&self) -> Result<(), String> {
+        if self.label.trim().len() > 50 {
+            return Err("Label must be less than 50 characters".to_string());
+        }
+        if self.editing.is_some() {
+            return Ok(());
+        }
+
+        if self.username.trim().is_empty() {
+            return Err("Username is required".to_string());
+        }
+        if self.public_key.trim().is_empty() {
+            return Err("Public key is required".to_string());
+        }
+        self.parsed_key().map(|_| ()).map_err(|e| e.to_string())
+    }
+
+    /// The pasted public key
+    pub fn parsed_key(&self) -> crate::utils::Result<PublicKey> {
+        PublicKey::parse(self.public_key.trim())
+    }
+
+    /// Username and label for saving; an empty label falls back to the
+    /// key's comment when adding and clears the label when relabelling
+    pub fn as_db_values(&self) -> (String, Option<String>) {
+        let label = Some(self.label.trim().to_string()).filter(|label| !label.is_empty());
+        let label = match self.editing {
+            Some(_) => label,
+            None => label.or_else(|| self.parsed_key().ok().and_then(|key| key.comment)),
+        };
+        (self.username.trim().to_string(), label)
+    }
+}
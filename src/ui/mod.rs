@@ -1,17 +1,23 @@
 pub mod app_ui;
+pub mod group_form;
+pub mod key_form;
 pub mod navigation;
 pub mod session_filter;
 pub mod session_form;
 pub mod session_edit_form;
 pub mod text_editor;
 pub mod help;
+pub mod theme;
 pub mod training_content_form;
 
 pub use app_ui::App;
+pub use group_form::GroupForm;
+pub use key_form::KeyForm;
 pub use navigation::Screen;
-pub use session_filter::SessionFilter;
+pub use session_filter::{SessionFilter, SessionFilterBar};
 pub use session_form::SessionForm;
 pub use session_edit_form::SessionEditForm;
 pub use text_editor::TextEditor;
 pub use help::HelpScreen;
+pub use theme::Theme;
 pub use training_content_form::TrainingContentForm;
\ No newline at end of file
@@ -8,10 +8,27 @@ pub enum Screen {
     SessionCreate,
     SessionEdit(i64),          // session_id
     SessionDelete(i64),        // session_id
+    SessionCancel(i64),        // session_id - Coach: reason for cancelling
     TrainingContentCreate(i64),// session_id - Create content for session
     TrainingContentEdit(i64),  // content_id
     TrainingContentDelete(i64),// content_id
     Help,                      // Help/commands screen
+    SessionFilterEdit,         // Edit tag/skill level/date filter bar
+    GroupList,                 // Coach: manage player groups
+    GroupCreate,               // Coach: create a new group
+    GroupMembers(i64),         // group_id - Toggle group membership
+    GroupDelete(i64),          // group_id
+    GroupAssign(i64),          // session_id - Pick a group to subscribe
+    Search,                    // Full-text search prompt and results
+    PlayerProfile(i64),        // user_id - Profile and training history
+    SessionAudit(i64),         // session_id - Coach: change history
+    Archive,                   // Coach: archived sessions, content and templates
+    ArchivePurge,              // Coach: confirm permanent deletion of the selected archived item
+    UserKeys,                  // Coach: SSH keys users log in with
+    UserKeyCreate,             // Coach: register a public key for a user
+    UserKeyLabel(i64),         // key_id - Change a key's label
+    UserKeyRevoke(i64),        // key_id
+    Inbox,                     // Player: notifications about subscribed sessions and feedback
 }
 
 impl Screen {
@@ -21,6 +38,7 @@ impl Screen {
             vec![
                 ("Home", Screen::Home),
                 ("Manage Session", Screen::SessionList),
+                ("Manage Groups", Screen::GroupList),
             ]
         } else {
             vec![("Home", Screen::Home), ("My Session", Screen::SessionList)]
@@ -1,3 +1,7 @@
+use chrono::NaiveDate;
+
+use crate::models::{Session, SkillLevel};
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SessionFilter {
     MySubscriptions,
@@ -18,4 +22,193 @@ impl SessionFilter {
             SessionFilter::AllAvailable => "All Available",
         }
     }
-}
\ No newline at end of file
+}
+
+/// Filter bar on the session list combining tags, skill level and a date range
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionFilterBar {
+    pub tags: String,
+    pub skill_level: Option<SkillLevel>,
+    pub date_from: String,
+    pub date_to: String,
+    pub focus_field: FilterField,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterField {
+    Tags,
+    SkillLevel,
+    DateFrom,
+    DateTo,
+}
+
+impl Default for SessionFilterBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionFilterBar {
+    pub fn new() -> Self {
+        Self {
+            tags: String::new(),
+            skill_level: None,
+            date_from: String::new(),
+            date_to: String::new(),
+            focus_field: FilterField::Tags,
+        }
+    }
+
+    /// Move focus to next field
+    pub fn next_field(&mut self) {
+        self.focus_field = match self.focus_field {
+            FilterField::Tags => FilterField::SkillLevel,
+            FilterField::SkillLevel => FilterField::DateFrom,
+            FilterField::DateFrom => FilterField::DateTo,
+            FilterField::DateTo => FilterField::Tags,
+        };
+    }
+
+    /// Move focus to previous field
+    pub fn prev_field(&mut self) {
+        self.focus_field = match self.focus_field {
+            FilterField::Tags => FilterField::DateTo,
+            FilterField::SkillLevel => FilterField::Tags,
+            FilterField::DateFrom => FilterField::SkillLevel,
+            FilterField::DateTo => FilterField::DateFrom,
+        };
+    }
+
+    /// Add character to current field
+    pub fn add_char(&mut self, c: char) {
+        match self.focus_field {
+            FilterField::Tags => self.tags.push(c),
+            FilterField::DateFrom => self.date_from.push(c),
+            FilterField::DateTo => self.date_to.push(c),
+            FilterField::SkillLevel => {
+                // Don't allow direct character input for skill level
+            }
+        }
+    }
+
+    /// Remove last character from current field
+    pub fn backspace(&mut self) {
+        match self.focus_field {
+            FilterField::Tags => {
+                self.tags.pop();
+            }
+            FilterField::DateFrom => {
+                self.date_from.pop();
+            }
+            FilterField::DateTo => {
+                self.date_to.pop();
+            }
+            FilterField::SkillLevel => {
+                self.skill_level = None;
+            }
+        }
+    }
+
+    /// Cycle skill level: Any -> Beginner -> Intermediate -> Advanced -> Any
+    pub fn cycle_skill_level_forward(&mut self) {
+        self.skill_level = match self.skill_level {
+            None => Some(SkillLevel::Beginner),
+            Some(SkillLevel::Beginner) => Some(SkillLevel::Intermediate),
+            Some(SkillLevel::Intermediate) => Some(SkillLevel::Advanced),
+            Some(SkillLevel::Advanced) => None,
+        };
+    }
+
+    /// Validate the date range
+    pub fn validate(&self) -> Result<(), String> {
+        crate::models::Tag::validate_list(&self.tags)?;
+
+        let from = Self::parse_date(&self.date_from, "From")?;
+        let to = Self::parse_date(&self.date_to, "To")?;
+        if let (Some(from), Some(to)) = (from, to)
+            && from > to
+        {
+            return Err("From date must be before To date".to_string());
+        }
+        Ok(())
+    }
+
+    /// Whether any criterion is set
+    pub fn is_active(&self) -> bool {
+        !self.tag_list().is_empty()
+            || self.skill_level.is_some()
+            || !self.date_from.is_empty()
+            || !self.date_to.is_empty()
+    }
+
+    /// Get the parsed tag list
+    pub fn tag_list(&self) -> Vec<String> {
+        crate::models::Tag::parse_list(&self.tags)
+    }
+
+    /// Check a session (and its tags) against every criterion.
+    ///
+    /// A session must carry all selected tags; sessions without a date never
+    /// match an active date range.
+    pub fn matches(&self, session: &Session, session_tags: &[String]) -> bool {
+        if !self
+            .tag_list()
+            .iter()
+            .all(|tag| session_tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        {
+            return false;
+        }
+
+        if let Some(level) = &self.skill_level
+            && session.skill_level.as_ref() != Some(level)
+        {
+            return false;
+        }
+
+        let from = Self::parse_date(&self.date_from, "From").ok().flatten();
+        let to = Self::parse_date(&self.date_to, "To").ok().flatten();
+        if from.is_some() || to.is_some() {
+            let Some(date) = session.scheduled_date else {
+                return false;
+            };
+            if from.is_some_and(|from| date < from) || to.is_some_and(|to| date > to) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// One-line description for the filter bar
+    pub fn describe(&self) -> String {
+        if !self.is_active() {
+            return "No filters".to_string();
+        }
+
+        let mut parts = Vec::new();
+        let tags = self.tag_list();
+        if !tags.is_empty() {
+            parts.push(format!("tags: {}", tags.join(", ")));
+        }
+        if let Some(level) = &self.skill_level {
+            parts.push(format!("level: {}", level.as_str()));
+        }
+        if !self.date_from.is_empty() || !self.date_to.is_empty() {
+            parts.push(format!(
+                "dates: {} .. {}",
+                if self.date_from.is_empty() { "*" } else { &self.date_from },
+                if self.date_to.is_empty() { "*" } else { &self.date_to },
+            ));
+        }
+        parts.join(" | ")
+    }
+
+    fn parse_date(value: &str, label: &str) -> Result<Option<NaiveDate>, String> {
+        if value.is_empty() {
+            return Ok(None);
+        }
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(Some)
+            .map_err(|_| format!("{} date format should be YYYY-MM-DD", label))
+    }
+}
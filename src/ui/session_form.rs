@@ -6,7 +6,10 @@ pub struct SessionForm {
     pub scheduled_date: String,
     pub scheduled_time: String,
     pub duration_minutes: String,
+    /// Court picked from `App::courts`; cycled with Left/Right
+    pub court_id: Option<i64>,
     pub skill_level: String,
+    pub tags: String,
     pub focus_field: FormField,
 }
 
@@ -17,7 +20,9 @@ pub enum FormField {
     ScheduledDate,
     ScheduledTime,
     DurationMinutes,
+    Court,
     SkillLevel,
+    Tags,
 }
 
 impl SessionForm {
@@ -28,7 +33,9 @@ impl SessionForm {
             scheduled_date: String::new(),
             scheduled_time: String::new(),
             duration_minutes: String::new(),
+            court_id: None,
             skill_level: "beginner".to_string(),
+            tags: String::new(),
             focus_field: FormField::Title,
         }
     }
@@ -40,20 +47,24 @@ impl SessionForm {
             FormField::Description => FormField::ScheduledDate,
             FormField::ScheduledDate => FormField::ScheduledTime,
             FormField::ScheduledTime => FormField::DurationMinutes,
-            FormField::DurationMinutes => FormField::SkillLevel,
-            FormField::SkillLevel => FormField::Title,
+            FormField::DurationMinutes => FormField::Court,
+            FormField::Court => FormField::SkillLevel,
+            FormField::SkillLevel => FormField::Tags,
+            FormField::Tags => FormField::Title,
         };
     }
 
     /// Move focus to previous field
     pub fn prev_field(&mut self) {
         self.focus_field = match self.focus_field {
-            FormField::Title => FormField::SkillLevel,
+            FormField::Title => FormField::Tags,
             FormField::Description => FormField::Title,
             FormField::ScheduledDate => FormField::Description,
             FormField::ScheduledTime => FormField::ScheduledDate,
             FormField::DurationMinutes => FormField::ScheduledTime,
-            FormField::SkillLevel => FormField::DurationMinutes,
+            FormField::Court => FormField::DurationMinutes,
+            FormField::SkillLevel => FormField::Court,
+            FormField::Tags => FormField::SkillLevel,
         };
     }
 
@@ -70,9 +81,10 @@ impl SessionForm {
                     self.duration_minutes.push(c);
                 }
             }
-            FormField::SkillLevel => {
-                // Don't allow direct character input for skill level
+            FormField::Court | FormField::SkillLevel => {
+                // Don't allow direct character input for court or skill level
             }
+            FormField::Tags => self.tags.push(c),
         }
     }
 
@@ -94,6 +106,12 @@ impl SessionForm {
             FormField::DurationMinutes => {
                 self.duration_minutes.pop();
             }
+            FormField::Tags => {
+                self.tags.pop();
+            }
+            FormField::Court => {
+                self.court_id = None;
+            }
             FormField::SkillLevel => {
                 // Handle skill level cycling
                 self.skill_level = match self.skill_level.as_str() {
@@ -106,6 +124,19 @@ impl SessionForm {
         }
     }
 
+    /// Step to the next (or previous) court, passing through "no court"
+    /// between the last and the first
+    pub fn cycle_court(&mut self, courts: &[crate::models::Court], forward: bool) {
+        let current = self.court_id.and_then(|id| courts.iter().position(|c| c.id == id));
+        let next = match (current, forward) {
+            (None, true) => courts.first(),
+            (None, false) => courts.last(),
+            (Some(i), true) => courts.get(i + 1),
+            (Some(i), false) => i.checked_sub(1).and_then(|i| courts.get(i)),
+        };
+        self.court_id = next.map(|c| c.id);
+    }
+
     /// Cycle skill level forward
     pub fn cycle_skill_level_forward(&mut self) {
         self.skill_level = match self.skill_level.as_str() {
@@ -162,9 +193,16 @@ impl SessionForm {
             }
         }
 
+        crate::models::Tag::validate_list(&self.tags)?;
+
         Ok(())
     }
 
+    /// Get the parsed tag list (lowercased, deduplicated)
+    pub fn tag_list(&self) -> Vec<String> {
+        crate::models::Tag::parse_list(&self.tags)
+    }
+
     /// Get all fields as a tuple for database insertion
     pub fn as_db_values(&self) -> (String, String, Option<String>, Option<String>, Option<i32>, String) {
         (
@@ -0,0 +1,29 @@
+use ratatui::style::Color;
+
+pub use crate::config::Theme;
+
+impl Theme {
+    /// Borders of the help screen, selection markers and key hints
+    pub fn accent(&self) -> Color {
+        match self {
+            Theme::Dark => Color::Cyan,
+            Theme::Light => Color::Blue,
+        }
+    }
+
+    /// Titles, labels and notices
+    pub fn highlight(&self) -> Color {
+        match self {
+            Theme::Dark => Color::Yellow,
+            Theme::Light => Color::Magenta,
+        }
+    }
+
+    /// Emphasised text such as the selected item
+    pub fn text(&self) -> Color {
+        match self {
+            Theme::Dark => Color::White,
+            Theme::Light => Color::Black,
+        }
+    }
+}
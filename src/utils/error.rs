@@ -1,3 +1,4 @@
+use rusqlite::ErrorCode;
 use std::fmt;
 
 #[derive(Debug)]
@@ -10,9 +11,24 @@ pub enum AppError {
     Other(String),
 }
 
+impl AppError {
+    /// Another connection kept the database locked for longer than the busy
+    /// timeout; retrying later usually succeeds
+    pub fn is_busy(&self) -> bool {
+        matches!(
+            self,
+            AppError::Database(rusqlite::Error::SqliteFailure(e, _))
+                if matches!(e.code, ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked)
+        )
+    }
+}
+
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            AppError::Database(_) if self.is_busy() => {
+                write!(f, "The database is busy with other users' changes, please try again")
+            }
             AppError::Database(e) => write!(f, "Database error: {}", e),
             AppError::Io(e) => write!(f, "IO error: {}", e),
             AppError::NotFound(msg) => write!(f, "Not found: {}", msg),
@@ -0,0 +1,78 @@
+use std::path::{Path, PathBuf};
+use tracing::Level;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::{filter_fn, EnvFilter};
+use tracing_subscriber::prelude::*;
+
+use crate::config::Settings;
+use crate::utils::{AppError, Result};
+
+/// Target of user-action events (DESIGN.md §10.1). They are written to the
+/// main log and, separately, to `audit.log` next to it.
+pub const AUDIT_TARGET: &str = "audit";
+
+/// Rotated log files kept per log (one per day)
+const KEEP_LOG_FILES: usize = 7;
+
+/// Send all logging to daily-rotated files under the directory of
+/// `logging.path`; nothing is written to the terminal, which belongs to the TUI.
+/// Returns the directory the logs are written to.
+pub fn init(settings: &Settings) -> Result<PathBuf> {
+    let (dir, prefix) = split_log_path(&settings.log_path)?;
+    std::fs::create_dir_all(&dir)?;
+
+    let main_log = appender(&dir, &prefix)?;
+    let audit_log = appender(&dir, "audit.log")?;
+
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(main_log)
+                .with_ansi(false)
+                .with_filter(EnvFilter::new(&settings.log_level)),
+        )
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(audit_log)
+                .with_ansi(false)
+                .with_target(false)
+                .with_filter(filter_fn(|meta| {
+                    // Info spans are kept so audit lines carry the user and screen
+                    *meta.level() <= Level::INFO && (meta.is_span() || meta.target() == AUDIT_TARGET)
+                })),
+        )
+        .try_init()
+        .map_err(|e| AppError::Other(format!("Could not install logger: {}", e)))?;
+
+    Ok(dir)
+}
+
+/// Span entered while the TUI handles a key, so every line logged for it
+/// (audit lines included) names the screen it came from. It is an info span:
+/// at the default `logging.level` a debug span would be filtered out.
+pub fn screen_span(screen: &dyn std::fmt::Debug) -> tracing::Span {
+    tracing::info_span!("screen", screen = ?screen)
+}
+
+/// `logs/tennis-tui.log` -> (`logs`, `tennis-tui.log`); rotated files get a
+/// date suffix (`tennis-tui.log.2026-10-18`)
+fn split_log_path(path: &Path) -> Result<(PathBuf, String)> {
+    let prefix = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| AppError::Validation(format!("logging.path '{}' has no file name", path.display())))?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    Ok((dir, prefix.to_string()))
+}
+
+fn appender(dir: &Path, prefix: &str) -> Result<RollingFileAppender> {
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(prefix)
+        .max_log_files(KEEP_LOG_FILES)
+        .build(dir)
+        .map_err(|e| AppError::Other(format!("Could not open log file in {}: {}", dir.display(), e)))
+}
@@ -1,3 +1,6 @@
 pub mod error;
+pub mod logging;
+pub mod validation;
 
 pub use error::{AppError, Result};
+pub use validation::validate_email;
@@ -0,0 +1,12 @@
+/// A plausible single address: something@domain.tld, no spaces
+pub fn validate_email(email: &str) -> std::result::Result<(), String> {
+    let valid = email.len() <= 254
+        && !email.contains(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | ',' | ';'))
+        && email
+            .split_once('@')
+            .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.') && !domain.contains('@'));
+    if !valid {
+        return Err(format!("email '{}' is not a valid address", email));
+    }
+    Ok(())
+}
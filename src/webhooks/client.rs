@@ -0,0 +1,135 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::utils::{AppError, Result};
+
+/// How long to wait for an endpoint to connect and to answer
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// Most of a response read before hanging up; the body is ignored
+const MAX_RESPONSE: u64 = 64 * 1024;
+
+/// Where a webhook URL points
+#[derive(Debug, Clone, PartialEq)]
+pub struct Endpoint {
+    pub host: String,
+    pub port: u16,
+    /// Path and query string
+    pub path: String,
+}
+
+impl Endpoint {
+    /// Parse `http://host[:port][/path]`, with IPv6 hosts in brackets as in
+    /// `http://[::1]:8080/`. HTTPS needs TLS, which isn't available here;
+    /// point webhooks at a local relay for that. Spaces and control
+    /// characters are refused, since host and path go into the request as is.
+    pub fn parse(url: &str) -> Result<Self> {
+        if !url.bytes().all(|b| b.is_ascii_graphic()) {
+            return Err(AppError::Validation(format!(
+                "Webhook URL {:?} may only contain visible ASCII characters",
+                url
+            )));
+        }
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| AppError::Validation(format!("Webhook URL '{}' must start with http://", url)))?;
+        let (authority, path) = match rest.find('/') {
+            Some(slash) => (&rest[..slash], &rest[slash..]),
+            None => (rest, "/"),
+        };
+        let invalid = |what: &str| AppError::Validation(format!("{} in webhook URL '{}'", what, url));
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => {
+                let (host, rest) = bracketed.split_once(']').ok_or_else(|| invalid("Unclosed '['"))?;
+                match rest {
+                    "" => (host, None),
+                    _ => (host, Some(rest.strip_prefix(':').ok_or_else(|| invalid("Invalid port"))?)),
+                }
+            }
+            None => match authority.split_once(':') {
+                Some((_, port)) if port.contains(':') => return Err(invalid("IPv6 address without brackets")),
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            },
+        };
+        let port = match port {
+            Some(port) => port.parse().map_err(|_| invalid("Invalid port"))?,
+            None => 80,
+        };
+        if host.is_empty() {
+            return Err(AppError::Validation(format!("Webhook URL '{}' has no host", url)));
+        }
+
+        Ok(Self { host: host.to_string(), port, path: path.to_string() })
+    }
+
+    /// `host:port` as sent in the `Host` header
+    pub fn authority(&self) -> String {
+        if self.host.contains(':') {
+            format!("[{}]:{}", self.host, self.port)
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
+
+/// POST a JSON body; any 2xx answer counts as delivered
+pub fn post_json(url: &str, headers: &[(&str, String)], body: &str) -> Result<()> {
+    let endpoint = Endpoint::parse(url)?;
+    let addr = (endpoint.host.as_str(), endpoint.port)
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| AppError::Other(format!("Can't resolve {}", endpoint.host)))?;
+
+    let mut stream = TcpStream::connect_timeout(&addr, TIMEOUT)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nUser-Agent: tennis-tui\r\nConnection: close\r\n",
+        endpoint.path,
+        endpoint.authority(),
+        body.len()
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    request.push_str("\r\n");
+    request.push_str(body);
+    stream.write_all(request.as_bytes())?;
+    stream.flush()?;
+
+    let mut reader = BufReader::new((&stream).take(MAX_RESPONSE));
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+    let status: u16 = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| AppError::Other(format!("Invalid HTTP response '{}'", status_line.trim())))?;
+
+    // Read the headers and only the body they announce, so an endpoint that
+    // keeps the connection open despite `Connection: close` doesn't hold the
+    // delivery up until the read timeout. Without a Content-Length (e.g. a
+    // chunked body) the headers are all that is read.
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+    // Drain the body so the endpoint isn't cut off mid-response; the status
+    // is all that counts, so a body that doesn't arrive is no failure
+    let _ = std::io::copy(&mut reader.take(content_length), &mut std::io::sink());
+
+    if !(200..300).contains(&status) {
+        return Err(AppError::Other(format!("HTTP {}", status)));
+    }
+    Ok(())
+}
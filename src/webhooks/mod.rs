@@ -0,0 +1,96 @@
+//! Outbound webhooks for session events (`tennis-tui webhook`).
+//!
+//! Events are queued in `webhook_outbox` in the same transaction as the
+//! change that caused them, then POSTed as JSON by [`deliver_due`]. A failed
+//! delivery is retried with exponential backoff and given up on after
+//! [`MAX_ATTEMPTS`]; `webhook retry` queues it again. Each body is signed
+//! with the webhook's secret (see [`signature`]).
+
+pub mod client;
+
+use chrono::{DateTime, Duration, Utc};
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::auth::digest::{hex, hmac_sha256};
+use crate::db::repositories::WebhookRepository;
+use crate::utils::Result;
+
+/// Attempts before a delivery is marked failed
+pub const MAX_ATTEMPTS: u32 = 8;
+/// Wait before the first retry; doubled after each failed attempt
+const FIRST_RETRY_SECS: i64 = 30;
+/// Longest wait between attempts
+const MAX_RETRY_SECS: i64 = 60 * 60;
+/// Deliveries attempted per pass
+const BATCH_SIZE: usize = 100;
+/// How long a claimed delivery is kept from other delivery runs; well over
+/// the longest a single attempt can take
+const CLAIM_SECS: i64 = 5 * 60;
+
+/// Outcome of one delivery pass
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize)]
+pub struct DeliverySummary {
+    pub delivered: usize,
+    /// Failed, to be retried later
+    pub retrying: usize,
+    /// Failed for the last time
+    pub failed: usize,
+}
+
+impl DeliverySummary {
+    pub fn is_empty(&self) -> bool {
+        self.delivered + self.retrying + self.failed == 0
+    }
+}
+
+/// How long to wait after `attempts` failed attempts
+pub fn backoff(attempts: u32) -> Duration {
+    let secs = FIRST_RETRY_SECS.saturating_mul(1 << attempts.saturating_sub(1).min(20));
+    Duration::seconds(secs.min(MAX_RETRY_SECS))
+}
+
+/// `X-Tennis-Signature` of a delivery: `sha256=` and the hex HMAC-SHA256 of
+/// the body, keyed with the webhook's secret
+pub fn signature(secret: &str, body: &str) -> String {
+    format!("sha256={}", hex(&hmac_sha256(secret.as_bytes(), body.as_bytes())))
+}
+
+/// Attempt every delivery that is due at `now`
+pub fn deliver_due(conn: &Connection, now: DateTime<Utc>) -> Result<DeliverySummary> {
+    let mut summary = DeliverySummary::default();
+
+    for _ in 0..BATCH_SIZE {
+        // Claimed one at a time, so a claim only has to outlast one attempt
+        let claimed_until = now.max(Utc::now()) + Duration::seconds(CLAIM_SECS);
+        let Some(delivery) = WebhookRepository::claim_due(conn, now, claimed_until)? else {
+            break;
+        };
+        let mut headers = vec![
+            ("X-Tennis-Event", delivery.event.clone()),
+            ("X-Tennis-Delivery", delivery.id.to_string()),
+        ];
+        if let Some(secret) = &delivery.secret {
+            headers.push(("X-Tennis-Signature", signature(secret, &delivery.payload)));
+        }
+        match client::post_json(&delivery.url, &headers, &delivery.payload) {
+            Ok(()) => {
+                WebhookRepository::mark_delivered(conn, delivery.id, now)?;
+                summary.delivered += 1;
+            }
+            Err(e) => {
+                let attempts = delivery.attempts as u32 + 1;
+                let retry_at = (attempts < MAX_ATTEMPTS).then(|| now + backoff(attempts));
+                WebhookRepository::record_failure(conn, delivery.id, &e.to_string(), retry_at)?;
+                tracing::warn!(delivery = delivery.id, url = %delivery.url, attempts, error = %e, "webhook delivery failed");
+                if retry_at.is_some() {
+                    summary.retrying += 1;
+                } else {
+                    summary.failed += 1;
+                }
+            }
+        }
+    }
+
+    Ok(summary)
+}
@@ -1 +1,49 @@
 mod test_subscriptions;
+mod test_groups;
+mod test_duplicate_session;
+mod test_tags;
+mod test_search;
+mod test_cli;
+mod test_backup;
+mod test_history;
+mod test_player_import;
+mod test_session_plan;
+mod test_progress;
+mod test_logging;
+mod test_audit;
+mod test_archive;
+mod test_unit_of_work;
+mod test_connection;
+mod test_user_keys;
+mod test_api;
+mod test_webhooks;
+mod test_reminders;
+mod test_notifications;
+mod test_cancellation;
+mod test_venues;
+
+use tempfile::TempDir;
+use tui_coach::cli::commands;
+use tui_coach::db::{establish_connection, run_migrations};
+
+/// Open (creating if needed) a migrated database file inside `temp_dir`
+pub fn open_db(temp_dir: &TempDir, name: &str) -> rusqlite::Connection {
+    let db_path = temp_dir.path().join(name);
+    let conn = establish_connection(db_path.to_str().unwrap()).unwrap();
+    run_migrations(&conn).unwrap();
+    conn
+}
+
+/// Fresh migrated database at `test.db`, without any rows
+pub fn setup_empty_db() -> (TempDir, rusqlite::Connection) {
+    let temp_dir = TempDir::new().unwrap();
+    let conn = open_db(&temp_dir, "test.db");
+    (temp_dir, conn)
+}
+
+/// Fresh migrated database at `test.db` with the sample club data
+pub fn setup_test_db() -> (TempDir, rusqlite::Connection) {
+    let (temp_dir, conn) = setup_empty_db();
+    commands::insert_sample_data(&conn).unwrap();
+    (temp_dir, conn)
+}
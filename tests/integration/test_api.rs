@@ -0,0 +1,245 @@
+#[cfg(test)]
+mod api_tests {
+    use serde_json::{json, Value};
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::{Duration, Instant};
+    use tempfile::TempDir;
+    use tui_coach::api::http::Response;
+    use tui_coach::api::ApiServer;
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{ApiTokenRepository, AuditRepository, SessionRepository, UserRepository};
+    use tui_coach::db::establish_connection;
+    use tui_coach::models::UserRole;
+    use tui_coach::utils::AppError;
+
+    struct Club {
+        _temp_dir: TempDir,
+        conn: rusqlite::Connection,
+        addr: SocketAddr,
+        coach: String,
+        alice: String,
+    }
+
+    /// Sample club with tokens for the coach and Alice, served on a free port
+    fn start_club() -> Club {
+        let (temp_dir, conn) = crate::setup_test_db();
+        let db_path = temp_dir.path().join("test.db").to_string_lossy().to_string();
+
+        let coach = ApiTokenRepository::create(&conn, user_id(&conn, "coach"), Some("tests")).unwrap().1;
+        let alice = ApiTokenRepository::create(&conn, user_id(&conn, "alice"), None).unwrap().1;
+
+        let server = ApiServer::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let conn = establish_connection(&db_path).unwrap();
+            server.run(&conn).unwrap();
+        });
+
+        Club { _temp_dir: temp_dir, conn, addr, coach, alice }
+    }
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    /// Send one request and return the status and JSON body
+    fn call(addr: SocketAddr, method: &str, path: &str, token: Option<&str>, body: Option<Value>) -> (u16, Value) {
+        let mut request = format!("{} {} HTTP/1.1\r\nHost: localhost\r\n", method, path);
+        if let Some(token) = token {
+            request.push_str(&format!("Authorization: Bearer {}\r\n", token));
+        }
+        let body = body.map(|b| b.to_string()).unwrap_or_default();
+        request.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+        raw(addr, &request)
+    }
+
+    fn raw(addr: SocketAddr, request: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    fn create_session(club: &Club, title: &str) -> i64 {
+        let (status, body) = call(club.addr, "POST", "/api/sessions", Some(&club.coach), Some(json!({ "title": title })));
+        assert_eq!(status, 201, "{}", body);
+        body["id"].as_i64().unwrap()
+    }
+
+    #[test]
+    fn test_requests_need_an_active_token() {
+        let club = start_club();
+        assert_eq!(call(club.addr, "GET", "/api/me", None, None).0, 401);
+        assert_eq!(call(club.addr, "GET", "/api/me", Some("not-a-token"), None).0, 401);
+
+        let (status, me) = call(club.addr, "GET", "/api/me", Some(&club.alice), None);
+        assert_eq!(status, 200);
+        assert_eq!(me["username"], "alice");
+
+        let alice_token = ApiTokenRepository::find_all(&club.conn).unwrap().into_iter().find(|t| t.username == "alice").unwrap();
+        ApiTokenRepository::revoke(&club.conn, alice_token.id).unwrap();
+        assert_eq!(call(club.addr, "GET", "/api/me", Some(&club.alice), None).0, 401);
+    }
+
+    #[test]
+    fn test_coach_creates_and_player_subscribes() {
+        let club = start_club();
+        let (status, created) = call(
+            club.addr,
+            "POST",
+            "/api/sessions",
+            Some(&club.coach),
+            Some(json!({ "title": "Serve clinic", "date": "2026-11-02", "time": "18:00", "duration_minutes": 90, "tags": ["serve"] })),
+        );
+        assert_eq!(status, 201, "{}", created);
+        let session_id = created["id"].as_i64().unwrap();
+        assert_eq!(created["duration_minutes"], 90);
+
+        let (_, detail) = call(club.addr, "GET", &format!("/api/sessions/{}", session_id), Some(&club.alice), None);
+        assert_eq!(detail["session"]["title"], "Serve clinic");
+        assert_eq!(detail["tags"], json!(["serve"]));
+
+        let path = format!("/api/sessions/{}/subscription", session_id);
+        assert_eq!(call(club.addr, "POST", &path, Some(&club.alice), None).0, 201);
+        assert_eq!(call(club.addr, "POST", &path, Some(&club.alice), None).0, 400);
+
+        let (_, sessions) = call(club.addr, "GET", "/api/sessions", Some(&club.alice), None);
+        assert_eq!(sessions[0]["subscription"]["status"], "Active");
+
+        let (status, subscribers) =
+            call(club.addr, "GET", &format!("/api/sessions/{}/subscriptions", session_id), Some(&club.coach), None);
+        assert_eq!(status, 200);
+        assert_eq!(subscribers[0]["user_id"], user_id(&club.conn, "alice"));
+
+        assert_eq!(call(club.addr, "DELETE", &path, Some(&club.alice), None).0, 200);
+        assert_eq!(call(club.addr, "DELETE", &path, Some(&club.alice), None).0, 404);
+    }
+
+    #[test]
+    fn test_role_checks_match_the_tui() {
+        let club = start_club();
+        let session_id = create_session(&club, "Footwork");
+
+        let forbidden = [
+            ("POST", "/api/sessions".to_string(), &club.alice),
+            ("DELETE", format!("/api/sessions/{}", session_id), &club.alice),
+            ("GET", format!("/api/sessions/{}/subscriptions", session_id), &club.alice),
+            ("GET", "/api/users".to_string(), &club.alice),
+            ("GET", format!("/api/users/{}", user_id(&club.conn, "bob")), &club.alice),
+            ("GET", "/api/templates".to_string(), &club.alice),
+            ("POST", format!("/api/sessions/{}/subscription", session_id), &club.coach),
+        ];
+        for (method, path, token) in forbidden {
+            let body = (method == "POST").then(|| json!({ "title": "Sneaky session" }));
+            let (status, error) = call(club.addr, method, &path, Some(token), body);
+            assert_eq!(status, 403, "{} {}: {}", method, path, error);
+        }
+
+        let (status, me) = call(club.addr, "GET", &format!("/api/users/{}", user_id(&club.conn, "alice")), Some(&club.alice), None);
+        assert_eq!((status, me["username"].as_str()), (200, Some("alice")));
+        assert_eq!(call(club.addr, "GET", "/api/users", Some(&club.coach), None).1.as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_coaches_only_manage_their_own_sessions() {
+        let club = start_club();
+        let other = UserRepository::create(&club.conn, "serena", "Coach Serena", &UserRole::Coach, None, None).unwrap();
+        let other_session = SessionRepository::create(&club.conn, "Not yours", None, None, None, None, None, other).unwrap();
+        let own_session = create_session(&club, "Mine");
+
+        assert_eq!(call(club.addr, "DELETE", &format!("/api/sessions/{}", other_session), Some(&club.coach), None).0, 403);
+
+        let (_, sessions) = call(club.addr, "GET", "/api/sessions", Some(&club.coach), None);
+        let titles: Vec<&str> = sessions.as_array().unwrap().iter().map(|s| s["session"]["title"].as_str().unwrap()).collect();
+        assert_eq!(titles, vec!["Mine"]);
+
+        assert_eq!(call(club.addr, "DELETE", &format!("/api/sessions/{}", own_session), Some(&club.coach), None).0, 200);
+        assert_eq!(call(club.addr, "GET", &format!("/api/sessions/{}", own_session), Some(&club.coach), None).0, 404);
+        assert!(SessionRepository::find_by_id(&club.conn, other_session).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_changes_are_attributed_to_the_token_owner() {
+        let club = start_club();
+        let session_id = create_session(&club, "Audited");
+
+        let entries = AuditRepository::for_session(&club.conn, session_id).unwrap();
+        assert!(!entries.is_empty());
+        assert!(entries.iter().all(|e| e.actor_id == Some(user_id(&club.conn, "coach"))));
+    }
+
+    #[test]
+    fn test_bad_requests_get_json_errors() {
+        let club = start_club();
+        let (status, error) = call(club.addr, "POST", "/api/sessions", Some(&club.coach), Some(json!({ "title": "x" })));
+        assert_eq!(status, 400);
+        assert!(error["error"].as_str().unwrap().contains("Title"), "{}", error);
+
+        let (status, _) = raw(
+            club.addr,
+            &format!("POST /api/sessions HTTP/1.1\r\nAuthorization: Bearer {}\r\nContent-Length: 5\r\n\r\n{{nope", club.coach),
+        );
+        assert_eq!(status, 400);
+
+        assert_eq!(call(club.addr, "GET", "/api/sessions/999", Some(&club.coach), None).0, 404);
+        assert_eq!(call(club.addr, "GET", "/api/sessions/abc", Some(&club.coach), None).0, 404);
+        assert_eq!(call(club.addr, "PATCH", "/api/me", Some(&club.coach), None).0, 404);
+        assert_eq!(raw(club.addr, "garbage\r\n\r\n").0, 400);
+    }
+
+    #[test]
+    fn test_slow_client_does_not_hold_up_others() {
+        let club = start_club();
+        let mut slow = TcpStream::connect(club.addr).unwrap();
+        slow.write_all(b"GET /api/me HTTP/1.1\r\nHost: local").unwrap();
+
+        let started = Instant::now();
+        assert_eq!(call(club.addr, "GET", "/api/me", Some(&club.alice), None).0, 200);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_oversized_headers_are_rejected() {
+        let club = start_club();
+        let headers: String = (0..100).map(|i| format!("X-Filler-{}: {}\r\n", i, i)).collect();
+        let (status, error) = raw(club.addr, &format!("GET /api/me HTTP/1.1\r\n{}\r\n", headers));
+        assert_eq!(status, 400);
+        assert!(error["error"].as_str().unwrap().contains("too large"), "{}", error);
+    }
+
+    #[test]
+    fn test_internal_errors_are_not_shown_to_clients() {
+        let response = Response::from_error(&AppError::Database(rusqlite::Error::InvalidColumnName("secret_column".to_string())));
+        assert_eq!(response.status, 500);
+        assert_eq!(response.body["error"], "Internal server error");
+    }
+
+    #[test]
+    fn test_cli_token_is_shown_once_and_hashed() {
+        let club = start_club();
+        let mut out = Vec::new();
+        commands::token_create(&club.conn, "bob", Some("phone"), true, &mut out).unwrap();
+        let created: Value = serde_json::from_slice(&out).unwrap();
+        let token = created["token"].as_str().unwrap();
+        assert_eq!(token.len(), 64);
+
+        let stored: i64 = club
+            .conn
+            .query_row("SELECT COUNT(*) FROM api_tokens WHERE token_hash = ?", [token], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, 0);
+        assert_eq!(call(club.addr, "GET", "/api/me", Some(token), None).1["username"], "bob");
+
+        let mut out = Vec::new();
+        commands::token_revoke(&club.conn, created["id"].as_i64().unwrap(), false, &mut Vec::new()).unwrap();
+        commands::token_list(&club.conn, false, &mut out).unwrap();
+        let listing = String::from_utf8(out).unwrap();
+        assert!(listing.lines().any(|line| line.contains("bob") && line.contains("phone") && line.contains("revoked")));
+        assert_eq!(call(club.addr, "GET", "/api/me", Some(token), None).0, 401);
+    }
+}
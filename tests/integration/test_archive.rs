@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod archive_tests {
+    use crate::setup_test_db;
+    use tui_coach::cli::commands;
+    use tui_coach::db::backup;
+    use tui_coach::db::repositories::{
+        ArchiveRepository, AuditRepository, SearchRepository, SessionRepository, SubscriptionRepository,
+        TrainingContentRepository, TrainingTemplateRepository, UserRepository,
+    };
+    use tui_coach::models::{ArchiveKind, AuditAction, ContentType, TrainingTemplate, User};
+
+    fn user(conn: &rusqlite::Connection, username: &str) -> User {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap()
+    }
+
+    fn create_template(conn: &rusqlite::Connection, coach_id: i64, title: &str) -> i64 {
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id,
+            title: title.to_string(),
+            content_type: "drill".to_string(),
+            description: Some("Cross-court rally".to_string()),
+            duration_minutes: Some(15),
+            created_at: String::new(),
+            created_by: coach_id,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: true,
+        };
+        TrainingTemplateRepository::create(conn, &template, coach_id).unwrap()
+    }
+
+    /// Session "Volley clinic" with one drill and Alice subscribed
+    fn seed(conn: &rusqlite::Connection) -> (i64, i64, i64) {
+        let coach = user(conn, "coach");
+        let alice = user(conn, "alice");
+        let session_id =
+            SessionRepository::create(conn, "Volley clinic", None, None, None, Some(60), None, coach.id).unwrap();
+        let content_id =
+            TrainingContentRepository::create(conn, session_id, &ContentType::Drill, "Reflex volleys", None, Some(10), 0)
+                .unwrap();
+        let subscription_id = SubscriptionRepository::create(conn, alice.id, session_id).unwrap();
+        (session_id, content_id, subscription_id)
+    }
+
+    #[test]
+    fn test_archived_session_is_hidden_and_restored_intact() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user(&conn, "coach");
+        let (session_id, _, _) = seed(&conn);
+
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id).unwrap();
+        assert!(SessionRepository::find_all(&conn).unwrap().is_empty());
+        assert!(SessionRepository::find_by_coach(&conn, coach.id).unwrap().is_empty());
+        assert!(SessionRepository::find_by_id(&conn, session_id).unwrap().is_none());
+        assert!(SubscriptionRepository::history_for_player(&conn, user(&conn, "alice").id).unwrap().is_empty());
+
+        let archived = ArchiveRepository::find_by_coach(&conn, coach.id).unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!((archived[0].kind, archived[0].id), (ArchiveKind::Session, session_id));
+        assert_eq!(archived[0].title, "Volley clinic");
+
+        ArchiveRepository::restore(&conn, ArchiveKind::Session, session_id).unwrap();
+        assert!(SessionRepository::find_by_id(&conn, session_id).unwrap().is_some());
+        assert_eq!(TrainingContentRepository::find_by_session(&conn, session_id).unwrap().len(), 1);
+        assert_eq!(SubscriptionRepository::find_by_session(&conn, session_id).unwrap().len(), 1);
+        assert!(ArchiveRepository::find_by_coach(&conn, coach.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_archived_content_is_listed_with_its_session() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user(&conn, "coach");
+        let (session_id, content_id, _) = seed(&conn);
+
+        ArchiveRepository::archive(&conn, ArchiveKind::Content, content_id).unwrap();
+        assert!(TrainingContentRepository::find_by_session(&conn, session_id).unwrap().is_empty());
+        assert!(TrainingContentRepository::find_by_id(&conn, content_id).unwrap().is_none());
+
+        let archived = ArchiveRepository::find_by_coach(&conn, coach.id).unwrap();
+        assert_eq!(archived[0].kind, ArchiveKind::Content);
+        assert_eq!(archived[0].session_title.as_deref(), Some("Volley clinic"));
+        assert!(ArchiveRepository::find_by_coach(&conn, user(&conn, "alice").id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_only_archived_rows_can_be_purged() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (session_id, content_id, subscription_id) = seed(&conn);
+
+        assert!(ArchiveRepository::purge(&conn, ArchiveKind::Session, session_id).is_err());
+        assert!(ArchiveRepository::restore(&conn, ArchiveKind::Session, session_id).is_err());
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id).unwrap();
+        assert!(ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id).is_err());
+
+        ArchiveRepository::purge(&conn, ArchiveKind::Session, session_id).unwrap();
+        let remaining: i64 = conn
+            .query_row(
+                "SELECT (SELECT COUNT(*) FROM sessions WHERE id = ?1)
+                      + (SELECT COUNT(*) FROM training_content WHERE id = ?2)
+                      + (SELECT COUNT(*) FROM subscriptions WHERE id = ?3)",
+                [session_id, content_id, subscription_id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+
+    #[test]
+    fn test_archived_template_is_hidden_from_lists_and_search() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user(&conn, "coach");
+        let template_id = create_template(&conn, coach.id, "Crosscourt rally");
+
+        let mut out = Vec::new();
+        commands::template_delete(&conn, template_id, false, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().trim(), format!("Deleted template {} (Crosscourt rally)", template_id));
+
+        assert!(TrainingTemplateRepository::list_all(&conn).unwrap().is_empty());
+        assert!(TrainingTemplateRepository::list_by_coach(&conn, coach.id).unwrap().is_empty());
+        assert!(SearchRepository::search(&conn, "crosscourt", &coach, 10).unwrap().is_empty());
+        assert_eq!(ArchiveRepository::find_by_coach(&conn, coach.id).unwrap()[0].kind, ArchiveKind::Template);
+    }
+
+    #[test]
+    fn test_search_and_export_skip_archived_sessions() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user(&conn, "coach");
+        let (session_id, content_id, _) = seed(&conn);
+        let kept_id = SessionRepository::create(&conn, "Serve clinic", None, None, None, None, None, coach.id).unwrap();
+
+        ArchiveRepository::archive(&conn, ArchiveKind::Content, content_id).unwrap();
+        assert!(SearchRepository::search(&conn, "reflex", &coach, 10).unwrap().is_empty());
+        assert_eq!(SearchRepository::search(&conn, "volley", &coach, 10).unwrap().len(), 1);
+
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id).unwrap();
+        assert!(SearchRepository::search(&conn, "volley", &coach, 10).unwrap().is_empty());
+
+        let doc = backup::export(&conn).unwrap();
+        backup::validate(&doc).unwrap();
+        assert_eq!(doc.sessions.iter().map(|s| s.id).collect::<Vec<_>>(), vec![kept_id]);
+        assert!(doc.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn test_archiving_is_recorded_in_the_change_history() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (session_id, _, _) = seed(&conn);
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id).unwrap();
+        ArchiveRepository::restore(&conn, ArchiveKind::Session, session_id).unwrap();
+
+        let entries = AuditRepository::for_session(&conn, session_id).unwrap();
+        assert_eq!(entries[0].action, AuditAction::Update);
+        assert_eq!(entries[0].changed_fields(), vec!["archived_at".to_string()]);
+        assert_eq!(entries[0].changes()[0].2, "-");
+        assert_eq!(entries[1].changed_fields(), vec!["archived_at".to_string()]);
+    }
+
+    #[test]
+    fn test_cli_session_delete_archives() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user(&conn, "coach");
+        let (session_id, _, _) = seed(&conn);
+
+        commands::session_delete(&conn, session_id, false, &mut Vec::new()).unwrap();
+        assert!(SessionRepository::find_by_id(&conn, session_id).unwrap().is_none());
+        ArchiveRepository::restore(&conn, ArchiveKind::Session, session_id).unwrap();
+        assert_eq!(SessionRepository::find_by_coach(&conn, coach.id).unwrap().len(), 1);
+    }
+}
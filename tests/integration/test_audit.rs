@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod audit_tests {
+    use crate::setup_test_db;
+    use chrono::NaiveDate;
+    use tui_coach::cli::args::AuditArgs;
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{
+        AuditRepository, SessionRepository, SubscriptionRepository, TrainingContentRepository, UserRepository,
+    };
+    use tui_coach::models::{AuditAction, AuditQuery, ContentType};
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    fn create_session(conn: &rusqlite::Connection, title: &str) -> i64 {
+        let coach = user_id(conn, "coach");
+        SessionRepository::create(conn, title, None, NaiveDate::from_ymd_opt(2026, 5, 4), None, Some(60), None, coach)
+            .unwrap()
+    }
+
+    fn audit_args() -> AuditArgs {
+        AuditArgs {
+            user: None,
+            session: None,
+            from: None,
+            to: None,
+            limit: 100,
+        }
+    }
+
+    #[test]
+    fn test_insert_update_delete_are_recorded_with_before_and_after() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+        SessionRepository::update(&conn, session_id, "Serve & return", None, None, None, Some(90), None, None).unwrap();
+        SessionRepository::delete(&conn, session_id).unwrap();
+
+        let entries = AuditRepository::for_session(&conn, session_id).unwrap();
+        let actions: Vec<AuditAction> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::Delete, AuditAction::Update, AuditAction::Insert]);
+
+        let (deleted, updated, inserted) = (&entries[0], &entries[1], &entries[2]);
+        assert!(inserted.before.is_none());
+        assert_eq!(inserted.after.as_ref().unwrap()["title"], "Serve clinic");
+        assert_eq!(updated.before.as_ref().unwrap()["title"], "Serve clinic");
+        assert_eq!(updated.after.as_ref().unwrap()["title"], "Serve & return");
+        assert!(updated.changed_fields().contains(&"duration_minutes".to_string()));
+        assert!(updated.changes().contains(&("duration_minutes".to_string(), "60".to_string(), "90".to_string())));
+        assert_eq!(deleted.before.as_ref().unwrap()["title"], "Serve & return");
+        assert!(deleted.after.is_none());
+        assert!(entries.iter().all(|e| e.entity == "sessions" && e.entity_id == Some(session_id)));
+    }
+
+    #[test]
+    fn test_changes_are_attributed_to_the_connection_actor() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user_id(&conn, "coach");
+        let system_session = create_session(&conn, "Imported");
+
+        AuditRepository::set_actor(&conn, Some(coach)).unwrap();
+        let coach_session = create_session(&conn, "Net play");
+
+        let system_entry = &AuditRepository::for_session(&conn, system_session).unwrap()[0];
+        assert_eq!(system_entry.actor_id, None);
+        assert_eq!(system_entry.actor_label(), "system");
+
+        let coach_entry = &AuditRepository::for_session(&conn, coach_session).unwrap()[0];
+        assert_eq!(coach_entry.actor_id, Some(coach));
+        assert_eq!(coach_entry.actor_label(), "Coach Peter");
+        assert_eq!(coach_entry.summary(), format!("created sessions #{} \"Net play\"", coach_session));
+    }
+
+    #[test]
+    fn test_unchanged_update_is_not_recorded() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+        conn.execute("UPDATE sessions SET title = title WHERE id = ?", [session_id]).unwrap();
+
+        let entries = AuditRepository::for_session(&conn, session_id).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AuditAction::Insert);
+    }
+
+    #[test]
+    fn test_session_history_includes_content_and_subscriptions() {
+        let (_temp_dir, conn) = setup_test_db();
+        let alice = user_id(&conn, "alice");
+        let session_id = create_session(&conn, "Serve clinic");
+        let other_id = create_session(&conn, "Other");
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Drill, "Target serves", None, Some(15), 0)
+            .unwrap();
+        let subscription_id = SubscriptionRepository::create(&conn, alice, session_id).unwrap();
+        SubscriptionRepository::mark_completed(&conn, subscription_id).unwrap();
+        SubscriptionRepository::create(&conn, alice, other_id).unwrap();
+
+        let entries = AuditRepository::for_session(&conn, session_id).unwrap();
+        let entities: Vec<(&str, AuditAction)> = entries.iter().map(|e| (e.entity.as_str(), e.action)).collect();
+        assert_eq!(
+            entities,
+            vec![
+                ("subscriptions", AuditAction::Update),
+                ("subscriptions", AuditAction::Insert),
+                ("training_content", AuditAction::Insert),
+                ("sessions", AuditAction::Insert),
+            ]
+        );
+        assert!(entries[0].changed_fields().contains(&"status".to_string()));
+    }
+
+    #[test]
+    fn test_find_filters_by_user_and_date() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user_id(&conn, "coach");
+        let alice = user_id(&conn, "alice");
+        AuditRepository::set_actor(&conn, Some(coach)).unwrap();
+        let session_id = create_session(&conn, "Serve clinic");
+        AuditRepository::set_actor(&conn, Some(alice)).unwrap();
+        SubscriptionRepository::create(&conn, alice, session_id).unwrap();
+
+        let by_alice = AuditRepository::find(
+            &conn,
+            &AuditQuery {
+                actor_id: Some(alice),
+                ..AuditQuery::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(by_alice.len(), 1);
+        assert_eq!(by_alice[0].entity, "subscriptions");
+
+        let today = chrono::Utc::now().date_naive();
+        let tomorrow = today.succ_opt().unwrap();
+        let query = |from, to| AuditQuery {
+            from: Some(from),
+            to: Some(to),
+            ..AuditQuery::default()
+        };
+        assert!(AuditRepository::find(&conn, &query(today, today)).unwrap().len() >= 2);
+        assert!(AuditRepository::find(&conn, &query(tomorrow, tomorrow)).unwrap().is_empty());
+
+        let limited = AuditRepository::find(
+            &conn,
+            &AuditQuery {
+                limit: Some(1),
+                ..AuditQuery::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].entity, "subscriptions");
+    }
+
+    #[test]
+    fn test_cli_audit_lists_changes() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user_id(&conn, "coach");
+        AuditRepository::set_actor(&conn, Some(coach)).unwrap();
+        let session_id = create_session(&conn, "Serve clinic");
+        SessionRepository::update(&conn, session_id, "Serve & return", None, None, None, Some(60), None, None).unwrap();
+
+        let args = AuditArgs {
+            user: Some("coach".to_string()),
+            session: Some(session_id),
+            ..audit_args()
+        };
+        let mut out = Vec::new();
+        commands::audit(&conn, &args, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("TIME"));
+        assert!(lines[2].contains("Coach Peter") && lines[2].contains("update") && lines[2].contains("title"));
+        assert!(lines[3].contains("insert") && lines[3].contains("Serve clinic"));
+
+        let mut out = Vec::new();
+        commands::audit(&conn, &args, true, &mut out).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+        assert_eq!(json[0]["action"], "update");
+        assert_eq!(json[0]["after"]["title"], "Serve & return");
+
+        let unknown = AuditArgs {
+            user: Some("nobody".to_string()),
+            ..audit_args()
+        };
+        assert!(commands::audit(&conn, &unknown, false, &mut Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+#[cfg(test)]
+mod backup_tests {
+    use crate::open_db;
+    use chrono::NaiveDate;
+    use tempfile::TempDir;
+    use tui_coach::db::backup::{self, ImportMode};
+    use tui_coach::db::repositories::{
+        ArchiveRepository, GroupRepository, SessionRepository, SessionTrainingLinkRepository,
+        SubscriptionRepository, TagRepository, TrainingContentRepository, TrainingTemplateRepository, UserRepository,
+    };
+    use tui_coach::models::{ArchiveKind, ContentType, SkillLevel, TrainingTemplate};
+
+    /// A small club: a coach, two players, a session with a full plan, a group
+    fn seed(conn: &rusqlite::Connection) {
+        conn.execute_batch(
+            "INSERT INTO users (username, display_name, role) VALUES ('coach', 'Coach', 'coach');
+             INSERT INTO users (username, display_name, role, skill_level) VALUES ('alice', 'Alice', 'player', 'beginner');
+             INSERT INTO users (username, display_name, role) VALUES ('bob', 'Bob', 'player');",
+        ).unwrap();
+        let coach = UserRepository::find_by_username(conn, "coach").unwrap().unwrap();
+        let alice = UserRepository::find_by_username(conn, "alice").unwrap().unwrap();
+
+        let session_id = SessionRepository::create(
+            conn, "Serve Clinic", Some("Kick serve"), NaiveDate::from_ymd_opt(2026, 3, 1), None,
+            Some(60), Some(&SkillLevel::Beginner), coach.id,
+        ).unwrap();
+        TrainingContentRepository::create(conn, session_id, &ContentType::Warmup, "Jog", None, Some(10), 1).unwrap();
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id: coach.id,
+            title: "Targets".to_string(),
+            content_type: "drill".to_string(),
+            description: None,
+            duration_minutes: Some(20),
+            created_at: String::new(),
+            created_by: coach.id,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: false,
+        };
+        let template_id = TrainingTemplateRepository::create(conn, &template, coach.id).unwrap();
+        SessionTrainingLinkRepository::add_to_session_with_notes(conn, session_id, template_id, 0, Some("Wide")).unwrap();
+        TagRepository::set_session_tags(conn, session_id, &["serve".to_string()]).unwrap();
+        let sub_id = SubscriptionRepository::create(conn, alice.id, session_id).unwrap();
+        SubscriptionRepository::mark_completed(conn, sub_id).unwrap();
+
+        let group_id = GroupRepository::create(conn, "Juniors", None, coach.id).unwrap();
+        GroupRepository::add_member(conn, group_id, alice.id).unwrap();
+    }
+
+    fn count(conn: &rusqlite::Connection, table: &str) -> i64 {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_into_empty_database() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = open_db(&temp_dir, "source.db");
+        seed(&source);
+
+        let json = serde_json::to_string(&backup::export(&source).unwrap()).unwrap();
+        let doc = serde_json::from_str(&json).unwrap();
+
+        let target = open_db(&temp_dir, "target.db");
+        // Occupy low IDs so remapping is actually exercised
+        target.execute("INSERT INTO users (username, display_name, role) VALUES ('zed', 'Zed', 'player')", []).unwrap();
+        let summary = backup::import(&target, &doc, ImportMode::Merge).unwrap();
+        assert_eq!(summary.users.added, 3);
+        assert_eq!(summary.sessions.added, 1);
+
+        let session = &SessionRepository::find_all(&target).unwrap()[0];
+        let coach = UserRepository::find_by_username(&target, "coach").unwrap().unwrap();
+        assert_eq!(session.created_by, coach.id);
+        assert_eq!(session.skill_level, Some(SkillLevel::Beginner));
+        assert_eq!(TrainingContentRepository::find_by_session(&target, session.id).unwrap()[0].title, "Jog");
+        assert_eq!(TagRepository::find_by_session(&target, session.id).unwrap(), vec!["serve"]);
+
+        let links = SessionTrainingLinkRepository::get_for_session_with_templates(&target, session.id).unwrap();
+        assert_eq!(links[0].link.custom_notes, Some("Wide".to_string()));
+        assert_eq!(links[0].template.as_ref().unwrap().title, "Targets");
+        assert!(!links[0].template.as_ref().unwrap().is_public);
+
+        let alice = UserRepository::find_by_username(&target, "alice").unwrap().unwrap();
+        let subs = SubscriptionRepository::find_by_user(&target, alice.id).unwrap();
+        assert_eq!(subs.len(), 1);
+        assert!(subs[0].completed_at.is_some());
+
+        let group = GroupRepository::find_by_name(&target, "Juniors").unwrap().unwrap();
+        assert!(GroupRepository::is_member(&target, group.id, alice.id).unwrap());
+    }
+
+    #[test]
+    fn test_merge_is_idempotent() {
+        let temp_dir = TempDir::new().unwrap();
+        let conn = open_db(&temp_dir, "club.db");
+        seed(&conn);
+        let doc = backup::export(&conn).unwrap();
+
+        let summary = backup::import(&conn, &doc, ImportMode::Merge).unwrap();
+        for (table, counts) in summary.rows() {
+            assert_eq!(counts.added, 0, "{} should not add rows", table);
+        }
+        assert_eq!(summary.users.existing, 3);
+        assert_eq!(count(&conn, "sessions"), 1);
+        assert_eq!(count(&conn, "training_content"), 1);
+        assert_eq!(count(&conn, "subscriptions"), 1);
+        assert_eq!(count(&conn, "group_members"), 1);
+    }
+
+    #[test]
+    fn test_replace_discards_existing_data() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = open_db(&temp_dir, "source.db");
+        seed(&source);
+        let doc = backup::export(&source).unwrap();
+
+        let target = open_db(&temp_dir, "target.db");
+        seed(&target);
+        target.execute("INSERT INTO users (username, display_name, role) VALUES ('zed', 'Zed', 'player')", []).unwrap();
+        let coach = UserRepository::find_by_username(&target, "coach").unwrap().unwrap();
+        SessionRepository::create(&target, "Local only", None, None, None, None, None, coach.id).unwrap();
+
+        let summary = backup::import(&target, &doc, ImportMode::Replace).unwrap();
+        assert_eq!(summary.users.added, 3);
+        assert_eq!(summary.users.existing, 0);
+        assert!(UserRepository::find_by_username(&target, "zed").unwrap().is_none());
+        assert_eq!(count(&target, "sessions"), 1);
+        assert_eq!(count(&target, "training_templates"), 1);
+    }
+
+    #[test]
+    fn test_replace_is_refused_while_anything_is_archived() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = open_db(&temp_dir, "source.db");
+        seed(&source);
+        let doc = backup::export(&source).unwrap();
+
+        let target = open_db(&temp_dir, "target.db");
+        seed(&target);
+        let session_id = SessionRepository::find_all(&target).unwrap()[0].id;
+        ArchiveRepository::archive(&target, ArchiveKind::Session, session_id).unwrap();
+
+        let err = backup::import(&target, &doc, ImportMode::Replace).unwrap_err();
+        assert!(err.to_string().contains("1 archived item(s)"), "{}", err);
+        assert_eq!(count(&target, "sessions"), 1);
+
+        ArchiveRepository::purge(&target, ArchiveKind::Session, session_id).unwrap();
+        backup::import(&target, &doc, ImportMode::Replace).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_reference_is_rejected_without_writes() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = open_db(&temp_dir, "source.db");
+        seed(&source);
+        let mut doc = backup::export(&source).unwrap();
+        doc.subscriptions[0].session_id = 999;
+
+        let target = open_db(&temp_dir, "target.db");
+        let err = backup::import(&target, &doc, ImportMode::Replace).unwrap_err();
+        assert!(err.to_string().contains("session_id 999"));
+        assert_eq!(count(&target, "users"), 0);
+    }
+
+    #[test]
+    fn test_unsupported_version_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let conn = open_db(&temp_dir, "club.db");
+        let mut doc = backup::export(&conn).unwrap();
+        doc.format_version = 99;
+
+        assert!(backup::validate(&doc).is_err());
+    }
+
+    #[test]
+    fn test_failed_import_rolls_back() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = open_db(&temp_dir, "source.db");
+        seed(&source);
+        let mut doc = backup::export(&source).unwrap();
+        // Passes validation but violates the (session, order) uniqueness on insert
+        let mut duplicate = doc.session_training_links[0].clone();
+        duplicate.id += 100;
+        doc.session_training_links.push(duplicate);
+
+        let target = open_db(&temp_dir, "target.db");
+        seed(&target);
+        assert!(backup::import(&target, &doc, ImportMode::Replace).is_err());
+
+        // The replace never happened
+        assert_eq!(count(&target, "users"), 3);
+        assert_eq!(count(&target, "sessions"), 1);
+        assert_eq!(count(&target, "subscriptions"), 1);
+
+        // and the change history triggers are back
+        let history = count(&target, "audit_log");
+        target.execute("UPDATE users SET display_name = 'Bobby' WHERE username = 'bob'", []).unwrap();
+        assert_eq!(count(&target, "audit_log"), history + 1);
+    }
+
+    /// Tables the export doesn't carry: the change history and webhook
+    /// setup stay with the server, and the search index is rebuilt from the
+    /// imported rows
+    fn is_server_table(name: &str) -> bool {
+        name.starts_with("sqlite_") || name.starts_with("search_index") || name == "audit_log" || name.starts_with("webhook")
+    }
+
+    fn club_tables(conn: &rusqlite::Connection) -> Vec<String> {
+        let mut stmt = conn.prepare("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name").unwrap();
+        let names = stmt.query_map([], |row| row.get::<_, String>(0)).unwrap();
+        names.map(|n| n.unwrap()).filter(|n| !is_server_table(n)).collect()
+    }
+
+    #[test]
+    fn test_replace_round_trip_keeps_every_table() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = open_db(&temp_dir, "source.db");
+        seed(&source);
+        source.execute_batch(
+            "INSERT INTO venues (name) VALUES ('Riverside');
+             INSERT INTO courts (venue_id, name) SELECT id, 'Court 1' FROM venues;
+             UPDATE sessions SET court_id = (SELECT id FROM courts);
+             INSERT INTO quizzes (session_id, question, correct_answer, order_index) SELECT id, 'Grip?', 'Continental', 0 FROM sessions;
+             INSERT INTO homework (session_id, title, description, order_index) SELECT id, 'Shadow swings', '50 a day', 0 FROM sessions;
+             INSERT INTO quiz_responses (subscription_id, quiz_id, answer, is_correct) SELECT s.id, q.id, 'Continental', 1 FROM subscriptions s, quizzes q;
+             INSERT INTO homework_submissions (subscription_id, homework_id, notes) SELECT s.id, h.id, 'Done' FROM subscriptions s, homework h;
+             UPDATE users SET skill_level = 'intermediate' WHERE username = 'alice';
+             INSERT INTO player_comments (player_id, coach_id, body)
+                 SELECT p.id, c.id, 'Great toss' FROM users p, users c WHERE p.username = 'alice' AND c.username = 'coach';
+             UPDATE notifications SET read_at = '2026-03-02 09:00:00';
+             INSERT INTO user_keys (user_id, key_type, public_key, fingerprint, label)
+                 SELECT id, 'ssh-ed25519', 'AAAAC3Nz', 'SHA256:abc', 'laptop' FROM users WHERE username = 'coach';
+             INSERT INTO api_tokens (user_id, token_hash) SELECT id, 'feedbeef' FROM users WHERE username = 'coach';
+             INSERT INTO sent_reminders (session_id, user_id, starts_at, email)
+                 SELECT s.id, u.id, '2026-03-01 10:00', 'alice@club.example' FROM sessions s, users u WHERE u.username = 'alice';",
+        ).unwrap();
+        let template_id = TrainingTemplateRepository::list_including_private(&source).unwrap()[0].id;
+        TagRepository::set_template_tags(&source, template_id, &["footwork".to_string()]).unwrap();
+
+        let json = serde_json::to_string(&backup::export(&source).unwrap()).unwrap();
+        let doc = serde_json::from_str(&json).unwrap();
+
+        let target = open_db(&temp_dir, "target.db");
+        seed(&target);
+        target.execute("INSERT INTO webhooks (url) VALUES ('http://127.0.0.1:9/hook')", []).unwrap();
+        assert!(count(&target, "audit_log") > 0);
+
+        backup::import(&target, &doc, ImportMode::Replace).unwrap();
+
+        for table in club_tables(&source) {
+            assert!(count(&source, &table) > 0, "{} has no rows to round-trip", table);
+            assert_eq!(count(&target, &table), count(&source, &table), "{}", table);
+        }
+        let read: Option<String> = target.query_row("SELECT read_at FROM notifications", [], |row| row.get(0)).unwrap();
+        assert_eq!(read.as_deref(), Some("2026-03-02 09:00:00"));
+
+        // The old history is cleared, and the restore itself isn't recorded
+        // or sent out
+        assert_eq!(count(&target, "audit_log"), 0);
+        assert_eq!(count(&target, "webhook_outbox"), 0);
+
+        // ... but later changes are
+        let coach = UserRepository::find_by_username(&target, "coach").unwrap().unwrap();
+        SessionRepository::create(&target, "Volleys", None, None, None, None, None, coach.id).unwrap();
+        assert_eq!(count(&target, "audit_log"), 1);
+        assert_eq!(count(&target, "webhook_outbox"), 1);
+    }
+}
@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod cancellation_tests {
+    use crate::{open_db, setup_test_db};
+    use chrono::{NaiveDate, NaiveTime};
+    use tui_coach::db::backup::{self, ImportMode};
+    use tui_coach::db::migrations::run_migrations;
+    use tui_coach::db::repositories::{
+        GroupRepository, NotificationRepository, ReminderRepository, SessionRepository, SubscriptionRepository, UserRepository,
+    };
+    use tui_coach::export::progress::ProgressReport;
+    use tui_coach::export::DocumentFormat;
+    use tui_coach::models::{NotificationKind, SubscriptionStatus};
+    use tui_coach::utils::AppError;
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    /// A session with Alice subscribed and Bob's subscription completed
+    fn create_session(conn: &rusqlite::Connection, title: &str) -> i64 {
+        let id = SessionRepository::create(
+            conn,
+            title,
+            None,
+            NaiveDate::from_ymd_opt(2026, 11, 2),
+            NaiveTime::from_hms_opt(10, 0, 0),
+            Some(90),
+            None,
+            user_id(conn, "coach"),
+        )
+        .unwrap();
+        SubscriptionRepository::create(conn, user_id(conn, "alice"), id).unwrap();
+        let bob = SubscriptionRepository::create(conn, user_id(conn, "bob"), id).unwrap();
+        SubscriptionRepository::mark_completed(conn, bob).unwrap();
+        id
+    }
+
+    fn status(conn: &rusqlite::Connection, username: &str, session_id: i64) -> SubscriptionStatus {
+        SubscriptionRepository::find_by_user_and_session(conn, user_id(conn, username), session_id)
+            .unwrap()
+            .unwrap()
+            .status
+    }
+
+    #[test]
+    fn test_cancel_keeps_session_and_history() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+
+        let cancelled = SessionRepository::cancel(&conn, session_id, "  Courts flooded ").unwrap();
+        assert_eq!(cancelled, 1);
+
+        let session = SessionRepository::find_by_id(&conn, session_id).unwrap().unwrap();
+        assert!(session.is_cancelled());
+        assert_eq!(session.cancel_reason.as_deref(), Some("Courts flooded"));
+        assert!(SessionRepository::find_all(&conn).unwrap().iter().any(|s| s.id == session_id));
+
+        assert_eq!(status(&conn, "alice", session_id), SubscriptionStatus::Cancelled);
+        assert_eq!(status(&conn, "bob", session_id), SubscriptionStatus::Completed);
+
+        let history = SubscriptionRepository::history_for_player(&conn, user_id(&conn, "alice")).unwrap();
+        let entry = history.iter().find(|e| e.subscription.session_id == session_id).unwrap();
+        assert_eq!(entry.cancel_reason.as_deref(), Some("Courts flooded"));
+    }
+
+    #[test]
+    fn test_cancel_notifies_active_subscribers_only() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+
+        SessionRepository::cancel(&conn, session_id, "Courts flooded").unwrap();
+
+        let alice = NotificationRepository::find_by_user(&conn, user_id(&conn, "alice")).unwrap();
+        assert_eq!(alice.len(), 1);
+        assert_eq!(alice[0].kind, NotificationKind::SessionCancelled);
+        assert_eq!(alice[0].session_id, Some(session_id));
+        assert_eq!(alice[0].message, "\"Serve clinic\" on 2026-11-02 was cancelled: Courts flooded");
+        assert!(NotificationRepository::find_by_user(&conn, user_id(&conn, "bob")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_migration_widens_the_inbox_of_an_existing_database() {
+        let (_temp_dir, conn) = setup_test_db();
+        // The inbox as first shipped, without 'session_cancelled'
+        conn.execute_batch(
+            "CREATE TABLE notifications_old (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                user_id INTEGER NOT NULL,
+                session_id INTEGER,
+                kind TEXT NOT NULL CHECK(kind IN ('session_changed', 'session_deleted', 'session_restored', 'feedback')),
+                message TEXT NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                read_at DATETIME,
+                FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+                FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE SET NULL
+            );
+            PRAGMA legacy_alter_table = ON;
+            DROP TABLE notifications;
+            ALTER TABLE notifications_old RENAME TO notifications;
+            PRAGMA legacy_alter_table = OFF;",
+        )
+        .unwrap();
+        let session_id = create_session(&conn, "Serve clinic");
+        SessionRepository::update(&conn, session_id, "Serve clinic II", None, None, None, Some(90), None, None).unwrap();
+        assert!(SessionRepository::cancel(&conn, session_id, "Courts flooded").is_err());
+
+        run_migrations(&conn).unwrap();
+        run_migrations(&conn).unwrap();
+
+        SessionRepository::cancel(&conn, session_id, "Courts flooded").unwrap();
+        let kinds: Vec<NotificationKind> = NotificationRepository::find_by_user(&conn, user_id(&conn, "alice"))
+            .unwrap()
+            .into_iter()
+            .map(|n| n.kind)
+            .collect();
+        assert_eq!(kinds.len(), 2);
+        assert!(kinds.contains(&NotificationKind::SessionChanged));
+        assert!(kinds.contains(&NotificationKind::SessionCancelled));
+    }
+
+    #[test]
+    fn test_cancelled_sessions_take_no_new_subscribers() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user_id(&conn, "coach");
+        let alice = user_id(&conn, "alice");
+        let session_id = SessionRepository::create(&conn, "Rained off", None, None, None, Some(60), None, coach).unwrap();
+        SessionRepository::cancel(&conn, session_id, "Rain").unwrap();
+
+        let direct = SubscriptionRepository::create(&conn, alice, session_id);
+        assert!(matches!(direct, Err(AppError::Validation(message)) if message.contains("cancelled")));
+
+        let group = GroupRepository::create(&conn, "Juniors", None, coach).unwrap();
+        GroupRepository::add_member(&conn, group, alice).unwrap();
+        let assigned = GroupRepository::assign_session(&conn, group, session_id);
+        assert!(matches!(assigned, Err(AppError::Validation(message)) if message.contains("cancelled")));
+        assert!(SubscriptionRepository::find_by_session(&conn, session_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cancelled_sessions_get_no_reminders() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+        let from = NaiveDate::from_ymd_opt(2026, 11, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+        let to = NaiveDate::from_ymd_opt(2026, 11, 2).unwrap().and_hms_opt(10, 0, 0).unwrap();
+        assert_eq!(ReminderRepository::due(&conn, from, to).unwrap().len(), 1);
+
+        SessionRepository::cancel(&conn, session_id, "Courts flooded").unwrap();
+        assert!(ReminderRepository::due(&conn, from, to).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cancel_rejects_missing_reason_and_repeats() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+
+        assert!(matches!(SessionRepository::cancel(&conn, session_id, "   "), Err(AppError::Validation(_))));
+        assert!(!SessionRepository::find_by_id(&conn, session_id).unwrap().unwrap().is_cancelled());
+
+        SessionRepository::cancel(&conn, session_id, "Rain").unwrap();
+        assert!(matches!(SessionRepository::cancel(&conn, session_id, "Rain"), Err(AppError::Validation(_))));
+        assert!(matches!(SessionRepository::cancel(&conn, 9999, "Rain"), Err(AppError::NotFound(_))));
+
+        // Nothing more was sent the second time
+        let alice = NotificationRepository::find_by_user(&conn, user_id(&conn, "alice")).unwrap();
+        assert_eq!(alice.len(), 1);
+    }
+
+    #[test]
+    fn test_progress_report_strikes_cancelled_sessions() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+        SessionRepository::cancel(&conn, session_id, "Courts flooded").unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2026, 11, 10).unwrap();
+        let report = ProgressReport::load(&conn, user_id(&conn, "alice"), None, None, today).unwrap();
+        assert_eq!(report.subscribed(), 0);
+
+        let markdown = report.render(DocumentFormat::Markdown);
+        assert!(markdown.contains("| ~~2026-11-02~~ | ~~Serve clinic~~ | ~~cancelled: Courts flooded~~ |"));
+
+        let html = report.render(DocumentFormat::Html);
+        assert!(html.contains("<tr class=\"cancelled\"><td>2026-11-02</td><td>Serve clinic</td>"));
+    }
+
+    #[test]
+    fn test_backup_round_trip_keeps_cancellation() {
+        let (temp_dir, source) = setup_test_db();
+        let session_id = create_session(&source, "Serve clinic");
+        SessionRepository::cancel(&source, session_id, "Courts flooded").unwrap();
+
+        let json = serde_json::to_string(&backup::export(&source).unwrap()).unwrap();
+        let doc = serde_json::from_str(&json).unwrap();
+
+        let target = open_db(&temp_dir, "target.db");
+        backup::import(&target, &doc, ImportMode::Merge).unwrap();
+
+        let session = SessionRepository::find_all(&target)
+            .unwrap()
+            .into_iter()
+            .find(|s| s.title == "Serve clinic")
+            .unwrap();
+        assert!(session.is_cancelled());
+        assert_eq!(session.cancel_reason.as_deref(), Some("Courts flooded"));
+        assert_eq!(status(&target, "alice", session.id), SubscriptionStatus::Cancelled);
+    }
+}
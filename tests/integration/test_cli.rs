@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod cli_tests {
+    use crate::setup_test_db;
+    use tui_coach::cli::args::{ReportArgs, SessionCreateArgs};
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{
+        SessionRepository, SubscriptionRepository, TagRepository, TrainingContentRepository,
+        TrainingTemplateRepository, UserRepository,
+    };
+    use tui_coach::models::{ContentType, SkillLevel};
+
+    fn create_args(title: &str) -> SessionCreateArgs {
+        SessionCreateArgs {
+            coach: "coach".to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            date: Some("2026-05-04".to_string()),
+            time: Some("18:30".to_string()),
+            duration: Some(90),
+            skill_level: Some("intermediate".to_string()),
+            tags: "Serve, footwork".to_string(),
+            court: None,
+        }
+    }
+
+    fn output(f: impl FnOnce(&mut Vec<u8>) -> tui_coach::utils::Result<()>) -> String {
+        let mut out = Vec::new();
+        f(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_sample_data_is_idempotent() {
+        let (_temp_dir, conn) = setup_test_db();
+        commands::insert_sample_data(&conn).unwrap();
+
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_session_create_and_list() {
+        let (_temp_dir, conn) = setup_test_db();
+
+        let created = output(|out| commands::session_create(&conn, &create_args("Serve Clinic"), false, out));
+        assert!(created.starts_with("Created session "));
+
+        let session = &SessionRepository::find_all(&conn).unwrap()[0];
+        assert_eq!(session.title, "Serve Clinic");
+        assert_eq!(session.skill_level, Some(SkillLevel::Intermediate));
+        assert_eq!(session.scheduled_time.unwrap().to_string(), "18:30:00");
+        assert_eq!(TagRepository::find_by_session(&conn, session.id).unwrap(), vec!["footwork", "serve"]);
+
+        let table = output(|out| commands::session_list(&conn, Some("coach"), false, out));
+        assert!(table.starts_with("ID"));
+        assert!(table.contains("Serve Clinic"));
+        assert!(table.contains("footwork,serve"));
+
+        let json = output(|out| commands::session_list(&conn, None, true, out));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["title"], "Serve Clinic");
+        assert_eq!(value[0]["subscribers"], 0);
+        assert_eq!(value[0]["tags"][1], "serve");
+    }
+
+    #[test]
+    fn test_session_create_validation() {
+        let (_temp_dir, conn) = setup_test_db();
+
+        let mut args = create_args("Serve Clinic");
+        args.date = Some("2026-02-30".to_string());
+        assert!(commands::session_create(&conn, &args, false, &mut Vec::new()).is_err());
+
+        let mut args = create_args("No");
+        args.date = None;
+        assert!(commands::session_create(&conn, &args, false, &mut Vec::new()).is_err());
+
+        // Players can't create sessions
+        let mut args = create_args("Serve Clinic");
+        args.coach = "alice".to_string();
+        assert!(commands::session_create(&conn, &args, false, &mut Vec::new()).is_err());
+
+        assert!(SessionRepository::find_all(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_session_delete() {
+        let (_temp_dir, conn) = setup_test_db();
+        commands::session_create(&conn, &create_args("Serve Clinic"), false, &mut Vec::new()).unwrap();
+        let id = SessionRepository::find_all(&conn).unwrap()[0].id;
+
+        let deleted = output(|out| commands::session_delete(&conn, id, false, out));
+        assert_eq!(deleted.trim(), format!("Deleted session {} (Serve Clinic)", id));
+        assert!(commands::session_delete(&conn, id, false, &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_template_import_and_list() {
+        let (temp_dir, conn) = setup_test_db();
+        let file = temp_dir.path().join("templates.json");
+        std::fs::write(
+            &file,
+            r#"[
+                {"title": "Split Step", "content_type": "drill", "duration_minutes": 10, "tags": ["Footwork", "warmup"]},
+                {"title": "Private Notes", "content_type": "exercise", "is_public": false}
+            ]"#,
+        ).unwrap();
+
+        let imported = output(|out| commands::template_import(&conn, &file, "coach", false, out));
+        assert_eq!(imported.trim(), "Imported 2 templates");
+
+        let public = output(|out| commands::template_list(&conn, None, &[], false, out));
+        assert!(public.contains("Split Step"));
+        assert!(public.contains("footwork,warmup"));
+        assert!(!public.contains("Private Notes"));
+
+        let own = output(|out| commands::template_list(&conn, Some("coach"), &[], true, out));
+        let value: serde_json::Value = serde_json::from_str(&own).unwrap();
+        assert_eq!(value.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_template_tags_filter_list() {
+        let (temp_dir, conn) = setup_test_db();
+        let file = temp_dir.path().join("templates.json");
+        std::fs::write(
+            &file,
+            r#"[
+                {"title": "Split Step", "content_type": "drill", "tags": ["footwork"]},
+                {"title": "Kick Serve", "content_type": "drill", "tags": ["serve"]}
+            ]"#,
+        ).unwrap();
+        commands::template_import(&conn, &file, "coach", false, &mut Vec::new()).unwrap();
+        let listed = |tags: &[String]| -> Vec<String> {
+            let json = output(|out| commands::template_list(&conn, Some("coach"), tags, true, out));
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            value.as_array().unwrap().iter().map(|t| t["title"].as_str().unwrap().to_string()).collect()
+        };
+
+        assert_eq!(listed(&["Serve".to_string()]), vec!["Kick Serve"]);
+
+        let coach = UserRepository::find_by_username(&conn, "coach").unwrap().unwrap();
+        let split_step = TrainingTemplateRepository::list_by_coach(&conn, coach.id)
+            .unwrap()
+            .into_iter()
+            .find(|t| t.title == "Split Step")
+            .unwrap();
+        commands::template_tag(&conn, split_step.id, "footwork, serve", false, &mut Vec::new()).unwrap();
+        assert_eq!(TagRepository::find_by_template(&conn, split_step.id).unwrap(), vec!["footwork", "serve"]);
+        assert_eq!(listed(&["serve".to_string(), "footwork".to_string()]), vec!["Split Step"]);
+
+        commands::template_tag(&conn, split_step.id, "", false, &mut Vec::new()).unwrap();
+        assert!(TagRepository::find_by_template(&conn, split_step.id).unwrap().is_empty());
+        assert!(commands::template_tag(&conn, 9999, "serve", false, &mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_template_import_is_all_or_nothing() {
+        let (temp_dir, conn) = setup_test_db();
+        let file = temp_dir.path().join("templates.json");
+        std::fs::write(
+            &file,
+            r#"[
+                {"title": "Split Step", "content_type": "drill"},
+                {"title": "Mystery", "content_type": "juggling"}
+            ]"#,
+        ).unwrap();
+
+        let err = commands::template_import(&conn, &file, "coach", false, &mut Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("Template #2"));
+
+        let coach = UserRepository::find_by_username(&conn, "coach").unwrap().unwrap();
+        assert!(TrainingTemplateRepository::list_by_coach(&conn, coach.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export_sessions_csv() {
+        let (_temp_dir, conn) = setup_test_db();
+        commands::session_create(&conn, &create_args("Serve, Volley"), false, &mut Vec::new()).unwrap();
+        // A week later, so the coach isn't double-booked
+        let empty_plan = SessionCreateArgs { date: Some("2026-05-11".to_string()), ..create_args("Empty Plan") };
+        commands::session_create(&conn, &empty_plan, false, &mut Vec::new()).unwrap();
+        let sessions = SessionRepository::find_all(&conn).unwrap();
+        let with_plan = sessions.iter().find(|s| s.title == "Serve, Volley").unwrap();
+        TrainingContentRepository::create(&conn, with_plan.id, &ContentType::Warmup, "Jog", None, Some(10), 1).unwrap();
+        TrainingContentRepository::create(&conn, with_plan.id, &ContentType::Drill, "Targets", None, Some(20), 2).unwrap();
+
+        let csv = output(|out| commands::export_sessions(&conn, None, out));
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].starts_with("session_id,title,date"));
+        // Two content rows plus one row for the session without a plan
+        assert_eq!(lines.len(), 4);
+        assert!(csv.contains("\"Serve, Volley\""));
+        assert!(csv.contains("content,warmup,Jog,10"));
+    }
+
+    #[test]
+    fn test_report_counts_attendance() {
+        let (_temp_dir, conn) = setup_test_db();
+        commands::session_create(&conn, &create_args("Serve Clinic"), false, &mut Vec::new()).unwrap();
+        let session_id = SessionRepository::find_all(&conn).unwrap()[0].id;
+        let alice = UserRepository::find_by_username(&conn, "alice").unwrap().unwrap();
+        let bob = UserRepository::find_by_username(&conn, "bob").unwrap().unwrap();
+        let sub_id = SubscriptionRepository::create(&conn, alice.id, session_id).unwrap();
+        SubscriptionRepository::create(&conn, bob.id, session_id).unwrap();
+        SubscriptionRepository::mark_completed(&conn, sub_id).unwrap();
+
+        let args = ReportArgs { coach: None, from: Some("2026-05-01".to_string()), to: None };
+        let json = output(|out| commands::report(&conn, &args, true, out));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["subscribed"], 2);
+        assert_eq!(value[0]["completed"], 1);
+        assert_eq!(value[0]["completion_rate"], 0.5);
+
+        // Date range excludes the session
+        let args = ReportArgs { coach: None, from: None, to: Some("2026-04-30".to_string()) };
+        let table = output(|out| commands::report(&conn, &args, false, out));
+        assert!(table.contains("0 sessions, 0 subscriptions, 0 completed"));
+    }
+}
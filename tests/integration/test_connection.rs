@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod connection_tests {
+    use std::thread;
+    use std::time::Duration;
+    use tempfile::TempDir;
+    use tui_coach::db::connection::{data_version, BUSY_TIMEOUT};
+    use tui_coach::db::establish_connection;
+    use tui_coach::utils::AppError;
+
+    fn setup_test_db() -> (TempDir, String) {
+        let (temp_dir, _) = crate::setup_empty_db();
+        let db_path = temp_dir.path().join("test.db").to_str().unwrap().to_string();
+        (temp_dir, db_path)
+    }
+
+    fn add_user(conn: &rusqlite::Connection, username: &str) -> Result<usize, AppError> {
+        Ok(conn.execute(
+            "INSERT INTO users (username, display_name, role) VALUES (?, ?, 'player')",
+            [username, username],
+        )?)
+    }
+
+    #[test]
+    fn test_connection_uses_wal_and_busy_timeout() {
+        let (_temp_dir, db_path) = setup_test_db();
+        let conn = establish_connection(&db_path).unwrap();
+
+        let journal_mode: String = conn.query_row("PRAGMA journal_mode", [], |row| row.get(0)).unwrap();
+        assert_eq!(journal_mode, "wal");
+        let busy_timeout: u64 = conn.query_row("PRAGMA busy_timeout", [], |row| row.get(0)).unwrap();
+        assert_eq!(busy_timeout, BUSY_TIMEOUT.as_millis() as u64);
+    }
+
+    #[test]
+    fn test_readers_are_not_blocked_by_a_writer() {
+        let (_temp_dir, db_path) = setup_test_db();
+        let writer = establish_connection(&db_path).unwrap();
+        let reader = establish_connection(&db_path).unwrap();
+
+        writer.execute_batch("BEGIN IMMEDIATE").unwrap();
+        add_user(&writer, "carol").unwrap();
+
+        let users: i64 = reader.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0)).unwrap();
+        assert_eq!(users, 0);
+
+        writer.execute_batch("COMMIT").unwrap();
+        let users: i64 = reader.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0)).unwrap();
+        assert_eq!(users, 1);
+    }
+
+    #[test]
+    fn test_writer_waits_for_a_short_lock() {
+        let (_temp_dir, db_path) = setup_test_db();
+        let holder = establish_connection(&db_path).unwrap();
+        holder.execute_batch("BEGIN IMMEDIATE").unwrap();
+
+        let waiter_path = db_path.clone();
+        let waiter = thread::spawn(move || add_user(&establish_connection(&waiter_path).unwrap(), "dave"));
+
+        thread::sleep(Duration::from_millis(200));
+        holder.execute_batch("COMMIT").unwrap();
+        assert_eq!(waiter.join().unwrap().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_lock_held_past_the_timeout_reports_busy() {
+        let (_temp_dir, db_path) = setup_test_db();
+        let holder = establish_connection(&db_path).unwrap();
+        holder.execute_batch("BEGIN IMMEDIATE").unwrap();
+
+        let conn = establish_connection(&db_path).unwrap();
+        conn.busy_timeout(Duration::from_millis(50)).unwrap();
+        let err = add_user(&conn, "erin").unwrap_err();
+
+        assert!(err.is_busy());
+        assert!(err.to_string().contains("busy"), "{}", err);
+        assert!(!AppError::NotFound("User erin".to_string()).is_busy());
+    }
+
+    #[test]
+    fn test_data_version_only_changes_for_other_connections() {
+        let (_temp_dir, db_path) = setup_test_db();
+        let app = establish_connection(&db_path).unwrap();
+        let other = establish_connection(&db_path).unwrap();
+        let seen = data_version(&app).unwrap();
+
+        add_user(&app, "frank").unwrap();
+        assert_eq!(data_version(&app).unwrap(), seen);
+
+        add_user(&other, "grace").unwrap();
+        let changed = data_version(&app).unwrap();
+        assert_ne!(changed, seen);
+        assert_eq!(data_version(&app).unwrap(), changed);
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod duplicate_session_tests {
+    use crate::setup_empty_db;
+    use chrono::{NaiveDate, NaiveTime};
+    use tempfile::TempDir;
+    use tui_coach::db::repositories::{
+        SessionRepository, SessionTrainingLinkRepository, SubscriptionRepository,
+        TrainingContentRepository, TrainingTemplateRepository,
+    };
+    use tui_coach::models::{ContentType, SkillLevel, TrainingTemplate};
+
+    fn setup_test_db() -> (TempDir, rusqlite::Connection, i64) {
+        let (temp_dir, conn) = setup_empty_db();
+
+        conn.execute(
+            "INSERT INTO users (username, display_name, role, created_at, updated_at)
+             VALUES (?, ?, ?, datetime('now'), datetime('now'))",
+            ["coach", "Test Coach", "coach"],
+        ).unwrap();
+        let coach_id = conn.last_insert_rowid();
+
+        (temp_dir, conn, coach_id)
+    }
+
+    fn create_template(conn: &rusqlite::Connection, coach_id: i64, title: &str) -> i64 {
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id,
+            title: title.to_string(),
+            content_type: "drill".to_string(),
+            description: None,
+            duration_minutes: Some(15),
+            created_at: String::new(),
+            created_by: coach_id,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: true,
+        };
+        TrainingTemplateRepository::create(conn, &template, coach_id).unwrap()
+    }
+
+    #[test]
+    fn test_duplicate_copies_session_fields() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+
+        let session_id = SessionRepository::create(
+            &conn,
+            "Serve Clinic",
+            Some("Kick serve focus"),
+            NaiveDate::from_ymd_opt(2026, 3, 1),
+            NaiveTime::from_hms_opt(18, 0, 0),
+            Some(90),
+            Some(&SkillLevel::Intermediate),
+            coach_id,
+        ).unwrap();
+
+        let new_date = NaiveDate::from_ymd_opt(2026, 3, 8);
+        let new_time = NaiveTime::from_hms_opt(19, 30, 0);
+        let copy_id = SessionRepository::duplicate(&conn, session_id, new_date, new_time, coach_id).unwrap();
+        assert_ne!(copy_id, session_id);
+
+        let copy = SessionRepository::find_by_id(&conn, copy_id).unwrap().unwrap();
+        assert_eq!(copy.title, "Serve Clinic");
+        assert_eq!(copy.description, Some("Kick serve focus".to_string()));
+        assert_eq!(copy.duration_minutes, Some(90));
+        assert_eq!(copy.skill_level, Some(SkillLevel::Intermediate));
+        assert_eq!(copy.scheduled_date, new_date);
+        assert_eq!(copy.scheduled_time, new_time);
+
+        // Original is untouched
+        let original = SessionRepository::find_by_id(&conn, session_id).unwrap().unwrap();
+        assert_eq!(original.scheduled_date, NaiveDate::from_ymd_opt(2026, 3, 1));
+    }
+
+    #[test]
+    fn test_duplicate_copies_training_content_in_order() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = SessionRepository::create(&conn, "Footwork", None, None, None, None, None, coach_id).unwrap();
+
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Warmup, "Jog", None, Some(10), 1).unwrap();
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Drill, "Ladder", Some("Fast feet"), Some(20), 2).unwrap();
+
+        let copy_id = SessionRepository::duplicate(&conn, session_id, None, None, coach_id).unwrap();
+
+        let content = TrainingContentRepository::find_by_session(&conn, copy_id).unwrap();
+        assert_eq!(content.len(), 2);
+        assert_eq!(content[0].title, "Jog");
+        assert_eq!(content[0].content_type, ContentType::Warmup);
+        assert_eq!(content[1].title, "Ladder");
+        assert_eq!(content[1].description, Some("Fast feet".to_string()));
+        assert_eq!(content[1].order_index, 2);
+
+        // Copies are independent rows
+        assert_eq!(TrainingContentRepository::find_by_session(&conn, session_id).unwrap().len(), 2);
+        assert_ne!(content[0].id, TrainingContentRepository::find_by_session(&conn, session_id).unwrap()[0].id);
+    }
+
+    #[test]
+    fn test_duplicate_copies_template_links_with_notes() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = SessionRepository::create(&conn, "Doubles", None, None, None, None, None, coach_id).unwrap();
+
+        let volley = create_template(&conn, coach_id, "Volley Drill");
+        let poach = create_template(&conn, coach_id, "Poaching");
+        SessionTrainingLinkRepository::add_to_session_with_notes(&conn, session_id, poach, 0, Some("Net player only")).unwrap();
+        SessionTrainingLinkRepository::add_to_session(&conn, session_id, volley, 1).unwrap();
+
+        let copy_id = SessionRepository::duplicate(&conn, session_id, None, None, coach_id).unwrap();
+
+        let links = SessionTrainingLinkRepository::get_for_session(&conn, copy_id).unwrap();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].training_template_id, poach);
+        assert_eq!(links[0].custom_notes, Some("Net player only".to_string()));
+        assert_eq!(links[1].training_template_id, volley);
+        assert_eq!(links[1].order_index, 1);
+    }
+
+    #[test]
+    fn test_duplicate_does_not_copy_subscriptions() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        conn.execute(
+            "INSERT INTO users (username, display_name, role) VALUES ('alice', 'Alice', 'player')",
+            [],
+        ).unwrap();
+        let player_id = conn.last_insert_rowid();
+
+        let session_id = SessionRepository::create(&conn, "Rally", None, None, None, None, None, coach_id).unwrap();
+        SubscriptionRepository::create(&conn, player_id, session_id).unwrap();
+
+        let copy_id = SessionRepository::duplicate(&conn, session_id, None, None, coach_id).unwrap();
+        assert!(SubscriptionRepository::find_by_session(&conn, copy_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_missing_session() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+
+        assert!(SessionRepository::duplicate(&conn, 42, None, None, coach_id).is_err());
+        assert!(SessionRepository::find_by_coach(&conn, coach_id).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod group_tests {
+    use crate::setup_empty_db;
+    use tempfile::TempDir;
+    use tui_coach::db::repositories::{GroupRepository, SessionRepository, SubscriptionRepository};
+    use tui_coach::models::SubscriptionStatus;
+
+    fn setup_test_db() -> (TempDir, rusqlite::Connection, i64, Vec<i64>) {
+        let (temp_dir, conn) = setup_empty_db();
+
+        conn.execute(
+            "INSERT INTO users (username, display_name, role, created_at, updated_at)
+             VALUES (?, ?, ?, datetime('now'), datetime('now'))",
+            ["coach", "Test Coach", "coach"],
+        ).unwrap();
+        let coach_id = conn.last_insert_rowid();
+
+        let mut player_ids = Vec::new();
+        for (username, display_name) in [("alice", "Alice Smith"), ("bob", "Bob Jones"), ("carol", "Carol White")] {
+            conn.execute(
+                "INSERT INTO users (username, display_name, role, skill_level, created_at, updated_at)
+                 VALUES (?, ?, 'player', 'beginner', datetime('now'), datetime('now'))",
+                [username, display_name],
+            ).unwrap();
+            player_ids.push(conn.last_insert_rowid());
+        }
+
+        (temp_dir, conn, coach_id, player_ids)
+    }
+
+    #[test]
+    fn test_create_and_find_group() {
+        let (_temp_dir, conn, coach_id, _) = setup_test_db();
+
+        let group_id = GroupRepository::create(&conn, "U14 Tuesday", Some("Juniors"), coach_id).unwrap();
+        assert!(group_id > 0);
+
+        let group = GroupRepository::find_by_id(&conn, group_id).unwrap().unwrap();
+        assert_eq!(group.name, "U14 Tuesday");
+        assert_eq!(group.description, Some("Juniors".to_string()));
+        assert_eq!(group.created_by, coach_id);
+
+        assert!(GroupRepository::find_by_name(&conn, "U14 Tuesday").unwrap().is_some());
+        assert_eq!(GroupRepository::find_all(&conn).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_group_name_is_unique() {
+        let (_temp_dir, conn, coach_id, _) = setup_test_db();
+
+        GroupRepository::create(&conn, "Squad", None, coach_id).unwrap();
+        assert!(GroupRepository::create(&conn, "Squad", None, coach_id).is_err());
+    }
+
+    #[test]
+    fn test_add_and_remove_members() {
+        let (_temp_dir, conn, coach_id, players) = setup_test_db();
+        let group_id = GroupRepository::create(&conn, "Squad", None, coach_id).unwrap();
+
+        GroupRepository::add_member(&conn, group_id, players[0]).unwrap();
+        GroupRepository::add_member(&conn, group_id, players[1]).unwrap();
+        // Adding twice is a no-op
+        GroupRepository::add_member(&conn, group_id, players[1]).unwrap();
+
+        let members = GroupRepository::find_members(&conn, group_id).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].display_name, "Alice Smith");
+        assert!(GroupRepository::is_member(&conn, group_id, players[1]).unwrap());
+
+        GroupRepository::remove_member(&conn, group_id, players[1]).unwrap();
+        assert!(!GroupRepository::is_member(&conn, group_id, players[1]).unwrap());
+        assert_eq!(GroupRepository::find_members(&conn, group_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_assign_session_reports_already_subscribed() {
+        let (_temp_dir, conn, coach_id, players) = setup_test_db();
+        let group_id = GroupRepository::create(&conn, "Squad", None, coach_id).unwrap();
+        for player_id in &players {
+            GroupRepository::add_member(&conn, group_id, *player_id).unwrap();
+        }
+
+        let session_id = SessionRepository::create(&conn, "Serve Clinic", None, None, None, None, None, coach_id).unwrap();
+
+        // Bob subscribed on his own beforehand
+        SubscriptionRepository::create(&conn, players[1], session_id).unwrap();
+
+        let assignment = GroupRepository::assign_session(&conn, group_id, session_id).unwrap();
+        assert_eq!(assignment.subscribed.len(), 2);
+        assert_eq!(assignment.already_subscribed.len(), 1);
+        assert_eq!(assignment.already_subscribed[0].username, "bob");
+        assert!(assignment.summary().contains("Bob Jones"));
+
+        for player_id in &players {
+            assert!(SubscriptionRepository::is_subscribed(&conn, *player_id, session_id).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_assign_session_reactivates_cancelled_subscriptions() {
+        let (_temp_dir, conn, coach_id, players) = setup_test_db();
+        let group_id = GroupRepository::create(&conn, "Squad", None, coach_id).unwrap();
+        for player_id in &players {
+            GroupRepository::add_member(&conn, group_id, *player_id).unwrap();
+        }
+
+        let session_id = SessionRepository::create(&conn, "Serve Clinic", None, None, None, None, None, coach_id).unwrap();
+        let alice = SubscriptionRepository::create(&conn, players[0], session_id).unwrap();
+        conn.execute("UPDATE subscriptions SET status = 'cancelled' WHERE id = ?", [alice]).unwrap();
+        let bob = SubscriptionRepository::create(&conn, players[1], session_id).unwrap();
+        SubscriptionRepository::mark_completed(&conn, bob).unwrap();
+
+        let assignment = GroupRepository::assign_session(&conn, group_id, session_id).unwrap();
+        let subscribed: Vec<&str> = assignment.subscribed.iter().map(|u| u.username.as_str()).collect();
+        assert_eq!(subscribed, vec!["alice", "carol"]);
+        assert_eq!(assignment.already_subscribed.len(), 1);
+        assert_eq!(assignment.already_subscribed[0].username, "bob");
+
+        let status = |player_id| {
+            SubscriptionRepository::find_by_user_and_session(&conn, player_id, session_id)
+                .unwrap()
+                .unwrap()
+                .status
+        };
+        assert_eq!(status(players[0]), SubscriptionStatus::Active);
+        assert_eq!(status(players[1]), SubscriptionStatus::Completed);
+    }
+
+    #[test]
+    fn test_assign_missing_session_rolls_back() {
+        let (_temp_dir, conn, coach_id, players) = setup_test_db();
+        let group_id = GroupRepository::create(&conn, "Squad", None, coach_id).unwrap();
+        for player_id in &players {
+            GroupRepository::add_member(&conn, group_id, *player_id).unwrap();
+        }
+
+        // Foreign key violation on a non-existent session aborts the whole batch
+        assert!(GroupRepository::assign_session(&conn, group_id, 9999).is_err());
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM subscriptions", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_delete_group_keeps_subscriptions() {
+        let (_temp_dir, conn, coach_id, players) = setup_test_db();
+        let group_id = GroupRepository::create(&conn, "Squad", None, coach_id).unwrap();
+        GroupRepository::add_member(&conn, group_id, players[0]).unwrap();
+
+        let session_id = SessionRepository::create(&conn, "Footwork", None, None, None, None, None, coach_id).unwrap();
+        GroupRepository::assign_session(&conn, group_id, session_id).unwrap();
+
+        GroupRepository::delete(&conn, group_id).unwrap();
+
+        assert!(GroupRepository::find_by_id(&conn, group_id).unwrap().is_none());
+        assert!(SubscriptionRepository::is_subscribed(&conn, players[0], session_id).unwrap());
+    }
+}
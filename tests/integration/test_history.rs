@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod history_tests {
+    use crate::setup_test_db;
+    use chrono::NaiveDate;
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{SessionRepository, SubscriptionRepository, UserRepository};
+    use tui_coach::export;
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    /// Two coaches' sessions; alice attends both coaches, bob only the first
+    fn seed_history(conn: &rusqlite::Connection) {
+        conn.execute(
+            "INSERT INTO users (username, display_name, role) VALUES ('other', 'Other Coach', 'coach')",
+            [],
+        )
+        .unwrap();
+        let coach = user_id(conn, "coach");
+        let other = user_id(conn, "other");
+        let alice = user_id(conn, "alice");
+        let bob = user_id(conn, "bob");
+
+        let later = SessionRepository::create(
+            conn, "Volleys", None, NaiveDate::from_ymd_opt(2026, 4, 2), None, Some(45), None, coach,
+        )
+        .unwrap();
+        let earlier = SessionRepository::create(
+            conn, "Serve, \"kick\" clinic", None, NaiveDate::from_ymd_opt(2026, 3, 1), None, Some(60), None,
+            coach,
+        )
+        .unwrap();
+        let elsewhere = SessionRepository::create(
+            conn, "Fitness", None, NaiveDate::from_ymd_opt(2026, 3, 15), None, None, None, other,
+        )
+        .unwrap();
+
+        let sub = SubscriptionRepository::create(conn, alice, earlier).unwrap();
+        SubscriptionRepository::mark_completed(conn, sub).unwrap();
+        conn.execute("UPDATE subscriptions SET notes = 'Felt good' WHERE id = ?", [sub]).unwrap();
+        SubscriptionRepository::create(conn, alice, later).unwrap();
+        SubscriptionRepository::create(conn, alice, elsewhere).unwrap();
+        SubscriptionRepository::create(conn, bob, earlier).unwrap();
+    }
+
+    #[test]
+    fn test_player_history_is_ordered_by_session_date() {
+        let (_temp_dir, conn) = setup_test_db();
+        seed_history(&conn);
+
+        let history = SubscriptionRepository::history_for_player(&conn, user_id(&conn, "alice")).unwrap();
+        let titles: Vec<&str> = history.iter().map(|e| e.session_title.as_str()).collect();
+        assert_eq!(titles, vec!["Serve, \"kick\" clinic", "Fitness", "Volleys"]);
+        assert!(history[0].subscription.completed_at.is_some());
+        assert_eq!(history[0].subscription.notes, Some("Felt good".to_string()));
+        assert_eq!(history[0].duration_minutes, Some(60));
+        assert!(history.iter().all(|e| e.username == "alice"));
+    }
+
+    #[test]
+    fn test_coach_history_covers_only_their_sessions() {
+        let (_temp_dir, conn) = setup_test_db();
+        seed_history(&conn);
+
+        let history = SubscriptionRepository::history_for_coach(&conn, user_id(&conn, "coach")).unwrap();
+        let rows: Vec<(&str, &str)> = history
+            .iter()
+            .map(|e| (e.username.as_str(), e.session_title.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("alice", "Serve, \"kick\" clinic"),
+                ("bob", "Serve, \"kick\" clinic"),
+                ("alice", "Volleys"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csv_export() {
+        let (_temp_dir, conn) = setup_test_db();
+        seed_history(&conn);
+        let history = SubscriptionRepository::history_for_player(&conn, user_id(&conn, "alice")).unwrap();
+
+        let mut out = Vec::new();
+        export::history::write_csv(&history, &mut out).unwrap();
+
+        let mut reader = csv::Reader::from_reader(out.as_slice());
+        assert_eq!(reader.headers().unwrap(), export::history::HEADERS.as_slice());
+        let records: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(records.len(), 3);
+        assert_eq!(&records[0][3], "2026-03-01");
+        assert_eq!(&records[0][4], "Serve, \"kick\" clinic");
+        assert_eq!(&records[0][5], "60");
+        assert_eq!(&records[0][6], "completed");
+        assert!(!records[0][7].is_empty());
+        assert_eq!(&records[0][8], "Felt good");
+        assert_eq!(&records[2][6], "active");
+        assert_eq!(&records[2][7], "");
+    }
+
+    #[test]
+    fn test_export_to_dir_uses_dated_file_name() {
+        let (temp_dir, conn) = setup_test_db();
+        seed_history(&conn);
+        let history = SubscriptionRepository::history_for_coach(&conn, user_id(&conn, "coach")).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        let dir = temp_dir.path().join("exports");
+        let path = export::history::export_to_dir(&history, &dir, None, today).unwrap();
+        assert_eq!(path, dir.join("history-all-2026-10-18.csv"));
+        assert_eq!(std::fs::read_to_string(path).unwrap().lines().count(), 4);
+    }
+
+    #[test]
+    fn test_cli_history() {
+        let (_temp_dir, conn) = setup_test_db();
+        seed_history(&conn);
+
+        let mut out = Vec::new();
+        commands::history(&conn, Some("bob"), None, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert!(text.starts_with("username,display_name,session_id"));
+
+        let mut out = Vec::new();
+        commands::history(&conn, None, Some("coach"), true, &mut out).unwrap();
+        let rows: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(rows.as_array().unwrap().len(), 3);
+        assert_eq!(rows[0]["subscription"]["status"], "Completed");
+
+        assert!(commands::history(&conn, None, Some("alice"), false, &mut Vec::new()).is_err());
+        assert!(commands::history(&conn, Some("nobody"), None, false, &mut Vec::new()).is_err());
+    }
+}
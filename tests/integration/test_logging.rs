@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod logging_tests {
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+    use tui_coach::config::Settings;
+    use tui_coach::ui::Screen;
+    use tui_coach::utils::logging::{self, AUDIT_TARGET};
+
+    fn read_log(dir: &Path, prefix: &str) -> String {
+        fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.file_name().unwrap().to_str().unwrap().starts_with(prefix))
+            .map(|path| fs::read_to_string(path).unwrap())
+            .collect()
+    }
+
+    // The logger is process-wide, so everything is checked in one test
+    #[test]
+    fn test_logs_and_audit_events_go_to_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut settings = Settings::default();
+        settings.log_path = temp_dir.path().join("logs").join("tennis-tui.log");
+        assert_eq!(settings.log_level, "info");
+
+        let dir = logging::init(&settings).unwrap();
+        assert_eq!(dir, temp_dir.path().join("logs"));
+
+        {
+            let _span = tracing::info_span!("tui", user = "alice").entered();
+            tracing::info!(target: AUDIT_TARGET, session_id = 7, "subscribed");
+            tracing::info!("loading sessions");
+            tracing::debug!("below the configured level");
+
+            let _screen = logging::screen_span(&Screen::SessionList).entered();
+            tracing::info!(target: AUDIT_TARGET, session_id = 8, "archived");
+            tracing::info!("loading session list");
+        }
+
+        let main_log = read_log(&dir, "tennis-tui.log");
+        assert!(main_log.contains("tui{user=\"alice\"}"));
+        assert!(main_log.contains("subscribed session_id=7"));
+        assert!(main_log.contains("loading sessions"));
+        assert!(!main_log.contains("below the configured level"));
+        let listed = main_log.lines().find(|line| line.contains("loading session list")).unwrap();
+        assert!(listed.contains("tui{user=\"alice\"}:screen{screen=SessionList}"));
+
+        let audit_log = read_log(&dir, "audit.log");
+        assert!(audit_log.contains("subscribed session_id=7"));
+        assert!(!audit_log.contains("loading sessions"));
+        assert!(audit_log.contains("tui{user=\"alice\"}:screen{screen=SessionList}: archived session_id=8"));
+
+        // A second logger can't be installed
+        assert!(logging::init(&settings).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod notification_tests {
+    use crate::setup_test_db;
+    use chrono::{NaiveDate, NaiveTime};
+    use tui_coach::db::repositories::{
+        ArchiveRepository, NotificationRepository, ProgressRepository, SessionRepository, SubscriptionRepository,
+        UserRepository,
+    };
+    use tui_coach::models::{ArchiveKind, NotificationKind};
+    use tui_coach::utils::AppError;
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    /// A session with Alice subscribed and Bob's subscription completed
+    fn create_session(conn: &rusqlite::Connection, title: &str) -> i64 {
+        let id = SessionRepository::create(
+            conn,
+            title,
+            None,
+            NaiveDate::from_ymd_opt(2026, 11, 2),
+            NaiveTime::from_hms_opt(10, 0, 0),
+            Some(90),
+            None,
+            user_id(conn, "coach"),
+        )
+        .unwrap();
+        SubscriptionRepository::create(conn, user_id(conn, "alice"), id).unwrap();
+        let bob = SubscriptionRepository::create(conn, user_id(conn, "bob"), id).unwrap();
+        SubscriptionRepository::mark_completed(conn, bob).unwrap();
+        id
+    }
+
+    fn messages(conn: &rusqlite::Connection, username: &str) -> Vec<(NotificationKind, String)> {
+        NotificationRepository::find_by_user(conn, user_id(conn, username))
+            .unwrap()
+            .into_iter()
+            .map(|n| (n.kind, n.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_session_changes_notify_active_subscribers() {
+        let (_temp_dir, conn) = setup_test_db();
+        let session_id = create_session(&conn, "Serve clinic");
+        let date = NaiveDate::from_ymd_opt(2026, 11, 2);
+
+        // Saving without changes is not news
+        SessionRepository::update(&conn, session_id, "Serve clinic", None, date, NaiveTime::from_hms_opt(10, 0, 0), Some(90), None, None).unwrap();
+        assert!(messages(&conn, "alice").is_empty());
+
+        SessionRepository::update(&conn, session_id, "Serve clinic", None, date, NaiveTime::from_hms_opt(11, 30, 0), Some(90), None, None).unwrap();
+        SessionRepository::update(&conn, session_id, "Serve and volley", None, date, NaiveTime::from_hms_opt(11, 30, 0), Some(90), None, None).unwrap();
+        SessionRepository::update(&conn, session_id, "Serve and volley", Some("Bring balls"), date, NaiveTime::from_hms_opt(11, 30, 0), Some(90), None, None).unwrap();
+
+        assert_eq!(
+            messages(&conn, "alice"),
+            vec![
+                (NotificationKind::SessionChanged, "\"Serve and volley\" was updated".to_string()),
+                (NotificationKind::SessionChanged, "\"Serve clinic\" was renamed to \"Serve and volley\"".to_string()),
+                (NotificationKind::SessionChanged, "\"Serve clinic\" was moved to 2026-11-02 11:30".to_string()),
+            ]
+        );
+        assert!(messages(&conn, "bob").is_empty());
+        assert!(messages(&conn, "coach").is_empty());
+    }
+
+    #[test]
+    fn test_deleting_a_session_notifies_subscribers() {
+        let (_temp_dir, conn) = setup_test_db();
+        let archived = create_session(&conn, "Footwork");
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, archived).unwrap();
+        ArchiveRepository::restore(&conn, ArchiveKind::Session, archived).unwrap();
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, archived).unwrap();
+        ArchiveRepository::purge(&conn, ArchiveKind::Session, archived).unwrap();
+
+        let deleted = create_session(&conn, "Volleys");
+        SessionRepository::delete(&conn, deleted).unwrap();
+
+        assert_eq!(
+            messages(&conn, "alice"),
+            vec![
+                (NotificationKind::SessionDeleted, "\"Volleys\" was deleted".to_string()),
+                (NotificationKind::SessionDeleted, "\"Footwork\" was deleted".to_string()),
+                (NotificationKind::SessionRestored, "\"Footwork\" is back on the schedule".to_string()),
+                (NotificationKind::SessionDeleted, "\"Footwork\" was deleted".to_string()),
+            ]
+        );
+        let inbox = NotificationRepository::find_by_user(&conn, user_id(&conn, "alice")).unwrap();
+        assert!(inbox.iter().all(|n| n.session_id.is_none()));
+    }
+
+    #[test]
+    fn test_coach_comment_is_feedback() {
+        let (_temp_dir, conn) = setup_test_db();
+        ProgressRepository::add_comment(&conn, user_id(&conn, "alice"), user_id(&conn, "coach"), "Great serve today").unwrap();
+
+        assert_eq!(
+            messages(&conn, "alice"),
+            vec![(NotificationKind::Feedback, "Coach Peter left you feedback: Great serve today".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_marking_notifications_read() {
+        let (_temp_dir, conn) = setup_test_db();
+        let alice = user_id(&conn, "alice");
+        let coach = user_id(&conn, "coach");
+        ProgressRepository::add_comment(&conn, alice, coach, "Watch the ball").unwrap();
+        ProgressRepository::add_comment(&conn, alice, coach, "Bend your knees").unwrap();
+        assert_eq!(NotificationRepository::unread_count(&conn, alice).unwrap(), 2);
+
+        let newest = NotificationRepository::find_by_user(&conn, alice).unwrap().remove(0);
+        assert!(!newest.is_read());
+        let other_user = NotificationRepository::mark_read(&conn, user_id(&conn, "bob"), newest.id);
+        assert!(matches!(other_user, Err(AppError::NotFound(_))));
+
+        NotificationRepository::mark_read(&conn, alice, newest.id).unwrap();
+        NotificationRepository::mark_read(&conn, alice, newest.id).unwrap();
+        assert_eq!(NotificationRepository::unread_count(&conn, alice).unwrap(), 1);
+        assert!(NotificationRepository::find_by_user(&conn, alice).unwrap()[0].is_read());
+
+        assert_eq!(NotificationRepository::mark_all_read(&conn, alice).unwrap(), 1);
+        assert_eq!(NotificationRepository::unread_count(&conn, alice).unwrap(), 0);
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod player_import_tests {
+    use tempfile::TempDir;
+    use tui_coach::cli::args::PlayerImportArgs;
+    use tui_coach::cli::commands;
+    use tui_coach::db::player_import::{self, DEFAULT_BIN_PATH};
+    use tui_coach::db::repositories::{GroupRepository, UserRepository};
+    use tui_coach::models::{SkillLevel, UserRole};
+
+    fn setup_test_db() -> (TempDir, rusqlite::Connection, i64) {
+        let (temp_dir, conn) = crate::setup_test_db();
+        let coach_id = UserRepository::find_by_username(&conn, "coach").unwrap().unwrap().id;
+        (temp_dir, conn, coach_id)
+    }
+
+    fn user_count(conn: &rusqlite::Connection) -> i64 {
+        conn.query_row("SELECT COUNT(*) FROM users", [], |row| row.get(0)).unwrap()
+    }
+
+    const VALID_CSV: &str = "\
+username,display name,skill_level,goals,group
+carol,Carol King,Beginner,\"Serve, volley\",Juniors
+dave,Dave Lee,,,Juniors
+erin,Erin Moss,advanced,,Seniors
+";
+
+    #[test]
+    fn test_import_creates_players_and_groups() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        conn.execute("INSERT INTO player_groups (name, created_by) VALUES ('Seniors', ?)", [coach_id]).unwrap();
+
+        let summary = player_import::import(&conn, VALID_CSV.as_bytes(), coach_id, DEFAULT_BIN_PATH).unwrap();
+        assert_eq!(summary.players.len(), 3);
+        assert_eq!(summary.groups_created, vec!["Juniors"]);
+
+        let carol = UserRepository::find_by_username(&conn, "carol").unwrap().unwrap();
+        assert_eq!(carol.display_name, "Carol King");
+        assert_eq!(carol.role, UserRole::Player);
+        assert_eq!(carol.skill_level, Some(SkillLevel::Beginner));
+        assert_eq!(carol.goals, Some("Serve, volley".to_string()));
+        let dave = UserRepository::find_by_username(&conn, "dave").unwrap().unwrap();
+        assert_eq!(dave.skill_level, None);
+
+        let juniors = GroupRepository::find_by_name(&conn, "Juniors").unwrap().unwrap();
+        assert_eq!(juniors.created_by, coach_id);
+        assert_eq!(GroupRepository::find_members(&conn, juniors.id).unwrap().len(), 2);
+        let seniors = GroupRepository::find_by_name(&conn, "Seniors").unwrap().unwrap();
+        assert!(GroupRepository::is_member(&conn, seniors.id, summary.players[2].id).unwrap());
+    }
+
+    #[test]
+    fn test_row_errors_are_reported_without_partial_writes() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let csv = "\
+username,display_name,role,skill_level
+frank,Frank,player,beginner
+alice,Alice Again,player,
+Bad Name,Someone,player,
+gina,,referee,pro
+frank,Frank Twice,,
+";
+        let before = user_count(&conn);
+
+        let (rows, errors) = player_import::check(&conn, csv.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 3);
+        let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![3, 4, 5, 5, 5, 6]);
+        assert!(errors[0].message.contains("already exists"));
+        assert!(errors[1].message.contains("lowercase"));
+        assert!(errors.iter().any(|e| e.message.contains("invalid role 'referee'")));
+        assert!(errors.iter().any(|e| e.message.contains("invalid skill level 'pro'")));
+        assert!(errors[5].message.contains("already used on line 2"));
+
+        let err = player_import::import(&conn, csv.as_bytes(), coach_id, DEFAULT_BIN_PATH).unwrap_err();
+        assert!(err.to_string().contains("line 4: username 'Bad Name'"));
+        assert_eq!(user_count(&conn), before);
+    }
+
+    #[test]
+    fn test_email_column_sets_reminder_address() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let csv = "username,display_name,email\nhana,Hana,hana@club.example\nivan,Ivan,\njon,Jon,not-an-address\n";
+
+        let (_, errors) = player_import::check(&conn, csv.as_bytes()).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("email 'not-an-address'"));
+
+        let csv = csv.replace("jon,Jon,not-an-address\n", "");
+        player_import::import(&conn, csv.as_bytes(), coach_id, DEFAULT_BIN_PATH).unwrap();
+        let hana = UserRepository::find_by_username(&conn, "hana").unwrap().unwrap();
+        assert_eq!(hana.email.as_deref(), Some("hana@club.example"));
+        assert_eq!(UserRepository::find_by_username(&conn, "ivan").unwrap().unwrap().email, None);
+    }
+
+    #[test]
+    fn test_header_problems() {
+        let (_temp_dir, conn, _coach_id) = setup_test_db();
+
+        let (_, errors) = player_import::check(&conn, "username,name\nx,y\n".as_bytes()).unwrap();
+        assert_eq!(errors[0].line, 1);
+        assert!(errors[0].message.contains("unknown column 'name'"));
+
+        let (_, errors) = player_import::check(&conn, "username\nx\n".as_bytes()).unwrap();
+        assert!(errors[0].message.contains("missing column 'display_name'"));
+    }
+
+    #[test]
+    fn test_database_failure_rolls_back() {
+        let (_temp_dir, conn, _coach_id) = setup_test_db();
+        let before = user_count(&conn);
+
+        // Groups need an existing owner; the failure happens after carol was inserted
+        let result = player_import::import(&conn, VALID_CSV.as_bytes(), 9999, DEFAULT_BIN_PATH);
+        assert!(result.is_err());
+        assert_eq!(user_count(&conn), before);
+        assert!(GroupRepository::find_by_name(&conn, "Juniors").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_authorized_keys_lines() {
+        assert_eq!(
+            player_import::authorized_keys_line("alice", Some("ssh-ed25519 AAAAC3Nza alice@laptop"), DEFAULT_BIN_PATH),
+            "command=\"/home/tennis-tui/bin/tennis-tui --user=alice\",no-pty,no-port-forwarding,no-X11-forwarding ssh-ed25519 AAAAC3Nza alice"
+        );
+        assert!(player_import::authorized_keys_line("bob", None, "/opt/tennis-tui")
+            .starts_with("command=\"/opt/tennis-tui --user=bob\""));
+
+        let (_temp_dir, conn, _coach_id) = setup_test_db();
+        let csv = "username,display_name,ssh_key\nhank,Hank,ssh-ed25519 AAAA\"x\n";
+        let (_, errors) = player_import::check(&conn, csv.as_bytes()).unwrap();
+        assert!(errors[0].message.contains("not an OpenSSH public key"));
+    }
+
+    #[test]
+    fn test_cli_player_import() {
+        let (temp_dir, conn, _coach_id) = setup_test_db();
+        let file = temp_dir.path().join("players.csv");
+        std::fs::write(&file, "username,display_name,role,ssh_key\nivy,Ivy,coach,ssh-ed25519 AAAAkey\njon,Jon,,\n").unwrap();
+
+        let args = PlayerImportArgs {
+            file,
+            coach: "coach".to_string(),
+            authorized_keys: true,
+            bin_path: DEFAULT_BIN_PATH.to_string(),
+        };
+        let mut out = Vec::new();
+        commands::player_import(&conn, &args, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.contains("Imported 2 users"));
+        assert!(text.contains("--user=ivy\",no-pty,no-port-forwarding,no-X11-forwarding ssh-ed25519 AAAAkey ivy"));
+        assert!(text.contains("ssh-ed25519 <public-key> jon"));
+        assert!(UserRepository::find_by_username(&conn, "ivy").unwrap().unwrap().is_coach());
+    }
+}
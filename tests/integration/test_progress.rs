@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod progress_tests {
+    use crate::setup_test_db;
+    use chrono::NaiveDate;
+    use tui_coach::cli::args::ProgressArgs;
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{ProgressRepository, SessionRepository, SubscriptionRepository, UserRepository};
+    use tui_coach::export::progress::ProgressReport;
+    use tui_coach::export::DocumentFormat;
+    use tui_coach::models::{HomeworkState, SkillLevel};
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    /// Alice: January session (completed, 2 quiz answers, submitted homework),
+    /// March session (active, 1 quiz answer, homework due Mar 10 not submitted)
+    fn seed(conn: &rusqlite::Connection) -> (i64, i64) {
+        let coach = user_id(conn, "coach");
+        let alice = user_id(conn, "alice");
+        let january = SessionRepository::create(conn, "Serve basics", None, Some(date(1, 15)), None, None, None, coach).unwrap();
+        let march = SessionRepository::create(conn, "Net play", None, Some(date(3, 5)), None, None, None, coach).unwrap();
+
+        let jan_sub = SubscriptionRepository::create(conn, alice, january).unwrap();
+        SubscriptionRepository::mark_completed(conn, jan_sub).unwrap();
+        let mar_sub = SubscriptionRepository::create(conn, alice, march).unwrap();
+
+        conn.execute_batch(&format!(
+            "INSERT INTO quizzes (id, session_id, question, correct_answer, order_index) VALUES
+                 (1, {january}, 'Grip?', 'continental', 0), (2, {january}, 'Toss?', 'high', 1),
+                 (3, {march}, 'Split step?', 'yes', 0);
+             INSERT INTO quiz_responses (subscription_id, quiz_id, answer, is_correct) VALUES
+                 ({jan_sub}, 1, 'continental', 1), ({jan_sub}, 2, 'low', 0), ({mar_sub}, 3, 'yes', 1);
+             INSERT INTO homework (id, session_id, title, description, due_date, order_index) VALUES
+                 (1, {january}, 'Shadow serves', '50 reps', '2026-01-20', 0),
+                 (2, {march}, 'Wall volleys', '10 min', '2026-03-10', 0);
+             INSERT INTO homework_submissions (subscription_id, homework_id, notes, submitted_at) VALUES
+                 ({jan_sub}, 1, 'done', '2026-01-18 10:00:00');"
+        ))
+        .unwrap();
+        (coach, alice)
+    }
+
+    #[test]
+    fn test_skill_level_changes_are_recorded_by_trigger() {
+        let (_temp_dir, conn) = setup_test_db();
+        let alice = user_id(&conn, "alice");
+        conn.execute("UPDATE users SET skill_level = 'intermediate' WHERE id = ?", [alice]).unwrap();
+        conn.execute("UPDATE users SET display_name = 'Alice S.' WHERE id = ?", [alice]).unwrap();
+        conn.execute("UPDATE users SET skill_level = 'intermediate' WHERE id = ?", [alice]).unwrap();
+
+        let changes = ProgressRepository::skill_level_changes(&conn, alice, None, None).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].old_level, Some(SkillLevel::Beginner));
+        assert_eq!(changes[0].new_level, Some(SkillLevel::Intermediate));
+    }
+
+    #[test]
+    fn test_report_contents_and_date_range() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (coach, alice) = seed(&conn);
+        ProgressRepository::add_comment(&conn, alice, coach, "Great toss\nKeep it up").unwrap();
+
+        let report = ProgressReport::load(&conn, alice, None, None, date(3, 20)).unwrap();
+        assert_eq!((report.subscribed(), report.attended()), (2, 1));
+        assert_eq!(report.completion_rate(), 50.0);
+        assert_eq!(report.quiz_totals(), (2, 3));
+        let states: Vec<HomeworkState> = report.homework.iter().map(|h| h.state).collect();
+        assert_eq!(states, vec![HomeworkState::Submitted, HomeworkState::Overdue]);
+        assert_eq!(report.comments[0].coach_name, "Coach Peter");
+
+        // Before the due date the March homework is still open
+        let report = ProgressReport::load(&conn, alice, None, None, date(3, 6)).unwrap();
+        assert_eq!(report.homework[1].state, HomeworkState::Open);
+
+        let january = ProgressReport::load(&conn, alice, Some(date(1, 1)), Some(date(1, 31)), date(3, 20)).unwrap();
+        assert_eq!(january.sessions.len(), 1);
+        assert_eq!(january.quiz_totals(), (1, 2));
+        assert_eq!(january.homework.len(), 1);
+        assert!(january.comments.is_empty());
+        assert_eq!(january.period_label(), "2026-01-01 to 2026-01-31");
+        assert_eq!(january.file_name(DocumentFormat::Html), "progress-alice-2026-01-01_2026-01-31.html");
+    }
+
+    #[test]
+    fn test_rendering() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (coach, alice) = seed(&conn);
+        ProgressRepository::add_comment(&conn, alice, coach, "Watch the <toss>").unwrap();
+
+        let report = ProgressReport::load(&conn, alice, None, None, date(3, 20)).unwrap();
+        let markdown = report.render(DocumentFormat::Markdown);
+        assert!(markdown.starts_with("# Progress report: Alice Smith\n"));
+        assert!(markdown.contains("2 sessions subscribed, 1 completed (50%)."));
+        assert!(markdown.contains("| 2026-01-15 | Serve basics | 1 | 2 | 50% |"));
+        assert!(markdown.contains("Overall: 2 of 3 correct (67%)."));
+        assert!(markdown.contains("| 2026-03-10 | Wall volleys | Net play | overdue |"));
+        assert!(markdown.contains("## Skill level\n\n_No changes in this period._\n"));
+        assert!(markdown.contains(", Coach Peter**\n\n> Watch the <toss>\n"));
+
+        let html = report.render(DocumentFormat::Html);
+        assert!(html.contains("<h1>Progress report: Alice Smith</h1>"));
+        assert!(html.contains("Watch the &lt;toss&gt;"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    fn progress_args() -> ProgressArgs {
+        ProgressArgs {
+            player: None,
+            all: false,
+            group: None,
+            from: None,
+            to: None,
+            format: DocumentFormat::Markdown,
+            output: None,
+            output_dir: None,
+        }
+    }
+
+    #[test]
+    fn test_cli_single_and_batch() {
+        let (temp_dir, conn) = setup_test_db();
+        seed(&conn);
+
+        let mut out = Vec::new();
+        let args = ProgressArgs { player: Some("alice".to_string()), ..progress_args() };
+        commands::progress(&conn, &args, false, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("## Quiz scores"));
+
+        let args = ProgressArgs { player: Some("coach".to_string()), ..progress_args() };
+        assert!(commands::progress(&conn, &args, false, &mut Vec::new()).is_err());
+        let args = ProgressArgs {
+            player: Some("alice".to_string()),
+            from: Some("2026-03-01".to_string()),
+            to: Some("2026-01-01".to_string()),
+            ..progress_args()
+        };
+        assert!(commands::progress(&conn, &args, false, &mut Vec::new()).is_err());
+
+        let dir = temp_dir.path().join("term");
+        let args = ProgressArgs {
+            all: true,
+            format: DocumentFormat::Html,
+            to: Some("2026-06-30".to_string()),
+            output_dir: Some(dir.clone()),
+            ..progress_args()
+        };
+        let mut out = Vec::new();
+        commands::progress(&conn, &args, false, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("2 reports written"));
+        assert!(dir.join("progress-alice_2026-06-30.html").exists());
+        assert!(dir.join("progress-bob_2026-06-30.html").exists());
+    }
+
+    #[test]
+    fn test_cli_player_comment() {
+        let (_temp_dir, conn) = setup_test_db();
+
+        let mut out = Vec::new();
+        commands::player_comment(&conn, "bob", "coach", "Solid footwork", false, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().starts_with("Added comment"));
+        let comments = ProgressRepository::comments(&conn, user_id(&conn, "bob"), None, None).unwrap();
+        assert_eq!(comments[0].body, "Solid footwork");
+
+        assert!(commands::player_comment(&conn, "bob", "alice", "x", false, &mut Vec::new()).is_err());
+        assert!(commands::player_comment(&conn, "bob", "coach", "  ", false, &mut Vec::new()).is_err());
+    }
+
+    fn created_id(out: &[u8]) -> i64 {
+        serde_json::from_slice::<serde_json::Value>(out).unwrap()["id"].as_i64().unwrap()
+    }
+
+    #[test]
+    fn test_cli_records_quizzes_homework_and_skill_levels() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user_id(&conn, "coach");
+        let alice = user_id(&conn, "alice");
+        let session = SessionRepository::create(&conn, "Net play", None, Some(date(3, 5)), None, None, None, coach).unwrap();
+        SubscriptionRepository::create(&conn, alice, session).unwrap();
+
+        let mut out = Vec::new();
+        commands::session_quiz(&conn, session, "Grip?", "Continental", Some("continental, eastern"), true, &mut out).unwrap();
+        let quiz = created_id(&out);
+        let mut out = Vec::new();
+        commands::session_homework(&conn, session, "Wall volleys", "10 min", Some("2026-03-10"), true, &mut out).unwrap();
+        let homework = created_id(&out);
+
+        let mut out = Vec::new();
+        commands::player_answer(&conn, "alice", quiz, "eastern", false, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "Recorded alice's answer to quiz 1: incorrect\n");
+        // A second answer replaces the first
+        commands::player_answer(&conn, "alice", quiz, " CONTINENTAL ", false, &mut Vec::new()).unwrap();
+        commands::player_submit(&conn, "alice", homework, Some("done"), false, &mut Vec::new()).unwrap();
+        commands::player_skill(&conn, "alice", "intermediate", false, &mut Vec::new()).unwrap();
+
+        let report = ProgressReport::load(&conn, alice, None, None, date(3, 20)).unwrap();
+        assert_eq!(report.quiz_totals(), (1, 1));
+        assert_eq!(report.homework[0].state, HomeworkState::Submitted);
+        assert_eq!(report.skill_level_changes[0].new_level, Some(SkillLevel::Intermediate));
+
+        // Only subscribed players can answer, and only to existing questions
+        assert!(commands::player_answer(&conn, "bob", quiz, "Continental", false, &mut Vec::new()).is_err());
+        assert!(commands::player_submit(&conn, "alice", 999, None, false, &mut Vec::new()).is_err());
+        assert!(commands::player_skill(&conn, "alice", "expert", false, &mut Vec::new()).is_err());
+        assert!(commands::session_quiz(&conn, session, "Grip?", " ", None, false, &mut Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod reminder_tests {
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use tempfile::TempDir;
+    use tui_coach::cli::commands;
+    use tui_coach::config::Settings;
+    use tui_coach::db::repositories::{
+        ArchiveRepository, SessionRepository, SubscriptionRepository, TrainingContentRepository, UserRepository,
+    };
+    use tui_coach::models::{ArchiveKind, ContentType, ReminderStatus};
+    use tui_coach::reminders::{self, smtp::Relay};
+    use tui_coach::utils::AppError;
+
+    fn setup_test_db() -> (TempDir, rusqlite::Connection) {
+        let (temp_dir, conn) = crate::setup_test_db();
+        let alice = user_id(&conn, "alice");
+        UserRepository::set_email(&conn, alice, Some("alice@club.example")).unwrap();
+        (temp_dir, conn)
+    }
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 11, 1).unwrap().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    /// A session at `start` with Alice and Bob subscribed
+    fn session_at(conn: &rusqlite::Connection, title: &str, start: NaiveDateTime) -> i64 {
+        let id = SessionRepository::create(
+            conn,
+            title,
+            None,
+            Some(start.date()),
+            Some(start.time()),
+            Some(90),
+            None,
+            user_id(conn, "coach"),
+        )
+        .unwrap();
+        SubscriptionRepository::create(conn, user_id(conn, "alice"), id).unwrap();
+        SubscriptionRepository::create(conn, user_id(conn, "bob"), id).unwrap();
+        id
+    }
+
+    /// Local SMTP sink: accepts every message except to `reject`, and passes
+    /// on each accepted message
+    fn smtp_sink(reject: Option<&'static str>) -> (Relay, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let relay = Relay {
+            host: "127.0.0.1".to_string(),
+            port: listener.local_addr().unwrap().port(),
+            from: "coach@club.example".to_string(),
+            helo: "tennis.club.example".to_string(),
+        };
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                stream.write_all(b"220 sink ready\r\n").unwrap();
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 0 {
+                    let reply = match line.trim_end() {
+                        "EHLO tennis.club.example" => "250-sink\r\n250 8BITMIME\r\n".to_string(),
+                        l if l.starts_with("EHLO") => "501 Unexpected host name\r\n".to_string(),
+                        l if reject.is_some_and(|address| l.contains(address)) => "550 No such user\r\n".to_string(),
+                        "DATA" => {
+                            stream.write_all(b"354 Go ahead\r\n").unwrap();
+                            let mut message = String::new();
+                            loop {
+                                let mut data = String::new();
+                                reader.read_line(&mut data).unwrap();
+                                if data == ".\r\n" {
+                                    break;
+                                }
+                                message.push_str(&data);
+                            }
+                            let _ = sender.send(message);
+                            "250 Queued\r\n".to_string()
+                        }
+                        "QUIT" => {
+                            stream.write_all(b"221 Bye\r\n").unwrap();
+                            break;
+                        }
+                        _ => "250 OK\r\n".to_string(),
+                    };
+                    stream.write_all(reply.as_bytes()).unwrap();
+                    line.clear();
+                }
+            }
+        });
+        (relay, receiver)
+    }
+
+    #[test]
+    fn test_only_upcoming_sessions_are_due() {
+        let (_temp_dir, conn) = setup_test_db();
+        let tomorrow = session_at(&conn, "Tomorrow", now() + Duration::hours(22));
+        session_at(&conn, "Next week", now() + Duration::days(7));
+        session_at(&conn, "Already started", now() - Duration::minutes(30));
+        let archived = session_at(&conn, "Archived", now() + Duration::hours(2));
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, archived).unwrap();
+
+        let outcomes = reminders::send_due(&conn, None, now(), 24, None).unwrap();
+        let seen: Vec<(i64, &str, ReminderStatus)> = outcomes
+            .iter()
+            .map(|o| (o.reminder.session_id, o.reminder.username.as_str(), o.status))
+            .collect();
+        assert_eq!(seen, vec![(tomorrow, "alice", ReminderStatus::Pending), (tomorrow, "bob", ReminderStatus::NoEmail)]);
+        assert_eq!(outcomes[0].reminder.starts_at, "2026-11-02 10:00");
+
+        // A dry run records nothing
+        assert_eq!(reminders::send_due(&conn, None, now(), 24, None).unwrap().len(), 2);
+        assert_eq!(reminders::send_due(&conn, None, now(), 96, None).unwrap().len(), 2);
+        assert_eq!(reminders::send_due(&conn, None, now(), 24 * 7, None).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_reminder_is_sent_once_per_start() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (relay, messages) = smtp_sink(None);
+        let start = now() + Duration::hours(22);
+        let session_id = session_at(&conn, "Serve clinic", start);
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Drill, "Serve and volley", None, Some(30), 0).unwrap();
+
+        let outcomes = reminders::send_due(&conn, Some(&relay), now(), 24, Some("Court 3, Riverside")).unwrap();
+        let statuses: Vec<ReminderStatus> = outcomes.iter().map(|o| o.status).collect();
+        assert_eq!(statuses, vec![ReminderStatus::Sent, ReminderStatus::NoEmail]);
+
+        let message = messages.recv().unwrap();
+        assert!(message.contains("To: alice@club.example\r\n"), "{}", message);
+        assert!(message.contains("Subject: Reminder: Serve clinic on Mon 2 Nov at 10:00\r\n"));
+        assert!(message.contains("When:  Monday 2 November 2026, 10:00-11:30"));
+        assert!(message.contains("Where: Court 3, Riverside"));
+        assert!(message.contains("10:00  Serve and volley (drill, 30 min)"));
+        assert!(message.contains("Coach Peter"));
+
+        let again = reminders::send_due(&conn, Some(&relay), now(), 24, None).unwrap();
+        assert!(again.iter().all(|o| o.status == ReminderStatus::NoEmail));
+
+        // Moving the session earns a new reminder
+        let moved = start + Duration::hours(1);
+        SessionRepository::update(&conn, session_id, "Serve clinic", None, Some(moved.date()), Some(moved.time()), Some(90), None, None).unwrap();
+        let outcomes = reminders::send_due(&conn, Some(&relay), now(), 24, None).unwrap();
+        assert_eq!(outcomes[0].status, ReminderStatus::Sent);
+        assert!(messages.recv().unwrap().contains("at 11:00"));
+    }
+
+    #[test]
+    fn test_failed_send_is_retried_next_run() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (refusing, _) = smtp_sink(Some("alice@club.example"));
+        session_at(&conn, "Footwork", now() + Duration::hours(3));
+
+        let outcomes = reminders::send_due(&conn, Some(&refusing), now(), 24, None).unwrap();
+        assert_eq!(outcomes[0].status, ReminderStatus::Failed);
+        assert!(outcomes[0].error.as_deref().unwrap().contains("550"));
+
+        let (relay, messages) = smtp_sink(None);
+        let outcomes = reminders::send_due(&conn, Some(&relay), now(), 24, None).unwrap();
+        assert_eq!(outcomes[0].status, ReminderStatus::Sent);
+        assert!(messages.recv().unwrap().contains("Footwork"));
+    }
+
+    #[test]
+    fn test_remind_command_needs_a_relay_unless_dry_run() {
+        let (_temp_dir, conn) = setup_test_db();
+        session_at(&conn, "Soon", chrono::Local::now().naive_local() + Duration::hours(2));
+
+        let settings = Settings::default();
+        let result = commands::remind(&conn, &settings, None, false, false, &mut Vec::new());
+        assert!(matches!(result, Err(AppError::Validation(message)) if message.contains("smtp.host")));
+
+        let mut out = Vec::new();
+        commands::remind(&conn, &settings, None, true, false, &mut out).unwrap();
+        let listing = String::from_utf8(out).unwrap();
+        assert!(listing.lines().any(|line| line.contains("alice@club.example") && line.contains("pending")), "{}", listing);
+        assert!(listing.lines().any(|line| line.contains("bob") && line.contains("no email")));
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod search_tests {
+    use crate::setup_empty_db;
+    use tempfile::TempDir;
+    use tui_coach::db::repositories::{
+        SearchRepository, SessionRepository, SessionTrainingLinkRepository,
+        SubscriptionRepository, TrainingContentRepository, TrainingTemplateRepository,
+        UserRepository,
+    };
+    use tui_coach::db::{establish_connection, run_migrations};
+    use tui_coach::models::{ContentType, SearchKind, TrainingTemplate, User};
+
+    fn setup_test_db() -> (TempDir, rusqlite::Connection, User, User) {
+        let (temp_dir, conn) = setup_empty_db();
+
+        conn.execute(
+            "INSERT INTO users (username, display_name, role, created_at, updated_at)
+             VALUES (?, ?, ?, datetime('now'), datetime('now'))",
+            ["coach", "Test Coach", "coach"],
+        ).unwrap();
+        conn.execute(
+            "INSERT INTO users (username, display_name, role, created_at, updated_at)
+             VALUES (?, ?, ?, datetime('now'), datetime('now'))",
+            ["alice", "Alice", "player"],
+        ).unwrap();
+
+        let coach = UserRepository::find_by_username(&conn, "coach").unwrap().unwrap();
+        let player = UserRepository::find_by_username(&conn, "alice").unwrap().unwrap();
+        (temp_dir, conn, coach, player)
+    }
+
+    fn create_template(conn: &rusqlite::Connection, coach_id: i64, title: &str, description: &str) -> i64 {
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id,
+            title: title.to_string(),
+            content_type: "drill".to_string(),
+            description: Some(description.to_string()),
+            duration_minutes: Some(15),
+            created_at: String::new(),
+            created_by: coach_id,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: true,
+        };
+        TrainingTemplateRepository::create(conn, &template, coach_id).unwrap()
+    }
+
+    #[test]
+    fn test_search_finds_each_kind_grouped() {
+        let (_temp_dir, conn, coach, player) = setup_test_db();
+
+        let session_id = SessionRepository::create(&conn, "Volley Basics", Some("Soft hands at the net"), None, None, None, None, coach.id).unwrap();
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Drill, "Reflex volleys", None, Some(10), 1).unwrap();
+        let template_id = create_template(&conn, coach.id, "Drop volley", "Touch drill");
+        SessionTrainingLinkRepository::add_to_session(&conn, session_id, template_id, 0).unwrap();
+        let sub_id = SubscriptionRepository::create(&conn, player.id, session_id).unwrap();
+        conn.execute("UPDATE subscriptions SET notes = 'Volley felt better today' WHERE id = ?", [sub_id]).unwrap();
+
+        let hits = SearchRepository::search(&conn, "volley", &coach, 50).unwrap();
+        let kinds: Vec<SearchKind> = hits.iter().map(|h| h.kind).collect();
+        assert_eq!(kinds, vec![SearchKind::Session, SearchKind::Content, SearchKind::Template, SearchKind::Note]);
+
+        // Every hit resolves to the session it belongs to
+        assert!(hits.iter().all(|h| h.session_id == Some(session_id)));
+        // Notes are titled after their session
+        assert_eq!(hits[3].title, "Volley Basics");
+    }
+
+    #[test]
+    fn test_search_prefix_and_stemming() {
+        let (_temp_dir, conn, coach, _) = setup_test_db();
+        SessionRepository::create(&conn, "Serving practice", None, None, None, None, None, coach.id).unwrap();
+
+        assert_eq!(SearchRepository::search(&conn, "serv", &coach, 50).unwrap().len(), 1);
+        assert_eq!(SearchRepository::search(&conn, "serves", &coach, 50).unwrap().len(), 1);
+        assert_eq!(SearchRepository::search(&conn, "serve practice", &coach, 50).unwrap().len(), 1);
+        assert!(SearchRepository::search(&conn, "serve footwork", &coach, 50).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_ignores_fts_syntax() {
+        let (_temp_dir, conn, coach, _) = setup_test_db();
+        SessionRepository::create(&conn, "Kick serve", None, None, None, None, None, coach.id).unwrap();
+
+        assert_eq!(SearchRepository::search(&conn, "\"kick-serve\" (", &coach, 50).unwrap().len(), 1);
+        assert!(SearchRepository::search(&conn, "  *  ", &coach, 50).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_index_follows_updates_and_deletes() {
+        let (_temp_dir, conn, coach, _) = setup_test_db();
+        let session_id = SessionRepository::create(&conn, "Footwork", None, None, None, None, None, coach.id).unwrap();
+        let content_id = TrainingContentRepository::create(&conn, session_id, &ContentType::Warmup, "Ladder", None, None, 1).unwrap();
+
+        SessionRepository::update(&conn, session_id, "Movement", None, None, None, None, None, None).unwrap();
+        assert!(SearchRepository::search(&conn, "footwork", &coach, 50).unwrap().is_empty());
+        assert_eq!(SearchRepository::search(&conn, "movement", &coach, 50).unwrap().len(), 1);
+
+        TrainingContentRepository::delete(&conn, content_id).unwrap();
+        assert!(SearchRepository::search(&conn, "ladder", &coach, 50).unwrap().is_empty());
+
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Warmup, "Ladder", None, None, 1).unwrap();
+        SessionRepository::delete(&conn, session_id).unwrap();
+        assert!(SearchRepository::search(&conn, "ladder", &coach, 50).unwrap().is_empty());
+        assert!(SearchRepository::search(&conn, "movement", &coach, 50).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_respects_visibility() {
+        let (_temp_dir, conn, coach, player) = setup_test_db();
+        conn.execute(
+            "INSERT INTO users (username, display_name, role) VALUES ('bob', 'Bob', 'player')",
+            [],
+        ).unwrap();
+        let other = UserRepository::find_by_username(&conn, "bob").unwrap().unwrap();
+        conn.execute(
+            "INSERT INTO users (username, display_name, role) VALUES ('coach2', 'Other Coach', 'coach')",
+            [],
+        ).unwrap();
+        let other_coach = UserRepository::find_by_username(&conn, "coach2").unwrap().unwrap();
+
+        let session_id = SessionRepository::create(&conn, "Rally", None, None, None, None, None, coach.id).unwrap();
+        let sub_id = SubscriptionRepository::create(&conn, other.id, session_id).unwrap();
+        conn.execute("UPDATE subscriptions SET notes = 'backhand slice' WHERE id = ?", [sub_id]).unwrap();
+
+        // Other players' notes are private
+        assert!(SearchRepository::search(&conn, "slice", &player, 50).unwrap().is_empty());
+        assert_eq!(SearchRepository::search(&conn, "slice", &other, 50).unwrap().len(), 1);
+        assert_eq!(SearchRepository::search(&conn, "slice", &coach, 50).unwrap().len(), 1);
+
+        // Coaches only search their own sessions
+        assert_eq!(SearchRepository::search(&conn, "rally", &player, 50).unwrap().len(), 1);
+        assert!(SearchRepository::search(&conn, "rally", &other_coach, 50).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unused_template_has_no_session() {
+        let (_temp_dir, conn, coach, _) = setup_test_db();
+        create_template(&conn, coach.id, "Split step", "Timing");
+
+        let hits = SearchRepository::search(&conn, "split", &coach, 50).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].kind, SearchKind::Template);
+        assert_eq!(hits[0].session_id, None);
+    }
+
+    #[test]
+    fn test_migration_backfills_existing_rows() {
+        let (temp_dir, conn, coach, _) = setup_test_db();
+        SessionRepository::create(&conn, "Lob defence", None, None, None, None, None, coach.id).unwrap();
+
+        // Simulate a database created before the search index existed
+        conn.execute_batch("DROP TABLE search_index").unwrap();
+        drop(conn);
+
+        let conn = establish_connection(temp_dir.path().join("test.db").to_str().unwrap()).unwrap();
+        run_migrations(&conn).unwrap();
+        assert_eq!(SearchRepository::search(&conn, "lob", &coach, 50).unwrap().len(), 1);
+
+        // Re-running migrations doesn't index rows twice
+        run_migrations(&conn).unwrap();
+        assert_eq!(SearchRepository::search(&conn, "lob", &coach, 50).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod session_plan_tests {
+    use chrono::{NaiveDate, NaiveTime};
+    use tempfile::TempDir;
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{
+        SessionRepository, SessionTrainingLinkRepository, TrainingContentRepository,
+        TrainingTemplateRepository, UserRepository,
+    };
+    use tui_coach::export::plan::SessionPlan;
+use tui_coach::export::DocumentFormat;
+    use tui_coach::models::{ContentType, SkillLevel, TrainingTemplate};
+
+    fn setup_test_db() -> (TempDir, rusqlite::Connection, i64) {
+        let (temp_dir, conn) = crate::setup_test_db();
+        let coach_id = UserRepository::find_by_username(&conn, "coach").unwrap().unwrap().id;
+        (temp_dir, conn, coach_id)
+    }
+
+    /// 18:00 session: warmup (10), linked template (20, notes), drill without duration, cooldown (5)
+    fn create_session(conn: &rusqlite::Connection, coach_id: i64, duration: i32) -> i64 {
+        let session_id = SessionRepository::create(
+            conn, "Serve <Clinic> & more", Some("Kick serve focus\nBring balls"),
+            NaiveDate::from_ymd_opt(2026, 3, 2), NaiveTime::from_hms_opt(18, 0, 0), Some(duration),
+            Some(&SkillLevel::Beginner), coach_id,
+        )
+        .unwrap();
+        TrainingContentRepository::create(conn, session_id, &ContentType::Warmup, "Jog | skip", None, Some(10), 0).unwrap();
+        TrainingContentRepository::create(conn, session_id, &ContentType::Drill, "Shadow swings", Some("Slow motion"), None, 2).unwrap();
+        TrainingContentRepository::create(conn, session_id, &ContentType::Cooldown, "Stretch", None, Some(5), 3).unwrap();
+
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id,
+            title: "Target serves".to_string(),
+            content_type: "drill".to_string(),
+            description: Some("Cones in the corners".to_string()),
+            duration_minutes: Some(20),
+            created_at: String::new(),
+            created_by: coach_id,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: true,
+        };
+        let template_id = TrainingTemplateRepository::create(conn, &template, coach_id).unwrap();
+        SessionTrainingLinkRepository::add_to_session_with_notes(conn, session_id, template_id, 1, Some("Wide <T>"))
+            .unwrap();
+        session_id
+    }
+
+    #[test]
+    fn test_items_are_merged_in_order_with_timings() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = create_session(&conn, coach_id, 60);
+
+        let plan = SessionPlan::load(&conn, session_id).unwrap();
+        let timings: Vec<(&str, i32, Option<NaiveTime>)> = plan
+            .items
+            .iter()
+            .map(|i| (i.title.as_str(), i.start_offset_minutes, i.start_time))
+            .collect();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+        assert_eq!(
+            timings,
+            vec![
+                ("Jog | skip", 0, at(18, 0)),
+                ("Target serves", 10, at(18, 10)),
+                ("Shadow swings", 30, at(18, 30)),
+                ("Stretch", 30, at(18, 30)),
+            ]
+        );
+        assert_eq!(plan.items[1].notes, Some("Wide <T>".to_string()));
+        assert_eq!(plan.total_minutes(), 35);
+        assert_eq!(plan.overrun_minutes(), None);
+    }
+
+    #[test]
+    fn test_markdown() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = create_session(&conn, coach_id, 30);
+
+        let markdown = SessionPlan::load(&conn, session_id).unwrap().to_markdown();
+        assert!(markdown.starts_with("# Serve <Clinic> & more\n"));
+        assert!(markdown.contains("- **Time:** 18:00\n"));
+        assert!(markdown.contains("- **Duration:** 30 min scheduled, 35 min planned (5 min over)\n"));
+        assert!(markdown.contains("| 1 | 18:00 (+0) | 10 | warmup | Jog \\| skip |\n"));
+        assert!(markdown.contains("| 3 | 18:30 (+30) | - | drill | Shadow swings |\n"));
+        assert!(markdown.contains("### 2. Target serves (18:10 (+10))\n\nCones in the corners\n\n> **Notes:** Wide <T>\n"));
+    }
+
+    #[test]
+    fn test_html_is_escaped_and_self_contained() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = create_session(&conn, coach_id, 60);
+
+        let html = SessionPlan::load(&conn, session_id).unwrap().to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>Serve &lt;Clinic&gt; &amp; more</h1>"));
+        assert!(html.contains("<p>Kick serve focus<br>Bring balls</p>"));
+        assert!(html.contains("Notes: Wide &lt;T&gt;"));
+        assert!(!html.contains("<T>"));
+        assert!(!html.contains("http"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    #[test]
+    fn test_unscheduled_session_uses_offsets() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = SessionRepository::create(&conn, "Open court", None, None, None, None, None, coach_id).unwrap();
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Drill, "Rally", None, Some(15), 0).unwrap();
+        TrainingContentRepository::create(&conn, session_id, &ContentType::Drill, "Points", None, Some(15), 1).unwrap();
+
+        let plan = SessionPlan::load(&conn, session_id).unwrap();
+        assert_eq!(plan.file_name(DocumentFormat::Html), format!("plan-{}-undated.html", session_id));
+        assert!(plan.to_markdown().contains("| 2 | +15 min | 15 | drill | Points |"));
+
+        let empty_id = SessionRepository::create(&conn, "Empty", None, None, None, None, None, coach_id).unwrap();
+        assert!(SessionPlan::load(&conn, empty_id).unwrap().to_markdown().contains("_No training content yet._"));
+    }
+
+    #[test]
+    fn test_cli_session_plan() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = create_session(&conn, coach_id, 60);
+
+        let mut out = Vec::new();
+        commands::session_plan(&conn, session_id, DocumentFormat::Html, false, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("<table>"));
+
+        let mut out = Vec::new();
+        commands::session_plan(&conn, session_id, DocumentFormat::Markdown, true, &mut out).unwrap();
+        let plan: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(plan["items"][1]["start_offset_minutes"], 10);
+
+        assert!(commands::session_plan(&conn, 999, DocumentFormat::Markdown, false, &mut Vec::new()).is_err());
+    }
+}
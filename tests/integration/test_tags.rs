@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tag_tests {
+    use crate::setup_empty_db;
+    use tempfile::TempDir;
+    use tui_coach::db::repositories::{SessionRepository, TagRepository, TrainingTemplateRepository};
+    use tui_coach::models::TrainingTemplate;
+
+    fn setup_test_db() -> (TempDir, rusqlite::Connection, i64) {
+        let (temp_dir, conn) = setup_empty_db();
+
+        conn.execute(
+            "INSERT INTO users (username, display_name, role, created_at, updated_at)
+             VALUES (?, ?, ?, datetime('now'), datetime('now'))",
+            ["coach", "Test Coach", "coach"],
+        ).unwrap();
+        let coach_id = conn.last_insert_rowid();
+
+        (temp_dir, conn, coach_id)
+    }
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn test_set_and_find_session_tags() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = SessionRepository::create(&conn, "Serve Clinic", None, None, None, None, None, coach_id).unwrap();
+
+        TagRepository::set_session_tags(&conn, session_id, &tags(&["serve", "fitness"])).unwrap();
+        assert_eq!(TagRepository::find_by_session(&conn, session_id).unwrap(), tags(&["fitness", "serve"]));
+
+        // Setting again replaces the previous tags
+        TagRepository::set_session_tags(&conn, session_id, &tags(&["doubles"])).unwrap();
+        assert_eq!(TagRepository::find_by_session(&conn, session_id).unwrap(), tags(&["doubles"]));
+
+        // Tags themselves are kept for reuse
+        assert_eq!(TagRepository::find_all(&conn).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_find_or_create_is_case_insensitive() {
+        let (_temp_dir, conn, _) = setup_test_db();
+
+        let id1 = TagRepository::find_or_create(&conn, "serve").unwrap();
+        let id2 = TagRepository::find_or_create(&conn, "Serve").unwrap();
+        assert_eq!(id1, id2);
+    }
+
+    #[test]
+    fn test_find_all_session_tags() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let s1 = SessionRepository::create(&conn, "Session 1", None, None, None, None, None, coach_id).unwrap();
+        let s2 = SessionRepository::create(&conn, "Session 2", None, None, None, None, None, coach_id).unwrap();
+        let s3 = SessionRepository::create(&conn, "Session 3", None, None, None, None, None, coach_id).unwrap();
+
+        TagRepository::set_session_tags(&conn, s1, &tags(&["serve"])).unwrap();
+        TagRepository::set_session_tags(&conn, s2, &tags(&["serve", "footwork"])).unwrap();
+
+        let all = TagRepository::find_all_session_tags(&conn).unwrap();
+        assert_eq!(all.get(&s1), Some(&tags(&["serve"])));
+        assert_eq!(all.get(&s2), Some(&tags(&["footwork", "serve"])));
+        assert!(!all.contains_key(&s3));
+    }
+
+    #[test]
+    fn test_session_delete_removes_tag_links() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = SessionRepository::create(&conn, "Session", None, None, None, None, None, coach_id).unwrap();
+        TagRepository::set_session_tags(&conn, session_id, &tags(&["serve"])).unwrap();
+
+        SessionRepository::delete(&conn, session_id).unwrap();
+        assert!(TagRepository::find_all_session_tags(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_copies_tags() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let session_id = SessionRepository::create(&conn, "Session", None, None, None, None, None, coach_id).unwrap();
+        TagRepository::set_session_tags(&conn, session_id, &tags(&["serve", "doubles"])).unwrap();
+
+        let copy_id = SessionRepository::duplicate(&conn, session_id, None, None, coach_id).unwrap();
+        assert_eq!(TagRepository::find_by_session(&conn, copy_id).unwrap(), tags(&["doubles", "serve"]));
+    }
+
+    #[test]
+    fn test_template_tags() {
+        let (_temp_dir, conn, coach_id) = setup_test_db();
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id,
+            title: "Split Step".to_string(),
+            content_type: "drill".to_string(),
+            description: None,
+            duration_minutes: Some(10),
+            created_at: String::new(),
+            created_by: coach_id,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: true,
+        };
+        let template_id = TrainingTemplateRepository::create(&conn, &template, coach_id).unwrap();
+
+        TagRepository::set_template_tags(&conn, template_id, &tags(&["footwork"])).unwrap();
+        assert_eq!(TagRepository::find_by_template(&conn, template_id).unwrap(), tags(&["footwork"]));
+    }
+}
@@ -0,0 +1,242 @@
+#[cfg(test)]
+mod unit_of_work_tests {
+    use crate::setup_test_db;
+    use tui_coach::db::repositories::{
+        SessionRepository, SessionTrainingLinkRepository, SubscriptionRepository, TrainingTemplateRepository,
+        UserRepository,
+    };
+    use tui_coach::db::{atomically, UnitOfWork};
+    use tui_coach::models::TrainingTemplate;
+    use tui_coach::utils::AppError;
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    fn session_count(conn: &rusqlite::Connection) -> i64 {
+        conn.query_row("SELECT COUNT(*) FROM sessions", [], |row| row.get(0)).unwrap()
+    }
+
+    fn create_session(conn: &rusqlite::Connection, title: &str) -> i64 {
+        let coach = user_id(conn, "coach");
+        SessionRepository::create(conn, title, None, None, None, Some(60), None, coach).unwrap()
+    }
+
+    fn create_template(conn: &rusqlite::Connection, title: &str) -> i64 {
+        let coach = user_id(conn, "coach");
+        let template = TrainingTemplate {
+            id: 0,
+            coach_id: coach,
+            title: title.to_string(),
+            content_type: "drill".to_string(),
+            description: None,
+            duration_minutes: Some(10),
+            created_at: String::new(),
+            created_by: coach,
+            last_edited_by: None,
+            last_edited_at: None,
+            is_public: true,
+        };
+        TrainingTemplateRepository::create(conn, &template, coach).unwrap()
+    }
+
+    /// Session with templates A, B, C linked in that order
+    fn seed_plan(conn: &rusqlite::Connection) -> (i64, [i64; 3]) {
+        let session_id = create_session(conn, "Footwork");
+        let templates = [create_template(conn, "A"), create_template(conn, "B"), create_template(conn, "C")];
+        for (order_index, template_id) in templates.iter().enumerate() {
+            SessionTrainingLinkRepository::add_to_session(conn, session_id, *template_id, order_index as i32).unwrap();
+        }
+        (session_id, templates)
+    }
+
+    fn plan_order(conn: &rusqlite::Connection, session_id: i64) -> Vec<(i64, i32)> {
+        SessionTrainingLinkRepository::get_for_session(conn, session_id)
+            .unwrap()
+            .iter()
+            .map(|link| (link.training_template_id, link.order_index))
+            .collect()
+    }
+
+    #[test]
+    fn test_commit_keeps_and_rollback_discards_changes() {
+        let (_temp_dir, conn) = setup_test_db();
+        let before = session_count(&conn);
+
+        let uow = UnitOfWork::begin(&conn).unwrap();
+        create_session(&uow, "Kept");
+        uow.commit().unwrap();
+        assert_eq!(session_count(&conn), before + 1);
+
+        let uow = UnitOfWork::begin(&conn).unwrap();
+        create_session(&uow, "Rolled back");
+        uow.rollback().unwrap();
+        assert_eq!(session_count(&conn), before + 1);
+
+        {
+            let uow = UnitOfWork::begin(&conn).unwrap();
+            create_session(&uow, "Dropped");
+        }
+        assert_eq!(session_count(&conn), before + 1);
+    }
+
+    #[test]
+    fn test_atomically_rolls_back_on_error() {
+        let (_temp_dir, conn) = setup_test_db();
+        let before = session_count(&conn);
+
+        let result: tui_coach::utils::Result<()> = atomically(&conn, |conn| {
+            create_session(conn, "Half done");
+            Err(AppError::Validation("stop".to_string()))
+        });
+        assert!(result.is_err());
+        assert_eq!(session_count(&conn), before);
+
+        let id = atomically(&conn, |conn| Ok(create_session(conn, "Done"))).unwrap();
+        assert!(SessionRepository::find_by_id(&conn, id).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_outermost_unit_takes_the_write_lock_at_begin() {
+        let (temp_dir, conn) = setup_test_db();
+        let other = crate::open_db(&temp_dir, "test.db");
+        other.busy_timeout(std::time::Duration::ZERO).unwrap();
+
+        let uow = UnitOfWork::begin(&conn).unwrap();
+        assert!(!conn.is_autocommit());
+        // Nothing has been written yet, but a second writer is already locked out
+        let err = UnitOfWork::begin(&other).err().expect("second writer should be busy");
+        assert!(err.is_busy());
+        assert!(other.is_autocommit());
+
+        let inner = UnitOfWork::begin(&uow).unwrap();
+        create_session(&inner, "Inner");
+        inner.commit().unwrap();
+        assert!(!conn.is_autocommit(), "a nested commit must not end the transaction");
+        uow.commit().unwrap();
+        assert!(conn.is_autocommit());
+
+        let before = session_count(&other);
+        let uow = UnitOfWork::begin(&other).unwrap();
+        create_session(&uow, "Other");
+        uow.commit().unwrap();
+        assert_eq!(session_count(&conn), before + 1);
+    }
+
+    #[test]
+    fn test_failed_inner_unit_only_undoes_its_own_writes() {
+        let (_temp_dir, conn) = setup_test_db();
+        let alice = user_id(&conn, "alice");
+        let bob = user_id(&conn, "bob");
+        let before = session_count(&conn);
+
+        let outer = UnitOfWork::begin(&conn).unwrap();
+        let session_id = create_session(&outer, "Outer");
+        SubscriptionRepository::create(&outer, alice, session_id).unwrap();
+        // Alice is already subscribed, so the whole bulk subscribe fails and Bob is not added
+        assert!(SubscriptionRepository::create_many(&outer, &[bob, alice], session_id).is_err());
+        outer.commit().unwrap();
+
+        assert_eq!(session_count(&conn), before + 1);
+        let subscribers: Vec<i64> = SubscriptionRepository::find_by_session(&conn, session_id)
+            .unwrap()
+            .iter()
+            .map(|s| s.user_id)
+            .collect();
+        assert_eq!(subscribers, vec![alice]);
+    }
+
+    #[test]
+    fn test_composed_clone_and_subscribe_roll_back_together() {
+        let (_temp_dir, conn) = setup_test_db();
+        let coach = user_id(&conn, "coach");
+        let alice = user_id(&conn, "alice");
+        let bob = user_id(&conn, "bob");
+        let (session_id, _) = seed_plan(&conn);
+        let before = session_count(&conn);
+
+        let result = atomically(&conn, |conn| {
+            let copy_id = SessionRepository::duplicate(conn, session_id, None, None, coach)?;
+            SubscriptionRepository::create_many(conn, &[alice, bob], copy_id)?;
+            SessionTrainingLinkRepository::reorder_in_session(conn, copy_id, &[999])
+        });
+        assert!(result.is_err());
+        assert_eq!(session_count(&conn), before);
+        let links: i64 = conn.query_row("SELECT COUNT(*) FROM session_training_links", [], |row| row.get(0)).unwrap();
+        assert_eq!(links, 3);
+
+        let copy_id = atomically(&conn, |conn| {
+            let copy_id = SessionRepository::duplicate(conn, session_id, None, None, coach)?;
+            SubscriptionRepository::create_many(conn, &[alice, bob], copy_id)?;
+            Ok(copy_id)
+        })
+        .unwrap();
+        assert_eq!(SubscriptionRepository::find_by_session(&conn, copy_id).unwrap().len(), 2);
+        assert_eq!(plan_order(&conn, copy_id).len(), 3);
+    }
+
+    #[test]
+    fn test_reorder_permutes_without_unique_conflicts() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (session_id, [a, b, c]) = seed_plan(&conn);
+
+        SessionTrainingLinkRepository::reorder_in_session(&conn, session_id, &[c, a, b]).unwrap();
+        assert_eq!(plan_order(&conn, session_id), vec![(c, 0), (a, 1), (b, 2)]);
+
+        SessionTrainingLinkRepository::reorder_in_session(&conn, session_id, &[b, a, c]).unwrap();
+        assert_eq!(plan_order(&conn, session_id), vec![(b, 0), (a, 1), (c, 2)]);
+    }
+
+    #[test]
+    fn test_reorder_keeps_repeated_templates_distinct() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (session_id, [a, b, _]) = seed_plan(&conn);
+        SessionTrainingLinkRepository::remove_from_session(&conn, session_id, b).unwrap();
+        SessionTrainingLinkRepository::add_to_session_with_notes(&conn, session_id, a, 1, Some("again")).unwrap();
+
+        let before = SessionTrainingLinkRepository::get_for_session(&conn, session_id).unwrap();
+        let ids: Vec<i64> = before.iter().map(|link| link.training_template_id).collect();
+        let reversed: Vec<i64> = ids.iter().rev().copied().collect();
+        SessionTrainingLinkRepository::reorder_in_session(&conn, session_id, &reversed).unwrap();
+
+        let after = SessionTrainingLinkRepository::get_for_session(&conn, session_id).unwrap();
+        assert_eq!(after.iter().map(|link| link.training_template_id).collect::<Vec<_>>(), reversed);
+        assert_eq!(after.len(), before.len());
+    }
+
+    #[test]
+    fn test_reorder_rejects_incomplete_or_unknown_orders() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (session_id, [a, b, c]) = seed_plan(&conn);
+        let original = plan_order(&conn, session_id);
+
+        for order in [vec![c, a], vec![c, a, b, b], vec![c, a, 999]] {
+            let err = SessionTrainingLinkRepository::reorder_in_session(&conn, session_id, &order).unwrap_err();
+            assert!(matches!(err, AppError::Validation(_)), "{:?}", order);
+            assert_eq!(plan_order(&conn, session_id), original);
+        }
+    }
+
+    #[test]
+    fn test_reorder_failing_midway_leaves_order_unchanged() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (session_id, [a, b, c]) = seed_plan(&conn);
+        let original = plan_order(&conn, session_id);
+
+        // Fail on the last link's final update, after the others have moved
+        conn.execute_batch(&format!(
+            "CREATE TEMP TRIGGER fail_reorder BEFORE UPDATE OF order_index ON session_training_links
+             WHEN NEW.training_template_id = {} AND NEW.order_index >= 0
+             BEGIN SELECT RAISE(ABORT, 'disk on fire'); END;",
+            b
+        ))
+        .unwrap();
+
+        assert!(SessionTrainingLinkRepository::reorder_in_session(&conn, session_id, &[c, a, b]).is_err());
+        assert_eq!(plan_order(&conn, session_id), original);
+
+        conn.execute_batch("DROP TRIGGER fail_reorder").unwrap();
+        SessionTrainingLinkRepository::reorder_in_session(&conn, session_id, &[c, a, b]).unwrap();
+        assert_eq!(plan_order(&conn, session_id), vec![(c, 0), (a, 1), (b, 2)]);
+    }
+}
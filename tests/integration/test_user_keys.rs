@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod user_keys_tests {
+    use crate::setup_test_db;
+    use std::path::Path;
+    use tui_coach::auth::PublicKey;
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{AuditRepository, UserKeyRepository, UserRepository};
+    use tui_coach::models::AuditQuery;
+
+    const ALICE_KEY: &str =
+        "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIF3e7CepJHjPkn7kXRO8cLib/SCEiOwqpRyN4Bz21SMd alice@laptop";
+    const BOB_KEY: &str = "ecdsa-sha2-nistp256 AAAAE2VjZHNhLXNoYTItbmlzdHAyNTYAAAAIbmlzdHAyNTYAAABBBKCnrRedRNliiheFGLaW5bSUasBeBXSrO1rCOeerjwWRH5WrEj75Bmj+WEbpx1RHxGVUhy3ki5VdMhE3fwZSHJ0= bob";
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    fn add_key(conn: &rusqlite::Connection, username: &str, line: &str) -> i64 {
+        UserKeyRepository::add(conn, user_id(conn, username), &PublicKey::parse(line).unwrap(), Some("laptop")).unwrap()
+    }
+
+    fn authorized_keys(conn: &rusqlite::Connection, program: &str) -> String {
+        let mut out = Vec::new();
+        commands::authorized_keys(conn, Path::new(program), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_key_is_stored_with_its_fingerprint() {
+        let (_temp_dir, conn) = setup_test_db();
+        add_key(&conn, "alice", ALICE_KEY);
+
+        let key = UserKeyRepository::find_by_fingerprint(&conn, "SHA256:soHN6GQezxkPNU1pZMr1rwv0scJxIpDy4a2LfsAe8bQ")
+            .unwrap()
+            .unwrap();
+        assert_eq!(key.username, "alice");
+        assert_eq!(key.label.as_deref(), Some("laptop"));
+        assert_eq!(key.openssh(), ALICE_KEY.trim_end_matches(" alice@laptop"));
+        assert!(!key.is_revoked());
+    }
+
+    #[test]
+    fn test_key_can_only_belong_to_one_user() {
+        let (_temp_dir, conn) = setup_test_db();
+        add_key(&conn, "alice", ALICE_KEY);
+
+        let key = PublicKey::parse(ALICE_KEY).unwrap();
+        let err = UserKeyRepository::add(&conn, user_id(&conn, "bob"), &key, None).unwrap_err();
+        assert!(err.to_string().contains("already registered for alice"), "{}", err);
+    }
+
+    #[test]
+    fn test_authorized_keys_forces_the_tui_for_the_key_owner() {
+        let (_temp_dir, conn) = setup_test_db();
+        add_key(&conn, "bob", BOB_KEY);
+        add_key(&conn, "alice", ALICE_KEY);
+
+        let output = authorized_keys(&conn, "/opt/tennis tui/bin/tennis-tui");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let db_path = conn.path().unwrap();
+        assert_eq!(
+            lines[0],
+            format!(
+                "command=\"'/opt/tennis tui/bin/tennis-tui' --db-path '{}' run --user 'alice'\",restrict,pty {} alice",
+                db_path,
+                ALICE_KEY.trim_end_matches(" alice@laptop")
+            )
+        );
+        assert!(lines[1].contains("run --user 'bob'") && lines[1].contains("ecdsa-sha2-nistp256 AAAAE2"));
+    }
+
+    #[test]
+    fn test_authorized_keys_quotes_program_for_the_shell() {
+        let (_temp_dir, conn) = setup_test_db();
+        add_key(&conn, "alice", ALICE_KEY);
+
+        let output = authorized_keys(&conn, "/opt/it's \"here\"/tennis-tui");
+        assert!(output.starts_with("command=\"'/opt/it'\\''s \\\"here\\\"/tennis-tui' --db-path"), "{}", output);
+    }
+
+    #[test]
+    fn test_revoked_and_orphaned_keys_are_not_authorized() {
+        let (_temp_dir, conn) = setup_test_db();
+        let alice_key = add_key(&conn, "alice", ALICE_KEY);
+        add_key(&conn, "bob", BOB_KEY);
+
+        conn.execute("UPDATE user_keys SET revoked_at = datetime('now') WHERE id = ?", [alice_key]).unwrap();
+        let output = authorized_keys(&conn, "tennis-tui");
+        assert!(!output.contains("alice") && output.contains("bob"));
+
+        conn.execute("DELETE FROM users WHERE username = 'bob'", []).unwrap();
+        assert_eq!(authorized_keys(&conn, "tennis-tui"), "");
+        assert!(UserKeyRepository::find_active(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_key_changes_are_audited() {
+        let (_temp_dir, conn) = setup_test_db();
+        let key_id = add_key(&conn, "alice", ALICE_KEY);
+
+        let entries = AuditRepository::find(&conn, &AuditQuery::default()).unwrap();
+        assert_eq!(entries[0].entity, "user_keys");
+        assert_eq!(entries[0].entity_id, Some(key_id));
+        assert_eq!(entries[0].after.as_ref().unwrap()["label"], "laptop");
+    }
+
+    #[test]
+    fn test_revoke_and_relabel_keys() {
+        let (_temp_dir, conn) = setup_test_db();
+        let alice_key = add_key(&conn, "alice", ALICE_KEY);
+        add_key(&conn, "bob", BOB_KEY);
+
+        UserKeyRepository::set_label(&conn, alice_key, Some("desktop")).unwrap();
+        UserKeyRepository::revoke(&conn, alice_key).unwrap();
+        assert!(UserKeyRepository::revoke(&conn, alice_key).is_err());
+
+        let keys = UserKeyRepository::find_by_user(&conn, user_id(&conn, "alice")).unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].label.as_deref(), Some("desktop"));
+        assert!(keys[0].is_revoked());
+        assert_eq!(UserKeyRepository::find_all(&conn).unwrap().len(), 2);
+
+        UserKeyRepository::set_label(&conn, alice_key, None).unwrap();
+        assert_eq!(UserKeyRepository::find_by_id(&conn, alice_key).unwrap().unwrap().label, None);
+        assert!(UserKeyRepository::set_label(&conn, 999, Some("x")).is_err());
+    }
+
+    #[test]
+    fn test_cli_key_add_list_label_and_revoke() {
+        let (temp_dir, conn) = setup_test_db();
+        let file = temp_dir.path().join("id_ed25519.pub");
+        std::fs::write(&file, format!("{}\n", ALICE_KEY)).unwrap();
+
+        let mut out = Vec::new();
+        commands::key_add(&conn, "alice", &file, None, false, &mut out).unwrap();
+        let key = UserKeyRepository::find_by_user(&conn, user_id(&conn, "alice")).unwrap().remove(0);
+        assert_eq!(key.label.as_deref(), Some("alice@laptop"));
+        assert_eq!(
+            String::from_utf8(out).unwrap().trim(),
+            format!("Added key {} ({}) for alice", key.id, key.fingerprint)
+        );
+        assert!(commands::key_add(&conn, "bob", &file, None, false, &mut Vec::new()).is_err());
+
+        commands::key_label(&conn, &key.fingerprint, "work laptop", false, &mut Vec::new()).unwrap();
+        commands::key_revoke(&conn, &key.id.to_string(), false, &mut Vec::new()).unwrap();
+        assert!(commands::key_revoke(&conn, "SHA256:unknown", false, &mut Vec::new()).is_err());
+
+        let mut out = Vec::new();
+        commands::key_list(&conn, Some("alice"), false, &mut out).unwrap();
+        let output = String::from_utf8(out).unwrap();
+        let row = output.lines().nth(2).unwrap();
+        assert!(row.contains("work laptop") && row.contains(&key.fingerprint) && row.contains("revoked"), "{}", row);
+
+        let mut out = Vec::new();
+        commands::key_list(&conn, None, true, &mut out).unwrap();
+        let keys: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(keys[0]["username"], "alice");
+        assert!(keys[0]["revoked_at"].is_string());
+    }
+}
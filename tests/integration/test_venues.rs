@@ -0,0 +1,339 @@
+#[cfg(test)]
+mod venue_tests {
+    use crate::open_db;
+    use chrono::{NaiveDate, NaiveTime};
+    use tempfile::TempDir;
+    use tui_coach::cli::args::SessionCreateArgs;
+    use tui_coach::cli::commands;
+    use tui_coach::db::backup::{self, ImportMode};
+    use tui_coach::db::repositories::{ArchiveRepository, SessionRepository, UserRepository, VenueRepository};
+    use tui_coach::models::{ArchiveKind, Booking, ClashKind};
+    use tui_coach::utils::AppError;
+
+    /// Sample users plus a second coach and a venue with two courts
+    fn setup_test_db() -> (TempDir, rusqlite::Connection, i64, i64) {
+        let (temp_dir, conn) = crate::setup_test_db();
+        conn.execute(
+            "INSERT INTO users (username, display_name, role) VALUES ('coach2', 'Coach Sam', 'coach')",
+            [],
+        )
+        .unwrap();
+
+        let venue_id = VenueRepository::create(&conn, "Riverside", Some("1 River Rd")).unwrap();
+        let court_1 = VenueRepository::add_court(&conn, venue_id, "Court 1").unwrap();
+        let court_2 = VenueRepository::add_court(&conn, venue_id, "Court 2").unwrap();
+        (temp_dir, conn, court_1, court_2)
+    }
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    /// `session create` arguments for a 90 minute session on 2026-11-02
+    fn args(coach: &str, title: &str, time: &str, court: Option<i64>) -> SessionCreateArgs {
+        SessionCreateArgs {
+            coach: coach.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            date: Some("2026-11-02".to_string()),
+            time: Some(time.to_string()),
+            duration: Some(90),
+            skill_level: None,
+            tags: String::new(),
+            court,
+        }
+    }
+
+    fn booking(conn: &rusqlite::Connection, coach: &str, time: &str, court_id: Option<i64>) -> Booking {
+        Booking {
+            date: NaiveDate::from_ymd_opt(2026, 11, 2),
+            time: NaiveTime::parse_from_str(time, "%H:%M").ok(),
+            duration_minutes: Some(60),
+            court_id,
+            coach_id: user_id(conn, coach),
+            session_id: None,
+        }
+    }
+
+    #[test]
+    fn test_create_books_court() {
+        let (_temp_dir, conn, court_1, _) = setup_test_db();
+
+        let id = commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+        let session = SessionRepository::find_by_id(&conn, id).unwrap().unwrap();
+        assert_eq!(session.court_id, Some(court_1));
+
+        let court = VenueRepository::find_court(&conn, court_1).unwrap().unwrap();
+        assert_eq!(court.label(), "Court 1 @ Riverside");
+
+        let missing = commands::create_session(&conn, &args("coach", "Volleys", "14:00", Some(9999)));
+        assert!(matches!(missing, Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_court_double_booking_is_rejected() {
+        let (_temp_dir, conn, court_1, court_2) = setup_test_db();
+        commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+
+        // Another coach, same court, overlapping the last half hour
+        let clash = commands::create_session(&conn, &args("coach2", "Volleys", "11:00", Some(court_1)));
+        match clash {
+            Err(AppError::Validation(message)) => assert_eq!(
+                message,
+                "Court 1 @ Riverside is already booked for \"Serve clinic\" (2026-11-02 10:00-11:30)"
+            ),
+            other => panic!("expected a court clash, got {:?}", other),
+        }
+
+        // The other court is free
+        commands::create_session(&conn, &args("coach2", "Volleys", "11:00", Some(court_2))).unwrap();
+        assert_eq!(SessionRepository::find_all(&conn).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_coach_double_booking_is_rejected() {
+        let (_temp_dir, conn, court_1, court_2) = setup_test_db();
+        commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+
+        let clashes = SessionRepository::find_clashes(&conn, &booking(&conn, "coach", "11:00", Some(court_2))).unwrap();
+        assert_eq!(clashes.len(), 1);
+        assert_eq!(clashes[0].kind, ClashKind::Coach);
+        assert_eq!(clashes[0].court, None);
+
+        // Without a court the coach still can't be in two places
+        let clash = commands::create_session(&conn, &args("coach", "Volleys", "09:00", None));
+        assert!(matches!(clash, Err(AppError::Validation(m)) if m.starts_with("The coach already runs")));
+    }
+
+    #[test]
+    fn test_back_to_back_and_unscheduled_sessions_dont_clash() {
+        let (_temp_dir, conn, court_1, _) = setup_test_db();
+        commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+
+        // Ends exactly when the clinic starts, and starts exactly when it ends
+        assert!(SessionRepository::find_clashes(&conn, &booking(&conn, "coach", "09:00", Some(court_1))).unwrap().is_empty());
+        commands::create_session(&conn, &args("coach", "Volleys", "11:30", Some(court_1))).unwrap();
+
+        let mut unscheduled = booking(&conn, "coach", "10:00", Some(court_1));
+        unscheduled.time = None;
+        assert!(SessionRepository::find_clashes(&conn, &unscheduled).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_overlap_across_midnight() {
+        let (_temp_dir, conn, court_1, _) = setup_test_db();
+        commands::create_session(&conn, &args("coach", "Night session", "23:00", Some(court_1))).unwrap();
+
+        let mut early = booking(&conn, "coach2", "00:00", Some(court_1));
+        early.date = NaiveDate::from_ymd_opt(2026, 11, 3);
+        let clashes = SessionRepository::find_clashes(&conn, &early).unwrap();
+        assert_eq!(clashes.len(), 1);
+        assert_eq!(clashes[0].title, "Night session");
+    }
+
+    #[test]
+    fn test_booking_running_past_midnight_clashes_with_the_next_morning() {
+        let (_temp_dir, conn, court_1, _) = setup_test_db();
+        let mut early = args("coach2", "Early bird", "00:30", Some(court_1));
+        early.date = Some("2026-11-03".to_string());
+        commands::create_session(&conn, &early).unwrap();
+
+        let mut late = booking(&conn, "coach", "23:00", Some(court_1));
+        late.duration_minutes = Some(120);
+        let clashes = SessionRepository::find_clashes(&conn, &late).unwrap();
+        assert_eq!(clashes.len(), 1);
+        assert_eq!((clashes[0].kind, clashes[0].title.as_str()), (ClashKind::Court, "Early bird"));
+    }
+
+    #[test]
+    fn test_duplicate_into_a_booked_slot_is_rejected() {
+        let (_temp_dir, conn, court_1, _) = setup_test_db();
+        let original = commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+        let mut next_week = args("coach2", "Rally", "10:30", Some(court_1));
+        next_week.date = Some("2026-11-09".to_string());
+        commands::create_session(&conn, &next_week).unwrap();
+        let count = |conn: &rusqlite::Connection| -> i64 {
+            conn.query_row("SELECT COUNT(*) FROM sessions", [], |row| row.get(0)).unwrap()
+        };
+        let before = count(&conn);
+
+        let copy = SessionRepository::duplicate(
+            &conn,
+            original,
+            NaiveDate::from_ymd_opt(2026, 11, 9),
+            NaiveTime::from_hms_opt(10, 0, 0),
+            user_id(&conn, "coach"),
+        );
+        assert!(matches!(copy, Err(AppError::Validation(message)) if message.contains("Rally")));
+        assert_eq!(count(&conn), before);
+
+        let copy = SessionRepository::duplicate(
+            &conn,
+            original,
+            NaiveDate::from_ymd_opt(2026, 11, 16),
+            NaiveTime::from_hms_opt(10, 0, 0),
+            user_id(&conn, "coach"),
+        )
+        .unwrap();
+        assert_eq!(SessionRepository::find_by_id(&conn, copy).unwrap().unwrap().court_id, Some(court_1));
+    }
+
+    #[test]
+    fn test_session_lasting_days_clashes_with_later_bookings() {
+        let (_temp_dir, conn, _, _) = setup_test_db();
+        SessionRepository::create(
+            &conn,
+            "Training camp",
+            None,
+            NaiveDate::from_ymd_opt(2026, 10, 31),
+            NaiveTime::from_hms_opt(9, 0, 0),
+            Some(3 * 24 * 60),
+            None,
+            user_id(&conn, "coach"),
+        )
+        .unwrap();
+
+        let clashes = SessionRepository::find_clashes(&conn, &booking(&conn, "coach", "10:00", None)).unwrap();
+        assert_eq!(clashes.len(), 1);
+        assert_eq!((clashes[0].kind, clashes[0].title.as_str()), (ClashKind::Coach, "Training camp"));
+        assert!(commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", None)).is_err());
+    }
+
+    #[test]
+    fn test_set_court_refuses_a_booked_court() {
+        let (_temp_dir, conn, court_1, court_2) = setup_test_db();
+        commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+        let id = commands::create_session(&conn, &args("coach2", "Volleys", "10:30", Some(court_2))).unwrap();
+
+        let moved = SessionRepository::set_court(&conn, id, Some(court_1));
+        assert!(matches!(moved, Err(AppError::Validation(message)) if message.contains("Serve clinic")));
+        assert_eq!(SessionRepository::find_by_id(&conn, id).unwrap().unwrap().court_id, Some(court_2));
+
+        SessionRepository::set_court(&conn, id, None).unwrap();
+        assert_eq!(SessionRepository::find_by_id(&conn, id).unwrap().unwrap().court_id, None);
+    }
+
+    #[test]
+    fn test_update_can_move_a_session_to_a_free_court() {
+        let (_temp_dir, conn, court_1, court_2) = setup_test_db();
+        commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+        let id = commands::create_session(&conn, &args("coach2", "Volleys", "14:00", Some(court_1))).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 11, 2);
+        let ten_thirty = NaiveTime::from_hms_opt(10, 30, 0);
+
+        // Moving to 10:30 is only free on the other court
+        let clash = SessionRepository::update(&conn, id, "Volleys", None, date, ten_thirty, Some(90), None, Some(court_1));
+        assert!(matches!(clash, Err(AppError::Validation(_))));
+        SessionRepository::update(&conn, id, "Volleys", None, date, ten_thirty, Some(90), None, Some(court_2)).unwrap();
+
+        let session = SessionRepository::find_by_id(&conn, id).unwrap().unwrap();
+        assert_eq!((session.scheduled_time, session.court_id), (ten_thirty, Some(court_2)));
+    }
+
+    #[test]
+    fn test_restoring_a_session_whose_slot_was_rebooked_is_refused() {
+        let (_temp_dir, conn, court_1, _) = setup_test_db();
+        let archived = commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, archived).unwrap();
+        let rebooked = commands::create_session(&conn, &args("coach2", "Volleys", "10:30", Some(court_1))).unwrap();
+
+        let restored = ArchiveRepository::restore(&conn, ArchiveKind::Session, archived);
+        assert!(matches!(restored, Err(AppError::Validation(message)) if message.contains("Volleys")));
+        assert!(SessionRepository::find_by_id(&conn, archived).unwrap().is_none());
+
+        // Once the slot is free again the session comes back
+        SessionRepository::cancel(&conn, rebooked, "Rain").unwrap();
+        ArchiveRepository::restore(&conn, ArchiveKind::Session, archived).unwrap();
+        assert!(SessionRepository::find_by_id(&conn, archived).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_merge_import_refuses_a_double_booking() {
+        let (temp_dir, source, court_1, _) = setup_test_db();
+        commands::create_session(&source, &args("coach2", "Volleys", "10:30", Some(court_1))).unwrap();
+        let json = serde_json::to_string(&backup::export(&source).unwrap()).unwrap();
+        let doc = serde_json::from_str(&json).unwrap();
+
+        let target = open_db(&temp_dir, "target.db");
+        let venue_id = VenueRepository::create(&target, "Riverside", None).unwrap();
+        let court = VenueRepository::add_court(&target, venue_id, "Court 1").unwrap();
+        target
+            .execute("INSERT INTO users (username, display_name, role) VALUES ('head_coach', 'Head Coach', 'coach')", [])
+            .unwrap();
+        let coach = target.last_insert_rowid();
+        SessionRepository::create(
+            &target,
+            "Serve clinic",
+            None,
+            NaiveDate::from_ymd_opt(2026, 11, 2),
+            NaiveTime::from_hms_opt(10, 0, 0),
+            Some(90),
+            None,
+            coach,
+        )
+        .unwrap();
+        let booked = SessionRepository::find_all(&target).unwrap()[0].id;
+        SessionRepository::set_court(&target, booked, Some(court)).unwrap();
+
+        let imported = backup::import(&target, &doc, ImportMode::Merge);
+        assert!(matches!(imported, Err(AppError::Validation(message)) if message.contains("Serve clinic")));
+        assert_eq!(SessionRepository::find_all(&target).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_cancelled_archived_and_edited_sessions_dont_clash() {
+        let (_temp_dir, conn, court_1, _) = setup_test_db();
+        let cancelled = commands::create_session(&conn, &args("coach", "Serve clinic", "10:00", Some(court_1))).unwrap();
+        SessionRepository::cancel(&conn, cancelled, "Rain").unwrap();
+        let archived = commands::create_session(&conn, &args("coach", "Volleys", "10:00", Some(court_1))).unwrap();
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, archived).unwrap();
+
+        let id = commands::create_session(&conn, &args("coach", "Footwork", "10:00", Some(court_1))).unwrap();
+
+        // Saving a session again doesn't clash with itself
+        let mut edit = booking(&conn, "coach", "10:30", Some(court_1));
+        edit.session_id = Some(id);
+        assert!(SessionRepository::ensure_no_clash(&conn, &edit).is_ok());
+        edit.session_id = None;
+        assert!(SessionRepository::ensure_no_clash(&conn, &edit).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_venue_and_court_names_are_rejected() {
+        let (_temp_dir, conn, _, _) = setup_test_db();
+        let venue = VenueRepository::find_by_name(&conn, "Riverside").unwrap().unwrap();
+
+        assert!(matches!(VenueRepository::create(&conn, "Riverside", None), Err(AppError::Validation(_))));
+        assert!(matches!(VenueRepository::add_court(&conn, venue.id, "Court 1"), Err(AppError::Validation(_))));
+        assert!(matches!(VenueRepository::add_court(&conn, venue.id, "  "), Err(AppError::Validation(_))));
+
+        let other = VenueRepository::create(&conn, "Hilltop", None).unwrap();
+        VenueRepository::add_court(&conn, other, "Court 1").unwrap();
+        let labels: Vec<String> = VenueRepository::find_courts(&conn).unwrap().iter().map(|c| c.label()).collect();
+        assert_eq!(labels, vec!["Court 1 @ Hilltop", "Court 1 @ Riverside", "Court 2 @ Riverside"]);
+    }
+
+    #[test]
+    fn test_backup_round_trip_keeps_courts() {
+        let (temp_dir, source, _, court_2) = setup_test_db();
+        commands::create_session(&source, &args("coach", "Serve clinic", "10:00", Some(court_2))).unwrap();
+
+        let json = serde_json::to_string(&backup::export(&source).unwrap()).unwrap();
+        let doc = serde_json::from_str(&json).unwrap();
+
+        let target = open_db(&temp_dir, "target.db");
+        let summary = backup::import(&target, &doc, ImportMode::Merge).unwrap();
+        assert_eq!(summary.venues.added, 1);
+        assert_eq!(summary.courts.added, 2);
+
+        let session = SessionRepository::find_all(&target).unwrap().pop().unwrap();
+        let court = VenueRepository::find_court(&target, session.court_id.unwrap()).unwrap().unwrap();
+        assert_eq!(court.label(), "Court 2 @ Riverside");
+        assert_eq!(VenueRepository::find_all(&target).unwrap()[0].address.as_deref(), Some("1 River Rd"));
+
+        // Importing again reuses the venue and its courts
+        let summary = backup::import(&target, &doc, ImportMode::Merge).unwrap();
+        assert_eq!(summary.courts.existing, 2);
+        assert_eq!(VenueRepository::find_courts(&target).unwrap().len(), 2);
+    }
+}
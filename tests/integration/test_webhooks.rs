@@ -0,0 +1,274 @@
+#[cfg(test)]
+mod webhook_tests {
+    use crate::setup_test_db;
+    use chrono::{Duration, Utc};
+    use serde_json::Value;
+    use std::io::Write;
+    use std::net::{SocketAddr, TcpListener};
+    use std::sync::mpsc::{self, Receiver};
+    use std::time::Instant;
+    use tui_coach::api::http::{Request, Response};
+    use tui_coach::cli::commands;
+    use tui_coach::db::repositories::{
+        ArchiveRepository, SessionRepository, SubscriptionRepository, UserRepository, WebhookRepository,
+    };
+    use tui_coach::models::{ArchiveKind, WebhookDelivery};
+    use tui_coach::utils::AppError;
+    use tui_coach::webhooks::{self, backoff, DeliverySummary, MAX_ATTEMPTS};
+
+    fn user_id(conn: &rusqlite::Connection, username: &str) -> i64 {
+        UserRepository::find_by_username(conn, username).unwrap().unwrap().id
+    }
+
+    fn create_session(conn: &rusqlite::Connection, title: &str) -> i64 {
+        SessionRepository::create(conn, title, None, None, None, None, None, user_id(conn, "coach")).unwrap()
+    }
+
+    /// Events queued for a webhook, oldest first
+    fn queued(conn: &rusqlite::Connection, webhook_id: i64) -> Vec<WebhookDelivery> {
+        let mut deliveries: Vec<WebhookDelivery> = WebhookRepository::find_deliveries(conn, true, 100)
+            .unwrap()
+            .into_iter()
+            .filter(|d| d.webhook_id == webhook_id)
+            .collect();
+        deliveries.reverse();
+        deliveries
+    }
+
+    /// Local stand-in for the club website: answers with `statuses` in turn
+    /// (then 200) and passes on each request it receives
+    fn stand_in(statuses: Vec<u16>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hooks/tennis", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut statuses = statuses.into_iter();
+            for stream in listener.incoming() {
+                let stream = stream.unwrap();
+                let request = Request::read_from(&stream).unwrap();
+                let status = statuses.next().unwrap_or(200);
+                let _ = Response::error(status, "stand-in").write_to(&stream);
+                if sender.send(request).is_err() {
+                    return;
+                }
+            }
+        });
+        (url, receiver)
+    }
+
+    /// An address nothing is listening on
+    fn closed_port() -> SocketAddr {
+        TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap()
+    }
+
+    #[test]
+    fn test_session_changes_queue_events() {
+        let (_temp_dir, conn) = setup_test_db();
+        let all = WebhookRepository::create(&conn, "http://example.test/all", &[]).unwrap().0;
+        let subscribed = WebhookRepository::create(&conn, "http://example.test/subs", &["session.subscribed".to_string()]).unwrap().0;
+
+        let session_id = create_session(&conn, "Serve clinic");
+        SessionRepository::update(&conn, session_id, "Serve clinic II", None, None, None, Some(90), None, None).unwrap();
+        let alice_sub = SubscriptionRepository::create(&conn, user_id(&conn, "alice"), session_id).unwrap();
+        SubscriptionRepository::create(&conn, user_id(&conn, "bob"), session_id).unwrap();
+        SubscriptionRepository::mark_completed(&conn, alice_sub).unwrap();
+        SubscriptionRepository::delete_by_user_and_session(&conn, user_id(&conn, "bob"), session_id).unwrap();
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id).unwrap();
+        ArchiveRepository::restore(&conn, ArchiveKind::Session, session_id).unwrap();
+
+        let events: Vec<String> = queued(&conn, all).into_iter().map(|d| d.event).collect();
+        assert_eq!(
+            events,
+            vec![
+                "session.created",
+                "session.updated",
+                "session.subscribed",
+                "session.subscribed",
+                "session.completed",
+                "session.unsubscribed",
+                "session.deleted",
+                "session.restored",
+            ]
+        );
+
+        let subscriptions = queued(&conn, subscribed);
+        assert_eq!(subscriptions.len(), 2);
+        let second: Value = serde_json::from_str(&subscriptions[1].payload).unwrap();
+        assert_eq!(second["subscriber_count"], 2);
+        assert_eq!(second["session"]["title"], "Serve clinic II");
+        assert_eq!(second["after"]["user_id"], user_id(&conn, "bob"));
+    }
+
+    #[test]
+    fn test_purging_an_archived_session_sends_nothing_more() {
+        let (_temp_dir, conn) = setup_test_db();
+        let webhook = WebhookRepository::create(&conn, "http://example.test/", &[]).unwrap().0;
+        let session_id = create_session(&conn, "Footwork");
+        SubscriptionRepository::create(&conn, user_id(&conn, "alice"), session_id).unwrap();
+        ArchiveRepository::archive(&conn, ArchiveKind::Session, session_id).unwrap();
+        let before = queued(&conn, webhook).len();
+
+        ArchiveRepository::purge(&conn, ArchiveKind::Session, session_id).unwrap();
+        assert_eq!(queued(&conn, webhook).len(), before);
+        assert_eq!(queued(&conn, webhook).last().unwrap().event, "session.deleted");
+    }
+
+    #[test]
+    fn test_delivery_posts_json_to_the_endpoint() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (url, requests) = stand_in(vec![]);
+        let (_, secret) = WebhookRepository::create(&conn, &url, &["session.created".to_string()]).unwrap();
+        let session_id = create_session(&conn, "Volleys");
+
+        let summary = webhooks::deliver_due(&conn, Utc::now()).unwrap();
+        assert_eq!(summary, DeliverySummary { delivered: 1, retrying: 0, failed: 0 });
+
+        let request = requests.recv().unwrap();
+        assert_eq!((request.method.as_str(), request.path.as_str()), ("POST", "/hooks/tennis"));
+        assert_eq!(request.header("x-tennis-event"), Some("session.created"));
+        let signed = webhooks::signature(&secret, std::str::from_utf8(&request.body).unwrap());
+        assert_eq!(request.header("x-tennis-signature"), Some(signed.as_str()));
+        let body: Value = request.json().unwrap();
+        assert_eq!(body["session_id"], session_id);
+        assert_eq!(body["session"]["title"], "Volleys");
+
+        assert!(webhooks::deliver_due(&conn, Utc::now()).unwrap().is_empty());
+        let delivery = &WebhookRepository::find_deliveries(&conn, true, 10).unwrap()[0];
+        assert_eq!((delivery.status.as_str(), delivery.attempts), ("delivered", 1));
+    }
+
+    #[test]
+    fn test_delivery_does_not_wait_for_a_kept_alive_connection() {
+        let (_temp_dir, conn) = setup_test_db();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let (done, hold) = mpsc::channel::<()>();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            Request::read_from(&stream).unwrap();
+            stream.write_all(b"HTTP/1.1 204 No Content\r\nConnection: keep-alive\r\nContent-Length: 0\r\n\r\n").unwrap();
+            // Keep the connection open until the test is over
+            let _ = hold.recv();
+        });
+        WebhookRepository::create(&conn, &url, &[]).unwrap();
+        create_session(&conn, "Kept alive");
+
+        let started = Instant::now();
+        assert_eq!(webhooks::deliver_due(&conn, Utc::now()).unwrap().delivered, 1);
+        assert!(started.elapsed() < std::time::Duration::from_secs(5));
+        drop(done);
+    }
+
+    #[test]
+    fn test_claimed_delivery_is_not_sent_twice() {
+        let (temp_dir, conn) = setup_test_db();
+        let other = crate::open_db(&temp_dir, "test.db");
+        WebhookRepository::create(&conn, "http://example.test/", &[]).unwrap();
+        create_session(&conn, "Claimed");
+
+        let now = Utc::now();
+        let claimed = WebhookRepository::claim_due(&conn, now, now + Duration::minutes(5)).unwrap().unwrap();
+        assert!(WebhookRepository::claim_due(&other, now, now + Duration::minutes(5)).unwrap().is_none());
+
+        // A claim whose outcome was never recorded runs out
+        let later = now + Duration::minutes(5);
+        let reclaimed = WebhookRepository::claim_due(&other, later, later + Duration::minutes(5)).unwrap().unwrap();
+        assert_eq!(reclaimed.id, claimed.id);
+    }
+
+    #[test]
+    fn test_concurrent_delivery_runs_send_each_event_once() {
+        let (temp_dir, conn) = setup_test_db();
+        let (url, requests) = stand_in(vec![]);
+        WebhookRepository::create(&conn, &url, &[]).unwrap();
+        for title in ["One", "Two", "Three", "Four", "Five", "Six"] {
+            create_session(&conn, title);
+        }
+
+        let runs: Vec<_> = (0..2)
+            .map(|_| {
+                let conn = crate::open_db(&temp_dir, "test.db");
+                std::thread::spawn(move || webhooks::deliver_due(&conn, Utc::now()).unwrap().delivered)
+            })
+            .collect();
+        let delivered: usize = runs.into_iter().map(|run| run.join().unwrap()).sum();
+
+        assert_eq!(delivered, 6);
+        let mut ids: Vec<String> = requests
+            .iter()
+            .take(6)
+            .map(|request| request.header("x-tennis-delivery").unwrap().to_string())
+            .collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 6);
+        assert!(webhooks::deliver_due(&conn, Utc::now()).unwrap().is_empty());
+        assert!(requests.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_failed_delivery_is_retried_after_backoff() {
+        let (_temp_dir, conn) = setup_test_db();
+        let (url, requests) = stand_in(vec![500]);
+        WebhookRepository::create(&conn, &url, &[]).unwrap();
+        create_session(&conn, "Retry me");
+
+        let now = Utc::now();
+        assert_eq!(webhooks::deliver_due(&conn, now).unwrap().retrying, 1);
+        let delivery = &WebhookRepository::find_deliveries(&conn, false, 10).unwrap()[0];
+        assert!(delivery.last_error.as_deref().unwrap().contains("HTTP 500"));
+
+        assert!(webhooks::deliver_due(&conn, now + Duration::seconds(1)).unwrap().is_empty());
+        assert_eq!(webhooks::deliver_due(&conn, now + backoff(1)).unwrap().delivered, 1);
+        assert_eq!(requests.iter().take(2).count(), 2);
+    }
+
+    #[test]
+    fn test_delivery_is_given_up_and_can_be_retried() {
+        let (_temp_dir, conn) = setup_test_db();
+        WebhookRepository::create(&conn, &format!("http://{}/", closed_port()), &[]).unwrap();
+        create_session(&conn, "Unreachable");
+
+        let mut now = Utc::now();
+        let mut summary = DeliverySummary::default();
+        for _ in 0..MAX_ATTEMPTS {
+            summary = webhooks::deliver_due(&conn, now).unwrap();
+            now += Duration::hours(2);
+        }
+        assert_eq!(summary.failed, 1);
+        let delivery = WebhookRepository::find_deliveries(&conn, false, 10).unwrap().remove(0);
+        assert_eq!((delivery.status.as_str(), delivery.attempts), ("failed", MAX_ATTEMPTS as i64));
+        assert!(webhooks::deliver_due(&conn, now).unwrap().is_empty());
+
+        WebhookRepository::retry(&conn, delivery.id).unwrap();
+        assert!(matches!(WebhookRepository::retry(&conn, delivery.id), Err(AppError::NotFound(_))));
+        assert_eq!(WebhookRepository::find_deliveries(&conn, false, 10).unwrap()[0].status, "pending");
+    }
+
+    #[test]
+    fn test_removing_a_webhook_drops_its_outbox() {
+        let (_temp_dir, conn) = setup_test_db();
+        let webhook = WebhookRepository::create(&conn, "http://example.test/", &[]).unwrap().0;
+        create_session(&conn, "Dropped");
+
+        WebhookRepository::delete(&conn, webhook).unwrap();
+        assert!(WebhookRepository::find_deliveries(&conn, true, 10).unwrap().is_empty());
+        assert!(matches!(WebhookRepository::delete(&conn, webhook), Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_cli_validates_urls_and_events() {
+        let (_temp_dir, conn) = setup_test_db();
+        let mut out = Vec::new();
+        let https = commands::webhook_add(&conn, "https://club.example/hook", &[], false, &mut out);
+        assert!(matches!(https, Err(AppError::Validation(_))));
+        let unknown = commands::webhook_add(&conn, "http://club.example/hook", &["session.moved".to_string()], false, &mut out);
+        assert!(matches!(unknown, Err(AppError::Validation(_))));
+
+        commands::webhook_add(&conn, "http://club.example/hook", &["session.created".to_string()], false, &mut out).unwrap();
+        let mut out = Vec::new();
+        commands::webhook_list(&conn, false, &mut out).unwrap();
+        let listing = String::from_utf8(out).unwrap();
+        assert!(listing.lines().any(|line| line.contains("http://club.example/hook") && line.contains("session.created")));
+    }
+}
@@ -13,6 +13,7 @@ mod tests {
             role: UserRole::Coach,
             skill_level: Some(SkillLevel::Advanced),
             goals: None,
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -31,6 +32,7 @@ mod tests {
             role: UserRole::Player,
             skill_level: None,
             goals: None,
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -48,6 +50,7 @@ mod tests {
             role: UserRole::Coach,
             skill_level: Some(SkillLevel::Advanced),
             goals: None,
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -66,6 +69,7 @@ mod tests {
             role: UserRole::Player,
             skill_level: Some(SkillLevel::Beginner),
             goals: Some("Improve".to_string()),
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -84,6 +88,7 @@ mod tests {
             role: UserRole::Coach,
             skill_level: Some(SkillLevel::Advanced),
             goals: None,
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use tui_coach::cli::output::{cell, Table};
+    use tui_coach::cli::{Cli, Command, ConfigCommand, PlayerCommand, SessionCommand, TemplateCommand};
+
+    #[test]
+    fn test_legacy_flags_still_parse() {
+        let cli = Cli::try_parse_from(["tennis-tui", "--user=alice"]).unwrap();
+        assert_eq!(cli.user, Some("alice".to_string()));
+        assert!(cli.command.is_none());
+        assert_eq!(cli.db_path, None);
+
+        let cli = Cli::try_parse_from(["tennis-tui", "--init-db"]).unwrap();
+        assert!(cli.init_db);
+    }
+
+    #[test]
+    fn test_subcommands_parse() {
+        let cli = Cli::try_parse_from(["tennis-tui", "session", "list", "--coach", "coach", "--json"]).unwrap();
+        assert!(cli.json);
+        assert!(matches!(cli.command, Some(Command::Session(SessionCommand::List { coach: Some(_) }))));
+
+        let cli = Cli::try_parse_from(["tennis-tui", "--db-path", "x.db", "session", "delete", "7"]).unwrap();
+        assert_eq!(cli.db_path.as_deref(), Some("x.db"));
+        assert!(matches!(cli.command, Some(Command::Session(SessionCommand::Delete { id: 7 }))));
+
+        let cli = Cli::try_parse_from(["tennis-tui", "template", "import", "t.json", "--coach", "coach"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Template(TemplateCommand::Import { .. }))));
+
+        let cli = Cli::try_parse_from(["tennis-tui", "run", "--user", "bob"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Run { user: Some(user), user_from_key: false }) if user == "bob"));
+
+        let cli = Cli::try_parse_from(["tennis-tui", "run", "--user-from-key"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Run { user: None, user_from_key: true })));
+        assert!(Cli::try_parse_from(["tennis-tui", "run"]).is_err());
+        assert!(Cli::try_parse_from(["tennis-tui", "run", "--user", "bob", "--user-from-key"]).is_err());
+
+        let cli = Cli::try_parse_from(["tennis-tui", "remind", "--hours", "48", "--dry-run"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Remind { hours: Some(48), dry_run: true })));
+        let cli = Cli::try_parse_from(["tennis-tui", "player", "email", "alice"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::Player(PlayerCommand::Email { email: None, .. }))));
+
+        let cli = Cli::try_parse_from(["tennis-tui", "config", "show", "--config", "c.toml"]).unwrap();
+        assert_eq!(cli.config.as_deref(), Some(std::path::Path::new("c.toml")));
+        assert!(matches!(cli.command, Some(Command::Config(ConfigCommand::Show))));
+    }
+
+    #[test]
+    fn test_history_requires_exactly_one_scope() {
+        let cli = Cli::try_parse_from(["tennis-tui", "history", "--player", "alice", "-o", "a.csv"]).unwrap();
+        assert!(matches!(cli.command, Some(Command::History(args)) if args.player.as_deref() == Some("alice")));
+
+        assert!(Cli::try_parse_from(["tennis-tui", "history"]).is_err());
+        assert!(Cli::try_parse_from(["tennis-tui", "history", "--player", "alice", "--coach", "coach"]).is_err());
+    }
+
+    #[test]
+    fn test_session_create_requires_coach_and_title() {
+        assert!(Cli::try_parse_from(["tennis-tui", "session", "create", "--title", "Serve"]).is_err());
+        assert!(Cli::try_parse_from(["tennis-tui", "session", "create", "--coach", "coach"]).is_err());
+    }
+
+    #[test]
+    fn test_table_aligns_columns() {
+        let mut table = Table::new(&["ID", "TITLE"]);
+        table.add_row(vec!["1".to_string(), "Serve Clinic".to_string()]);
+        table.add_row(vec!["12".to_string(), "-".to_string()]);
+
+        let mut out = Vec::new();
+        table.write_to(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text, "ID  TITLE\n--  ------------\n1   Serve Clinic\n12  -\n");
+    }
+
+    #[test]
+    fn test_cell() {
+        assert_eq!(cell(Some(5)), "5");
+        assert_eq!(cell(None::<i32>), "-");
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
+    use tui_coach::cli::commands;
+    use tui_coach::config::{KeyBindings, Settings, Source, Theme};
+
+    fn write(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn test_defaults_without_files() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::resolve(&[dir.path().join("missing.toml")], no_env, None, &[]).unwrap();
+        assert_eq!(settings.database_path, "data/tennis.db");
+        assert_eq!(settings.log_level, "info");
+        assert_eq!(settings.log_path, PathBuf::from("logs/tennis-tui.log"));
+        assert_eq!(settings.page_size, 20);
+        assert_eq!(settings.theme, Theme::Dark);
+        assert_eq!(settings.keys, KeyBindings::default());
+        assert_eq!(settings.source("database.path"), &Source::Default);
+    }
+
+    #[test]
+    fn test_layers_override_in_order() {
+        let dir = TempDir::new().unwrap();
+        let system = write(
+            dir.path(),
+            "system.toml",
+            "[database]\npath = \"/srv/tennis.db\"\n[logging]\nlevel = \"warn\"\npath = \"/var/log/tennis.log\"\n[app]\nmax_sessions_per_page = 50\n",
+        );
+        let user = write(dir.path(), "user.toml", "[logging]\nlevel = \"DEBUG\"\n[ui]\ntheme = \"light\"\n[keys]\nquit = \"z\"\n");
+        let files = [system.clone(), user.clone()];
+
+        let settings = Settings::resolve(&files, no_env, None, &[]).unwrap();
+        assert_eq!(settings.database_path, "/srv/tennis.db");
+        assert_eq!(settings.source("database.path"), &Source::File(system.clone()));
+        assert_eq!(settings.log_level, "debug");
+        assert_eq!(settings.source("logging.level"), &Source::File(user.clone()));
+        assert_eq!(settings.log_path, PathBuf::from("/var/log/tennis.log"));
+        assert_eq!(settings.page_size, 50);
+        assert_eq!(settings.theme, Theme::Light);
+        assert_eq!(settings.keys.quit, 'z');
+        assert_eq!(settings.keys.help, '?');
+
+        let env: HashMap<&str, &str> = [("TENNIS_DB_PATH", "/env/tennis.db"), ("TENNIS_LOG_LEVEL", "error")].into();
+        let lookup = |name: &str| env.get(name).map(|v| v.to_string());
+        let settings = Settings::resolve(&files, lookup, None, &[]).unwrap();
+        assert_eq!(settings.database_path, "/env/tennis.db");
+        assert_eq!(settings.source("database.path"), &Source::Env("TENNIS_DB_PATH"));
+        assert_eq!(settings.log_level, "error");
+
+        let settings = Settings::resolve(&files, lookup, Some("cli.db"), &[]).unwrap();
+        assert_eq!(settings.database_path, "cli.db");
+        assert_eq!(settings.source("database.path"), &Source::Cli("--db-path"));
+        assert_eq!(settings.source("database.path").to_string(), "--db-path");
+    }
+
+    #[test]
+    fn test_every_setting_has_env_and_cli_layers() {
+        let env: HashMap<&str, &str> = [
+            ("TENNIS_LOG_PATH", "/env/tennis.log"),
+            ("TENNIS_PAGE_SIZE", "40"),
+            ("TENNIS_THEME", "light"),
+            ("TENNIS_SMTP_HOST", "relay.club.example"),
+            ("TENNIS_SMTP_PORT", "2525"),
+            ("TENNIS_REMINDERS_HOURS", "48"),
+            ("TENNIS_KEYS_QUIT", "z"),
+        ]
+        .into();
+        let lookup = |name: &str| env.get(name).map(|v| v.to_string());
+        let settings = Settings::resolve(&[], lookup, None, &[]).unwrap();
+        assert_eq!(settings.log_path, PathBuf::from("/env/tennis.log"));
+        assert_eq!((settings.page_size, settings.theme), (40, Theme::Light));
+        assert_eq!((settings.smtp_host.as_deref(), settings.smtp_port), (Some("relay.club.example"), 2525));
+        assert_eq!((settings.reminder_hours, settings.keys.quit), (48, 'z'));
+        assert_eq!(settings.source("smtp.port"), &Source::Env("TENNIS_SMTP_PORT"));
+
+        let overrides = ["app.page_size=10".to_string(), "smtp.host=".to_string(), "keys.search=\\".to_string()];
+        let settings = Settings::resolve(&[], lookup, None, &overrides).unwrap();
+        assert_eq!(settings.page_size, 10);
+        assert_eq!(settings.source("app.page_size").to_string(), "--set");
+        assert_eq!(settings.smtp_host, None);
+        assert_eq!(settings.keys.search, '\\');
+        assert_eq!(settings.theme, Theme::Light);
+
+        let err = |overrides: &[String]| Settings::resolve(&[], no_env, None, overrides).unwrap_err().to_string();
+        assert!(err(&["ui.colour=dark".to_string()]).contains("unknown setting 'ui.colour'"));
+        assert!(err(&["ui.theme".to_string()]).contains("expected key=value"));
+        assert!(err(&["ui.theme=neon".to_string()]).contains("ui.theme (from --set): 'neon' is not one of dark, light"));
+        assert!(err(&["smtp.port=lots".to_string()]).contains("smtp.port"));
+    }
+
+    #[test]
+    fn test_invalid_settings_are_rejected() {
+        let dir = TempDir::new().unwrap();
+        let check = |contents: &str| {
+            let path = write(dir.path(), "config.toml", contents);
+            Settings::resolve(&[path], no_env, None, &[]).unwrap_err().to_string()
+        };
+
+        assert!(check("[logging]\nlevel = \"loud\"\n").contains("logging.level"));
+        assert!(check("[app]\npage_size = 0\n").contains("app.page_size"));
+        assert!(check("[ui]\ntheme = \"neon\"\n").contains("config.toml"));
+        assert!(check("[keys]\nquit = \"ab\"\n").contains("config.toml"));
+        assert!(check("[keys]\nsearch = \"?\"\n").contains("keys.help and keys.search are both bound to '?'"));
+        assert!(check("[keys]\nhelp = \"1\"\n").contains("keys.help"));
+        assert!(check("[keys]\nsearch = \"p\"\n").contains("keys.search 'p' is already the 'p' action key"));
+        assert!(check("[keys]\nquit = \"u\"\n").contains("keys.quit 'u' is already the 'u' action key"));
+        assert!(check("[keys]\nquit = \"J\"\n").contains("the 'j' action key"));
+        assert!(check("[keys]\nquit = \"Z\"\nsearch = \"z\"\n").contains("keys.quit and keys.search are both bound"));
+        assert!(check("[database\n").contains("config.toml"));
+        assert!(check("[smtp]\nfrom = \"club\"\n").contains("smtp.from"));
+        assert!(check("[smtp]\nport = 0\n").contains("smtp.port"));
+        assert!(check("[smtp]\nhelo = \"bad name\"\n").contains("smtp.helo"));
+        assert!(check("[reminders]\nhours = 0\n").contains("reminders.hours"));
+
+        let env = |name: &str| (name == "TENNIS_LOG_LEVEL").then(|| "verbose".to_string());
+        let err = Settings::resolve(&[], env, None, &[]).unwrap_err().to_string();
+        assert!(err.contains("env TENNIS_LOG_LEVEL"));
+    }
+
+    #[test]
+    fn test_reminder_settings() {
+        let dir = TempDir::new().unwrap();
+        let path = write(
+            dir.path(),
+            "config.toml",
+            "[smtp]\nhost = \"localhost\"\nport = 2525\nfrom = \"coach@club.example\"\nhelo = \"tennis.club.example\"\n[reminders]\nhours = 48\nlocation = \"Court 3, Riverside\"\n",
+        );
+        let settings = Settings::resolve(std::slice::from_ref(&path), no_env, None, &[]).unwrap();
+        assert_eq!(settings.smtp_host.as_deref(), Some("localhost"));
+        assert_eq!(settings.smtp_port, 2525);
+        assert_eq!(settings.smtp_from.as_deref(), Some("coach@club.example"));
+        assert_eq!(settings.smtp_helo, "tennis.club.example");
+        assert_eq!(settings.reminder_hours, 48);
+        assert_eq!(settings.club_location.as_deref(), Some("Court 3, Riverside"));
+        assert_eq!(settings.source("smtp.port"), &Source::File(path));
+
+        let defaults = Settings::resolve(&[], no_env, None, &[]).unwrap();
+        assert_eq!((defaults.smtp_host, defaults.smtp_port, defaults.reminder_hours), (None, 25, 24));
+        assert_eq!(defaults.smtp_helo, "localhost");
+    }
+
+    #[test]
+    fn test_explicit_config_file_must_exist() {
+        let dir = TempDir::new().unwrap();
+        assert!(Settings::load(Some(&dir.path().join("nope.toml")), None, &[]).is_err());
+    }
+
+    #[test]
+    fn test_key_labels_follow_bindings() {
+        let keys = KeyBindings { quit: '?', help: 'q', search: '/' };
+        assert_eq!(keys.relabel("[q] Quit [?] Help [/] Search"), "[?] Quit [q] Help [/] Search");
+        assert_eq!(KeyBindings::default().relabel("  [?]        Show help"), "  [?]        Show help");
+    }
+
+    #[test]
+    fn test_config_show_lists_values_and_sources() {
+        let dir = TempDir::new().unwrap();
+        let path = write(dir.path(), "config.toml", "[ui]\ntheme = \"light\"\n");
+        let settings = Settings::resolve(std::slice::from_ref(&path), no_env, Some("x.db"), &[]).unwrap();
+
+        let mut out = Vec::new();
+        commands::config_show(&settings, false, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("KEY"));
+        assert_eq!(lines[2].split_whitespace().collect::<Vec<_>>(), ["database.path", "x.db", "--db-path"]);
+        let theme = lines.iter().find(|l| l.starts_with("ui.theme")).unwrap();
+        assert_eq!(theme.split_whitespace().nth(2), Some(path.to_str().unwrap()));
+        assert_eq!(lines.len(), 2 + 14);
+
+        let mut out = Vec::new();
+        commands::config_show(&settings, true, &mut out).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json[4]["key"], "ui.theme");
+        assert_eq!(json[5]["source"], "default");
+    }
+}
@@ -29,4 +29,29 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_migrations_add_archived_at_to_existing_database() -> Result<()> {
+        let dir = tempdir().map_err(tui_coach::utils::error::AppError::Io)?;
+        let db_path = dir.path().join("test.db");
+        let conn = establish_connection(db_path.to_str().unwrap())?;
+
+        // A sessions table from before soft-delete existed
+        conn.execute_batch(
+            "CREATE TABLE sessions (id INTEGER PRIMARY KEY AUTOINCREMENT, title TEXT NOT NULL,
+                description TEXT, scheduled_date DATE, scheduled_time TIME, duration_minutes INTEGER,
+                skill_level TEXT, created_by INTEGER NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP, updated_at DATETIME DEFAULT CURRENT_TIMESTAMP);
+             INSERT INTO sessions (title, created_by) VALUES ('Old session', 1);",
+        )?;
+
+        run_migrations(&conn)?;
+        run_migrations(&conn)?;
+
+        let archived: Option<String> =
+            conn.query_row("SELECT archived_at FROM sessions WHERE title = 'Old session'", [], |row| row.get(0))?;
+        assert_eq!(archived, None);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use tui_coach::ui::group_form::{FormField, GroupForm};
+
+    #[test]
+    fn test_group_form_creation() {
+        let form = GroupForm::new();
+        assert_eq!(form.name, "");
+        assert_eq!(form.description, "");
+        assert_eq!(form.focus_field, FormField::Name);
+    }
+
+    #[test]
+    fn test_field_navigation_and_input() {
+        let mut form = GroupForm::new();
+        form.add_char('U');
+        form.add_char('1');
+        form.add_char('4');
+        assert_eq!(form.name, "U14");
+
+        form.next_field();
+        assert_eq!(form.focus_field, FormField::Description);
+        form.add_char('x');
+        form.backspace();
+        assert_eq!(form.description, "");
+
+        form.next_field();
+        assert_eq!(form.focus_field, FormField::Name);
+    }
+
+    #[test]
+    fn test_validate_name_length() {
+        let mut form = GroupForm::new();
+        assert!(form.validate().is_err());
+
+        form.name = "A".to_string();
+        assert!(form.validate().is_err());
+
+        form.name = "A".repeat(51);
+        assert!(form.validate().is_err());
+
+        form.name = "U14 Tuesday".to_string();
+        assert!(form.validate().is_ok());
+    }
+
+    #[test]
+    fn test_as_db_values() {
+        let mut form = GroupForm::new();
+        form.name = "  Squad  ".to_string();
+        assert_eq!(form.as_db_values(), ("Squad".to_string(), None));
+
+        form.description = "Weekend juniors".to_string();
+        assert_eq!(form.as_db_values(), ("Squad".to_string(), Some("Weekend juniors".to_string())));
+    }
+}
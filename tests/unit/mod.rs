@@ -6,4 +6,10 @@ mod db_connection;
 mod db_migrations;
 mod session_form;
 mod session_edit_form;
+mod group_form;
+mod cli;
+mod session_filter;
 mod text_editor;
+mod config;
+mod ssh_key;
+mod webhooks;
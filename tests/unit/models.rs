@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
-    use tui_coach::models::{User, UserRole, SkillLevel};
+    use chrono::{NaiveDate, NaiveTime};
+    use tui_coach::models::{booked_span, Clash, ClashKind, NotificationKind, SearchHit, SearchKind, User, UserRole, SkillLevel};
 
     #[test]
     fn test_user_role_from_str_coach() {
@@ -84,6 +85,7 @@ mod tests {
             role: UserRole::Coach,
             skill_level: Some(SkillLevel::Advanced),
             goals: None,
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -101,6 +103,7 @@ mod tests {
             role: UserRole::Player,
             skill_level: Some(SkillLevel::Intermediate),
             goals: Some("Improve serve".to_string()),
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -119,6 +122,7 @@ mod tests {
             role: UserRole::Player,
             skill_level: Some(SkillLevel::Beginner),
             goals: Some("Learn tennis".to_string()),
+            email: None,
             created_at: now,
             updated_at: now,
         };
@@ -140,6 +144,7 @@ mod tests {
             role: UserRole::Player,
             skill_level: None,
             goals: None,
+            email: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -147,4 +152,71 @@ mod tests {
         assert_eq!(user.skill_level, None);
         assert_eq!(user.goals, None);
     }
+
+    #[test]
+    fn test_search_kind_round_trip() {
+        for kind in [SearchKind::Session, SearchKind::Content, SearchKind::Template, SearchKind::Note] {
+            assert_eq!(kind.as_str().parse::<SearchKind>(), Ok(kind));
+        }
+        assert!("invalid".parse::<SearchKind>().is_err());
+    }
+
+    #[test]
+    fn test_notification_kind_round_trip() {
+        for kind in [
+            NotificationKind::SessionChanged,
+            NotificationKind::SessionCancelled,
+            NotificationKind::SessionDeleted,
+            NotificationKind::SessionRestored,
+            NotificationKind::Feedback,
+        ] {
+            assert_eq!(kind.as_str().parse::<NotificationKind>(), Ok(kind));
+        }
+        assert!("invalid".parse::<NotificationKind>().is_err());
+    }
+
+    #[test]
+    fn test_search_match_expression() {
+        assert_eq!(SearchHit::match_expression("Serve"), Some("\"serve\"*".to_string()));
+        assert_eq!(
+            SearchHit::match_expression("kick-serve  \"drill\""),
+            Some("\"kick\"* \"serve\"* \"drill\"*".to_string())
+        );
+        assert_eq!(SearchHit::match_expression("  * ( ) "), None);
+        assert_eq!(SearchHit::match_expression(""), None);
+    }
+
+    #[test]
+    fn test_booked_span_defaults_to_an_hour() {
+        let date = NaiveDate::from_ymd_opt(2026, 11, 2).unwrap();
+        let time = NaiveTime::from_hms_opt(23, 30, 0).unwrap();
+
+        let (start, end) = booked_span(date, time, None);
+        assert_eq!(start, date.and_time(time));
+        assert_eq!(end, NaiveDate::from_ymd_opt(2026, 11, 3).unwrap().and_hms_opt(0, 30, 0).unwrap());
+
+        let (_, end) = booked_span(date, time, Some(15));
+        assert_eq!(end, date.and_hms_opt(23, 45, 0).unwrap());
+    }
+
+    #[test]
+    fn test_clash_describe() {
+        let date = NaiveDate::from_ymd_opt(2026, 11, 2).unwrap();
+        let mut clash = Clash {
+            kind: ClashKind::Court,
+            session_id: 1,
+            title: "Serve clinic".to_string(),
+            starts_at: date.and_hms_opt(10, 0, 0).unwrap(),
+            ends_at: date.and_hms_opt(11, 30, 0).unwrap(),
+            court: Some("Court 1 @ Riverside".to_string()),
+        };
+        assert_eq!(
+            clash.describe(),
+            "Court 1 @ Riverside is already booked for \"Serve clinic\" (2026-11-02 10:00-11:30)"
+        );
+
+        clash.kind = ClashKind::Coach;
+        clash.court = None;
+        assert_eq!(clash.describe(), "The coach already runs \"Serve clinic\" (2026-11-02 10:00-11:30)");
+    }
 }
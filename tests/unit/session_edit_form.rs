@@ -16,6 +16,9 @@ mod tests {
             created_by: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            cancelled_at: None,
+            cancel_reason: None,
+            court_id: None,
         }
     }
 
@@ -109,6 +112,7 @@ mod tests {
         form.next_field(); // ScheduledDate
         form.next_field(); // ScheduledTime
         form.next_field(); // DurationMinutes
+        form.next_field(); // Court
         form.next_field(); // SkillLevel
 
         form.cycle_skill_level_forward();
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use tui_coach::models::{Session, SkillLevel, Tag};
+    use tui_coach::ui::session_filter::{FilterField, SessionFilterBar};
+
+    fn session_on(date: Option<NaiveDate>, skill_level: Option<SkillLevel>) -> Session {
+        let now = Utc::now();
+        Session {
+            id: 1,
+            title: "Serve Clinic".to_string(),
+            description: None,
+            scheduled_date: date,
+            scheduled_time: None,
+            duration_minutes: Some(60),
+            skill_level,
+            created_by: 1,
+            created_at: now,
+            updated_at: now,
+            cancelled_at: None,
+            cancel_reason: None,
+            court_id: None,
+        }
+    }
+
+    #[test]
+    fn test_tag_parse_list() {
+        assert_eq!(
+            Tag::parse_list(" Serve, footwork,,SERVE , doubles "),
+            vec!["serve".to_string(), "footwork".to_string(), "doubles".to_string()]
+        );
+        assert!(Tag::parse_list("  ,  ").is_empty());
+    }
+
+    #[test]
+    fn test_tag_validate_list() {
+        assert!(Tag::validate_list("serve, footwork").is_ok());
+        assert!(Tag::validate_list(&"a".repeat(31)).is_err());
+
+        let eleven: Vec<String> = (0..11).map(|i| format!("tag{}", i)).collect();
+        assert!(Tag::validate_list(&eleven.join(",")).is_err());
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let filter = SessionFilterBar::new();
+        assert!(!filter.is_active());
+        assert!(filter.matches(&session_on(None, None), &[]));
+        assert_eq!(filter.describe(), "No filters");
+    }
+
+    #[test]
+    fn test_tag_filter_requires_all_tags() {
+        let mut filter = SessionFilterBar::new();
+        filter.tags = "serve, footwork".to_string();
+        let session = session_on(None, None);
+
+        assert!(filter.matches(&session, &["footwork".to_string(), "serve".to_string(), "fitness".to_string()]));
+        assert!(!filter.matches(&session, &["serve".to_string()]));
+        assert!(!filter.matches(&session, &[]));
+    }
+
+    #[test]
+    fn test_skill_level_filter() {
+        let mut filter = SessionFilterBar::new();
+        filter.cycle_skill_level_forward();
+        filter.cycle_skill_level_forward();
+        assert_eq!(filter.skill_level, Some(SkillLevel::Intermediate));
+
+        assert!(filter.matches(&session_on(None, Some(SkillLevel::Intermediate)), &[]));
+        assert!(!filter.matches(&session_on(None, Some(SkillLevel::Beginner)), &[]));
+        assert!(!filter.matches(&session_on(None, None), &[]));
+
+        filter.cycle_skill_level_forward();
+        filter.cycle_skill_level_forward();
+        assert_eq!(filter.skill_level, None);
+    }
+
+    #[test]
+    fn test_date_range_filter() {
+        let mut filter = SessionFilterBar::new();
+        filter.date_from = "2026-03-01".to_string();
+        filter.date_to = "2026-03-31".to_string();
+
+        assert!(filter.matches(&session_on(NaiveDate::from_ymd_opt(2026, 3, 1), None), &[]));
+        assert!(filter.matches(&session_on(NaiveDate::from_ymd_opt(2026, 3, 31), None), &[]));
+        assert!(!filter.matches(&session_on(NaiveDate::from_ymd_opt(2026, 4, 1), None), &[]));
+        assert!(!filter.matches(&session_on(None, None), &[]));
+
+        // Open-ended range
+        filter.date_to.clear();
+        assert!(filter.matches(&session_on(NaiveDate::from_ymd_opt(2027, 1, 1), None), &[]));
+    }
+
+    #[test]
+    fn test_validate_dates() {
+        let mut filter = SessionFilterBar::new();
+        filter.date_from = "2026-13-01".to_string();
+        assert!(filter.validate().is_err());
+
+        filter.date_from = "2026-03-10".to_string();
+        filter.date_to = "2026-03-01".to_string();
+        assert!(filter.validate().is_err());
+
+        filter.date_to = "2026-03-20".to_string();
+        assert!(filter.validate().is_ok());
+    }
+
+    #[test]
+    fn test_field_navigation_and_describe() {
+        let mut filter = SessionFilterBar::new();
+        assert_eq!(filter.focus_field, FilterField::Tags);
+        filter.add_char('s');
+        filter.next_field();
+        assert_eq!(filter.focus_field, FilterField::SkillLevel);
+        filter.add_char('x'); // ignored
+        filter.next_field();
+        filter.add_char('2');
+        assert_eq!(filter.date_from, "2");
+        filter.prev_field();
+        filter.prev_field();
+        assert_eq!(filter.focus_field, FilterField::Tags);
+
+        filter.date_from = "2026-01-01".to_string();
+        assert_eq!(filter.describe(), "tags: s | dates: 2026-01-01 .. *");
+    }
+}
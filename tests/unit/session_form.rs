@@ -214,4 +214,31 @@ mod tests {
         assert_eq!(duration, None);
         assert_eq!(skill, "beginner");
     }
+
+    #[test]
+    fn test_tags_field() {
+        let mut form = SessionForm::new();
+        form.focus_field = FormField::SkillLevel;
+        form.next_field();
+        assert_eq!(form.focus_field, FormField::Tags);
+
+        for c in "Serve, footwork, serve".chars() {
+            form.add_char(c);
+        }
+        assert_eq!(form.tag_list(), vec!["serve".to_string(), "footwork".to_string()]);
+
+        form.next_field();
+        assert_eq!(form.focus_field, FormField::Title);
+    }
+
+    #[test]
+    fn test_validation_tag_length() {
+        let mut form = SessionForm::new();
+        form.title = "Valid Title".to_string();
+        form.tags = "x".repeat(31);
+        assert!(form.validate().is_err());
+
+        form.tags = "doubles".to_string();
+        assert!(form.validate().is_ok());
+    }
 }
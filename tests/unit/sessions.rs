@@ -19,6 +19,9 @@ mod tests {
             created_by: 1,
             created_at: now,
             updated_at: now,
+            cancelled_at: None,
+            cancel_reason: None,
+            court_id: None,
         };
 
         assert_eq!(session.id, 1);
@@ -41,6 +44,9 @@ mod tests {
             created_by: 1,
             created_at: now,
             updated_at: now,
+            cancelled_at: None,
+            cancel_reason: None,
+            court_id: None,
         };
 
         assert_eq!(session.description, None);
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use tui_coach::auth::ssh_key::{login_fingerprint, SSH_USER_AUTH_ENV};
+    use tui_coach::auth::PublicKey;
+    use tui_coach::utils::AppError;
+
+    // Generated with ssh-keygen; fingerprints from `ssh-keygen -lf`
+    const ED25519: &str =
+        "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIF3e7CepJHjPkn7kXRO8cLib/SCEiOwqpRyN4Bz21SMd alice@laptop";
+    const RSA: &str = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAAAgQDKUIAGMyBm9rMY4CfgmzjOVWMip5RbbiTRqG7UX7UVF9QDTNvpLfaHRusCThcF07e/UTClKgD/wUPVa93YyqFMVWNGWslaHpdaHF97uQ/SdIzFl3OVvA+RcVyMLpU1oCgvKbqGjALbylWd8rxj0+c5qeuqQIcgDMfs6WKJWpuDPw==";
+    const ECDSA: &str = "ecdsa-sha2-nistp256 AAAAE2VjZHNhLXNoYTItbmlzdHAyNTYAAAAIbmlzdHAyNTYAAABBBKCnrRedRNliiheFGLaW5bSUasBeBXSrO1rCOeerjwWRH5WrEj75Bmj+WEbpx1RHxGVUhy3ki5VdMhE3fwZSHJ0= bob";
+
+    #[test]
+    fn test_parse_public_key() {
+        let key = PublicKey::parse(ED25519).unwrap();
+        assert_eq!(key.key_type, "ssh-ed25519");
+        assert_eq!(key.comment.as_deref(), Some("alice@laptop"));
+        assert_eq!(key.to_string(), ED25519.trim_end_matches(" alice@laptop"));
+
+        let key = PublicKey::parse(&format!("  {}  \n", RSA)).unwrap();
+        assert_eq!(key.comment, None);
+    }
+
+    #[test]
+    fn test_fingerprint_matches_ssh_keygen() {
+        let fingerprint = |line| PublicKey::parse(line).unwrap().fingerprint();
+        assert_eq!(fingerprint(ED25519), "SHA256:soHN6GQezxkPNU1pZMr1rwv0scJxIpDy4a2LfsAe8bQ");
+        assert_eq!(fingerprint(RSA), "SHA256:+8CdDHuXiaY8K6/oOy8LdxY9pm3Q8Rk6ikuaUW7owjA");
+        assert_eq!(fingerprint(ECDSA), "SHA256:Ic6F5rH38fangDIc3AiPDIBJkkc09H2ttKA3iPbxguo");
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_keys() {
+        let ed25519_data = ED25519.split_whitespace().nth(1).unwrap();
+        for line in [
+            "",
+            "ssh-ed25519",
+            "ssh-dss AAAAB3NzaC1kc3MAAACBAP",
+            "ssh-ed25519 not*base64",
+            &format!("ssh-rsa {}", ed25519_data),
+            &format!("ssh-ed25519 {}", &ed25519_data[..8]),
+        ] {
+            assert!(PublicKey::parse(line).is_err(), "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn test_login_fingerprint_from_ssh_auth_info() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let auth_info = dir.path().join("auth_info");
+        std::fs::write(&auth_info, format!("publickey {}\n", ED25519)).unwrap();
+        let auth_info = auth_info.to_string_lossy().to_string();
+
+        let fingerprint = login_fingerprint(|name| (name == SSH_USER_AUTH_ENV).then(|| auth_info.clone())).unwrap();
+        assert_eq!(fingerprint, "SHA256:soHN6GQezxkPNU1pZMr1rwv0scJxIpDy4a2LfsAe8bQ");
+
+        assert!(matches!(login_fingerprint(|_| None), Err(AppError::Unauthorized(_))));
+
+        std::fs::write(dir.path().join("password_only"), "password\n").unwrap();
+        let password_only = dir.path().join("password_only").to_string_lossy().to_string();
+        assert!(login_fingerprint(|name| (name == SSH_USER_AUTH_ENV).then(|| password_only.clone())).is_err());
+    }
+
+    #[test]
+    fn test_login_fingerprint_ignores_fingerprint_from_environment() {
+        // A fingerprint in the environment could be set by the client, so only sshd's auth info counts
+        let env = |name: &str| {
+            (name == "TENNIS_KEY_FINGERPRINT").then(|| "SHA256:soHN6GQezxkPNU1pZMr1rwv0scJxIpDy4a2LfsAe8bQ".to_string())
+        };
+        assert!(matches!(login_fingerprint(env), Err(AppError::Unauthorized(_))));
+
+        let empty = |name: &str| (name == SSH_USER_AUTH_ENV).then(String::new);
+        assert!(matches!(login_fingerprint(empty), Err(AppError::Unauthorized(_))));
+    }
+}
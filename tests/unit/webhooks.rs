@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use tui_coach::webhooks::{backoff, signature};
+    use tui_coach::webhooks::client::Endpoint;
+
+    #[test]
+    fn test_parse_endpoint() {
+        let endpoint = Endpoint::parse("http://club.example:8081/hooks/tennis?source=tui").unwrap();
+        assert_eq!(endpoint.host, "club.example");
+        assert_eq!(endpoint.port, 8081);
+        assert_eq!(endpoint.path, "/hooks/tennis?source=tui");
+
+        let endpoint = Endpoint::parse("http://localhost").unwrap();
+        assert_eq!((endpoint.port, endpoint.path.as_str()), (80, "/"));
+
+        assert!(Endpoint::parse("https://club.example/").is_err());
+        assert!(Endpoint::parse("http://:80/").is_err());
+        assert!(Endpoint::parse("http://club.example:web/").is_err());
+    }
+
+    #[test]
+    fn test_parse_refuses_header_injection() {
+        for url in [
+            "http://club.example/x\r\nX-Evil: 1",
+            "http://club.example\r\nX-Evil: 1/",
+            "http://club.example/a b",
+            "http://club.example/\tx",
+            "http://club.example/\u{7f}",
+            "http://club.example/caf\u{e9}",
+        ] {
+            assert!(Endpoint::parse(url).is_err(), "{:?}", url);
+        }
+    }
+
+    #[test]
+    fn test_parse_ipv6_endpoint() {
+        let endpoint = Endpoint::parse("http://[::1]:8081/hooks").unwrap();
+        assert_eq!((endpoint.host.as_str(), endpoint.port, endpoint.path.as_str()), ("::1", 8081, "/hooks"));
+        assert_eq!(endpoint.authority(), "[::1]:8081");
+
+        let endpoint = Endpoint::parse("http://[fe80::1]").unwrap();
+        assert_eq!((endpoint.host.as_str(), endpoint.port), ("fe80::1", 80));
+        assert_eq!(Endpoint::parse("http://club.example/").unwrap().authority(), "club.example:80");
+
+        assert!(Endpoint::parse("http://::1:8081/").is_err());
+        assert!(Endpoint::parse("http://[::1:8081/").is_err());
+        assert!(Endpoint::parse("http://[::1]8081/").is_err());
+        assert!(Endpoint::parse("http://[]:80/").is_err());
+    }
+
+    #[test]
+    fn test_signature_is_hmac_sha256_of_the_body() {
+        // RFC 4231, test case 2
+        assert_eq!(
+            signature("Jefe", "what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        // Keys longer than a block are hashed first
+        assert_eq!(
+            signature(&"k".repeat(100), r#"{"event":"session.created"}"#),
+            "sha256=07fc7b499eaceee41f672a0101971714661a7179bd3e01f9e5f523ce9c2775f6"
+        );
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_an_hour() {
+        assert_eq!(backoff(1), Duration::seconds(30));
+        assert_eq!(backoff(2), Duration::seconds(60));
+        assert_eq!(backoff(5), Duration::seconds(480));
+        assert_eq!(backoff(10), Duration::hours(1));
+        assert_eq!(backoff(100), Duration::hours(1));
+    }
+}